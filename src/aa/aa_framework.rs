@@ -459,6 +459,73 @@ where
     pub fn grounded_extension(&self) -> Vec<&Argument<T>> {
         utils::grounded_extension(self)
     }
+
+    /// Computes the strongly connected components of the AF.
+    ///
+    /// The components are given in a topological order wrt. the attack relation:
+    /// the arguments of a component may only be attacked by arguments of the same component or of the previous ones.
+    /// They are computed in time linear in the size of the framework.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{ArgumentSet, AAFramework};
+    /// let arg_labels = vec!["a", "b", "c", "d"];
+    /// let args = ArgumentSet::new_with_labels(&arg_labels);
+    /// let mut af = AAFramework::new_with_argument_set(args);
+    /// af.new_attack(&"c", &"d").unwrap();
+    /// af.new_attack(&"a", &"b").unwrap();
+    /// af.new_attack(&"b", &"a").unwrap();
+    /// af.new_attack(&"b", &"c").unwrap();
+    /// let sccs = af.strongly_connected_components()
+    ///     .iter()
+    ///     .map(|scc| {
+    ///         let mut labels = scc.iter().map(|a| *a.label()).collect::<Vec<&str>>();
+    ///         labels.sort_unstable();
+    ///         labels
+    ///     })
+    ///     .collect::<Vec<Vec<&str>>>();
+    /// assert_eq!(vec![vec!["a", "b"], vec!["c"], vec!["d"]], sccs)
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&Argument<T>>> {
+        utils::strongly_connected_components(self)
+    }
+
+    /// Computes the reduct of the AF wrt. a set of arguments.
+    ///
+    /// The reduct is the AF obtained by removing the arguments of the set and the arguments they attack.
+    /// The arguments of the reduct keep their labels, but their identifiers may differ from the ones of this AF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{ArgumentSet, AAFramework};
+    /// let arg_labels = vec!["a", "b", "c", "d"];
+    /// let args = ArgumentSet::new_with_labels(&arg_labels);
+    /// let mut af = AAFramework::new_with_argument_set(args);
+    /// af.new_attack(&"a", &"b").unwrap();
+    /// af.new_attack(&"b", &"c").unwrap();
+    /// af.new_attack(&"c", &"d").unwrap();
+    /// let a = af.argument_set().get_argument(&"a").unwrap();
+    /// let reduct = af.reduct(&[a]);
+    /// assert_eq!(2, reduct.n_arguments());
+    /// assert_eq!(1, reduct.n_attacks());
+    /// assert!(reduct.argument_set().get_argument(&"b").is_err());
+    /// ```
+    pub fn reduct(&self, args: &[&Argument<T>]) -> AAFramework<T> {
+        let mut removed = vec![false; 1 + self.max_argument_id().unwrap_or_default()];
+        args.iter().for_each(|a| {
+            removed[a.id()] = true;
+            self.iter_attacks_from(a)
+                .for_each(|att| removed[att.attacked().id()] = true);
+        });
+        let remaining = self
+            .argument_set()
+            .iter()
+            .filter(|a| !removed[a.id()])
+            .collect::<Vec<&Argument<T>>>();
+        utils::restricted_framework(self, &remaining)
+    }
 }
 
 #[cfg(test)]
@@ -610,4 +677,30 @@ mod tests {
                 .count()
         )
     }
+
+    #[test]
+    fn test_reduct_after_arg_removal() {
+        let arg_labels = vec!["a", "b", "c", "d", "e"];
+        let args = ArgumentSet::new_with_labels(&arg_labels);
+        let mut af = AAFramework::new_with_argument_set(args);
+        af.new_attack(&"a", &"b").unwrap();
+        af.new_attack(&"b", &"c").unwrap();
+        af.new_attack(&"c", &"d").unwrap();
+        af.new_attack(&"d", &"c").unwrap();
+        af.new_attack(&"e", &"e").unwrap();
+        af.remove_argument(&"a").unwrap();
+        let c = af.argument_set().get_argument(&"c").unwrap();
+        let reduct = af.reduct(&[c]);
+        let mut labels = reduct
+            .argument_set()
+            .iter()
+            .map(|a| *a.label())
+            .collect::<Vec<&str>>();
+        labels.sort_unstable();
+        assert_eq!(vec!["b", "e"], labels);
+        assert_eq!(1, reduct.n_attacks());
+        assert!(reduct
+            .iter_attacks()
+            .all(|att| *att.attacker().label() == "e" && *att.attacked().label() == "e"));
+    }
 }
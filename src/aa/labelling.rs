@@ -0,0 +1,202 @@
+use super::{AAFramework, Argument};
+use crate::utils::LabelType;
+
+/// The value given to an argument by a [Labelling].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabellingValue {
+    /// The argument is accepted.
+    In,
+    /// The argument is rejected, since it is attacked by an accepted argument.
+    Out,
+    /// The argument is neither accepted nor rejected.
+    Undec,
+}
+
+/// A three-valued labelling of the arguments of an AF, as defined by Caminada.
+///
+/// Each argument is labelled `IN`, `OUT` or `UNDEC` (see [LabellingValue]).
+/// Labellings are built from extensions: the arguments of the extension are `IN`,
+/// the arguments attacked by the extension are `OUT`, and the other ones are `UNDEC`.
+/// Contrary to extensions, labellings distinguish rejected arguments from undecided ones.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet, Labelling, LabellingValue};
+/// let arg_labels = vec!["a", "b", "c", "d"];
+/// let args = ArgumentSet::new_with_labels(&arg_labels);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"c", &"d").unwrap();
+/// af.new_attack(&"d", &"c").unwrap();
+/// let labelling = Labelling::new_from_extension(&af, &af.grounded_extension());
+/// let arg = |l| af.argument_set().get_argument(l).unwrap();
+/// assert_eq!(LabellingValue::In, labelling.value_of(arg(&"a")));
+/// assert_eq!(LabellingValue::Out, labelling.value_of(arg(&"b")));
+/// assert_eq!(LabellingValue::Undec, labelling.value_of(arg(&"c")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Labelling<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    values: Vec<LabellingValue>,
+}
+
+impl<'a, T> Labelling<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds the labelling associated with an extension.
+    ///
+    /// The arguments of the extension are labelled `IN`, the ones attacked by the extension are labelled `OUT`,
+    /// and the remaining ones are labelled `UNDEC`.
+    ///
+    /// # Panics
+    ///
+    /// The arguments of the extension must belong to the AF.
+    /// If it is not the case, this function may panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework, Argument, Labelling};
+    /// # use crustabri::utils::LabelType;
+    /// fn count_undecided<T: LabelType>(af: &AAFramework<T>, extension: &[&Argument<T>]) -> usize {
+    ///     Labelling::new_from_extension(af, extension).undec_arguments().len()
+    /// }
+    /// # count_undecided(&AAFramework::<usize>::default(), &[]);
+    /// ```
+    pub fn new_from_extension(af: &'a AAFramework<T>, extension: &[&Argument<T>]) -> Self {
+        let mut values =
+            vec![LabellingValue::Undec; af.max_argument_id().map(|id| id + 1).unwrap_or_default()];
+        extension.iter().for_each(|arg| {
+            af.iter_attacks_from(arg)
+                .for_each(|att| values[att.attacked().id()] = LabellingValue::Out)
+        });
+        extension
+            .iter()
+            .for_each(|arg| values[arg.id()] = LabellingValue::In);
+        Self { af, values }
+    }
+
+    /// Returns the value given to an argument.
+    ///
+    /// # Panics
+    ///
+    /// The argument must belong to the AF.
+    /// If it is not the case, this function may panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{Argument, Labelling, LabellingValue};
+    /// # use crustabri::utils::LabelType;
+    /// fn is_undecided<T: LabelType>(labelling: &Labelling<T>, arg: &Argument<T>) -> bool {
+    ///     labelling.value_of(arg) == LabellingValue::Undec
+    /// }
+    /// ```
+    pub fn value_of(&self, arg: &Argument<T>) -> LabellingValue {
+        self.values[arg.id()]
+    }
+
+    /// Iterates over the arguments of the AF and their values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::Labelling;
+    /// # use crustabri::utils::LabelType;
+    /// fn print_labelling<T: LabelType>(labelling: &Labelling<T>) {
+    ///     labelling
+    ///         .iter()
+    ///         .for_each(|(arg, value)| println!("{}: {:?}", arg, value));
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Argument<T>, LabellingValue)> + '_ {
+        self.af
+            .argument_set()
+            .iter()
+            .map(|arg| (arg, self.values[arg.id()]))
+    }
+
+    /// Returns the arguments labelled `IN`, i.e. the extension associated with this labelling.
+    pub fn in_arguments(&self) -> Vec<&'a Argument<T>> {
+        self.arguments_with_value(LabellingValue::In)
+    }
+
+    /// Returns the arguments labelled `OUT`.
+    pub fn out_arguments(&self) -> Vec<&'a Argument<T>> {
+        self.arguments_with_value(LabellingValue::Out)
+    }
+
+    /// Returns the arguments labelled `UNDEC`.
+    pub fn undec_arguments(&self) -> Vec<&'a Argument<T>> {
+        self.arguments_with_value(LabellingValue::Undec)
+    }
+
+    fn arguments_with_value(&self, value: LabellingValue) -> Vec<&'a Argument<T>> {
+        self.iter()
+            .filter_map(|(arg, v)| if v == value { Some(arg) } else { None })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn labels<'a>(args: &[&'a Argument<String>]) -> Vec<&'a str> {
+        args.iter().map(|a| a.label().as_str()).collect()
+    }
+
+    #[test]
+    fn test_labelling_from_extension() {
+        let instance = r#"
+            arg(a0).
+            arg(a1).
+            arg(a2).
+            arg(a3).
+            arg(a4).
+            att(a0,a1).
+            att(a1,a2).
+            att(a3,a4).
+            att(a4,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let labelling = Labelling::new_from_extension(&af, &af.grounded_extension());
+        assert_eq!(vec!["a0", "a2"], labels(&labelling.in_arguments()));
+        assert_eq!(vec!["a1"], labels(&labelling.out_arguments()));
+        assert_eq!(vec!["a3", "a4"], labels(&labelling.undec_arguments()));
+        let a3 = af.argument_set().get_argument(&"a3".to_string()).unwrap();
+        let labelling =
+            Labelling::new_from_extension(&af, &[labelling.in_arguments(), vec![a3]].concat());
+        assert_eq!(vec!["a0", "a2", "a3"], labels(&labelling.in_arguments()));
+        assert_eq!(vec!["a1", "a4"], labels(&labelling.out_arguments()));
+        assert!(labelling.undec_arguments().is_empty());
+    }
+
+    #[test]
+    fn test_labelling_after_removal() {
+        let instance = r#"
+            arg(a0).
+            arg(a1).
+            arg(a2).
+            att(a0,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let mut af = reader.read(&mut instance.as_bytes()).unwrap();
+        af.remove_argument(&"a1".to_string()).unwrap();
+        let labelling = Labelling::new_from_extension(&af, &af.grounded_extension());
+        assert_eq!(
+            vec![("a0", LabellingValue::In), ("a2", LabellingValue::Out)],
+            labelling
+                .iter()
+                .map(|(a, v)| (a.label().as_str(), v))
+                .collect::<Vec<(&str, LabellingValue)>>()
+        );
+    }
+}
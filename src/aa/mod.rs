@@ -8,6 +8,10 @@ mod arguments;
 pub use arguments::Argument;
 pub use arguments::ArgumentSet;
 
+mod labelling;
+pub use labelling::Labelling;
+pub use labelling::LabellingValue;
+
 mod problem;
 pub use problem::Query;
 pub use problem::Semantics;
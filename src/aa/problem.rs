@@ -19,6 +19,30 @@ pub enum Semantics {
     STG,
     /// The ideal semantics
     ID,
+    /// The eager semantics
+    EG,
+    /// The admissible semantics
+    AD,
+    /// The conflict-free semantics
+    CF,
+    /// The naive semantics
+    NA,
+    /// The strongly admissible semantics
+    SA,
+    /// The CF2 semantics
+    CF2,
+    /// The stage2 semantics
+    STG2,
+    /// The weakly admissible semantics
+    WAD,
+    /// The weakly preferred semantics
+    WPR,
+    /// The weakly complete semantics
+    WCO,
+    /// The weakly grounded semantics
+    WGR,
+    /// The resolution-based grounded semantics (GR*)
+    RGR,
 }
 
 impl TryFrom<&str> for Semantics {
@@ -33,6 +57,18 @@ impl TryFrom<&str> for Semantics {
             "sst" => Ok(Semantics::SST),
             "stg" => Ok(Semantics::STG),
             "id" => Ok(Semantics::ID),
+            "eg" => Ok(Semantics::EG),
+            "ad" => Ok(Semantics::AD),
+            "cf" => Ok(Semantics::CF),
+            "na" => Ok(Semantics::NA),
+            "sa" => Ok(Semantics::SA),
+            "cf2" => Ok(Semantics::CF2),
+            "stg2" => Ok(Semantics::STG2),
+            "wad" => Ok(Semantics::WAD),
+            "wpr" => Ok(Semantics::WPR),
+            "wco" => Ok(Semantics::WCO),
+            "wgr" => Ok(Semantics::WGR),
+            "rgr" => Ok(Semantics::RGR),
             _ => Err(anyhow!(r#"undefined semantics "{}""#, value)),
         }
     }
@@ -47,6 +83,16 @@ pub enum Query {
     DC,
     /// Check skeptical acceptance
     DS,
+    /// Enumerate the extensions
+    EE,
+    /// Count the extensions
+    CE,
+    /// Verify an extension
+    VE,
+    /// Check the existence of a non-empty extension
+    NE,
+    /// Check the uniqueness of the extension
+    UN,
 }
 
 impl Query {
@@ -89,6 +135,11 @@ impl TryFrom<&str> for Query {
             "se" => Ok(Query::SE),
             "dc" => Ok(Query::DC),
             "ds" => Ok(Query::DS),
+            "ee" => Ok(Query::EE),
+            "ce" => Ok(Query::CE),
+            "ve" => Ok(Query::VE),
+            "ne" => Ok(Query::NE),
+            "un" => Ok(Query::UN),
             _ => Err(anyhow!(r#"undefined query "{}""#, value)),
         }
     }
@@ -128,9 +179,24 @@ mod tests {
     #[test]
     fn test_iter_as_strings() {
         let mut expected = [
-            "DC-CO", "DC-GR", "DC-ID", "DC-PR", "DC-SST", "DC-ST", "DC-STG", "DS-CO", "DS-GR",
-            "DS-ID", "DS-PR", "DS-SST", "DS-ST", "DS-STG", "SE-CO", "SE-GR", "SE-ID", "SE-PR",
-            "SE-SST", "SE-ST", "SE-STG",
+            "DC-AD", "DC-CF", "DC-CO", "DC-GR", "DC-ID", "DC-EG", "DC-NA", "DC-PR", "DC-CF2",
+            "DC-STG2", "DC-WAD", "DC-WPR", "DC-WCO", "DC-WGR", "DC-RGR", "DC-SA", "DC-SST",
+            "DC-ST", "DC-STG", "DS-AD", "DS-CF", "DS-CO", "DS-GR", "DS-ID", "DS-EG", "DS-NA",
+            "DS-PR", "DS-CF2", "DS-STG2", "DS-WAD", "DS-WPR", "DS-WCO", "DS-WGR", "DS-RGR",
+            "DS-SA", "DS-SST", "DS-ST", "DS-STG", "SE-AD", "SE-CF", "SE-CO", "SE-GR", "SE-ID",
+            "SE-EG", "SE-NA", "SE-PR", "SE-CF2", "SE-STG2", "SE-WAD", "SE-WPR", "SE-WCO", "SE-WGR",
+            "SE-RGR", "SE-SA", "SE-SST", "SE-ST", "SE-STG", "EE-AD", "EE-CF", "EE-CO", "EE-GR",
+            "EE-ID", "EE-EG", "EE-NA", "EE-PR", "EE-CF2", "EE-STG2", "EE-WAD", "EE-WPR", "EE-WCO",
+            "EE-WGR", "EE-RGR", "EE-SA", "EE-SST", "EE-ST", "EE-STG", "CE-AD", "CE-CF", "CE-CO",
+            "CE-GR", "CE-ID", "CE-EG", "CE-NA", "CE-PR", "CE-CF2", "CE-STG2", "CE-WAD", "CE-WPR",
+            "CE-WCO", "CE-WGR", "CE-RGR", "CE-SA", "CE-SST", "CE-ST", "CE-STG", "VE-AD", "VE-CF",
+            "VE-CO", "VE-GR", "VE-ID", "VE-EG", "VE-NA", "VE-PR", "VE-CF2", "VE-STG2", "VE-WAD",
+            "VE-WPR", "VE-WCO", "VE-WGR", "VE-RGR", "VE-SA", "VE-SST", "VE-ST", "VE-STG", "NE-AD",
+            "NE-CF", "NE-CO", "NE-GR", "NE-ID", "NE-EG", "NE-NA", "NE-PR", "NE-CF2", "NE-STG2",
+            "NE-WAD", "NE-WPR", "NE-WCO", "NE-WGR", "NE-RGR", "NE-SA", "NE-SST", "NE-ST", "NE-STG",
+            "UN-AD", "UN-CF", "UN-CO", "UN-GR", "UN-ID", "UN-EG", "UN-NA", "UN-PR", "UN-CF2",
+            "UN-STG2", "UN-WAD", "UN-WPR", "UN-WCO", "UN-WGR", "UN-RGR", "UN-SA", "UN-SST",
+            "UN-ST", "UN-STG",
         ]
         .iter()
         .map(|s| s.to_string())
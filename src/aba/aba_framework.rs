@@ -0,0 +1,260 @@
+use crate::utils::{Label, LabelSet, LabelType};
+use anyhow::{anyhow, Result};
+
+/// Handles a single atom of an ABA framework.
+///
+/// Atoms are built the same way as [`Argument`](crate::aa::Argument) objects:
+/// each atom has a label and an identifier which are unique in the atom set of its framework.
+pub type Atom<T> = Label<T>;
+
+#[derive(Debug)]
+pub(crate) struct Rule {
+    pub(crate) head: usize,
+    pub(crate) body: Vec<usize>,
+}
+
+/// A flat Assumption-based Argumentation framework.
+///
+/// An ABA framework is made of a set of atoms, a subset of which are assumptions, a contrary atom for each assumption,
+/// and a set of rules deriving an atom (the head of the rule) from a set of atoms (its body).
+/// The frameworks handled here are flat: assumptions cannot be the head of a rule.
+///
+/// Assumptions with no contrary are allowed; such assumptions cannot be attacked.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aba::AbaFramework;
+/// let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p", "q"]);
+/// aba.new_assumption(&"a").unwrap();
+/// aba.new_assumption(&"b").unwrap();
+/// aba.set_contrary(&"a", &"p").unwrap();
+/// aba.set_contrary(&"b", &"q").unwrap();
+/// aba.new_rule(&"p", &[&"b"]).unwrap();
+/// aba.new_rule(&"q", &[]).unwrap();
+/// assert_eq!(2, aba.n_assumptions());
+/// assert_eq!(2, aba.n_rules());
+/// assert!(aba.new_rule(&"a", &[]).is_err()); // the framework would not be flat
+/// ```
+#[derive(Debug)]
+pub struct AbaFramework<T>
+where
+    T: LabelType,
+{
+    atoms: LabelSet<T>,
+    assumptions: Vec<bool>,
+    contraries: Vec<Option<usize>>,
+    rules: Vec<Rule>,
+    heads: Vec<bool>,
+}
+
+impl<T> AbaFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds a new ABA framework given the labels of its atoms.
+    ///
+    /// The framework has initially no assumptions and no rules.
+    pub fn new_with_atoms(labels: &[T]) -> Self {
+        let atoms = LabelSet::new_with_labels(labels);
+        let n = atoms.max_id().map(|id| id + 1).unwrap_or_default();
+        Self {
+            atoms,
+            assumptions: vec![false; n],
+            contraries: vec![None; n],
+            rules: vec![],
+            heads: vec![false; n],
+        }
+    }
+
+    /// Returns the set of atoms of this framework.
+    pub fn atom_set(&self) -> &LabelSet<T> {
+        &self.atoms
+    }
+
+    /// Returns the number of atoms of this framework.
+    pub fn n_atoms(&self) -> usize {
+        self.atoms.len()
+    }
+
+    /// Returns the number of assumptions of this framework.
+    pub fn n_assumptions(&self) -> usize {
+        self.assumptions.iter().filter(|a| **a).count()
+    }
+
+    /// Returns the number of rules of this framework.
+    pub fn n_rules(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Declares an atom as an assumption.
+    ///
+    /// An error is returned if the atom does not exist, or if it is the head of a rule.
+    /// Declaring an assumption twice has no effect.
+    pub fn new_assumption(&mut self, atom: &T) -> Result<()> {
+        let id = self.atoms.get_label(atom)?.id();
+        if self.heads[id] {
+            return Err(anyhow!(
+                "cannot declare {} as an assumption since it is the head of a rule",
+                atom
+            ));
+        }
+        self.assumptions[id] = true;
+        Ok(())
+    }
+
+    /// Sets the contrary of an assumption.
+    ///
+    /// An error is returned if one of the atoms does not exist, if the first one is not an assumption, or if its contrary is already set to another atom.
+    pub fn set_contrary(&mut self, assumption: &T, contrary: &T) -> Result<()> {
+        let assumption_id = self.atoms.get_label(assumption)?.id();
+        let contrary_id = self.atoms.get_label(contrary)?.id();
+        if !self.assumptions[assumption_id] {
+            return Err(anyhow!("{} is not an assumption", assumption));
+        }
+        match self.contraries[assumption_id] {
+            Some(c) if c != contrary_id => Err(anyhow!(
+                "the contrary of {} is already set to {}",
+                assumption,
+                self.atoms.get_label_by_id(c)
+            )),
+            _ => {
+                self.contraries[assumption_id] = Some(contrary_id);
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds a rule given its head and its body.
+    ///
+    /// An error is returned if one of the atoms does not exist, or if the head is an assumption.
+    pub fn new_rule(&mut self, head: &T, body: &[&T]) -> Result<()> {
+        let head_id = self.atoms.get_label(head)?.id();
+        if self.assumptions[head_id] {
+            return Err(anyhow!(
+                "the assumption {} cannot be the head of a rule (the framework would not be flat)",
+                head
+            ));
+        }
+        let body_ids = body
+            .iter()
+            .map(|b| self.atoms.get_label(b).map(|a| a.id()))
+            .collect::<Result<Vec<usize>>>()?;
+        self.heads[head_id] = true;
+        self.rules.push(Rule {
+            head: head_id,
+            body: body_ids,
+        });
+        Ok(())
+    }
+
+    /// Returns `true` iff the atom is an assumption.
+    ///
+    /// # Panics
+    ///
+    /// The atom must belong to the framework.
+    /// If it is not the case, this function may panic.
+    pub fn is_assumption(&self, atom: &Atom<T>) -> bool {
+        self.assumptions[atom.id()]
+    }
+
+    /// Iterates over the assumptions of this framework.
+    pub fn iter_assumptions(&self) -> impl Iterator<Item = &Atom<T>> + '_ {
+        self.atoms.iter().filter(|a| self.assumptions[a.id()])
+    }
+
+    /// Returns the contrary of an assumption, if it is defined.
+    ///
+    /// # Panics
+    ///
+    /// The atom must belong to the framework.
+    /// If it is not the case, this function may panic.
+    pub fn contrary_of(&self, assumption: &Atom<T>) -> Option<&Atom<T>> {
+        self.contraries[assumption.id()].map(|c| self.atoms.get_label_by_id(c))
+    }
+
+    /// Iterates over the rules of this framework, given as couples composed of the head and the body of the rule.
+    pub fn iter_rules(&self) -> impl Iterator<Item = (&Atom<T>, Vec<&Atom<T>>)> + '_ {
+        self.rules.iter().map(|r| {
+            (
+                self.atoms.get_label_by_id(r.head),
+                r.body
+                    .iter()
+                    .map(|b| self.atoms.get_label_by_id(*b))
+                    .collect(),
+            )
+        })
+    }
+
+    pub(crate) fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub(crate) fn contrary_id(&self, assumption_id: usize) -> Option<usize> {
+        self.contraries[assumption_id]
+    }
+
+    pub(crate) fn is_assumption_id(&self, atom_id: usize) -> bool {
+        self.assumptions[atom_id]
+    }
+
+    pub(crate) fn max_atom_id(&self) -> Option<usize> {
+        self.atoms.max_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_framework() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p"]);
+        aba.new_assumption(&"a").unwrap();
+        aba.new_assumption(&"b").unwrap();
+        aba.set_contrary(&"a", &"p").unwrap();
+        aba.new_rule(&"p", &[&"b"]).unwrap();
+        let atom = |l| aba.atom_set().get_label(l).unwrap();
+        assert_eq!(3, aba.n_atoms());
+        assert!(aba.is_assumption(atom(&"a")));
+        assert!(!aba.is_assumption(atom(&"p")));
+        assert_eq!(
+            vec!["a", "b"],
+            aba.iter_assumptions()
+                .map(|a| *a.label())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(Some(atom(&"p")), aba.contrary_of(atom(&"a")));
+        assert_eq!(None, aba.contrary_of(atom(&"b")));
+        assert_eq!(
+            vec![(atom(&"p"), vec![atom(&"b")])],
+            aba.iter_rules().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_not_flat() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "p"]);
+        aba.new_rule(&"a", &[&"p"]).unwrap();
+        assert!(aba.new_assumption(&"a").is_err());
+    }
+
+    #[test]
+    fn test_contrary_errors() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "p", "q"]);
+        assert!(aba.set_contrary(&"a", &"p").is_err());
+        aba.new_assumption(&"a").unwrap();
+        aba.set_contrary(&"a", &"p").unwrap();
+        aba.set_contrary(&"a", &"p").unwrap();
+        assert!(aba.set_contrary(&"a", &"q").is_err());
+        assert!(aba.set_contrary(&"a", &"r").is_err());
+    }
+
+    #[test]
+    fn test_unknown_atom_in_rule() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "p"]);
+        assert!(aba.new_rule(&"p", &[&"r"]).is_err());
+        assert!(aba.new_rule(&"r", &[&"p"]).is_err());
+        assert_eq!(0, aba.n_rules());
+    }
+}
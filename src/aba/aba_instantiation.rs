@@ -0,0 +1,331 @@
+use super::{AbaFramework, Atom};
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet},
+    utils::LabelType,
+};
+
+/// The instantiation of a flat ABA framework into an [`AAFramework`].
+///
+/// Each argument of the instantiated AF is a deduction of an atom (its conclusion) from a minimal set of assumptions (its support).
+/// An argument attacks another one if its conclusion is the contrary of an assumption in the support of the latter.
+/// The arguments are labelled by their index, starting from `0`.
+///
+/// For the complete, grounded, stable and preferred semantics, the assumption sets that are extensions of the ABA framework
+/// are exactly the sets of assumptions whose singleton deductions belong to an extension of the instantiated AF.
+/// Since the number of minimal supports may be exponential in the size of the ABA framework, so may be the size of the instantiated AF.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aba::{AbaFramework, AbaInstantiation};
+/// let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p"]);
+/// aba.new_assumption(&"a").unwrap();
+/// aba.new_assumption(&"b").unwrap();
+/// aba.set_contrary(&"a", &"p").unwrap();
+/// aba.new_rule(&"p", &[&"b"]).unwrap();
+/// let instantiation = AbaInstantiation::new(&aba);
+/// let af = instantiation.framework();
+/// assert_eq!(3, af.n_arguments()); // {a} ⊢ a, {b} ⊢ b and {b} ⊢ p
+/// assert_eq!(1, af.n_attacks()); // {b} ⊢ p attacks {a} ⊢ a
+/// ```
+pub struct AbaInstantiation<'a, T>
+where
+    T: LabelType,
+{
+    aba: &'a AbaFramework<T>,
+    conclusions: Vec<usize>,
+    supports: Vec<Vec<usize>>,
+    arguments_by_conclusion: Vec<Vec<usize>>,
+    atom_supports: Vec<Vec<Vec<usize>>>,
+    af: AAFramework<usize>,
+}
+
+impl<'a, T> AbaInstantiation<'a, T>
+where
+    T: LabelType,
+{
+    /// Instantiates an ABA framework.
+    pub fn new(aba: &'a AbaFramework<T>) -> Self {
+        let atom_supports = compute_minimal_supports(aba);
+        let mut conclusions = vec![];
+        let mut supports = vec![];
+        let mut arguments_by_conclusion = vec![vec![]; atom_supports.len()];
+        for (atom, atom_supports) in atom_supports.iter().enumerate() {
+            for support in atom_supports {
+                arguments_by_conclusion[atom].push(conclusions.len());
+                conclusions.push(atom);
+                supports.push(support.clone());
+            }
+        }
+        let mut instantiation = Self {
+            aba,
+            conclusions,
+            supports,
+            arguments_by_conclusion,
+            atom_supports,
+            af: AAFramework::default(),
+        };
+        instantiation.af = instantiation.build_framework(&[]);
+        instantiation
+    }
+
+    /// Returns the instantiated AF.
+    pub fn framework(&self) -> &AAFramework<usize> {
+        &self.af
+    }
+
+    /// Returns the conclusion of an argument of the instantiated AF.
+    ///
+    /// # Panics
+    ///
+    /// The argument must belong to the instantiated AF.
+    /// If it is not the case, this function may panic.
+    pub fn conclusion_of(&self, arg: &Argument<usize>) -> &'a Atom<T> {
+        self.aba
+            .atom_set()
+            .get_label_by_id(self.conclusions[*arg.label()])
+    }
+
+    /// Returns the support of an argument of the instantiated AF, i.e. the assumptions it is deduced from.
+    ///
+    /// # Panics
+    ///
+    /// The argument must belong to the instantiated AF.
+    /// If it is not the case, this function may panic.
+    pub fn support_of(&self, arg: &Argument<usize>) -> Vec<&'a Atom<T>> {
+        self.atoms_of(&self.supports[*arg.label()])
+    }
+
+    /// Returns the arguments of the instantiated AF concluding an atom.
+    ///
+    /// # Panics
+    ///
+    /// The atom must belong to the ABA framework.
+    /// If it is not the case, this function may panic.
+    pub fn arguments_concluding(&self, atom: &Atom<T>) -> Vec<&Argument<usize>> {
+        self.arguments_by_conclusion[atom.id()]
+            .iter()
+            .map(|a| self.af.argument_set().get_argument_by_id(*a))
+            .collect()
+    }
+
+    /// Returns the assumption set associated with an extension of the instantiated AF.
+    ///
+    /// This set is made of the assumptions whose singleton deductions belong to the extension.
+    /// Arguments that do not belong to the instantiated AF are ignored.
+    pub fn assumptions_of(&self, extension: &[&Argument<usize>]) -> Vec<&'a Atom<T>> {
+        let mut assumptions = extension
+            .iter()
+            .filter(|arg| *arg.label() < self.conclusions.len())
+            .map(|arg| self.conclusions[*arg.label()])
+            .filter(|atom| self.aba.is_assumption_id(*atom))
+            .collect::<Vec<usize>>();
+        assumptions.sort_unstable();
+        self.atoms_of(&assumptions)
+    }
+
+    /// Returns the minimal assumption sets from which a disjunction of atoms is derived.
+    pub(crate) fn disjunction_supports(&self, atoms: &[&T]) -> Vec<Vec<usize>> {
+        let mut result = vec![];
+        for atom in atoms {
+            for support in self.atom_supports[self.atom_id(atom)].iter() {
+                insert_minimal(&mut result, support.clone());
+            }
+        }
+        result
+    }
+
+    /// Returns the minimal assumption sets from which a conjunction of atoms is derived.
+    pub(crate) fn conjunction_supports(&self, atoms: &[&T]) -> Vec<Vec<usize>> {
+        combine_supports(
+            &self.atom_supports,
+            &atoms
+                .iter()
+                .map(|a| self.atom_id(a))
+                .collect::<Vec<usize>>(),
+        )
+    }
+
+    /// Builds the instantiated AF extended by one argument per provided support, concluding a fresh query atom.
+    ///
+    /// The labels of the new arguments are returned together with the AF.
+    /// Since the new arguments attack no argument, the extensions of the new AF are in one-to-one correspondence with the ones of the instantiated AF.
+    pub(crate) fn framework_with_queries(
+        &self,
+        query_supports: &[Vec<usize>],
+    ) -> (AAFramework<usize>, Vec<usize>) {
+        let af = self.build_framework(query_supports);
+        let query_args = (self.supports.len()..self.supports.len() + query_supports.len())
+            .collect::<Vec<usize>>();
+        (af, query_args)
+    }
+
+    fn build_framework(&self, additional_supports: &[Vec<usize>]) -> AAFramework<usize> {
+        let all_supports = self
+            .supports
+            .iter()
+            .chain(additional_supports.iter())
+            .collect::<Vec<&Vec<usize>>>();
+        let labels = (0..all_supports.len()).collect::<Vec<usize>>();
+        let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        for (attacked, support) in all_supports.iter().enumerate() {
+            for assumption in support.iter() {
+                if let Some(contrary) = self.aba.contrary_id(*assumption) {
+                    for attacker in self.arguments_by_conclusion[contrary].iter() {
+                        af.new_attack_by_ids(*attacker, attacked).unwrap();
+                    }
+                }
+            }
+        }
+        af
+    }
+
+    fn atom_id(&self, atom: &T) -> usize {
+        self.aba.atom_set().get_label(atom).unwrap().id()
+    }
+
+    fn atoms_of(&self, ids: &[usize]) -> Vec<&'a Atom<T>> {
+        ids.iter()
+            .map(|id| self.aba.atom_set().get_label_by_id(*id))
+            .collect()
+    }
+}
+
+// Computes, for each atom, the minimal sets of assumptions it can be derived from.
+fn compute_minimal_supports<T>(aba: &AbaFramework<T>) -> Vec<Vec<Vec<usize>>>
+where
+    T: LabelType,
+{
+    let n_atoms = aba.max_atom_id().map(|id| id + 1).unwrap_or_default();
+    let mut supports = (0..n_atoms)
+        .map(|id| {
+            if aba.is_assumption_id(id) {
+                vec![vec![id]]
+            } else {
+                vec![]
+            }
+        })
+        .collect::<Vec<Vec<Vec<usize>>>>();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for rule in aba.rules() {
+            for support in combine_supports(&supports, &rule.body) {
+                changed |= insert_minimal(&mut supports[rule.head], support);
+            }
+        }
+    }
+    supports
+}
+
+// Computes the minimal unions of one support for each atom.
+fn combine_supports(supports: &[Vec<Vec<usize>>], atoms: &[usize]) -> Vec<Vec<usize>> {
+    let mut combinations = vec![vec![]];
+    for atom in atoms {
+        let mut new_combinations = vec![];
+        for c in combinations.iter() {
+            for s in supports[*atom].iter() {
+                insert_minimal(&mut new_combinations, union(c, s));
+            }
+        }
+        combinations = new_combinations;
+    }
+    combinations
+}
+
+// Inserts a set in an antichain, removing its supersets; returns false if the set has a subset in the antichain.
+fn insert_minimal(antichain: &mut Vec<Vec<usize>>, set: Vec<usize>) -> bool {
+    if antichain.iter().any(|s| is_subset(s, &set)) {
+        return false;
+    }
+    antichain.retain(|s| !is_subset(&set, s));
+    antichain.push(set);
+    true
+}
+
+fn union(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut result = a.iter().chain(b.iter()).copied().collect::<Vec<usize>>();
+    result.sort_unstable();
+    result.dedup();
+    result
+}
+
+fn is_subset(a: &[usize], b: &[usize]) -> bool {
+    let mut b_iter = b.iter();
+    a.iter().all(|x| b_iter.any(|y| y == x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(atoms: Vec<&Atom<&'static str>>) -> Vec<&'static str> {
+        atoms.iter().map(|a| *a.label()).collect()
+    }
+
+    #[test]
+    fn test_minimal_supports() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "b", "c", "p", "q"]);
+        aba.new_assumption(&"a").unwrap();
+        aba.new_assumption(&"b").unwrap();
+        aba.new_assumption(&"c").unwrap();
+        aba.new_rule(&"p", &[&"a", &"b"]).unwrap();
+        aba.new_rule(&"p", &[&"q"]).unwrap();
+        aba.new_rule(&"q", &[&"a"]).unwrap();
+        aba.new_rule(&"q", &[&"p", &"c"]).unwrap();
+        let instantiation = AbaInstantiation::new(&aba);
+        let af = instantiation.framework();
+        assert_eq!(5, af.n_arguments());
+        let p = aba.atom_set().get_label(&"p").unwrap();
+        let args = instantiation.arguments_concluding(p);
+        assert_eq!(1, args.len());
+        assert_eq!(vec!["a"], labels(instantiation.support_of(args[0])));
+        assert_eq!(p, instantiation.conclusion_of(args[0]));
+    }
+
+    #[test]
+    fn test_attacks() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p", "q"]);
+        aba.new_assumption(&"a").unwrap();
+        aba.new_assumption(&"b").unwrap();
+        aba.set_contrary(&"a", &"p").unwrap();
+        aba.set_contrary(&"b", &"q").unwrap();
+        aba.new_rule(&"p", &[&"b"]).unwrap();
+        aba.new_rule(&"q", &[&"a"]).unwrap();
+        let instantiation = AbaInstantiation::new(&aba);
+        let af = instantiation.framework();
+        assert_eq!(4, af.n_arguments());
+        assert_eq!(4, af.n_attacks());
+        let extension = af.argument_set().iter().collect::<Vec<&Argument<usize>>>();
+        assert_eq!(
+            vec!["a", "b"],
+            labels(instantiation.assumptions_of(&extension))
+        );
+    }
+
+    #[test]
+    fn test_query_supports() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p", "q"]);
+        aba.new_assumption(&"a").unwrap();
+        aba.new_assumption(&"b").unwrap();
+        aba.new_rule(&"p", &[&"a"]).unwrap();
+        aba.new_rule(&"p", &[&"b"]).unwrap();
+        let instantiation = AbaInstantiation::new(&aba);
+        assert_eq!(
+            vec![vec![0], vec![1]],
+            instantiation.disjunction_supports(&[&"p", &"a"])
+        );
+        assert_eq!(
+            vec![vec![0]],
+            instantiation.conjunction_supports(&[&"p", &"a"])
+        );
+        assert_eq!(
+            vec![vec![0, 1]],
+            instantiation.conjunction_supports(&[&"a", &"b"])
+        );
+        assert!(instantiation.disjunction_supports(&[&"q"]).is_empty());
+        let (af, query_args) = instantiation.framework_with_queries(&[vec![0, 1]]);
+        assert_eq!(vec![4], query_args);
+        assert_eq!(5, af.n_arguments());
+    }
+}
@@ -0,0 +1,308 @@
+use super::{AbaFramework, AbaInstantiation, Atom};
+use crate::{
+    aa::{AAFramework, Argument, Semantics},
+    sat::{self, SatSolverFactoryFn},
+    solvers::{
+        CompleteSemanticsSolver, CredulousAcceptanceComputer, GroundedSemanticsSolver,
+        PreferredSemanticsSolver, SingleExtensionComputer, SkepticalAcceptanceComputer,
+        StableSemanticsSolver,
+    },
+    utils::LabelType,
+};
+use anyhow::{anyhow, Result};
+use std::rc::Rc;
+
+type AcceptanceResult<'a, T> = (bool, Option<Vec<&'a Atom<T>>>);
+
+/// A SAT-based solver for flat ABA frameworks under the complete, stable and preferred semantics.
+///
+/// The ABA framework is instantiated into an [`AAFramework`] (see [`AbaInstantiation`]),
+/// and the problems are solved by the SAT-based solvers dedicated to the corresponding semantics of abstract argumentation.
+///
+/// The extensions returned by this solver are sets of assumptions.
+/// An atom is accepted by an extension if it is derived from it;
+/// in particular, an assumption is accepted iff it belongs to the extension.
+/// The queries may involve any atom; when checking the acceptance of a conjunction of atoms, the atoms must be derived from a single extension.
+///
+/// When a certificate is needed, an extension is given, as with the AF solvers.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::Semantics;
+/// # use crustabri::aba::{AbaFramework, AbaSolver};
+/// # use crustabri::solvers::CredulousAcceptanceComputer;
+/// let mut aba = AbaFramework::new_with_atoms(&["a", "b", "p", "q"]);
+/// aba.new_assumption(&"a").unwrap();
+/// aba.new_assumption(&"b").unwrap();
+/// aba.set_contrary(&"a", &"p").unwrap();
+/// aba.set_contrary(&"b", &"q").unwrap();
+/// aba.new_rule(&"p", &[&"b"]).unwrap();
+/// aba.new_rule(&"q", &[&"a"]).unwrap();
+/// let mut solver = AbaSolver::new(&aba, Semantics::ST).unwrap();
+/// assert!(solver.is_credulously_accepted(&"p"));
+/// assert!(!solver.are_all_credulously_accepted(&[&"a", &"b"]));
+/// ```
+pub struct AbaSolver<'a, T>
+where
+    T: LabelType,
+{
+    instantiation: AbaInstantiation<'a, T>,
+    semantics: Semantics,
+    solver_factory: Rc<SatSolverFactoryFn>,
+}
+
+impl<'a, T> AbaSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// The semantics must be the complete, the stable or the preferred one.
+    /// If it is not the case, an error is returned.
+    pub fn new(aba: &'a AbaFramework<T>, semantics: Semantics) -> Result<Self> {
+        Self::new_with_sat_solver_factory(aba, semantics, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The SAT solvers are created by the provided factory.
+    ///
+    /// The semantics must be the complete, the stable or the preferred one.
+    /// If it is not the case, an error is returned.
+    pub fn new_with_sat_solver_factory(
+        aba: &'a AbaFramework<T>,
+        semantics: Semantics,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Result<Self> {
+        if !matches!(semantics, Semantics::CO | Semantics::ST | Semantics::PR) {
+            return Err(anyhow!(
+                "unsupported semantics for ABA frameworks: {}",
+                semantics.as_ref()
+            ));
+        }
+        Ok(Self {
+            instantiation: AbaInstantiation::new(aba),
+            semantics,
+            solver_factory: Rc::from(solver_factory),
+        })
+    }
+
+    fn solver_factory(&self) -> Box<SatSolverFactoryFn> {
+        let factory = Rc::clone(&self.solver_factory);
+        Box::new(move || (factory)())
+    }
+
+    fn check_credulous_acceptance(&self, supports: Vec<Vec<usize>>) -> AcceptanceResult<'a, T> {
+        if supports.is_empty() {
+            return (false, None);
+        }
+        let (af, query_args) = self.instantiation.framework_with_queries(&supports);
+        let labels = query_args.iter().collect::<Vec<&usize>>();
+        let mut solver: Box<dyn CredulousAcceptanceComputer<usize>> = match self.semantics {
+            Semantics::CO | Semantics::PR => Box::new(
+                CompleteSemanticsSolver::new_with_sat_solver_factory(&af, self.solver_factory()),
+            ),
+            Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
+                &af,
+                self.solver_factory(),
+            )),
+            _ => unreachable!(),
+        };
+        let (status, certificate) = solver.are_credulously_accepted_with_certificate(&labels);
+        (
+            status,
+            certificate.map(|c| self.instantiation.assumptions_of(&c)),
+        )
+    }
+
+    fn check_skeptical_acceptance(&self, supports: Vec<Vec<usize>>) -> AcceptanceResult<'a, T> {
+        let (af, query_args) = self.instantiation.framework_with_queries(&supports);
+        if query_args.is_empty() {
+            return match self.compute_one_extension_of(&af) {
+                Some(ext) => (false, Some(ext)),
+                None => (true, None),
+            };
+        }
+        let labels = query_args.iter().collect::<Vec<&usize>>();
+        let mut solver: Box<dyn SkepticalAcceptanceComputer<usize>> = match self.semantics {
+            Semantics::CO => Box::new(GroundedSemanticsSolver::new(&af)),
+            Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
+                &af,
+                self.solver_factory(),
+            )),
+            Semantics::PR => Box::new(PreferredSemanticsSolver::new_with_sat_solver_factory(
+                &af,
+                self.solver_factory(),
+            )),
+            _ => unreachable!(),
+        };
+        let (status, certificate) = solver.are_skeptically_accepted_with_certificate(&labels);
+        (
+            status,
+            certificate.map(|c| self.instantiation.assumptions_of(&c)),
+        )
+    }
+
+    fn compute_one_extension_of(&self, af: &AAFramework<usize>) -> Option<Vec<&'a Atom<T>>> {
+        let mut solver: Box<dyn SingleExtensionComputer<usize>> = match self.semantics {
+            Semantics::CO => Box::new(GroundedSemanticsSolver::new(af)),
+            Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
+                af,
+                self.solver_factory(),
+            )),
+            Semantics::PR => Box::new(PreferredSemanticsSolver::new_with_sat_solver_factory(
+                af,
+                self.solver_factory(),
+            )),
+            _ => unreachable!(),
+        };
+        solver
+            .compute_one_extension()
+            .map(|ext| self.instantiation.assumptions_of(&ext))
+    }
+}
+
+impl<T> SingleExtensionComputer<T> for AbaSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        self.compute_one_extension_of(self.instantiation.framework())
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for AbaSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_credulously_accepted_with_certificate(args).0
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(self.instantiation.disjunction_supports(args))
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(self.instantiation.conjunction_supports(args))
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for AbaSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_skeptically_accepted_with_certificate(args).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_skeptical_acceptance(self.instantiation.disjunction_supports(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a and b attack each other, c is attacked by a, d is not attacked, and q is derived from b and d.
+    fn aba() -> AbaFramework<&'static str> {
+        let mut aba =
+            AbaFramework::new_with_atoms(&["a", "b", "c", "d", "na", "nb", "nc", "nd", "q"]);
+        for (assumption, contrary) in [("a", "na"), ("b", "nb"), ("c", "nc"), ("d", "nd")] {
+            aba.new_assumption(&assumption).unwrap();
+            aba.set_contrary(&assumption, &contrary).unwrap();
+        }
+        aba.new_rule(&"na", &[&"b"]).unwrap();
+        aba.new_rule(&"nb", &[&"a"]).unwrap();
+        aba.new_rule(&"nc", &[&"a"]).unwrap();
+        aba.new_rule(&"q", &[&"b", &"d"]).unwrap();
+        aba
+    }
+
+    fn labels(ext: Vec<&Atom<&'static str>>) -> Vec<&'static str> {
+        ext.iter().map(|a| *a.label()).collect()
+    }
+
+    #[test]
+    fn test_compute_one_extension() {
+        let aba = aba();
+        assert_eq!(
+            vec!["d"],
+            labels(
+                AbaSolver::new(&aba, Semantics::CO)
+                    .unwrap()
+                    .compute_one_extension()
+                    .unwrap()
+            )
+        );
+        for semantics in [Semantics::ST, Semantics::PR] {
+            let ext = labels(
+                AbaSolver::new(&aba, semantics)
+                    .unwrap()
+                    .compute_one_extension()
+                    .unwrap(),
+            );
+            assert!(ext == vec!["a", "d"] || ext == vec!["b", "c", "d"]);
+        }
+    }
+
+    #[test]
+    fn test_credulous_acceptance() {
+        let aba = aba();
+        for semantics in [Semantics::CO, Semantics::ST, Semantics::PR] {
+            let mut solver = AbaSolver::new(&aba, semantics).unwrap();
+            assert!(solver.is_credulously_accepted(&"q"));
+            assert!(solver.is_credulously_accepted(&"c"));
+            assert!(!solver.are_all_credulously_accepted(&[&"a", &"c"]));
+            let (status, certificate) =
+                solver.are_all_credulously_accepted_with_certificate(&[&"q", &"c"]);
+            assert!(status);
+            assert_eq!(vec!["b", "c", "d"], labels(certificate.unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_skeptical_acceptance() {
+        let aba = aba();
+        for semantics in [Semantics::CO, Semantics::ST, Semantics::PR] {
+            let mut solver = AbaSolver::new(&aba, semantics).unwrap();
+            assert!(solver.is_skeptically_accepted(&"d"));
+            assert!(!solver.is_skeptically_accepted(&"q"));
+            assert!(solver.are_skeptically_accepted(&[&"a", &"b"]) == (semantics != Semantics::CO));
+        }
+    }
+
+    #[test]
+    fn test_underivable_atom() {
+        let mut aba = AbaFramework::new_with_atoms(&["a", "na", "q"]);
+        aba.new_assumption(&"a").unwrap();
+        aba.set_contrary(&"a", &"na").unwrap();
+        aba.new_rule(&"na", &[&"a"]).unwrap();
+        let mut solver = AbaSolver::new(&aba, Semantics::ST).unwrap();
+        assert!(!solver.is_credulously_accepted(&"q"));
+        assert!(solver.is_skeptically_accepted(&"q"));
+        let mut solver = AbaSolver::new(&aba, Semantics::PR).unwrap();
+        assert!(!solver.is_credulously_accepted(&"q"));
+        let (status, certificate) = solver.is_skeptically_accepted_with_certificate(&"q");
+        assert!(!status);
+        assert!(certificate.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_semantics() {
+        assert!(AbaSolver::new(&aba(), Semantics::GR).is_err());
+    }
+}
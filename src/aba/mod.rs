@@ -0,0 +1,11 @@
+//! Flat Assumption-based Argumentation (ABA) frameworks and the solvers dedicated to them.
+
+mod aba_framework;
+pub use aba_framework::AbaFramework;
+pub use aba_framework::Atom;
+
+mod aba_instantiation;
+pub use aba_instantiation::AbaInstantiation;
+
+mod aba_solver;
+pub use aba_solver::AbaSolver;
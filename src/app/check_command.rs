@@ -1,7 +1,7 @@
 use super::{cli_manager, command::Command, common};
 use anyhow::Result;
 use clap::{App, AppSettings, ArgMatches, SubCommand};
-use crustabri::io::{AspartixReader, Iccma23Reader};
+use crustabri::io::{AspartixReader, Iccma23AbaReader, Iccma23Reader, TgfReader};
 
 const CMD_NAME: &str = "check";
 
@@ -20,7 +20,7 @@ impl<'a> Command<'a> for CheckCommand {
 
     fn clap_subcommand(&self) -> App<'a, 'a> {
         SubCommand::with_name(CMD_NAME)
-            .about("Checks input AF files for errors")
+            .about("Checks input AF or ABA files for errors")
             .setting(AppSettings::DisableVersion)
             .arg(common::input_args())
             .arg(common::reader_arg())
@@ -32,6 +32,10 @@ impl<'a> Command<'a> for CheckCommand {
         match arg_matches.value_of(common::ARG_READER).unwrap() {
             "apx" => common::read_file_path(file, &mut AspartixReader::default()).map(|_| ()),
             "iccma23" => common::read_file_path(file, &mut Iccma23Reader::default()).map(|_| ()),
+            "iccma23_aba" => {
+                common::read_aba_file_path(file, &mut Iccma23AbaReader::default()).map(|_| ())
+            }
+            "tgf" => common::read_file_path(file, &mut TgfReader::default()).map(|_| ()),
             _ => unreachable!(),
         }?;
         Ok(())
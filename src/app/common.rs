@@ -1,14 +1,21 @@
 use super::{
-    app_helper::AppHelper, command::Command, AuthorsCommand, CheckCommand, ProblemsCommand,
-    SolveCommand,
+    app_helper::AppHelper, command::Command, AuthorsCommand, CheckCommand, ConvertCommand,
+    GenerateCommand, ProblemsCommand, SolveCommand, StatsCommand,
 };
 use anyhow::{Context, Result};
 use clap::Arg;
-use crustabri::{aa::AAFramework, io::InstanceReader, utils::LabelType};
+use crustabri::{
+    aa::{AAFramework, Argument, ArgumentSet},
+    aba::AbaFramework,
+    io::{AspartixWriter, DotWriter, Iccma23AbaReader, Iccma23Writer, InstanceReader, TgfWriter},
+    utils::LabelType,
+};
+use lazy_static::lazy_static;
 use log::{info, warn};
+use regex::Regex;
 use std::{
     fs::{self, File},
-    io::{BufReader, Read},
+    io::{BufReader, Read, Write},
     path::PathBuf,
 };
 
@@ -25,8 +32,11 @@ pub(crate) fn create_app_helper() -> AppHelper<'static> {
     let commands: Vec<Box<dyn Command>> = vec![
         Box::new(AuthorsCommand::new(app_name, app_version, authors)),
         Box::new(CheckCommand::new()),
+        Box::new(ConvertCommand::new()),
+        Box::new(GenerateCommand::new()),
         Box::new(ProblemsCommand::new()),
         Box::new(SolveCommand::new()),
+        Box::new(StatsCommand::new()),
     ];
     for c in commands {
         app.add_command(c);
@@ -47,6 +57,7 @@ pub(crate) fn input_args() -> Arg<'static, 'static> {
 
 pub(crate) const ARG_PROBLEM: &str = "PROBLEM";
 pub(crate) const ARG_ARG: &str = "ARG";
+pub(crate) const ARG_EXTENSION: &str = "EXTENSION";
 
 pub(crate) fn problem_args() -> Vec<Arg<'static, 'static>> {
     vec![
@@ -59,8 +70,17 @@ pub(crate) fn problem_args() -> Vec<Arg<'static, 'static>> {
         Arg::with_name(ARG_ARG)
             .short("a")
             .empty_values(false)
-            .multiple(false)
-            .help("the argument (for DC/DS queries)")
+            .multiple(true)
+            .number_of_values(1)
+            .help("the argument (for DC/DS queries); may be repeated to check a set of arguments")
+            .required(false),
+        Arg::with_name(ARG_EXTENSION)
+            .short("e")
+            .long("extension")
+            .empty_values(false)
+            .multiple(true)
+            .min_values(0)
+            .help("the set of arguments to check (for VE queries)")
             .required(false),
     ]
 }
@@ -73,12 +93,115 @@ pub(crate) fn reader_arg() -> Arg<'static, 'static> {
         .long("reader")
         .empty_values(false)
         .multiple(false)
-        .possible_values(&["apx", "iccma23", "iccma23_aba"])
+        .possible_values(&["apx", "iccma23", "iccma23_aba", "tgf"])
         .default_value("iccma23")
         .help("the input file format")
         .required(false)
 }
 
+pub(crate) const ARG_WRITER: &str = "WRITER";
+
+pub(crate) fn writer_arg(default_value: &'static str) -> Arg<'static, 'static> {
+    Arg::with_name(ARG_WRITER)
+        .short("w")
+        .long("writer")
+        .empty_values(false)
+        .multiple(false)
+        .possible_values(&["apx", "iccma23", "tgf"])
+        .default_value(default_value)
+        .help("the output file format")
+        .required(false)
+}
+
+pub(crate) fn write_framework<T>(
+    af: &AAFramework<T>,
+    writer_name: &str,
+    writer: &mut dyn Write,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    match writer_name {
+        "apx" => AspartixWriter.write_framework(af, writer),
+        "iccma23" => Iccma23Writer.write_framework(af, writer),
+        "tgf" => TgfWriter.write_framework(af, writer),
+        _ => unreachable!(),
+    }
+}
+
+lazy_static! {
+    static ref APX_ARG_NAME_PATTERN: Regex = Regex::new(r"^[_[:alpha:]][_[:alpha:]\d]*$").unwrap();
+}
+
+/// Returns a copy of an AF in which the arguments are labelled `a1`, `a2`, ..., following the order of the argument set.
+///
+/// The copy is only built if the APX writer is requested and some labels are not valid APX argument names (e.g. numeric labels);
+/// otherwise, `None` is returned.
+pub(crate) fn relabel_for_writer<T>(
+    af: &AAFramework<T>,
+    writer_name: &str,
+) -> Option<AAFramework<String>>
+where
+    T: LabelType,
+{
+    if writer_name != "apx"
+        || af
+            .argument_set()
+            .iter()
+            .all(|a| APX_ARG_NAME_PATTERN.is_match(&a.label().to_string()))
+    {
+        return None;
+    }
+    let mut labels = vec![String::new(); af.max_argument_id().map(|id| id + 1).unwrap_or(0)];
+    af.argument_set()
+        .iter()
+        .enumerate()
+        .for_each(|(i, a)| labels[a.id()] = format!("a{}", i + 1));
+    let mut relabelled = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(
+        &af.argument_set()
+            .iter()
+            .map(|a| labels[a.id()].clone())
+            .collect::<Vec<String>>(),
+    ));
+    for attack in af.iter_attacks() {
+        relabelled
+            .new_attack(
+                &labels[attack.attacker().id()],
+                &labels[attack.attacked().id()],
+            )
+            .unwrap();
+    }
+    Some(relabelled)
+}
+
+pub(crate) const ARG_DOT: &str = "DOT";
+
+pub(crate) fn dot_arg(help: &'static str) -> Arg<'static, 'static> {
+    Arg::with_name(ARG_DOT)
+        .long("dot")
+        .empty_values(false)
+        .multiple(false)
+        .help(help)
+        .required(false)
+}
+
+pub(crate) fn write_dot_file<T>(
+    af: &AAFramework<T>,
+    extension: Option<&[&Argument<T>]>,
+    file_path: &str,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    info!("writing the DOT graph to {:?}", file_path);
+    let mut out = File::create(file_path)
+        .with_context(|| format!(r#"while creating file "{}""#, file_path))?;
+    match extension {
+        Some(e) => DotWriter.write_framework_with_extension(af, e, &mut out),
+        None => DotWriter.write_framework(af, &mut out),
+    }
+}
+
 pub(crate) fn read_file_path<T>(
     file_path: &str,
     reader: &mut dyn InstanceReader<T>,
@@ -96,6 +219,21 @@ where
     Ok(af)
 }
 
+pub(crate) fn read_aba_file_path(
+    file_path: &str,
+    reader: &mut Iccma23AbaReader,
+) -> Result<AbaFramework<usize>> {
+    reader.add_warning_handler(Box::new(|line, msg| warn!("at line {}: {}", line, msg)));
+    let aba = read_file_path_with(file_path, &|r| reader.read(r))?;
+    info!(
+        "the ABA framework has {} atom(s), {} assumption(s) and {} rule(s)",
+        aba.n_atoms(),
+        aba.n_assumptions(),
+        aba.n_rules(),
+    );
+    Ok(aba)
+}
+
 pub(crate) fn read_file_path_with<F, R>(file_path: &str, reader: &F) -> Result<R>
 where
     F: Fn(&mut dyn Read) -> Result<R>,
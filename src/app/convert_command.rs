@@ -0,0 +1,147 @@
+use super::{cli_manager, command::Command, common};
+use anyhow::{anyhow, Context, Result};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use crustabri::{
+    aa::AAFramework,
+    io::{AspartixReader, Iccma23Reader, Iccma23Writer, InstanceReader, TgfReader},
+    utils::LabelType,
+};
+use log::info;
+use std::{fs::File, io::Write};
+
+const CMD_NAME: &str = "convert";
+
+const ARG_OUTPUT: &str = "OUTPUT";
+const ARG_MAPPING: &str = "MAPPING";
+
+pub(crate) struct ConvertCommand;
+
+impl ConvertCommand {
+    pub(crate) fn new() -> Self {
+        ConvertCommand
+    }
+}
+
+impl<'a> Command<'a> for ConvertCommand {
+    fn name(&self) -> &str {
+        CMD_NAME
+    }
+
+    fn clap_subcommand(&self) -> App<'a, 'a> {
+        SubCommand::with_name(CMD_NAME)
+            .about("Converts an AF file into another format")
+            .setting(AppSettings::DisableVersion)
+            .arg(common::input_args())
+            .arg(common::reader_arg())
+            .arg(common::writer_arg("apx"))
+            .arg(
+                Arg::with_name(ARG_OUTPUT)
+                    .short("o")
+                    .long("output")
+                    .empty_values(false)
+                    .multiple(false)
+                    .help("the output file (the standard output is used if not set)")
+                    .required(false),
+            )
+            .arg(
+                Arg::with_name(ARG_MAPPING)
+                    .long("mapping")
+                    .empty_values(false)
+                    .multiple(false)
+                    .help("the file receiving the correspondence between the written argument names and the input labels, i.e. ICCMA 2023 indexes or renamed APX arguments (defaults to the output file followed by \".map\"; required when a mapping is written and the output is the standard output)")
+                    .required(false),
+            )
+            .arg(common::dot_arg(
+                "a file receiving a rendering of the AF in the Graphviz DOT language",
+            ))
+            .arg(cli_manager::logging_level_cli_arg())
+    }
+
+    fn execute(&self, arg_matches: &ArgMatches<'_>) -> Result<()> {
+        match arg_matches.value_of(common::ARG_READER).unwrap() {
+            "apx" => execute_with_reader(arg_matches, &mut AspartixReader::default(), true),
+            "iccma23" => execute_with_reader(arg_matches, &mut Iccma23Reader::default(), false),
+            "iccma23_aba" => Err(anyhow!(
+                "the convert command does not handle ABA frameworks"
+            )),
+            "tgf" => execute_with_reader(arg_matches, &mut TgfReader::default(), true),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn execute_with_reader<T>(
+    arg_matches: &ArgMatches<'_>,
+    reader: &mut dyn InstanceReader<T>,
+    labelled_input: bool,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    let file = arg_matches.value_of(common::ARG_INPUT).unwrap();
+    let af = common::read_file_path(file, reader)?;
+    let writer_name = arg_matches.value_of(common::ARG_WRITER).unwrap();
+    let output = arg_matches.value_of(ARG_OUTPUT);
+    let relabelled = common::relabel_for_writer(&af, writer_name);
+    let mapping = if relabelled.is_some() || (labelled_input && writer_name == "iccma23") {
+        Some(
+            arg_matches
+                .value_of(ARG_MAPPING)
+                .map(|m| m.to_string())
+                .or_else(|| output.map(|o| format!("{}.map", o)))
+                .ok_or_else(|| {
+                    anyhow!("the argument labels are not kept by the conversion; a mapping file must be given when writing to the standard output")
+                })?,
+        )
+    } else {
+        None
+    };
+    let mut out = create_output(output)?;
+    match &relabelled {
+        Some(r) => {
+            info!("some labels are not valid APX argument names; the arguments are renamed");
+            common::write_framework(r, writer_name, &mut out)?
+        }
+        None => common::write_framework(&af, writer_name, &mut out)?,
+    }
+    if let Some(path) = arg_matches.value_of(common::ARG_DOT) {
+        common::write_dot_file(&af, None, path)?;
+    }
+    if let Some(path) = mapping {
+        info!("writing the label mapping to {:?}", path);
+        let mut mapping_out = create_output(Some(&path))?;
+        match relabelled {
+            Some(r) => write_apx_label_mapping(&r, &af, &mut mapping_out)?,
+            None => Iccma23Writer.write_label_mapping(&af, &mut mapping_out)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_apx_label_mapping<T>(
+    relabelled: &AAFramework<String>,
+    af: &AAFramework<T>,
+    writer: &mut dyn Write,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    let context = "while writing a label mapping";
+    for (new_arg, arg) in relabelled
+        .argument_set()
+        .iter()
+        .zip(af.argument_set().iter())
+    {
+        writeln!(writer, "{} {}", new_arg, arg).context(context)?;
+    }
+    writer.flush().context(context)
+}
+
+fn create_output(path: Option<&str>) -> Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(p) => {
+            Box::new(File::create(p).with_context(|| format!(r#"while creating file "{}""#, p))?)
+        }
+        None => Box::new(std::io::stdout()),
+    })
+}
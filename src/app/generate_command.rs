@@ -0,0 +1,213 @@
+use super::{cli_manager, command::Command, common};
+use anyhow::{anyhow, Context, Result};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use crustabri::generators::{
+    AAFrameworkGenerator, BarabasiAlbertGenerator, ErdosRenyiGenerator, GridGenerator,
+    StableGenerator, WattsStrogatzGenerator,
+};
+use log::info;
+use std::{fs::File, io::Write, str::FromStr, time::SystemTime};
+
+const CMD_NAME: &str = "generate";
+
+const ARG_MODEL: &str = "MODEL";
+const ARG_N_ARGUMENTS: &str = "N_ARGUMENTS";
+const ARG_PROBABILITY: &str = "PROBABILITY";
+const ARG_LINKS: &str = "LINKS";
+const ARG_NEIGHBORS: &str = "NEIGHBORS";
+const ARG_ROWS: &str = "ROWS";
+const ARG_COLUMNS: &str = "COLUMNS";
+const ARG_EXTENSIONS: &str = "EXTENSIONS";
+const ARG_EXTENSION_SIZE: &str = "EXTENSION_SIZE";
+const ARG_GROUNDED: &str = "GROUNDED";
+const ARG_SEED: &str = "SEED";
+const ARG_OUTPUT: &str = "OUTPUT";
+
+pub(crate) struct GenerateCommand;
+
+impl GenerateCommand {
+    pub(crate) fn new() -> Self {
+        GenerateCommand
+    }
+}
+
+impl<'a> Command<'a> for GenerateCommand {
+    fn name(&self) -> &str {
+        CMD_NAME
+    }
+
+    fn clap_subcommand(&self) -> App<'a, 'a> {
+        let value_arg = |name, long, help| {
+            Arg::with_name(name)
+                .long(long)
+                .empty_values(false)
+                .multiple(false)
+                .help(help)
+                .required(false)
+        };
+        SubCommand::with_name(CMD_NAME)
+            .about("Generates a random argumentation framework")
+            .setting(AppSettings::DisableVersion)
+            .arg(
+                Arg::with_name(ARG_MODEL)
+                    .short("m")
+                    .long("model")
+                    .empty_values(false)
+                    .multiple(false)
+                    .possible_values(&["er", "ba", "ws", "grid", "stable"])
+                    .help("the generation model (Erdős–Rényi, Barabási–Albert, Watts–Strogatz, grid or stable)")
+                    .required(true),
+            )
+            .arg(
+                value_arg(ARG_N_ARGUMENTS, "arguments", "the number of arguments (all models but grid)")
+                    .short("n"),
+            )
+            .arg(value_arg(
+                ARG_PROBABILITY,
+                "probability",
+                "the probability of attacks (er, stable), of rewiring (ws) or of mutual attacks (grid)",
+            ))
+            .arg(value_arg(
+                ARG_LINKS,
+                "links",
+                "the number of links of each new argument (ba)",
+            ))
+            .arg(value_arg(
+                ARG_NEIGHBORS,
+                "neighbors",
+                "the number of neighbors on the ring, which must be even (ws)",
+            ))
+            .arg(value_arg(ARG_ROWS, "rows", "the number of rows (grid)"))
+            .arg(value_arg(ARG_COLUMNS, "columns", "the number of columns (grid)"))
+            .arg(value_arg(
+                ARG_EXTENSIONS,
+                "extensions",
+                "the number of intended stable extensions (stable)",
+            ))
+            .arg(value_arg(
+                ARG_EXTENSION_SIZE,
+                "extension-size",
+                "the number of arguments of each extension, excluding the grounded ones (stable)",
+            ))
+            .arg(value_arg(
+                ARG_GROUNDED,
+                "grounded",
+                "the number of arguments in the grounded extension (stable)",
+            ))
+            .arg(
+                value_arg(
+                    ARG_SEED,
+                    "seed",
+                    "the seed of the random generator (chosen from the current time if not set)",
+                )
+                .short("s"),
+            )
+            .arg(
+                value_arg(
+                    ARG_OUTPUT,
+                    "output",
+                    "the output file (the standard output is used if not set)",
+                )
+                .short("o"),
+            )
+            .arg(common::writer_arg("iccma23"))
+            .arg(cli_manager::logging_level_cli_arg())
+    }
+
+    fn execute(&self, arg_matches: &ArgMatches<'_>) -> Result<()> {
+        let generator: Box<dyn AAFrameworkGenerator> =
+            match arg_matches.value_of(ARG_MODEL).unwrap() {
+                "er" => Box::new(ErdosRenyiGenerator::new(
+                    required_value(arg_matches, ARG_N_ARGUMENTS)?,
+                    required_value(arg_matches, ARG_PROBABILITY)?,
+                )),
+                "ba" => Box::new(BarabasiAlbertGenerator::new(
+                    required_value(arg_matches, ARG_N_ARGUMENTS)?,
+                    required_value(arg_matches, ARG_LINKS)?,
+                )),
+                "ws" => Box::new(WattsStrogatzGenerator::new(
+                    required_value(arg_matches, ARG_N_ARGUMENTS)?,
+                    required_value(arg_matches, ARG_NEIGHBORS)?,
+                    required_value(arg_matches, ARG_PROBABILITY)?,
+                )),
+                "grid" => {
+                    let mut generator = GridGenerator::new(
+                        required_value(arg_matches, ARG_ROWS)?,
+                        required_value(arg_matches, ARG_COLUMNS)?,
+                    );
+                    if let Some(p) = optional_value(arg_matches, ARG_PROBABILITY)? {
+                        generator.set_mutual_attack_probability(p);
+                    }
+                    Box::new(generator)
+                }
+                "stable" => {
+                    let mut generator = StableGenerator::new(
+                        required_value(arg_matches, ARG_N_ARGUMENTS)?,
+                        required_value(arg_matches, ARG_EXTENSIONS)?,
+                    );
+                    if let Some(n) = optional_value(arg_matches, ARG_GROUNDED)? {
+                        generator.set_n_grounded_arguments(n);
+                    }
+                    if let Some(n) = optional_value(arg_matches, ARG_EXTENSION_SIZE)? {
+                        generator.set_extension_size(n);
+                    }
+                    if let Some(p) = optional_value(arg_matches, ARG_PROBABILITY)? {
+                        generator.set_attack_probability(p);
+                    }
+                    Box::new(generator)
+                }
+                _ => unreachable!(),
+            };
+        let seed = match optional_value(arg_matches, ARG_SEED)? {
+            Some(s) => s,
+            None => SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default(),
+        };
+        info!("generating a framework with seed {}", seed);
+        let af = generator.generate(seed)?;
+        info!(
+            "the argumentation framework has {} argument(s) and {} attack(s)",
+            af.n_arguments(),
+            af.n_attacks(),
+        );
+        let mut out: Box<dyn Write> = match arg_matches.value_of(ARG_OUTPUT) {
+            Some(path) => Box::new(
+                File::create(path).with_context(|| format!(r#"while creating file "{}""#, path))?,
+            ),
+            None => Box::new(std::io::stdout()),
+        };
+        let writer_name = arg_matches.value_of(common::ARG_WRITER).unwrap();
+        match common::relabel_for_writer(&af, writer_name) {
+            Some(relabelled) => common::write_framework(&relabelled, writer_name, &mut out),
+            None => common::write_framework(&af, writer_name, &mut out),
+        }
+    }
+}
+
+fn optional_value<T>(arg_matches: &ArgMatches<'_>, arg_name: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+{
+    arg_matches
+        .value_of(arg_name)
+        .map(|v| {
+            v.parse::<T>()
+                .map_err(|_| anyhow!(r#"invalid value "{}" for parameter <{}>"#, v, arg_name))
+        })
+        .transpose()
+}
+
+fn required_value<T>(arg_matches: &ArgMatches<'_>, arg_name: &str) -> Result<T>
+where
+    T: FromStr,
+{
+    optional_value(arg_matches, arg_name)?.ok_or_else(|| {
+        anyhow!(
+            "missing parameter <{}> for model {}",
+            arg_name,
+            arg_matches.value_of(ARG_MODEL).unwrap()
+        )
+    })
+}
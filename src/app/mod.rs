@@ -12,10 +12,19 @@ pub(crate) mod command;
 
 pub(crate) mod common;
 
+mod convert_command;
+pub(crate) use convert_command::ConvertCommand;
+
+mod generate_command;
+pub(crate) use generate_command::GenerateCommand;
+
 mod problems_command;
 pub(crate) use problems_command::ProblemsCommand;
 
 mod solve_command;
 pub(crate) use solve_command::SolveCommand;
 
+mod stats_command;
+pub(crate) use stats_command::StatsCommand;
+
 pub(crate) mod writable_string;
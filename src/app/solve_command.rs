@@ -1,30 +1,38 @@
 use super::{
     cli_manager,
     command::Command,
-    common::{self, ARG_ARG, ARG_PROBLEM},
+    common::{self, ARG_ARG, ARG_EXTENSION, ARG_PROBLEM},
 };
 use anyhow::{anyhow, Context, Result};
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 use crustabri::{
-    aa::{AAFramework, Argument, Query, Semantics},
+    aa::{AAFramework, Argument, Labelling, Query, Semantics},
+    aba::{AbaSolver, Atom},
     encodings::{
         aux_var_constraints_encoder, exp_constraints_encoder, ConstraintsEncoder,
         HybridCompleteConstraintsEncoder,
     },
     io::{
-        AspartixReader, AspartixWriter, Iccma23Reader, Iccma23Writer, InstanceReader,
-        ResponseWriter,
+        AspartixReader, AspartixWriter, Iccma23AbaReader, Iccma23Reader, Iccma23Writer,
+        InstanceReader, ResponseWriter, TgfReader,
     },
     sat::{self, ExternalSatSolver, SatSolver, SatSolverFactoryFn, SolvingListener, SolvingResult},
     solvers::{
-        CompleteSemanticsSolver, CredulousAcceptanceComputer, GroundedSemanticsSolver,
-        IdealSemanticsSolver, PreferredSemanticsSolver, SemiStableSemanticsSolver,
-        SingleExtensionComputer, SkepticalAcceptanceComputer, StableSemanticsSolver,
-        StageSemanticsSolver,
+        AdmissibleSemanticsSolver, Cf2SemanticsSolver, CompleteSemanticsSolver,
+        ConflictFreeSemanticsSolver, CredulousAcceptanceComputer, EagerSemanticsSolver,
+        ExtensionCounter, ExtensionEnumerator, GroundedSemanticsSolver, IdealSemanticsSolver,
+        NaiveSemanticsSolver, NonEmptyExtensionChecker, NonExtensionReason,
+        PreferredSemanticsSolver, ResolutionBasedGroundedSemanticsSolver,
+        SemiStableSemanticsSolver, SingleExtensionComputer, SingleLabellingComputer,
+        SkepticalAcceptanceComputer, StableSemanticsSolver, Stage2SemanticsSolver,
+        StageSemanticsSolver, StronglyAdmissibleSemanticsSolver, UniqueExtensionChecker,
+        VerifyExtension, WeaklyAdmissibleSemanticsSolver, WeaklyCompleteSemanticsSolver,
+        WeaklyGroundedSemanticsSolver, WeaklyPreferredSemanticsSolver,
     },
     utils::LabelType,
 };
 use log::{info, warn};
+use num_bigint::BigUint;
 
 const CMD_NAME: &str = "solve";
 
@@ -33,6 +41,12 @@ const ARG_EXTERNAL_SAT_SOLVER_OPTIONS: &str = "EXTERNAL_SAT_SOLVER_OPTIONS";
 
 const ARG_CERTIFICATE: &str = "CERTIFICATE";
 
+const ARG_LABELLING: &str = "LABELLING";
+
+const ARG_CONJUNCTIVE: &str = "CONJUNCTIVE";
+
+const ARG_MINIMUM_CERTIFICATE: &str = "MINIMUM_CERTIFICATE";
+
 const ARG_ENCODING: &str = "ENCODING";
 
 pub(crate) struct SolveCommand;
@@ -65,6 +79,31 @@ impl<'a> Command<'a> for SolveCommand {
                     .help("generate a certificate when possible")
                     .required(false),
             )
+            .arg(
+                Arg::with_name(ARG_LABELLING)
+                    .long("labelling")
+                    .takes_value(false)
+                    .help("write a labelling instead of an extension (for SE queries)")
+                    .required(false),
+            )
+            .arg(common::dot_arg(
+                "a file receiving a rendering of the AF in the Graphviz DOT language, colouring the extension or certificate if any",
+            ))
+            .arg(
+                Arg::with_name(ARG_CONJUNCTIVE)
+                    .long("conjunctive")
+                    .takes_value(false)
+                    .help("require all the arguments to be accepted instead of at least one of them (for DC/DS queries)")
+                    .required(false),
+            )
+            .arg(
+                Arg::with_name(ARG_MINIMUM_CERTIFICATE)
+                    .long("minimum-certificate")
+                    .takes_value(false)
+                    .requires(ARG_CERTIFICATE)
+                    .help("give a minimum-size strongly admissible set as certificate instead of the grounded extension (for DC-GR queries)")
+                    .required(false),
+            )
             .arg(
                 Arg::with_name(ARG_ENCODING)
                     .long("encoding")
@@ -81,13 +120,19 @@ impl<'a> Command<'a> for SolveCommand {
             "apx" => execute_with_reader_and_writer(
                 arg_matches,
                 &mut AspartixReader::default(),
-                &mut AspartixWriter::default(),
+                &mut AspartixWriter,
             ),
             "iccma23" => execute_with_reader_and_writer(
                 arg_matches,
                 &mut Iccma23Reader::default(),
-                &mut Iccma23Writer::default(),
+                &mut Iccma23Writer,
+            ),
+            "tgf" => execute_with_reader_and_writer(
+                arg_matches,
+                &mut TgfReader::default(),
+                &mut AspartixWriter,
             ),
+            "iccma23_aba" => execute_aba(arg_matches),
             _ => unreachable!(),
         }
     }
@@ -103,49 +148,230 @@ where
 {
     let file = arg_matches.value_of(common::ARG_INPUT).unwrap();
     let af = common::read_file_path(file, reader)?;
-    let arg = arg_matches
-        .value_of(ARG_ARG)
-        .map(|a| reader.read_arg_from_str(&af, a))
+    let args = arg_matches
+        .values_of(ARG_ARG)
+        .map(|values| {
+            values
+                .map(|a| reader.read_arg_from_str(&af, a))
+                .collect::<Result<Vec<&Argument<T>>>>()
+        })
         .transpose()
-        .context("while parsing the argument passed to the command line")?;
+        .context("while parsing the arguments passed to the command line")?;
     let (query, semantics) =
         Query::read_problem_string(arg_matches.value_of(ARG_PROBLEM).unwrap())?;
-    let args = arg.map(|a| vec![a]);
-    check_args_definition(query, args.as_ref())?;
+    let extension = if arg_matches.is_present(ARG_EXTENSION) {
+        Some(
+            arg_matches
+                .values_of(ARG_EXTENSION)
+                .map(|values| {
+                    values
+                        .map(|a| reader.read_arg_from_str(&af, a))
+                        .collect::<Result<Vec<&Argument<T>>>>()
+                })
+                .transpose()
+                .context("while parsing the extension passed to the command line")?
+                .unwrap_or_default(),
+        )
+    } else {
+        None
+    };
+    check_args_definition(query, args.as_ref(), extension.as_ref())?;
+    let conjunctive = arg_matches.is_present(ARG_CONJUNCTIVE);
+    if conjunctive && query != Query::DC && query != Query::DS {
+        warn!(
+            "unexpected conjunctive flag on the command line (useless for query {})",
+            query.as_ref()
+        );
+    }
     let mut out = std::io::stdout();
+    let mut dot_extension: Option<Vec<usize>> = None;
     let mut acceptance_status_writer = |status, opt_certificate: Option<Vec<&Argument<T>>>| {
         writer.write_acceptance_status(&mut out, status)?;
         if let Some(c) = opt_certificate {
-            writer.write_single_extension(&mut out, c.as_slice())?
+            writer.write_single_extension(&mut out, c.as_slice())?;
+            dot_extension = Some(c.iter().map(|a| a.id()).collect());
         }
         Ok(())
     };
-    match query {
-        Query::SE => {
-            compute_one_extension(
-                &af,
-                semantics,
-                arg_matches,
-                &mut |opt_model| match opt_model {
-                    Some(m) => writer.write_single_extension(&mut out, &m),
+    if arg_matches.is_present(ARG_MINIMUM_CERTIFICATE)
+        && (query != Query::DC || semantics != Semantics::GR)
+    {
+        warn!(
+            "unexpected minimum certificate flag on the command line (useless for problem {}-{})",
+            query.as_ref(),
+            semantics.as_ref()
+        );
+    }
+    let with_labelling = arg_matches.is_present(ARG_LABELLING);
+    if with_labelling && query != Query::SE {
+        warn!(
+            "unexpected labelling flag on the command line (useless for query {})",
+            query.as_ref()
+        );
+    }
+    let result = match query {
+        Query::SE if with_labelling => {
+            compute_one_labelling(&af, semantics, arg_matches, &mut |opt_labelling| {
+                dot_extension = opt_labelling
+                    .as_ref()
+                    .map(|l| l.in_arguments().iter().map(|a| a.id()).collect());
+                match opt_labelling {
+                    Some(l) => writer.write_single_labelling(&mut out, &l),
                     None => writer.write_no_extension(&mut out),
-                },
-            )
+                }
+            })
         }
+        Query::SE => compute_one_extension(&af, semantics, arg_matches, &mut |opt_model| {
+            dot_extension = opt_model
+                .as_ref()
+                .map(|m| m.iter().map(|a| a.id()).collect());
+            match opt_model {
+                Some(m) => writer.write_single_extension(&mut out, &m),
+                None => writer.write_no_extension(&mut out),
+            }
+        }),
         Query::DC => check_credulous_acceptance(
             &af,
             semantics,
-            vec![arg.unwrap()],
+            args.unwrap(),
+            conjunctive,
             arg_matches,
             &mut acceptance_status_writer,
         ),
         Query::DS => check_skeptical_acceptance(
             &af,
             semantics,
-            vec![arg.unwrap()],
+            args.unwrap(),
+            conjunctive,
             arg_matches,
             &mut acceptance_status_writer,
         ),
+        Query::VE => verify_extension(
+            &af,
+            semantics,
+            extension.unwrap(),
+            arg_matches,
+            &mut |status, opt_reason| {
+                writer.write_acceptance_status(&mut out, status)?;
+                if let Some(r) = opt_reason {
+                    writer.write_single_extension(&mut out, &r.involved_arguments())?
+                }
+                Ok(())
+            },
+        ),
+        Query::NE => {
+            check_nonempty_extension(&af, semantics, arg_matches, &mut acceptance_status_writer)
+        }
+        Query::UN => check_unique_extension(&af, semantics, arg_matches, &mut |status, exts| {
+            writer.write_acceptance_status(&mut out, status)?;
+            for ext in exts {
+                writer.write_single_extension(&mut out, &ext)?
+            }
+            Ok(())
+        }),
+        Query::CE => count_extensions(&af, semantics, arg_matches, &mut |count| {
+            writer.write_extension_count(&mut out, count)
+        }),
+        Query::EE => {
+            let mut n_extensions = 0;
+            enumerate_extensions(&af, semantics, arg_matches, &mut |ext| {
+                n_extensions += 1;
+                writer.write_single_extension(&mut out, ext)
+            })?;
+            if n_extensions == 0 {
+                writer.write_no_extension(&mut out)?;
+            }
+            Ok(())
+        }
+    };
+    result?;
+    if let Some(path) = arg_matches.value_of(common::ARG_DOT) {
+        let extension = dot_extension.map(|ids| {
+            ids.into_iter()
+                .map(|id| af.argument_set().get_argument_by_id(id))
+                .collect::<Vec<&Argument<T>>>()
+        });
+        common::write_dot_file(&af, extension.as_deref(), path)?;
+    }
+    Ok(())
+}
+
+fn execute_aba(arg_matches: &ArgMatches<'_>) -> Result<()> {
+    let file = arg_matches.value_of(common::ARG_INPUT).unwrap();
+    let mut reader = Iccma23AbaReader::default();
+    let aba = common::read_aba_file_path(file, &mut reader)?;
+    let atoms = arg_matches
+        .values_of(ARG_ARG)
+        .map(|values| {
+            values
+                .map(|a| reader.read_atom_from_str(&aba, a))
+                .collect::<Result<Vec<&Atom<usize>>>>()
+        })
+        .transpose()
+        .context("while parsing the atoms passed to the command line")?;
+    let (query, semantics) =
+        Query::read_problem_string(arg_matches.value_of(ARG_PROBLEM).unwrap())?;
+    if !matches!(query, Query::SE | Query::DC | Query::DS)
+        || !matches!(semantics, Semantics::CO | Semantics::ST | Semantics::PR)
+    {
+        return Err(anyhow!(
+            "problem {}-{} is not supported for ABA frameworks",
+            query.as_ref(),
+            semantics.as_ref()
+        ));
+    }
+    check_args_definition(query, atoms.as_ref(), arg_matches.values_of(ARG_EXTENSION))?;
+    let conjunctive = arg_matches.is_present(ARG_CONJUNCTIVE);
+    if conjunctive && query == Query::SE {
+        warn!(
+            "unexpected conjunctive flag on the command line (useless for query {})",
+            query.as_ref()
+        );
+    }
+    for (arg, what) in [
+        (ARG_LABELLING, "labelling flag"),
+        (ARG_MINIMUM_CERTIFICATE, "minimum certificate flag"),
+        (common::ARG_DOT, "DOT output file"),
+    ] {
+        if arg_matches.is_present(arg) {
+            warn!(
+                "unexpected {} on the command line (useless for ABA frameworks)",
+                what
+            );
+        }
+    }
+    warn_on_unexpected_encoding(arg_matches);
+    let mut solver = AbaSolver::new_with_sat_solver_factory(
+        &aba,
+        semantics,
+        create_sat_solver_factory(arg_matches),
+    )?;
+    let writer = Iccma23Writer;
+    let mut out = std::io::stdout();
+    if query == Query::SE {
+        return match solver.compute_one_extension() {
+            Some(ext) => writer.write_single_extension(&mut out, &ext),
+            None => writer.write_no_extension(&mut out),
+        };
+    }
+    let labels = atoms
+        .unwrap()
+        .iter()
+        .map(|a| a.label())
+        .collect::<Vec<&usize>>();
+    let (status, certificate) = match (query, conjunctive) {
+        (Query::DC, false) => solver.are_credulously_accepted_with_certificate(&labels),
+        (Query::DC, true) => solver.are_all_credulously_accepted_with_certificate(&labels),
+        (Query::DS, false) => solver.are_skeptically_accepted_with_certificate(&labels),
+        (Query::DS, true) => solver.are_all_skeptically_accepted_with_certificate(&labels),
+        _ => unreachable!(),
+    };
+    writer.write_acceptance_status(&mut out, status)?;
+    match certificate {
+        Some(c) if arg_matches.is_present(ARG_CERTIFICATE) => {
+            writer.write_single_extension(&mut out, &c)
+        }
+        _ => Ok(()),
     }
 }
 
@@ -167,30 +393,221 @@ fn external_sat_solver_args() -> Vec<Arg<'static, 'static>> {
     ]
 }
 
-fn check_args_definition<T>(query: Query, args: Option<T>) -> Result<()> {
+fn check_args_definition<T, U>(query: Query, args: Option<T>, extension: Option<U>) -> Result<()> {
+    let check_absent = |is_present: bool, what: &str| {
+        if is_present {
+            warn!(
+                "unexpected {} on the command line (useless for query {})",
+                what,
+                query.as_ref()
+            );
+        }
+    };
+    let check_present = |is_present: bool, what: &str| {
+        if is_present {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "missing {} on the command line (required for query {})",
+                what,
+                query.as_ref()
+            ))
+        }
+    };
     match query {
-        Query::SE => {
-            if args.is_some() {
-                warn!(
-                    "unexpected argument on the command line (useless for query {})",
-                    query.as_ref()
-                );
-            }
+        Query::SE | Query::EE | Query::CE | Query::NE | Query::UN => {
+            check_absent(args.is_some(), "argument");
+            check_absent(extension.is_some(), "extension");
             Ok(())
         }
         Query::DC | Query::DS => {
-            if args.is_none() {
-                Err(anyhow!(
-                    "missing argument on the command line (required for query {})",
-                    query.as_ref()
-                ))
-            } else {
-                Ok(())
-            }
+            check_absent(extension.is_some(), "extension");
+            check_present(args.is_some(), "argument")
+        }
+        Query::VE => {
+            check_absent(args.is_some(), "argument");
+            check_present(extension.is_some(), "extension")
         }
     }
 }
 
+// Builds the solver of a query for the provided semantics, as a boxed trait object of the query.
+// The solvers to use for the complete and the preferred semantics depend on the query; they may be given by the caller,
+// and default to a CompleteSemanticsSolver and a PreferredSemanticsSolver.
+macro_rules! new_solver {
+    (
+        $query_trait:ident,
+        $af:expr,
+        $semantics:expr,
+        $arg_matches:expr,
+        complete => $complete_solver:expr,
+        preferred => $preferred_solver:expr
+    ) => {{
+        let af = $af;
+        let semantics = $semantics;
+        let arg_matches = $arg_matches;
+        let solver: Box<dyn $query_trait<_>> = match semantics {
+            Semantics::GR => {
+                warn_on_unexpected_encoding(arg_matches);
+                Box::new(GroundedSemanticsSolver::new(af))
+            }
+            Semantics::CO => $complete_solver,
+            Semantics::PR => $preferred_solver,
+            Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
+                af,
+                create_sat_solver_factory(arg_matches),
+            )),
+            Semantics::SST => Box::new(
+                SemiStableSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::STG => Box::new(
+                StageSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::ID => Box::new(
+                IdealSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::EG => Box::new(
+                EagerSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::AD => Box::new(
+                AdmissibleSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::CF => Box::new(
+                ConflictFreeSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::NA => Box::new(
+                NaiveSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::SA => Box::new(
+                StronglyAdmissibleSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::CF2 => Box::new(
+                Cf2SemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::STG2 => Box::new(
+                Stage2SemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::WAD => Box::new(
+                WeaklyAdmissibleSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::WPR => Box::new(
+                WeaklyPreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::WCO => Box::new(
+                WeaklyCompleteSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::WGR => Box::new(
+                WeaklyGroundedSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                    create_encoder(arg_matches, semantics).unwrap(),
+                ),
+            ),
+            Semantics::RGR => Box::new(
+                ResolutionBasedGroundedSemanticsSolver::new_with_sat_solver_factory(
+                    af,
+                    create_sat_solver_factory(arg_matches),
+                ),
+            ),
+        };
+        solver
+    }};
+    (
+        $query_trait:ident,
+        $af:expr,
+        $semantics:expr,
+        $arg_matches:expr,
+        complete => $complete_solver:expr
+    ) => {
+        new_solver!(
+            $query_trait,
+            $af,
+            $semantics,
+            $arg_matches,
+            complete => $complete_solver,
+            preferred => Box::new(
+                PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    $af,
+                    create_sat_solver_factory($arg_matches),
+                    create_encoder($arg_matches, $semantics).unwrap(),
+                ),
+            )
+        )
+    };
+    (
+        $query_trait:ident,
+        $af:expr,
+        $semantics:expr,
+        $arg_matches:expr
+    ) => {
+        new_solver!(
+            $query_trait,
+            $af,
+            $semantics,
+            $arg_matches,
+            complete => Box::new(
+                CompleteSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                    $af,
+                    create_sat_solver_factory($arg_matches),
+                    create_encoder($arg_matches, $semantics).unwrap(),
+                ),
+            )
+        )
+    };
+}
+
 fn compute_one_extension<F, T>(
     af: &AAFramework<T>,
     semantics: Semantics,
@@ -201,51 +618,175 @@ where
     T: LabelType,
     F: FnMut(Option<Vec<&Argument<T>>>) -> Result<()>,
 {
-    let mut solver: Box<dyn SingleExtensionComputer<T>> = match semantics {
-        Semantics::GR | Semantics::CO => {
-            warn_on_unexpected_encoding(arg_matches);
-            Box::new(GroundedSemanticsSolver::new(af))
+    // the grounded extension is the complete extension to consider for this query
+    let mut solver = new_solver!(SingleExtensionComputer, af, semantics, arg_matches, complete => {
+        warn_on_unexpected_encoding(arg_matches);
+        Box::new(GroundedSemanticsSolver::new(af))
+    });
+    (writing_fn)(solver.compute_one_extension())
+}
+
+fn compute_one_labelling<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(Option<Labelling<T>>) -> Result<()>,
+{
+    // the grounded labelling is the complete labelling to consider for this query
+    let mut solver = new_solver!(SingleLabellingComputer, af, semantics, arg_matches, complete => {
+        warn_on_unexpected_encoding(arg_matches);
+        Box::new(GroundedSemanticsSolver::new(af))
+    });
+    (writing_fn)(solver.compute_one_labelling())
+}
+
+fn enumerate_extensions<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(&[&Argument<T>]) -> Result<()>,
+{
+    let mut solver = new_solver!(ExtensionEnumerator, af, semantics, arg_matches);
+    let mut result = Ok(());
+    solver.enumerate_extensions(&mut |ext| {
+        result = (writing_fn)(ext);
+        result.is_ok()
+    });
+    result
+}
+
+fn count_extensions<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(&BigUint) -> Result<()>,
+{
+    let mut solver = new_solver!(ExtensionCounter, af, semantics, arg_matches);
+    (writing_fn)(&solver.count_extensions())
+}
+
+fn verify_extension<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    extension: Vec<&Argument<T>>,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(bool, Option<NonExtensionReason<T>>) -> Result<()>,
+{
+    let mut solver = new_solver!(VerifyExtension, af, semantics, arg_matches);
+    let (status, reason) = solver
+        .verify_extension_with_reason(&extension.iter().map(|a| a.label()).collect::<Vec<&T>>());
+    if let Some(r) = &reason {
+        info!(
+            "the set is not an extension: {}",
+            describe_non_extension_reason(r)
+        );
+    }
+    if arg_matches.is_present(ARG_CERTIFICATE) {
+        (writing_fn)(status, reason)
+    } else {
+        (writing_fn)(status, None)
+    }
+}
+
+fn describe_non_extension_reason<T>(reason: &NonExtensionReason<T>) -> String
+where
+    T: LabelType,
+{
+    match reason {
+        NonExtensionReason::ConflictingAttack(a, b) => {
+            format!("{} attacks {}", a.label(), b.label())
         }
-        Semantics::PR => Box::new(
-            PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
-            af,
-            create_sat_solver_factory(arg_matches),
-        )),
-        Semantics::SST => Box::new(
-            SemiStableSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::STG => Box::new(
-            StageSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::ID => Box::new(
-            IdealSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
+        NonExtensionReason::UndefendedArgument(a, b) => {
+            format!("{} is not defended against {}", a.label(), b.label())
+        }
+        NonExtensionReason::MissingDefendedArgument(a) => {
+            format!("{} is defended but not in the set", a.label())
+        }
+        NonExtensionReason::UnattackedArgument(a) => {
+            format!("{} is neither in the set nor attacked by it", a.label())
+        }
+        NonExtensionReason::LargerExtension(_) => "a larger extension exists".to_string(),
+        NonExtensionReason::DistinctExtension(_) => {
+            "the (unique) extension is a different set".to_string()
+        }
+        NonExtensionReason::CircularDefense(args) => format!(
+            "{} are only defended through a cycle of defenses",
+            args.iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
         ),
-    };
-    (writing_fn)(solver.compute_one_extension())
+        NonExtensionReason::LargerLocalExtension(_) => {
+            "the set is not maximal in a strongly connected component".to_string()
+        }
+        NonExtensionReason::SmallerExtension(_) => "a smaller extension exists".to_string(),
+        NonExtensionReason::NoCompleteResolution => {
+            "the set is not complete for any resolution of the mutual attacks".to_string()
+        }
+    }
+}
+
+fn check_nonempty_extension<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(bool, Option<Vec<&Argument<T>>>) -> Result<()>,
+{
+    let mut solver = new_solver!(NonEmptyExtensionChecker, af, semantics, arg_matches);
+    let with_certificate = arg_matches.is_present(ARG_CERTIFICATE);
+    if with_certificate {
+        let (status, certificate) = solver.has_nonempty_extension_with_certificate();
+        (writing_fn)(status, certificate)
+    } else {
+        (writing_fn)(solver.has_nonempty_extension(), None)
+    }
+}
+
+fn check_unique_extension<F, T>(
+    af: &AAFramework<T>,
+    semantics: Semantics,
+    arg_matches: &ArgMatches<'_>,
+    writing_fn: &mut F,
+) -> Result<()>
+where
+    T: LabelType,
+    F: FnMut(bool, Vec<Vec<&Argument<T>>>) -> Result<()>,
+{
+    let mut solver = new_solver!(UniqueExtensionChecker, af, semantics, arg_matches);
+    let with_certificate = arg_matches.is_present(ARG_CERTIFICATE);
+    if with_certificate {
+        let (status, certificate) = solver.has_unique_extension_with_certificate();
+        (writing_fn)(status, certificate)
+    } else {
+        (writing_fn)(solver.has_unique_extension(), vec![])
+    }
 }
 
 fn check_credulous_acceptance<F, T>(
     af: &AAFramework<T>,
     semantics: Semantics,
     args: Vec<&Argument<T>>,
+    conjunctive: bool,
     arg_matches: &ArgMatches<'_>,
     writing_fn: &mut F,
 ) -> Result<()>
@@ -253,53 +794,48 @@ where
     T: LabelType,
     F: FnMut(bool, Option<Vec<&Argument<T>>>) -> Result<()>,
 {
-    let mut solver: Box<dyn CredulousAcceptanceComputer<T>> = match semantics {
-        Semantics::GR => {
-            warn_on_unexpected_encoding(arg_matches);
-            Box::new(GroundedSemanticsSolver::new(af))
-        }
-        Semantics::CO | Semantics::PR => Box::new(
+    let new_complete_semantics_solver = || -> Box<dyn CredulousAcceptanceComputer<T>> {
+        Box::new(
             CompleteSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
                 af,
                 create_sat_solver_factory(arg_matches),
                 create_encoder(arg_matches, semantics).unwrap(),
             ),
-        ),
-        Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
-            af,
-            create_sat_solver_factory(arg_matches),
-        )),
-        Semantics::SST => Box::new(
-            SemiStableSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::STG => Box::new(
-            StageSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+        )
+    };
+    let mut solver =
+        if semantics == Semantics::GR && arg_matches.is_present(ARG_MINIMUM_CERTIFICATE) {
+            warn_on_unexpected_encoding(arg_matches);
+            Box::new(GroundedSemanticsSolver::new_with_minimum_certificates(
                 af,
                 create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::ID => Box::new(
-            IdealSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+            ))
+        } else {
+            // the preferred extensions are the maximal complete extensions, so credulous acceptance is the same
+            new_solver!(
+                CredulousAcceptanceComputer,
                 af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-    };
+                semantics,
+                arg_matches,
+                complete => new_complete_semantics_solver(),
+                preferred => new_complete_semantics_solver()
+            )
+        };
+    let labels = args.iter().map(|a| a.label()).collect::<Vec<&T>>();
     let with_certificate = arg_matches.is_present(ARG_CERTIFICATE);
     if with_certificate {
-        let (acceptance_status, certificate) = solver.are_credulously_accepted_with_certificate(
-            &args.iter().map(|a| a.label()).collect::<Vec<&T>>(),
-        );
+        let (acceptance_status, certificate) = if conjunctive {
+            solver.are_all_credulously_accepted_with_certificate(&labels)
+        } else {
+            solver.are_credulously_accepted_with_certificate(&labels)
+        };
         (writing_fn)(acceptance_status, certificate)
     } else {
-        let acceptance_status =
-            solver.are_credulously_accepted(&args.iter().map(|a| a.label()).collect::<Vec<&T>>());
+        let acceptance_status = if conjunctive {
+            solver.are_all_credulously_accepted(&labels)
+        } else {
+            solver.are_credulously_accepted(&labels)
+        };
         (writing_fn)(acceptance_status, None)
     }
 }
@@ -308,6 +844,7 @@ fn check_skeptical_acceptance<F, T>(
     af: &AAFramework<T>,
     semantics: Semantics,
     args: Vec<&Argument<T>>,
+    conjunctive: bool,
     arg_matches: &ArgMatches<'_>,
     writing_fn: &mut F,
 ) -> Result<()>
@@ -315,53 +852,26 @@ where
     T: LabelType,
     F: FnMut(bool, Option<Vec<&Argument<T>>>) -> Result<()>,
 {
-    let mut solver: Box<dyn SkepticalAcceptanceComputer<T>> = match semantics {
-        Semantics::GR | Semantics::CO => {
-            warn_on_unexpected_encoding(arg_matches);
-            Box::new(GroundedSemanticsSolver::new(af))
-        }
-        Semantics::PR => Box::new(
-            PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::ST => Box::new(StableSemanticsSolver::new_with_sat_solver_factory(
-            af,
-            create_sat_solver_factory(arg_matches),
-        )),
-        Semantics::SST => Box::new(
-            SemiStableSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::STG => Box::new(
-            StageSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-        Semantics::ID => Box::new(
-            IdealSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-                af,
-                create_sat_solver_factory(arg_matches),
-                create_encoder(arg_matches, semantics).unwrap(),
-            ),
-        ),
-    };
+    // the grounded extension is the complete extension to consider for this query
+    let mut solver = new_solver!(SkepticalAcceptanceComputer, af, semantics, arg_matches, complete => {
+        warn_on_unexpected_encoding(arg_matches);
+        Box::new(GroundedSemanticsSolver::new(af))
+    });
+    let labels = args.iter().map(|a| a.label()).collect::<Vec<&T>>();
     let with_certificate = arg_matches.is_present(ARG_CERTIFICATE);
     if with_certificate {
-        let (acceptance_status, certificate) = solver.are_skeptically_accepted_with_certificate(
-            &args.iter().map(|a| a.label()).collect::<Vec<&T>>(),
-        );
+        let (acceptance_status, certificate) = if conjunctive {
+            solver.are_all_skeptically_accepted_with_certificate(&labels)
+        } else {
+            solver.are_skeptically_accepted_with_certificate(&labels)
+        };
         (writing_fn)(acceptance_status, certificate)
     } else {
-        let acceptance_status =
-            solver.are_skeptically_accepted(&args.iter().map(|a| a.label()).collect::<Vec<&T>>());
+        let acceptance_status = if conjunctive {
+            solver.are_all_skeptically_accepted(&labels)
+        } else {
+            solver.are_skeptically_accepted(&labels)
+        };
         (writing_fn)(acceptance_status, None)
     }
 }
@@ -425,8 +935,32 @@ where
         str_encoding
     };
     match sem {
-        Semantics::GR | Semantics::ST => None,
-        Semantics::STG => match encoding_as_str("exp") {
+        Semantics::GR | Semantics::ST | Semantics::RGR => None,
+        Semantics::AD | Semantics::SA => match encoding_as_str("aux_var") {
+            "aux_var" => Some(Box::new(
+                aux_var_constraints_encoder::new_for_admissibility(),
+            )),
+            e @ ("exp" | "hybrid") => {
+                warn!(
+                    r#"irrelevant encoding value "{}" for {} semantics; falling back to default "aux_var""#,
+                    e,
+                    sem.as_ref()
+                );
+                Some(Box::new(
+                    aux_var_constraints_encoder::new_for_admissibility(),
+                ))
+            }
+            _ => unreachable!(),
+        },
+        Semantics::CF
+        | Semantics::NA
+        | Semantics::STG
+        | Semantics::CF2
+        | Semantics::STG2
+        | Semantics::WAD
+        | Semantics::WPR
+        | Semantics::WCO
+        | Semantics::WGR => match encoding_as_str("exp") {
             "aux_var" => Some(Box::new(
                 aux_var_constraints_encoder::new_for_conflict_freeness(),
             )),
@@ -435,7 +969,8 @@ where
             )),
             "hybrid" => {
                 warn!(
-                    r#"irrelevant encoding value "hybrid" for STG semantics; falling back to default "exp""#
+                    r#"irrelevant encoding value "hybrid" for {} semantics; falling back to default "exp""#,
+                    sem.as_ref()
                 );
                 Some(Box::new(
                     exp_constraints_encoder::new_for_conflict_freeness(),
@@ -443,7 +978,12 @@ where
             }
             _ => unreachable!(),
         },
-        Semantics::PR if arg_matches.value_of(ARG_PROBLEM).unwrap() == "SE-PR" => {
+        Semantics::PR
+            if matches!(
+                arg_matches.value_of(ARG_PROBLEM).unwrap(),
+                "SE-PR" | "EE-PR"
+            ) =>
+        {
             match encoding_as_str("aux_var") {
                 "aux_var" => Some(Box::new(
                     aux_var_constraints_encoder::new_for_admissibility(),
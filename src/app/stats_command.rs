@@ -0,0 +1,125 @@
+use super::{cli_manager, command::Command, common};
+use anyhow::{anyhow, Result};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use crustabri::{
+    io::{AspartixReader, Iccma23Reader, InstanceReader, TgfReader},
+    utils::{FrameworkStatistics, LabelType},
+};
+
+const CMD_NAME: &str = "stats";
+
+const ARG_JSON: &str = "JSON";
+
+pub(crate) struct StatsCommand;
+
+impl StatsCommand {
+    pub(crate) fn new() -> Self {
+        StatsCommand
+    }
+}
+
+impl<'a> Command<'a> for StatsCommand {
+    fn name(&self) -> &str {
+        CMD_NAME
+    }
+
+    fn clap_subcommand(&self) -> App<'a, 'a> {
+        SubCommand::with_name(CMD_NAME)
+            .about("Displays structural metrics of an AF")
+            .setting(AppSettings::DisableVersion)
+            .arg(common::input_args())
+            .arg(common::reader_arg())
+            .arg(cli_manager::logging_level_cli_arg())
+            .arg(
+                Arg::with_name(ARG_JSON)
+                    .long("json")
+                    .takes_value(false)
+                    .help("write the metrics as a JSON object")
+                    .required(false),
+            )
+    }
+
+    fn execute(&self, arg_matches: &ArgMatches<'_>) -> Result<()> {
+        match arg_matches.value_of(common::ARG_READER).unwrap() {
+            "apx" => execute_with_reader(arg_matches, &mut AspartixReader::default()),
+            "iccma23" => execute_with_reader(arg_matches, &mut Iccma23Reader::default()),
+            "iccma23_aba" => Err(anyhow!("the stats command does not handle ABA frameworks")),
+            "tgf" => execute_with_reader(arg_matches, &mut TgfReader::default()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn execute_with_reader<T>(
+    arg_matches: &ArgMatches<'_>,
+    reader: &mut dyn InstanceReader<T>,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    let file = arg_matches.value_of(common::ARG_INPUT).unwrap();
+    let af = common::read_file_path(file, reader)?;
+    let stats = FrameworkStatistics::new(&af);
+    let list_to_string = |l: &[usize], sep| {
+        l.iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join(sep)
+    };
+    let entries = vec![
+        ("arguments", stats.n_arguments().to_string()),
+        ("attacks", stats.n_attacks().to_string()),
+        ("self_attacks", stats.n_self_attacks().to_string()),
+        (
+            "unattacked_arguments",
+            stats.n_unattacked_arguments().to_string(),
+        ),
+        (
+            "grounded_extension_size",
+            stats.grounded_extension_size().to_string(),
+        ),
+        (
+            "connected_components",
+            stats.connected_component_sizes().len().to_string(),
+        ),
+        (
+            "strongly_connected_components",
+            stats.n_strongly_connected_components().to_string(),
+        ),
+        ("density", stats.density().to_string()),
+        (
+            "equivalency_classes",
+            stats.n_equivalency_classes().to_string(),
+        ),
+    ];
+    let list_entries = vec![
+        (
+            "connected_component_sizes",
+            stats.connected_component_sizes(),
+        ),
+        ("in_degree_distribution", stats.in_degree_distribution()),
+        ("out_degree_distribution", stats.out_degree_distribution()),
+    ];
+    if arg_matches.is_present(ARG_JSON) {
+        let fields = entries
+            .into_iter()
+            .chain(
+                list_entries
+                    .into_iter()
+                    .map(|(k, l)| (k, format!("[{}]", list_to_string(l, ", ")))),
+            )
+            .map(|(k, v)| format!(r#"  "{}": {}"#, k, v))
+            .collect::<Vec<String>>();
+        println!("{{\n{}\n}}", fields.join(",\n"));
+    } else {
+        entries
+            .into_iter()
+            .chain(
+                list_entries
+                    .into_iter()
+                    .map(|(k, l)| (k, list_to_string(l, " "))),
+            )
+            .for_each(|(k, v)| println!("{}: {}", k, v));
+    }
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet, Attack},
+    utils::LabelType,
+};
+use anyhow::{Context, Result};
+
+/// A Bipolar Argumentation framework (BAF).
+///
+/// BAFs extend [`AAFramework`] objects with a support relation between arguments.
+/// The supports are taken into account by computing the complex attacks they induce,
+/// given the [`SupportInterpretation`] at hand.
+/// The resulting AF, given by [`flatten`](Self::flatten), can then be handled by any of the solvers dedicated to AFs.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::bipolar::{BipolarFramework, SupportInterpretation};
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+/// let mut baf = BipolarFramework::new_with_argument_set(arguments);
+/// baf.new_support(&"a", &"b").unwrap();
+/// baf.new_attack(&"b", &"c").unwrap();
+/// let af = baf.flatten(SupportInterpretation::Deductive);
+/// assert_eq!(2, af.n_attacks()); // b attacks c, and so does a
+/// ```
+#[derive(Debug)]
+pub struct BipolarFramework<T>
+where
+    T: LabelType,
+{
+    af: AAFramework<T>,
+    supports: Vec<(usize, usize)>,
+    supports_from: Vec<Vec<usize>>,
+    supports_to: Vec<Vec<usize>>,
+}
+
+/// The interpretations of the support relation of a [`BipolarFramework`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupportInterpretation {
+    /// If `a` supports `b`, the acceptance of `a` implies the one of `b`.
+    ///
+    /// Under this interpretation, the supports induce the supported and the mediated attacks.
+    Deductive,
+    /// If `a` supports `b`, the acceptance of `b` requires the one of `a`.
+    ///
+    /// Under this interpretation, the supports induce the secondary and the extended attacks.
+    Necessary,
+}
+
+/// A support, represented as a couple of two arguments.
+///
+/// Supports are built by [`BipolarFramework`] objects.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::bipolar::BipolarFramework;
+/// # use crustabri::utils::LabelType;
+/// fn print_supports<T>(baf: &BipolarFramework<T>) where T: LabelType {
+///     baf.iter_supports()
+///         .for_each(|sup| println!("{} supports {}", sup.supporter(), sup.supported()));
+/// }
+/// ```
+pub struct Support<'a, T>(&'a Argument<T>, &'a Argument<T>)
+where
+    T: LabelType;
+
+impl<'a, T> Support<'a, T>
+where
+    T: LabelType,
+{
+    /// Returns the supporting argument.
+    pub fn supporter(&self) -> &'a Argument<T> {
+        self.0
+    }
+
+    /// Returns the supported argument.
+    pub fn supported(&self) -> &'a Argument<T> {
+        self.1
+    }
+}
+
+type ArgumentPairs<'a, T> = Vec<(&'a Argument<T>, &'a Argument<T>)>;
+
+impl<T> BipolarFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds a BAF with its argument set, and neither attacks nor supports.
+    pub fn new_with_argument_set(arguments: ArgumentSet<T>) -> Self {
+        Self::new_from_af(AAFramework::new_with_argument_set(arguments))
+    }
+
+    /// Builds a BAF from an AF, which attacks are kept as the direct attacks of the BAF.
+    pub fn new_from_af(af: AAFramework<T>) -> Self {
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        BipolarFramework {
+            af,
+            supports: vec![],
+            supports_from: vec![vec![]; n],
+            supports_to: vec![vec![]; n],
+        }
+    }
+
+    /// Returns the AF made of the arguments and the direct attacks of this framework.
+    pub fn af(&self) -> &AAFramework<T> {
+        &self.af
+    }
+
+    /// Returns the argument set of this framework.
+    pub fn argument_set(&self) -> &ArgumentSet<T> {
+        self.af.argument_set()
+    }
+
+    /// Returns the number of arguments in this framework.
+    pub fn n_arguments(&self) -> usize {
+        self.af.n_arguments()
+    }
+
+    /// Returns the number of direct attacks in this framework.
+    pub fn n_attacks(&self) -> usize {
+        self.af.n_attacks()
+    }
+
+    /// Returns the number of supports in this framework.
+    pub fn n_supports(&self) -> usize {
+        self.supports.len()
+    }
+
+    /// Adds a direct attack given the labels of the attacker and the attacked arguments.
+    ///
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_attack(&mut self, from: &T, to: &T) -> Result<()> {
+        self.af.new_attack(from, to)
+    }
+
+    /// Adds a support given the labels of the supporting and the supported arguments.
+    ///
+    /// Adding a support which already exists leaves the BAF unchanged.
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_support(&mut self, from: &T, to: &T) -> Result<()> {
+        let context = || format!("cannot add a support from {:?} to {:?}", from, to);
+        let supporter_id = self
+            .argument_set()
+            .get_argument(from)
+            .with_context(context)?
+            .id();
+        let supported_id = self
+            .argument_set()
+            .get_argument(to)
+            .with_context(context)?
+            .id();
+        if !self.supports_from[supporter_id].contains(&supported_id) {
+            self.supports.push((supporter_id, supported_id));
+            self.supports_from[supporter_id].push(supported_id);
+            self.supports_to[supported_id].push(supporter_id);
+        }
+        Ok(())
+    }
+
+    /// Provides an iterator to the direct attacks.
+    pub fn iter_attacks(&self) -> impl Iterator<Item = Attack<'_, T>> + '_ {
+        self.af.iter_attacks()
+    }
+
+    /// Provides an iterator to the supports.
+    pub fn iter_supports(&self) -> impl Iterator<Item = Support<'_, T>> + '_ {
+        self.supports.iter().map(|(a, b)| {
+            Support(
+                self.argument_set().get_argument_by_id(*a),
+                self.argument_set().get_argument_by_id(*b),
+            )
+        })
+    }
+
+    /// Computes the supported attacks.
+    ///
+    /// An argument `a` has a supported attack on `c` if there is a sequence of supports from `a` to an argument `b` which directly attacks `c`.
+    /// The attacks are sorted by the identifiers of their attacker, then of their attacked argument.
+    pub fn supported_attacks(&self) -> ArgumentPairs<'_, T> {
+        let supporters = self.support_closures(false, false);
+        self.collect_attacks(|b, c, attacks| {
+            supporters[b].iter().for_each(|a| attacks.push((*a, c)))
+        })
+    }
+
+    /// Computes the secondary attacks.
+    ///
+    /// An argument `a` has a secondary attack on `c` if it directly attacks an argument `b` from which there is a sequence of supports to `c`.
+    /// The attacks are sorted by the identifiers of their attacker, then of their attacked argument.
+    pub fn secondary_attacks(&self) -> ArgumentPairs<'_, T> {
+        let supported = self.support_closures(true, false);
+        self.collect_attacks(|a, b, attacks| {
+            supported[b].iter().for_each(|c| attacks.push((a, *c)))
+        })
+    }
+
+    /// Computes the mediated attacks.
+    ///
+    /// An argument `a` has a mediated attack on `c` if it directly attacks an argument `b` to which there is a sequence of supports from `c`.
+    /// The attacks are sorted by the identifiers of their attacker, then of their attacked argument.
+    pub fn mediated_attacks(&self) -> ArgumentPairs<'_, T> {
+        let supporters = self.support_closures(false, false);
+        self.collect_attacks(|a, b, attacks| {
+            supporters[b].iter().for_each(|c| attacks.push((a, *c)))
+        })
+    }
+
+    /// Computes the extended attacks.
+    ///
+    /// An argument `a` has an extended attack on `c` if there is a sequence of supports to `a` from an argument `b` which directly attacks `c`.
+    /// The attacks are sorted by the identifiers of their attacker, then of their attacked argument.
+    pub fn extended_attacks(&self) -> ArgumentPairs<'_, T> {
+        let supported = self.support_closures(true, false);
+        self.collect_attacks(|b, c, attacks| {
+            supported[b].iter().for_each(|a| attacks.push((*a, c)))
+        })
+    }
+
+    /// Builds the AF obtained by replacing the supports by the complex attacks they induce.
+    ///
+    /// The attacks of the returned AF are the direct attacks and the complex attacks related to the interpretation,
+    /// including the ones obtained by combining them.
+    /// Under the deductive interpretation, `a` attacks `c` if there are sequences of supports (possibly empty)
+    /// from `a` to an argument `b` and from `c` to an argument `d` such that `b` directly attacks `d`.
+    /// Under the necessary interpretation, `a` attacks `c` if there are sequences of supports (possibly empty)
+    /// from an argument `b` to `a` and from an argument `d` to `c` such that `b` directly attacks `d`.
+    ///
+    /// The arguments of the returned AF have the same labels as the ones of this framework.
+    pub fn flatten(&self, interpretation: SupportInterpretation) -> AAFramework<T> {
+        let closures =
+            self.support_closures(interpretation == SupportInterpretation::Necessary, true);
+        let attacks = self.collect_attacks(|b, d, attacks| {
+            closures[b]
+                .iter()
+                .for_each(|a| closures[d].iter().for_each(|c| attacks.push((*a, *c))))
+        });
+        let labels = self
+            .argument_set()
+            .iter()
+            .map(|a| a.label().clone())
+            .collect::<Vec<T>>();
+        let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        attacks
+            .into_iter()
+            .for_each(|(a, c)| af.new_attack(a.label(), c.label()).unwrap());
+        af
+    }
+
+    // Computes, for each argument, the arguments it reaches through a nonempty sequence of supports
+    // (or the arguments reaching it if `forward` is false).
+    // If `reflexive` is true, each argument is also considered to reach itself.
+    fn support_closures(&self, forward: bool, reflexive: bool) -> Vec<Vec<usize>> {
+        let neighbors = if forward {
+            &self.supports_from
+        } else {
+            &self.supports_to
+        };
+        let mut closures = vec![vec![]; neighbors.len()];
+        let mut seen = vec![false; neighbors.len()];
+        for arg in self.argument_set().iter() {
+            let mut reached = vec![];
+            let mut to_visit = neighbors[arg.id()].clone();
+            while let Some(id) = to_visit.pop() {
+                if !seen[id] {
+                    seen[id] = true;
+                    reached.push(id);
+                    to_visit.extend_from_slice(&neighbors[id]);
+                }
+            }
+            if reflexive && !seen[arg.id()] {
+                reached.push(arg.id());
+            }
+            reached.iter().for_each(|id| seen[*id] = false);
+            closures[arg.id()] = reached;
+        }
+        closures
+    }
+
+    // Applies the function to each direct attack, given by the identifiers of its arguments,
+    // and returns the sorted and deduplicated attacks it registered.
+    fn collect_attacks<F>(&self, mut f: F) -> ArgumentPairs<'_, T>
+    where
+        F: FnMut(usize, usize, &mut Vec<(usize, usize)>),
+    {
+        let mut attacks = vec![];
+        self.af
+            .iter_attacks()
+            .for_each(|att| f(att.attacker().id(), att.attacked().id(), &mut attacks));
+        attacks.sort_unstable();
+        attacks.dedup();
+        attacks
+            .into_iter()
+            .map(|(a, b)| {
+                (
+                    self.argument_set().get_argument_by_id(a),
+                    self.argument_set().get_argument_by_id(b),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(attacks: ArgumentPairs<'_, &'static str>) -> Vec<(&'static str, &'static str)> {
+        attacks
+            .iter()
+            .map(|(a, b)| (*a.label(), *b.label()))
+            .collect()
+    }
+
+    fn af_labels(af: &AAFramework<&'static str>) -> Vec<(&'static str, &'static str)> {
+        let mut attacks = af
+            .iter_attacks()
+            .map(|att| (*att.attacker().label(), *att.attacked().label()))
+            .collect::<Vec<_>>();
+        attacks.sort_unstable();
+        attacks
+    }
+
+    // a supports b, b supports c, c attacks d, and e attacks a
+    fn baf() -> BipolarFramework<&'static str> {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d", "e"]);
+        let mut baf = BipolarFramework::new_with_argument_set(arguments);
+        baf.new_support(&"a", &"b").unwrap();
+        baf.new_support(&"b", &"c").unwrap();
+        baf.new_attack(&"c", &"d").unwrap();
+        baf.new_attack(&"e", &"a").unwrap();
+        baf
+    }
+
+    #[test]
+    fn test_new_support() {
+        let mut baf = baf();
+        baf.new_support(&"a", &"b").unwrap();
+        assert_eq!(2, baf.n_supports());
+        assert!(baf.new_support(&"a", &"f").is_err());
+        assert!(baf.new_support(&"f", &"a").is_err());
+        assert_eq!(2, baf.n_supports());
+        assert_eq!(
+            vec![("a", "b"), ("b", "c")],
+            baf.iter_supports()
+                .map(|s| (*s.supporter().label(), *s.supported().label()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_complex_attacks() {
+        let baf = baf();
+        assert_eq!(
+            vec![("a", "d"), ("b", "d")],
+            labels(baf.supported_attacks())
+        );
+        assert_eq!(
+            vec![("e", "b"), ("e", "c")],
+            labels(baf.secondary_attacks())
+        );
+        assert!(baf.mediated_attacks().is_empty());
+        assert!(baf.extended_attacks().is_empty());
+    }
+
+    #[test]
+    fn test_mediated_and_extended_attacks() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut baf = BipolarFramework::new_with_argument_set(arguments);
+        baf.new_support(&"a", &"b").unwrap();
+        baf.new_attack(&"c", &"b").unwrap();
+        baf.new_attack(&"a", &"c").unwrap();
+        assert_eq!(vec![("c", "a")], labels(baf.mediated_attacks()));
+        assert_eq!(vec![("b", "c")], labels(baf.extended_attacks()));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut baf = baf();
+        baf.new_support(&"d", &"e").unwrap();
+        assert_eq!(
+            vec![("a", "d"), ("b", "d"), ("c", "d"), ("d", "a"), ("e", "a")],
+            af_labels(&baf.flatten(SupportInterpretation::Deductive))
+        );
+        assert_eq!(
+            vec![("c", "d"), ("c", "e"), ("e", "a"), ("e", "b"), ("e", "c")],
+            af_labels(&baf.flatten(SupportInterpretation::Necessary))
+        );
+    }
+
+    #[test]
+    fn test_flatten_with_support_cycle() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut baf = BipolarFramework::new_with_argument_set(arguments);
+        baf.new_support(&"a", &"b").unwrap();
+        baf.new_support(&"b", &"a").unwrap();
+        baf.new_attack(&"a", &"c").unwrap();
+        assert_eq!(
+            vec![("a", "c"), ("b", "c")],
+            af_labels(&baf.flatten(SupportInterpretation::Deductive))
+        );
+        assert_eq!(
+            vec![("a", "c"), ("b", "c")],
+            af_labels(&baf.flatten(SupportInterpretation::Necessary))
+        );
+        assert_eq!(
+            3,
+            baf.flatten(SupportInterpretation::Necessary).n_arguments()
+        );
+    }
+}
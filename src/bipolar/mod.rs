@@ -0,0 +1,6 @@
+//! Bipolar argumentation frameworks, which add a support relation to the attacks of Abstract Argumentation frameworks.
+
+mod bipolar_framework;
+pub use bipolar_framework::BipolarFramework;
+pub use bipolar_framework::Support;
+pub use bipolar_framework::SupportInterpretation;
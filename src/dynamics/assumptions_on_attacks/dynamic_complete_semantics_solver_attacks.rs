@@ -179,6 +179,25 @@ where
     fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
         self.are_credulously_accepted_with_certificate(args).0
     }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.buffered_encoder.update_encoding(&mut self.af);
+        let encoder_ref = self.buffered_encoder.encoder();
+        let mut assumptions = encoder_ref.assumptions(&self.af);
+        assumptions.extend(args.iter().map(|a| encoder_ref.arg_to_lit(&self.af, a)));
+        match self
+            .solver
+            .borrow_mut()
+            .solve_under_assumptions(&assumptions)
+            .unwrap_model()
+        {
+            Some(m) => (true, Some(encoder_ref.assignment_to_extension(&self.af, m))),
+            None => (false, None),
+        }
+    }
 }
 
 impl<T> SkepticalAcceptanceComputer<T> for DynamicCompleteSemanticsSolverAttacks<T>
@@ -20,7 +20,7 @@ pub struct DynamicConstraintsEncoder {
 #[derive(Debug)]
 enum SolverVarType {
     Argument(usize),
-    AttackerDisjunctionVar(usize),
+    AttackerDisjunctionVar,
     AttackAssumption,
     Ignored,
 }
@@ -63,6 +63,15 @@ impl DynamicConstraintsEncoder {
         Literal::from(self.arg_id_to_solver_var[arg.id()].unwrap() as isize)
     }
 
+    pub fn attack_to_lit<T: LabelType>(&self, af: &AAFramework<T>, from: &T, to: &T) -> Literal {
+        let attacker = af.argument_set().get_argument(from).unwrap();
+        let attacked = af.argument_set().get_argument(to).unwrap();
+        let index = (self.arg_id_to_solver_var[attacked.id()].unwrap() - 1) * self.n_arg_vars
+            + self.arg_id_to_solver_var[attacker.id()].unwrap()
+            - 1;
+        Literal::from((1 + index + self.n_arg_vars) as isize)
+    }
+
     pub fn new_argument<T: LabelType>(&mut self, af: &mut AAFramework<T>, label: T) {
         af.new_argument(label);
         if self.next_dummy_arg_var >= self.n_arg_vars {
@@ -77,14 +86,14 @@ impl DynamicConstraintsEncoder {
         self.solver_vars[self.next_dummy_arg_var] = SolverVarType::Argument(arg_id);
         if self.semantics == Semantics::CO {
             self.solver_vars[self.next_dummy_arg_var + self.n_arg_vars * (1 + self.n_arg_vars)] =
-                SolverVarType::AttackerDisjunctionVar(arg_id);
+                SolverVarType::AttackerDisjunctionVar;
         }
         self.next_dummy_arg_var += 1;
     }
 
     pub fn update_encoding<T: LabelType>(
         &mut self,
-        af: &mut AAFramework<T>,
+        af: &AAFramework<T>,
         solver_factory: &dyn Fn() -> Box<dyn SatSolver>,
     ) {
         match self.semantics {
@@ -96,7 +105,7 @@ impl DynamicConstraintsEncoder {
 
     pub fn update_encoding_for_stable_semantics<T: LabelType>(
         &mut self,
-        af: &mut AAFramework<T>,
+        af: &AAFramework<T>,
         solver_factory: &dyn Fn() -> Box<dyn SatSolver>,
     ) {
         if !self.need_to_encode {
@@ -154,7 +163,7 @@ impl DynamicConstraintsEncoder {
 
     pub fn update_encoding_for_complete_semantics<T: LabelType>(
         &mut self,
-        af: &mut AAFramework<T>,
+        af: &AAFramework<T>,
         solver_factory: &dyn Fn() -> Box<dyn SatSolver>,
     ) {
         if !self.need_to_encode {
@@ -176,10 +185,9 @@ impl DynamicConstraintsEncoder {
         (0..self.n_arg_vars - n_args).for_each(|_| self.solver_vars.push(SolverVarType::Ignored));
         (0..self.n_arg_vars * self.n_arg_vars)
             .for_each(|_| self.solver_vars.push(SolverVarType::AttackAssumption));
-        af.argument_set().iter().for_each(|a| {
-            self.solver_vars
-                .push(SolverVarType::AttackerDisjunctionVar(a.id()));
-        });
+        af.argument_set()
+            .iter()
+            .for_each(|_| self.solver_vars.push(SolverVarType::AttackerDisjunctionVar));
         (0..self.n_arg_vars - n_args).for_each(|_| self.solver_vars.push(SolverVarType::Ignored));
         self.next_dummy_arg_var = n_args + 1;
         (1..=self.n_arg_vars).for_each(|arg_var| {
@@ -301,7 +309,7 @@ impl DynamicConstraintsEncoder {
             return None;
         }
         if let SolverVarType::Argument(arg_id) = self.solver_vars[solver_var] {
-            return Some(af.argument_set().get_argument_by_id(arg_id));
+            Some(af.argument_set().get_argument_by_id(arg_id))
         } else {
             None
         }
@@ -82,6 +82,21 @@ where
         });
         (status, extension)
     }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let mut acceptance_computer =
+            (self.credulous_acceptance_computer_factory.as_ref().unwrap())(&self.af);
+        let (status, ext) = acceptance_computer.are_all_credulously_accepted_with_certificate(args);
+        let extension = ext.map(|e| {
+            e.iter()
+                .map(|l| self.af.argument_set().get_argument_by_id(l.id()))
+                .collect()
+        });
+        (status, extension)
+    }
 }
 
 impl<T> SkepticalAcceptanceComputer<T> for DummyDynamicConstraintsEncoder<T>
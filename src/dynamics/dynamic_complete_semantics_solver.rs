@@ -155,6 +155,25 @@ where
     fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
         self.are_credulously_accepted_with_certificate(args).0
     }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.buffered_encoder.update_encoding(&mut self.af);
+        let encoder_ref = self.buffered_encoder.encoder();
+        let mut assumptions = encoder_ref.assumptions().to_vec();
+        assumptions.extend(args.iter().map(|a| encoder_ref.arg_to_lit(&self.af, a)));
+        match self
+            .solver
+            .borrow_mut()
+            .solve_under_assumptions(&assumptions)
+            .unwrap_model()
+        {
+            Some(m) => (true, Some(encoder_ref.assignment_to_extension(&self.af, m))),
+            None => (false, None),
+        }
+    }
 }
 
 impl<T> SkepticalAcceptanceComputer<T> for DynamicCompleteSemanticsSolver<T>
@@ -214,4 +233,28 @@ mod tests {
         solver.remove_argument(&2).unwrap();
         assert!(solver.is_credulously_accepted_with_certificate(&1).0);
     }
+
+    #[test]
+    fn test_conjunctive_credulous_acceptance() {
+        let mut solver = DynamicCompleteSemanticsSolver::new();
+        solver.new_argument(1);
+        solver.new_argument(2);
+        solver.new_argument(3);
+        solver.new_attack(&1, &2).unwrap();
+        solver.new_attack(&2, &1).unwrap();
+        solver.new_attack(&3, &3).unwrap();
+        assert!(solver.are_all_credulously_accepted(&[&1]));
+        assert!(!solver.are_all_credulously_accepted(&[&1, &2]));
+        assert!(!solver.are_all_credulously_accepted(&[&1, &3]));
+        solver.remove_attack(&3, &3).unwrap();
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&1, &3]);
+        assert!(status);
+        let mut labels = certificate
+            .unwrap()
+            .iter()
+            .map(|a| *a.label())
+            .collect::<Vec<_>>();
+        labels.sort_unstable();
+        assert_eq!(vec![1, 3], labels);
+    }
 }
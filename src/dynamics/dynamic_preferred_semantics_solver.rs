@@ -104,6 +104,37 @@ where
     ) -> (bool, Option<Vec<&Argument<T>>>) {
         unimplemented!()
     }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.buffered_encoder.update_encoding(&mut self.af);
+        let encoder_ref = self.buffered_encoder.encoder();
+        let mut assumptions = encoder_ref.assumptions().to_vec();
+        assumptions.extend(args.iter().map(|a| encoder_ref.arg_to_lit(&self.af, a)));
+        if self
+            .solver
+            .borrow_mut()
+            .solve_under_assumptions(&assumptions)
+            .unwrap_model()
+            .is_none()
+        {
+            return (false, None);
+        }
+        // a preferred extension containing the arguments is a maximal complete extension among the ones containing them
+        let constraints_encoder = LocalConstraintsEncoder {
+            encoder: encoder_ref,
+            af: &self.af,
+        };
+        let mut computer = maximal_extension_computer::new_for_preferred_semantics(
+            &self.af,
+            Rc::clone(&self.solver),
+            &constraints_encoder,
+        );
+        computer.set_additional_assumptions(assumptions);
+        (true, Some(computer.compute_maximal()))
+    }
 }
 
 impl<T> SkepticalAcceptanceComputer<T> for DynamicPreferredSemanticsSolver<T>
@@ -326,4 +357,23 @@ mod tests {
         assert!(!status);
         assert_eq!(1, witness.unwrap().len());
     }
+
+    #[test]
+    fn test_conjunctive_credulous_acceptance() {
+        let mut solver = DynamicPreferredSemanticsSolver::new();
+        solver.new_argument(1);
+        solver.new_argument(2);
+        solver.new_argument(3);
+        solver.new_argument(4);
+        solver.new_attack(&1, &2).unwrap();
+        solver.new_attack(&2, &1).unwrap();
+        solver.new_attack(&3, &4).unwrap();
+        solver.new_attack(&4, &3).unwrap();
+        assert!(!solver.are_all_credulously_accepted(&[&1, &2]));
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&2]);
+        assert!(status);
+        let certificate = certificate.unwrap();
+        assert_eq!(2, certificate.len());
+        assert!(certificate.iter().any(|a| *a.label() == 2));
+    }
 }
@@ -147,6 +147,25 @@ where
     fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
         self.are_credulously_accepted_with_certificate(args).0
     }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.buffered_encoder.update_encoding(&mut self.af);
+        let encoder_ref = self.buffered_encoder.encoder();
+        let mut assumptions = encoder_ref.assumptions().to_vec();
+        assumptions.extend(args.iter().map(|a| encoder_ref.arg_to_lit(&self.af, a)));
+        match self
+            .solver
+            .borrow_mut()
+            .solve_under_assumptions(&assumptions)
+            .unwrap_model()
+        {
+            Some(m) => (true, Some(encoder_ref.assignment_to_extension(&self.af, m))),
+            None => (false, None),
+        }
+    }
 }
 
 impl<T> SkepticalAcceptanceComputer<T> for DynamicStableSemanticsSolver<T>
@@ -219,7 +238,7 @@ where
     }
 
     fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
-        return self.are_skeptically_accepted_with_certificate(args).0;
+        self.are_skeptically_accepted_with_certificate(args).0
     }
 }
 
@@ -265,4 +284,23 @@ mod tests {
         assert!(!solver.is_skeptically_accepted(&3));
         assert!(!solver.is_skeptically_accepted(&4));
     }
+
+    #[test]
+    fn test_conjunctive_credulous_acceptance() {
+        let mut solver = DynamicStableSemanticsSolver::new();
+        solver.new_argument(1);
+        solver.new_argument(2);
+        solver.new_argument(3);
+        solver.new_argument(4);
+        solver.new_attack(&1, &2).unwrap();
+        solver.new_attack(&2, &1).unwrap();
+        solver.new_attack(&3, &4).unwrap();
+        solver.new_attack(&4, &3).unwrap();
+        assert!(solver.are_all_credulously_accepted(&[&1, &4]));
+        assert!(!solver.are_all_credulously_accepted(&[&1, &2]));
+        solver.new_attack(&1, &4).unwrap();
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&1, &4]);
+        assert!(!status);
+        assert!(certificate.is_none());
+    }
 }
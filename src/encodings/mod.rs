@@ -25,8 +25,11 @@ where
 mod hybrid_complete_constraints_encoder;
 pub use hybrid_complete_constraints_encoder::HybridCompleteConstraintsEncoder;
 
+pub mod setaf_constraints_encoder;
+
 mod specs;
 pub use specs::ConstraintsEncoder;
+pub use specs::SetafConstraintsEncoder;
 
 mod stable_constraints_encoder;
 pub use stable_constraints_encoder::DefaultStableConstraintsEncoder;
@@ -0,0 +1,261 @@
+//! A module dedicated to the encodings of SETAF semantics.
+//!
+//! In addition to one variable per argument, these encodings use one variable per argument to express it is attacked by the extension,
+//! one variable per attack to express all its attackers are in the extension,
+//! and one variable per attack to express one of its attackers is attacked by the extension.
+
+use super::SetafConstraintsEncoder;
+use crate::{
+    aa::Argument,
+    sat::{clause, Assignment, Literal, SatSolver},
+    setaf::SetafFramework,
+    utils::LabelType,
+};
+
+enum EncodingType {
+    ConflictFreeness,
+    Admissibility,
+    CompleteSemantics,
+    StableSemantics,
+}
+
+/// Returns an encoder for conflict-freeness in SETAFs.
+pub fn new_for_conflict_freeness() -> SetafAuxVarConstraintsEncoder {
+    SetafAuxVarConstraintsEncoder(EncodingType::ConflictFreeness)
+}
+
+/// Returns an encoder for admissibility in SETAFs.
+pub fn new_for_admissibility() -> SetafAuxVarConstraintsEncoder {
+    SetafAuxVarConstraintsEncoder(EncodingType::Admissibility)
+}
+
+/// Returns an encoder for the complete semantics of SETAFs.
+pub fn new_for_complete_semantics() -> SetafAuxVarConstraintsEncoder {
+    SetafAuxVarConstraintsEncoder(EncodingType::CompleteSemantics)
+}
+
+/// Returns an encoder for the stable semantics of SETAFs.
+pub fn new_for_stable_semantics() -> SetafAuxVarConstraintsEncoder {
+    SetafAuxVarConstraintsEncoder(EncodingType::StableSemantics)
+}
+
+/// A common type for the encodings of SETAF semantics.
+pub struct SetafAuxVarConstraintsEncoder(EncodingType);
+
+impl SetafAuxVarConstraintsEncoder {
+    fn encode<T>(&self, setaf: &SetafFramework<T>, solver: &mut dyn SatSolver, with_range: bool)
+    where
+        T: LabelType,
+    {
+        let n_args = n_args(setaf);
+        let n_attacks = setaf.n_attacks();
+        solver.reserve(3 * n_args + 2 * n_attacks);
+        let vars = VarMapping { n_args, n_attacks };
+        encode_attack_vars(setaf, solver, &vars);
+        for arg_id in 0..n_args {
+            let arg_var = vars.arg(arg_id);
+            let attacked_var = vars.attacked(arg_id);
+            solver.add_clause(clause![-arg_var, -attacked_var]);
+            match self.0 {
+                EncodingType::ConflictFreeness => {}
+                EncodingType::Admissibility | EncodingType::CompleteSemantics => {
+                    let mut full_cl = clause![arg_var];
+                    setaf.attack_indices_to(arg_id).iter().for_each(|i| {
+                        let countered_var = vars.countered(*i);
+                        solver.add_clause(clause![-arg_var, countered_var]);
+                        full_cl.push((-countered_var).into());
+                    });
+                    if matches!(self.0, EncodingType::CompleteSemantics) {
+                        solver.add_clause(full_cl);
+                    }
+                }
+                EncodingType::StableSemantics => {
+                    solver.add_clause(clause![arg_var, attacked_var]);
+                }
+            }
+            if with_range {
+                let range_var = vars.range(arg_id);
+                solver.add_clause(clause![-arg_var, range_var]);
+                solver.add_clause(clause![-attacked_var, range_var]);
+                solver.add_clause(clause![-range_var, arg_var, attacked_var]);
+            }
+        }
+    }
+}
+
+fn n_args<T>(setaf: &SetafFramework<T>) -> usize
+where
+    T: LabelType,
+{
+    setaf.argument_set().max_id().map(|id| id + 1).unwrap_or(0)
+}
+
+// Encodes the definitions of the auxiliary variables related to the attacks:
+// an attack is active iff all its attackers are in the extension,
+// an argument is attacked iff one of the attacks targeting it is active,
+// and an attack is countered iff one of its attackers is attacked.
+fn encode_attack_vars<T>(setaf: &SetafFramework<T>, solver: &mut dyn SatSolver, vars: &VarMapping)
+where
+    T: LabelType,
+{
+    for (i, (attackers, _)) in setaf.attacks().iter().enumerate() {
+        let active_var = vars.active(i);
+        let countered_var = vars.countered(i);
+        let mut active_cl = clause![active_var];
+        let mut countered_cl = clause![-countered_var];
+        for b in attackers {
+            solver.add_clause(clause![-active_var, vars.arg(*b)]);
+            active_cl.push((-vars.arg(*b)).into());
+            solver.add_clause(clause![countered_var, -vars.attacked(*b)]);
+            countered_cl.push(vars.attacked(*b).into());
+        }
+        solver.add_clause(active_cl);
+        solver.add_clause(countered_cl);
+    }
+    for arg_id in 0..vars.n_args {
+        let attacked_var = vars.attacked(arg_id);
+        let mut full_cl = clause![-attacked_var];
+        setaf.attack_indices_to(arg_id).iter().for_each(|i| {
+            solver.add_clause(clause![attacked_var, -vars.active(*i)]);
+            full_cl.push(vars.active(*i).into());
+        });
+        solver.add_clause(full_cl);
+    }
+}
+
+struct VarMapping {
+    n_args: usize,
+    n_attacks: usize,
+}
+
+impl VarMapping {
+    fn arg(&self, arg_id: usize) -> isize {
+        (arg_id + 1) as isize
+    }
+
+    fn attacked(&self, arg_id: usize) -> isize {
+        (self.n_args + arg_id + 1) as isize
+    }
+
+    fn range(&self, arg_id: usize) -> isize {
+        (2 * self.n_args + arg_id + 1) as isize
+    }
+
+    fn active(&self, attack_index: usize) -> isize {
+        (3 * self.n_args + attack_index + 1) as isize
+    }
+
+    fn countered(&self, attack_index: usize) -> isize {
+        (3 * self.n_args + self.n_attacks + attack_index + 1) as isize
+    }
+}
+
+impl<T> SetafConstraintsEncoder<T> for SetafAuxVarConstraintsEncoder
+where
+    T: LabelType,
+{
+    fn encode_constraints(&self, setaf: &SetafFramework<T>, solver: &mut dyn SatSolver) {
+        self.encode(setaf, solver, false)
+    }
+
+    fn encode_constraints_and_range(&self, setaf: &SetafFramework<T>, solver: &mut dyn SatSolver) {
+        self.encode(setaf, solver, true)
+    }
+
+    fn assignment_to_extension<'a>(
+        &self,
+        assignment: &Assignment,
+        setaf: &'a SetafFramework<T>,
+    ) -> Vec<&'a Argument<T>> {
+        let n_args = n_args(setaf);
+        assignment
+            .iter()
+            .filter_map(|(var, opt_v)| match opt_v {
+                Some(true) if var >= 1 && var <= n_args => {
+                    Some(setaf.argument_set().get_argument_by_id(var - 1))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn arg_to_lit(&self, arg: &Argument<T>) -> Literal {
+        Literal::from((arg.id() + 1) as isize)
+    }
+
+    fn first_range_var(&self, n_args: usize) -> usize {
+        2 * n_args + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aa::ArgumentSet, sat::default_solver};
+
+    fn count_extensions(
+        setaf: &SetafFramework<&str>,
+        encoder: &SetafAuxVarConstraintsEncoder,
+    ) -> usize {
+        let mut solver = default_solver();
+        encoder.encode_constraints(setaf, solver.as_mut());
+        let mut n = 0;
+        while let Some(model) = solver.solve().unwrap_model() {
+            n += 1;
+            let ext = encoder.assignment_to_extension(&model, setaf);
+            let blocking_cl = setaf
+                .argument_set()
+                .iter()
+                .map(|a| {
+                    let lit = encoder.arg_to_lit(a);
+                    if ext.contains(&a) {
+                        lit.negate()
+                    } else {
+                        lit
+                    }
+                })
+                .collect();
+            solver.add_clause(blocking_cl);
+        }
+        n
+    }
+
+    // a and b collectively attack c, and c attacks a
+    fn setaf() -> SetafFramework<&'static str> {
+        let mut setaf =
+            SetafFramework::new_with_argument_set(ArgumentSet::new_with_labels(&["a", "b", "c"]));
+        setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+        setaf.new_attack(&[&"c"], &"a").unwrap();
+        setaf
+    }
+
+    #[test]
+    fn test_conflict_freeness() {
+        // all sets but {a, b, c} and {a, c}
+        assert_eq!(6, count_extensions(&setaf(), &new_for_conflict_freeness()));
+    }
+
+    #[test]
+    fn test_admissibility() {
+        // {}, {b}, {c}, {a, b}, {b, c}
+        assert_eq!(5, count_extensions(&setaf(), &new_for_admissibility()));
+    }
+
+    #[test]
+    fn test_complete_semantics() {
+        // {b}, {a, b}, {b, c}
+        assert_eq!(3, count_extensions(&setaf(), &new_for_complete_semantics()));
+    }
+
+    #[test]
+    fn test_stable_semantics() {
+        // {a, b}, {b, c}
+        assert_eq!(2, count_extensions(&setaf(), &new_for_stable_semantics()));
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        let setaf = SetafFramework::<&str>::default();
+        assert_eq!(1, count_extensions(&setaf, &new_for_complete_semantics()));
+    }
+}
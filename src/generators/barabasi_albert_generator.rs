@@ -0,0 +1,95 @@
+use super::{specs, AAFrameworkGenerator};
+use crate::aa::AAFramework;
+use anyhow::{anyhow, Result};
+use rand::Rng;
+
+/// A generator following the Barabási–Albert preferential attachment model.
+///
+/// The arguments are added one by one.
+/// Each new argument is linked to a fixed number of distinct previous arguments,
+/// chosen with a probability proportional to the number of attacks they are involved in.
+/// The first arguments, which cannot be linked to enough previous arguments, are linked to all of them.
+/// The direction of each attack is chosen at random.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::generators::{AAFrameworkGenerator, BarabasiAlbertGenerator};
+/// let generator = BarabasiAlbertGenerator::new(10, 2);
+/// let af = generator.generate(0).unwrap();
+/// assert_eq!(10, af.n_arguments());
+/// assert_eq!(1 + 2 * 8, af.n_attacks());
+/// ```
+pub struct BarabasiAlbertGenerator {
+    n_arguments: usize,
+    n_links: usize,
+}
+
+impl BarabasiAlbertGenerator {
+    /// Builds a new generator given the number of arguments and the number of links of each new argument.
+    pub fn new(n_arguments: usize, n_links: usize) -> Self {
+        Self {
+            n_arguments,
+            n_links,
+        }
+    }
+}
+
+impl AAFrameworkGenerator for BarabasiAlbertGenerator {
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>> {
+        if self.n_links == 0 {
+            return Err(anyhow!("the number of links must be strictly positive"));
+        }
+        let mut rng = specs::new_rng(seed);
+        let mut af = specs::new_framework(self.n_arguments);
+        // each argument appears in this vector as many times as the number of attacks it is involved in
+        let mut endpoints: Vec<usize> = Vec::with_capacity(2 * self.n_arguments * self.n_links);
+        for new_arg in 0..self.n_arguments {
+            let mut targets = Vec::with_capacity(self.n_links);
+            if new_arg <= self.n_links {
+                targets.extend(0..new_arg);
+            } else {
+                while targets.len() < self.n_links {
+                    let target = endpoints[rng.gen_range(0..endpoints.len())];
+                    if !targets.contains(&target) {
+                        targets.push(target);
+                    }
+                }
+            }
+            for target in targets {
+                specs::new_attack_with_random_direction(&mut af, &mut rng, new_arg, target);
+                endpoints.push(new_arg);
+                endpoints.push(target);
+            }
+        }
+        Ok(af)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_of_attacks() {
+        let af = BarabasiAlbertGenerator::new(20, 3).generate(42).unwrap();
+        assert_eq!(3 + 3 * 17, af.n_attacks());
+        assert!(af
+            .argument_set()
+            .iter()
+            .all(|a| { af.iter_attacks_from(a).count() + af.iter_attacks_to(a).count() >= 3 }));
+    }
+
+    #[test]
+    fn test_seed() {
+        let generator = BarabasiAlbertGenerator::new(20, 2);
+        let attacks = |seed| {
+            let af = generator.generate(seed).unwrap();
+            af.iter_attacks()
+                .map(|att| (*att.attacker().label(), *att.attacked().label()))
+                .collect::<Vec<(usize, usize)>>()
+        };
+        assert_eq!(attacks(1), attacks(1));
+        assert_ne!(attacks(1), attacks(2));
+    }
+}
@@ -0,0 +1,82 @@
+use super::{specs, AAFrameworkGenerator};
+use crate::aa::AAFramework;
+use anyhow::Result;
+use rand::Rng;
+
+/// A generator following the Erdős–Rényi model.
+///
+/// Each attack between two distinct arguments is added independently with the same probability.
+/// By default, self-attacks are never added; a dedicated probability can be set with [set_self_attack_probability](Self::set_self_attack_probability).
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::generators::{AAFrameworkGenerator, ErdosRenyiGenerator};
+/// let generator = ErdosRenyiGenerator::new(10, 0.2);
+/// let af = generator.generate(0).unwrap();
+/// assert_eq!(10, af.n_arguments());
+/// assert_eq!(af.n_attacks(), generator.generate(0).unwrap().n_attacks());
+/// ```
+pub struct ErdosRenyiGenerator {
+    n_arguments: usize,
+    attack_probability: f64,
+    self_attack_probability: f64,
+}
+
+impl ErdosRenyiGenerator {
+    /// Builds a new generator given the number of arguments and the probability of each attack.
+    pub fn new(n_arguments: usize, attack_probability: f64) -> Self {
+        Self {
+            n_arguments,
+            attack_probability,
+            self_attack_probability: 0.,
+        }
+    }
+
+    /// Sets the probability of each self-attack.
+    pub fn set_self_attack_probability(&mut self, self_attack_probability: f64) {
+        self.self_attack_probability = self_attack_probability;
+    }
+}
+
+impl AAFrameworkGenerator for ErdosRenyiGenerator {
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>> {
+        specs::check_probability("attack probability", self.attack_probability)?;
+        specs::check_probability("self-attack probability", self.self_attack_probability)?;
+        let mut rng = specs::new_rng(seed);
+        let mut af = specs::new_framework(self.n_arguments);
+        for i in 1..=self.n_arguments {
+            for j in 1..=self.n_arguments {
+                let probability = if i == j {
+                    self.self_attack_probability
+                } else {
+                    self.attack_probability
+                };
+                if rng.gen_bool(probability) {
+                    af.new_attack(&i, &j).unwrap();
+                }
+            }
+        }
+        Ok(af)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extreme_probabilities() {
+        let mut generator = ErdosRenyiGenerator::new(5, 1.);
+        assert_eq!(20, generator.generate(0).unwrap().n_attacks());
+        generator.set_self_attack_probability(1.);
+        assert_eq!(25, generator.generate(0).unwrap().n_attacks());
+        let generator = ErdosRenyiGenerator::new(5, 0.);
+        assert_eq!(0, generator.generate(0).unwrap().n_attacks());
+    }
+
+    #[test]
+    fn test_invalid_probability() {
+        assert!(ErdosRenyiGenerator::new(5, 1.5).generate(0).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use super::{specs, AAFrameworkGenerator};
+use crate::aa::AAFramework;
+use anyhow::Result;
+use rand::Rng;
+
+/// A generator of grid-shaped frameworks.
+///
+/// The arguments are placed on a grid, row by row, and each argument is linked to its horizontal and vertical neighbors.
+/// Each link is turned into a mutual attack with a given probability (`0.5` by default);
+/// otherwise, the direction of the attack is chosen at random.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::generators::{AAFrameworkGenerator, GridGenerator};
+/// let mut generator = GridGenerator::new(3, 4);
+/// generator.set_mutual_attack_probability(0.);
+/// let af = generator.generate(0).unwrap();
+/// assert_eq!(12, af.n_arguments());
+/// assert_eq!(3 * 3 + 2 * 4, af.n_attacks());
+/// ```
+pub struct GridGenerator {
+    n_rows: usize,
+    n_columns: usize,
+    mutual_attack_probability: f64,
+}
+
+impl GridGenerator {
+    /// Builds a new generator given the dimensions of the grid.
+    pub fn new(n_rows: usize, n_columns: usize) -> Self {
+        Self {
+            n_rows,
+            n_columns,
+            mutual_attack_probability: 0.5,
+        }
+    }
+
+    /// Sets the probability for a link between two neighbors to be a mutual attack.
+    pub fn set_mutual_attack_probability(&mut self, mutual_attack_probability: f64) {
+        self.mutual_attack_probability = mutual_attack_probability;
+    }
+}
+
+impl AAFrameworkGenerator for GridGenerator {
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>> {
+        specs::check_probability("mutual attack probability", self.mutual_attack_probability)?;
+        let mut rng = specs::new_rng(seed);
+        let mut af = specs::new_framework(self.n_rows * self.n_columns);
+        let index = |row: usize, column: usize| row * self.n_columns + column;
+        for row in 0..self.n_rows {
+            for column in 0..self.n_columns {
+                let mut neighbors = Vec::with_capacity(2);
+                if column + 1 < self.n_columns {
+                    neighbors.push(index(row, column + 1));
+                }
+                if row + 1 < self.n_rows {
+                    neighbors.push(index(row + 1, column));
+                }
+                for neighbor in neighbors {
+                    let arg = index(row, column);
+                    if rng.gen_bool(self.mutual_attack_probability) {
+                        af.new_attack(&(arg + 1), &(neighbor + 1)).unwrap();
+                        af.new_attack(&(neighbor + 1), &(arg + 1)).unwrap();
+                    } else {
+                        specs::new_attack_with_random_direction(&mut af, &mut rng, arg, neighbor);
+                    }
+                }
+            }
+        }
+        Ok(af)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutual_attacks() {
+        let mut generator = GridGenerator::new(2, 2);
+        generator.set_mutual_attack_probability(1.);
+        let af = generator.generate(0).unwrap();
+        assert_eq!(8, af.n_attacks());
+        assert!(af.iter_attacks().all(|att| {
+            let (from, to) = (*att.attacker().label(), *att.attacked().label());
+            from.abs_diff(to) == 1 || from.abs_diff(to) == 2
+        }));
+    }
+}
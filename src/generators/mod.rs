@@ -0,0 +1,23 @@
+//! Random generators of argumentation frameworks.
+//!
+//! Each generator implements the [AAFrameworkGenerator] trait, and builds frameworks given a seed.
+//! Generating two frameworks with the same generator parameters and the same seed leads to the same framework.
+//! The labels of the arguments of the generated frameworks are the integers from `1` to the number of arguments, as for the frameworks read by [Iccma23Reader](crate::io::Iccma23Reader).
+
+mod barabasi_albert_generator;
+pub use barabasi_albert_generator::BarabasiAlbertGenerator;
+
+mod erdos_renyi_generator;
+pub use erdos_renyi_generator::ErdosRenyiGenerator;
+
+mod grid_generator;
+pub use grid_generator::GridGenerator;
+
+mod specs;
+pub use specs::AAFrameworkGenerator;
+
+mod stable_generator;
+pub use stable_generator::StableGenerator;
+
+mod watts_strogatz_generator;
+pub use watts_strogatz_generator::WattsStrogatzGenerator;
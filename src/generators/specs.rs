@@ -0,0 +1,52 @@
+use crate::aa::{AAFramework, ArgumentSet};
+use anyhow::{anyhow, Result};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A trait for random generators of argumentation frameworks.
+pub trait AAFrameworkGenerator {
+    /// Generates a framework using the provided seed.
+    ///
+    /// The arguments are labelled by the integers from `1` to the number of arguments.
+    /// If the parameters of the generator are inconsistent, an error is returned.
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>>;
+}
+
+/// Builds the random number generator used by the generators.
+pub(crate) fn new_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Builds a framework with no attacks, the arguments of which are labelled from `1` to `n_arguments`.
+pub(crate) fn new_framework(n_arguments: usize) -> AAFramework<usize> {
+    AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(
+        &(1..=n_arguments).collect::<Vec<usize>>(),
+    ))
+}
+
+/// Adds an attack between two arguments given by their indices (starting at `0`), choosing its direction at random.
+pub(crate) fn new_attack_with_random_direction(
+    af: &mut AAFramework<usize>,
+    rng: &mut ChaCha8Rng,
+    arg0: usize,
+    arg1: usize,
+) {
+    if rng.gen_bool(0.5) {
+        af.new_attack(&(arg0 + 1), &(arg1 + 1)).unwrap();
+    } else {
+        af.new_attack(&(arg1 + 1), &(arg0 + 1)).unwrap();
+    }
+}
+
+/// Checks a probability parameter belongs to `[0, 1]`.
+pub(crate) fn check_probability(name: &str, value: f64) -> Result<()> {
+    if (0. ..=1.).contains(&value) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "the {} must be between 0 and 1, got {}",
+            name,
+            value
+        ))
+    }
+}
@@ -0,0 +1,179 @@
+use super::{specs, AAFrameworkGenerator};
+use crate::aa::AAFramework;
+use anyhow::{anyhow, Result};
+use rand::{seq::SliceRandom, Rng};
+
+/// A generator of frameworks with stable extensions, in the spirit of the ICCMA StableGenerator.
+///
+/// The generator first chooses the arguments of the grounded part, which are not attacked.
+/// Then, it chooses disjoint sets of arguments outside of the grounded part;
+/// the union of each of these sets with the grounded part is intended to be a stable extension.
+/// For this purpose, each argument outside such an extension is attacked by an argument chosen at random in the corresponding set.
+/// Finally, additional attacks are added at random with a given probability (`0.1` by default),
+/// provided they neither target the grounded part nor link two arguments of the same set.
+///
+/// The sets chosen by the generator are guaranteed to be stable extensions, but the framework may have other ones.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::generators::{AAFrameworkGenerator, StableGenerator};
+/// let mut generator = StableGenerator::new(20, 3);
+/// generator.set_n_grounded_arguments(2);
+/// generator.set_extension_size(5);
+/// let af = generator.generate(0).unwrap();
+/// assert_eq!(20, af.n_arguments());
+/// ```
+pub struct StableGenerator {
+    n_arguments: usize,
+    n_extensions: usize,
+    n_grounded_arguments: usize,
+    extension_size: Option<usize>,
+    attack_probability: f64,
+}
+
+impl StableGenerator {
+    /// Builds a new generator given the number of arguments and the number of intended stable extensions.
+    ///
+    /// By default, the grounded part is empty,
+    /// and the size of the sets is the largest one allowing some arguments to belong to none of them.
+    pub fn new(n_arguments: usize, n_extensions: usize) -> Self {
+        Self {
+            n_arguments,
+            n_extensions,
+            n_grounded_arguments: 0,
+            extension_size: None,
+            attack_probability: 0.1,
+        }
+    }
+
+    /// Sets the number of arguments in the grounded part.
+    pub fn set_n_grounded_arguments(&mut self, n_grounded_arguments: usize) {
+        self.n_grounded_arguments = n_grounded_arguments;
+    }
+
+    /// Sets the number of arguments of each chosen set, in addition to the grounded part.
+    pub fn set_extension_size(&mut self, extension_size: usize) {
+        self.extension_size = Some(extension_size);
+    }
+
+    /// Sets the probability of the additional attacks.
+    pub fn set_attack_probability(&mut self, attack_probability: f64) {
+        self.attack_probability = attack_probability;
+    }
+
+    // Generates a framework, and returns it with the stable extensions built from the chosen sets.
+    fn generate_with_planted_extensions(
+        &self,
+        seed: u64,
+    ) -> Result<(AAFramework<usize>, Vec<Vec<usize>>)> {
+        specs::check_probability("attack probability", self.attack_probability)?;
+        if self.n_grounded_arguments > self.n_arguments {
+            return Err(anyhow!(
+                "the grounded part cannot be larger than the framework"
+            ));
+        }
+        let n_free_arguments = self.n_arguments - self.n_grounded_arguments;
+        let extension_size = self
+            .extension_size
+            .unwrap_or(n_free_arguments / (self.n_extensions + 1));
+        if self.n_extensions > 0 && extension_size == 0 {
+            return Err(anyhow!("the sets must not be empty"));
+        }
+        if self.n_extensions * extension_size > n_free_arguments {
+            return Err(anyhow!(
+                "not enough arguments outside the grounded part for {} sets of size {}",
+                self.n_extensions,
+                extension_size
+            ));
+        }
+        let mut rng = specs::new_rng(seed);
+        let mut af = specs::new_framework(self.n_arguments);
+        let mut args = (0..self.n_arguments).collect::<Vec<usize>>();
+        args.shuffle(&mut rng);
+        let mut set_of = vec![None; self.n_arguments];
+        let mut is_grounded = vec![false; self.n_arguments];
+        args[..self.n_grounded_arguments]
+            .iter()
+            .for_each(|a| is_grounded[*a] = true);
+        let sets = args[self.n_grounded_arguments..]
+            .chunks(extension_size.max(1))
+            .take(self.n_extensions)
+            .collect::<Vec<&[usize]>>();
+        for (i, set) in sets.iter().enumerate() {
+            set.iter().for_each(|a| set_of[*a] = Some(i));
+        }
+        for (i, set) in sets.iter().enumerate() {
+            for arg in 0..self.n_arguments {
+                if !is_grounded[arg] && set_of[arg] != Some(i) {
+                    let attacker = set[rng.gen_range(0..set.len())];
+                    af.new_attack(&(attacker + 1), &(arg + 1)).unwrap();
+                }
+            }
+        }
+        for attacker in 0..self.n_arguments {
+            for attacked in 0..self.n_arguments {
+                if is_grounded[attacked]
+                    || is_grounded[attacker]
+                    || (set_of[attacker].is_some() && set_of[attacker] == set_of[attacked])
+                {
+                    continue;
+                }
+                if rng.gen_bool(self.attack_probability) {
+                    af.new_attack(&(attacker + 1), &(attacked + 1)).unwrap();
+                }
+            }
+        }
+        let planted_extensions = sets
+            .iter()
+            .map(|set| {
+                args[..self.n_grounded_arguments]
+                    .iter()
+                    .chain(set.iter())
+                    .map(|a| a + 1)
+                    .collect()
+            })
+            .collect();
+        Ok((af, planted_extensions))
+    }
+}
+
+impl AAFrameworkGenerator for StableGenerator {
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>> {
+        self.generate_with_planted_extensions(seed)
+            .map(|(af, _)| af)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{ExtensionCounter, StableSemanticsSolver, VerifyExtension};
+
+    #[test]
+    fn test_stable_extensions() {
+        let mut generator = StableGenerator::new(30, 4);
+        generator.set_n_grounded_arguments(3);
+        generator.set_extension_size(5);
+        for seed in 0..10 {
+            let (af, planted_extensions) =
+                generator.generate_with_planted_extensions(seed).unwrap();
+            let grounded = af.grounded_extension();
+            assert_eq!(3, grounded.len());
+            assert_eq!(4, planted_extensions.len());
+            let mut solver = StableSemanticsSolver::new(&af);
+            assert!(solver.count_extensions() >= 4_usize.into());
+            for extension in planted_extensions.iter() {
+                assert_eq!(8, extension.len());
+                assert!(solver.verify_extension(&extension.iter().collect::<Vec<&usize>>()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_too_large_sets() {
+        let mut generator = StableGenerator::new(10, 3);
+        generator.set_extension_size(4);
+        assert!(generator.generate(0).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use super::{specs, AAFrameworkGenerator};
+use crate::aa::AAFramework;
+use anyhow::{anyhow, Result};
+use rand::Rng;
+use std::collections::HashSet;
+
+/// A generator following the Watts–Strogatz small-world model.
+///
+/// The arguments are first placed on a ring, each of them being linked to its nearest neighbors.
+/// Then, each link is rewired with a given probability, replacing its second endpoint by an argument chosen at random.
+/// The direction of each attack is chosen at random.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::generators::{AAFrameworkGenerator, WattsStrogatzGenerator};
+/// let generator = WattsStrogatzGenerator::new(10, 4, 0.1);
+/// let af = generator.generate(0).unwrap();
+/// assert_eq!(10, af.n_arguments());
+/// assert_eq!(20, af.n_attacks());
+/// ```
+pub struct WattsStrogatzGenerator {
+    n_arguments: usize,
+    n_neighbors: usize,
+    rewiring_probability: f64,
+}
+
+impl WattsStrogatzGenerator {
+    /// Builds a new generator given the number of arguments, the number of neighbors of each argument on the ring and the rewiring probability.
+    ///
+    /// The number of neighbors must be even and lower than the number of arguments.
+    pub fn new(n_arguments: usize, n_neighbors: usize, rewiring_probability: f64) -> Self {
+        Self {
+            n_arguments,
+            n_neighbors,
+            rewiring_probability,
+        }
+    }
+}
+
+impl AAFrameworkGenerator for WattsStrogatzGenerator {
+    fn generate(&self, seed: u64) -> Result<AAFramework<usize>> {
+        specs::check_probability("rewiring probability", self.rewiring_probability)?;
+        if !self.n_neighbors.is_multiple_of(2) || self.n_neighbors >= self.n_arguments {
+            return Err(anyhow!(
+                "the number of neighbors must be even and lower than the number of arguments, got {}",
+                self.n_neighbors
+            ));
+        }
+        let mut rng = specs::new_rng(seed);
+        let n = self.n_arguments;
+        let mut links = Vec::with_capacity(n * self.n_neighbors / 2);
+        let mut linked = HashSet::new();
+        for distance in 1..=self.n_neighbors / 2 {
+            for i in 0..n {
+                let j = (i + distance) % n;
+                links.push((i, j));
+                linked.insert((usize::min(i, j), usize::max(i, j)));
+            }
+        }
+        for link in links.iter_mut() {
+            if !rng.gen_bool(self.rewiring_probability) {
+                continue;
+            }
+            let (i, j) = *link;
+            let n_linked = (0..n)
+                .filter(|k| linked.contains(&(usize::min(i, *k), usize::max(i, *k))))
+                .count();
+            if n_linked == n - 1 {
+                continue;
+            }
+            let k = loop {
+                let k = rng.gen_range(0..n);
+                if k != i && !linked.contains(&(usize::min(i, k), usize::max(i, k))) {
+                    break k;
+                }
+            };
+            linked.remove(&(usize::min(i, j), usize::max(i, j)));
+            linked.insert((usize::min(i, k), usize::max(i, k)));
+            *link = (i, k);
+        }
+        let mut af = specs::new_framework(n);
+        for (i, j) in links {
+            specs::new_attack_with_random_direction(&mut af, &mut rng, i, j);
+        }
+        Ok(af)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_lattice() {
+        let af = WattsStrogatzGenerator::new(8, 2, 0.).generate(0).unwrap();
+        assert_eq!(8, af.n_attacks());
+        assert!(af
+            .argument_set()
+            .iter()
+            .all(|a| { af.iter_attacks_from(a).count() + af.iter_attacks_to(a).count() == 2 }));
+    }
+
+    #[test]
+    fn test_rewiring_keeps_the_number_of_attacks() {
+        let af = WattsStrogatzGenerator::new(30, 6, 1.).generate(7).unwrap();
+        assert_eq!(90, af.n_attacks());
+    }
+
+    #[test]
+    fn test_invalid_number_of_neighbors() {
+        assert!(WattsStrogatzGenerator::new(10, 3, 0.5).generate(0).is_err());
+        assert!(WattsStrogatzGenerator::new(4, 4, 0.5).generate(0).is_err());
+    }
+}
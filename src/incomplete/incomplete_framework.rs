@@ -0,0 +1,193 @@
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet, Attack},
+    utils::LabelType,
+};
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+
+/// An Incomplete Argumentation framework (IAF).
+///
+/// IAFs extend [`AAFramework`] objects by marking some arguments and attacks as uncertain.
+/// A completion of an IAF is an AF made of all the certain arguments and some of the uncertain ones,
+/// all the certain attacks and some of the uncertain ones between the arguments of the completion.
+/// Queries on IAFs consider all their completions; see [`IncompleteFrameworkSolver`](super::IncompleteFrameworkSolver).
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::incomplete::IncompleteFramework;
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+/// let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+/// iaf.set_uncertain_argument(&"c").unwrap();
+/// iaf.new_attack(&"a", &"b").unwrap();
+/// iaf.new_uncertain_attack(&"b", &"a").unwrap();
+/// assert_eq!(2, iaf.n_attacks());
+/// assert_eq!(1, iaf.n_uncertain_attacks());
+/// ```
+#[derive(Debug)]
+pub struct IncompleteFramework<T>
+where
+    T: LabelType,
+{
+    af: AAFramework<T>,
+    uncertain_arguments: Vec<bool>,
+    uncertain_attacks: HashSet<(usize, usize)>,
+}
+
+impl<T> IncompleteFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds an IAF with its argument set, which arguments are certain, and no attacks.
+    pub fn new_with_argument_set(arguments: ArgumentSet<T>) -> Self {
+        Self::new_from_af(AAFramework::new_with_argument_set(arguments))
+    }
+
+    /// Builds an IAF from an AF, which arguments and attacks are certain.
+    pub fn new_from_af(af: AAFramework<T>) -> Self {
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        IncompleteFramework {
+            af,
+            uncertain_arguments: vec![false; n],
+            uncertain_attacks: HashSet::new(),
+        }
+    }
+
+    /// Returns the AF made of all the arguments and all the attacks of this framework, either certain or not.
+    pub fn af(&self) -> &AAFramework<T> {
+        &self.af
+    }
+
+    /// Returns the argument set of this framework, including the uncertain arguments.
+    pub fn argument_set(&self) -> &ArgumentSet<T> {
+        self.af.argument_set()
+    }
+
+    /// Returns the number of arguments in this framework, including the uncertain arguments.
+    pub fn n_arguments(&self) -> usize {
+        self.af.n_arguments()
+    }
+
+    /// Returns the number of uncertain arguments in this framework.
+    pub fn n_uncertain_arguments(&self) -> usize {
+        self.argument_set()
+            .iter()
+            .filter(|a| self.uncertain_arguments[a.id()])
+            .count()
+    }
+
+    /// Returns the number of attacks in this framework, including the uncertain attacks.
+    pub fn n_attacks(&self) -> usize {
+        self.af.n_attacks()
+    }
+
+    /// Returns the number of uncertain attacks in this framework.
+    pub fn n_uncertain_attacks(&self) -> usize {
+        self.uncertain_attacks.len()
+    }
+
+    /// Marks an argument as uncertain given its label.
+    ///
+    /// If the argument does not belong to this framework, an error is returned.
+    pub fn set_uncertain_argument(&mut self, label: &T) -> Result<()> {
+        let id = self
+            .argument_set()
+            .get_argument(label)
+            .with_context(|| format!("cannot mark {:?} as uncertain", label))?
+            .id();
+        self.uncertain_arguments[id] = true;
+        Ok(())
+    }
+
+    /// Returns `true` if the argument is uncertain.
+    pub fn is_uncertain_argument(&self, arg: &Argument<T>) -> bool {
+        self.uncertain_arguments[arg.id()]
+    }
+
+    /// Adds a certain attack given the labels of the attacker and the attacked arguments.
+    ///
+    /// If the attack already exists as an uncertain one, it becomes certain.
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_attack(&mut self, from: &T, to: &T) -> Result<()> {
+        self.af.new_attack(from, to)?;
+        let ids = self.attack_ids(from, to);
+        self.uncertain_attacks.remove(&ids);
+        Ok(())
+    }
+
+    /// Adds an uncertain attack given the labels of the attacker and the attacked arguments.
+    ///
+    /// If the attack already exists as a certain one, it becomes uncertain.
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_uncertain_attack(&mut self, from: &T, to: &T) -> Result<()> {
+        self.af.new_attack(from, to)?;
+        let ids = self.attack_ids(from, to);
+        self.uncertain_attacks.insert(ids);
+        Ok(())
+    }
+
+    fn attack_ids(&self, from: &T, to: &T) -> (usize, usize) {
+        (
+            self.argument_set().get_argument(from).unwrap().id(),
+            self.argument_set().get_argument(to).unwrap().id(),
+        )
+    }
+
+    /// Returns `true` if the attack is uncertain.
+    pub fn is_uncertain_attack(&self, attack: &Attack<T>) -> bool {
+        self.uncertain_attacks
+            .contains(&(attack.attacker().id(), attack.attacked().id()))
+    }
+
+    /// Provides an iterator to the attacks, including the uncertain attacks.
+    pub fn iter_attacks(&self) -> impl Iterator<Item = Attack<'_, T>> + '_ {
+        self.af.iter_attacks()
+    }
+
+    pub(crate) fn is_uncertain_argument_id(&self, id: usize) -> bool {
+        self.uncertain_arguments[id]
+    }
+
+    pub(crate) fn is_uncertain_attack_ids(&self, attacker: usize, attacked: usize) -> bool {
+        self.uncertain_attacks.contains(&(attacker, attacked))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uncertain_attacks() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+        let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+        iaf.new_uncertain_attack(&"a", &"b").unwrap();
+        iaf.new_attack(&"b", &"a").unwrap();
+        assert_eq!(2, iaf.n_attacks());
+        assert_eq!(1, iaf.n_uncertain_attacks());
+        iaf.new_attack(&"a", &"b").unwrap();
+        assert_eq!(0, iaf.n_uncertain_attacks());
+        iaf.new_uncertain_attack(&"b", &"a").unwrap();
+        assert_eq!(2, iaf.n_attacks());
+        assert_eq!(
+            vec![false, true],
+            iaf.iter_attacks()
+                .map(|att| iaf.is_uncertain_attack(&att))
+                .collect::<Vec<bool>>()
+        );
+        assert!(iaf.new_uncertain_attack(&"a", &"c").is_err());
+    }
+
+    #[test]
+    fn test_uncertain_arguments() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+        let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+        iaf.set_uncertain_argument(&"b").unwrap();
+        iaf.set_uncertain_argument(&"b").unwrap();
+        assert!(iaf.set_uncertain_argument(&"c").is_err());
+        assert_eq!(1, iaf.n_uncertain_arguments());
+        let b = iaf.argument_set().get_argument(&"b").unwrap();
+        assert!(iaf.is_uncertain_argument(b));
+    }
+}
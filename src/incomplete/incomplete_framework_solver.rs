@@ -0,0 +1,597 @@
+use super::IncompleteFramework;
+use crate::{
+    aa::{Argument, Semantics},
+    dynamics::assumptions_on_attacks::dynamic_constraints_encoder_attacks::DynamicConstraintsEncoder,
+    sat::{self, Assignment, Literal, SatSolver, SatSolverFactoryFn},
+    utils::LabelType,
+};
+use anyhow::{anyhow, Result};
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+/// A SAT-based solver for the possible and necessary problems of IAFs under the complete and stable semantics.
+///
+/// An argument is possibly (resp. necessarily) credulously accepted if it belongs to an extension of some (resp. each) completion,
+/// and possibly (resp. necessarily) skeptically accepted if it belongs to all the extensions of some (resp. each) completion.
+/// An argument which is absent from a completion is not accepted in it.
+/// A set of arguments is a possible (resp. necessary) extension if it is an extension of some (resp. each) completion.
+///
+/// The IAF is encoded once using the dynamic encodings of the [`assumptions_on_attacks`](crate::dynamics::assumptions_on_attacks) module,
+/// in which each attack is switched on and off by a literal.
+/// The literals of the uncertain attacks are left free, and each uncertain argument gets a literal stating its presence;
+/// an absent argument has all its attacks switched off, which makes it irrelevant to the other arguments.
+/// The possible credulous acceptance, the necessary skeptical acceptance and the possible verification are solved by a single SAT call.
+/// The necessary credulous and the possible skeptical acceptance are solved by a counterexample-guided loop over the completions,
+/// in which each extension found for a completion discards the completions in which it is still a witness.
+/// The necessary verification is decided in polynomial time from the structure of the IAF.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{ArgumentSet, Semantics};
+/// # use crustabri::incomplete::{IncompleteFramework, IncompleteFrameworkSolver};
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+/// let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+/// iaf.new_uncertain_attack(&"a", &"b").unwrap();
+/// let mut solver = IncompleteFrameworkSolver::new(&iaf, Semantics::ST).unwrap();
+/// assert!(solver.is_necessarily_credulously_accepted(&"a"));
+/// assert!(solver.is_possibly_skeptically_accepted(&"b"));
+/// assert!(!solver.is_necessarily_skeptically_accepted(&"b"));
+/// assert!(solver.is_possible_extension(&[&"a", &"b"]));
+/// assert!(!solver.is_necessary_extension(&[&"a"]));
+/// ```
+pub struct IncompleteFrameworkSolver<'a, T>
+where
+    T: LabelType,
+{
+    iaf: &'a IncompleteFramework<T>,
+    semantics: Semantics,
+    solver_factory: Box<SatSolverFactoryFn>,
+    solver: Rc<RefCell<Box<dyn SatSolver>>>,
+    arg_lits: Vec<Option<Literal>>,
+    membership_lits: Vec<Option<Literal>>,
+    elements: Vec<CompletionElement>,
+    arg_elements: Vec<Option<usize>>,
+}
+
+// An uncertain argument or attack, given with the literal of the encoding stating its presence.
+struct CompletionElement {
+    kind: CompletionElementKind,
+    lit: Literal,
+}
+
+enum CompletionElementKind {
+    Argument,
+    Attack(usize, usize),
+}
+
+impl<'a, T> IncompleteFrameworkSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// The semantics must be the complete or the stable one.
+    /// If it is not the case, an error is returned.
+    pub fn new(iaf: &'a IncompleteFramework<T>, semantics: Semantics) -> Result<Self> {
+        Self::new_with_sat_solver_factory(iaf, semantics, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The SAT solvers are created by the provided factory.
+    ///
+    /// The semantics must be the complete or the stable one.
+    /// If it is not the case, an error is returned.
+    pub fn new_with_sat_solver_factory(
+        iaf: &'a IncompleteFramework<T>,
+        semantics: Semantics,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Result<Self> {
+        if !matches!(semantics, Semantics::CO | Semantics::ST) {
+            return Err(anyhow!(
+                "unsupported semantics for IAFs: {}",
+                semantics.as_ref()
+            ));
+        }
+        let solver = Rc::new(RefCell::new((solver_factory)()));
+        let mut encoder =
+            DynamicConstraintsEncoder::new_with_arg_factor(Rc::clone(&solver), semantics, 1.);
+        encoder.update_encoding(iaf.af(), solver_factory.as_ref());
+        let mut result = Self {
+            iaf,
+            semantics,
+            solver_factory,
+            solver,
+            arg_lits: vec![],
+            membership_lits: vec![],
+            elements: vec![],
+            arg_elements: vec![],
+        };
+        result.encode_completions(&encoder);
+        Ok(result)
+    }
+
+    // Adds the presence literals of the uncertain arguments and the membership literals,
+    // and links the attack literals to the presence of their arguments.
+    fn encode_completions(&mut self, encoder: &DynamicConstraintsEncoder) {
+        let af = self.iaf.af();
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        let mut solver = self.solver.borrow_mut();
+        let mut next_var = 1 + solver.n_vars() as isize;
+        let mut new_lit = || {
+            next_var += 1;
+            Literal::from(next_var - 1)
+        };
+        self.arg_lits = vec![None; n];
+        self.membership_lits = vec![None; n];
+        self.arg_elements = vec![None; n];
+        let mut presence_lits = vec![None; n];
+        for arg in af.argument_set().iter() {
+            let arg_lit = encoder.arg_to_lit(af, arg.label());
+            self.arg_lits[arg.id()] = Some(arg_lit);
+            if self.iaf.is_uncertain_argument_id(arg.id()) {
+                let presence_lit = new_lit();
+                let membership_lit = new_lit();
+                solver.add_clause(vec![membership_lit.negate(), arg_lit]);
+                solver.add_clause(vec![membership_lit.negate(), presence_lit]);
+                solver.add_clause(vec![
+                    membership_lit,
+                    arg_lit.negate(),
+                    presence_lit.negate(),
+                ]);
+                presence_lits[arg.id()] = Some(presence_lit);
+                self.membership_lits[arg.id()] = Some(membership_lit);
+                self.arg_elements[arg.id()] = Some(self.elements.len());
+                self.elements.push(CompletionElement {
+                    kind: CompletionElementKind::Argument,
+                    lit: presence_lit,
+                });
+            } else {
+                self.membership_lits[arg.id()] = Some(arg_lit);
+            }
+        }
+        let attacks = af
+            .iter_attacks()
+            .map(|att| (att.attacker().id(), att.attacked().id()))
+            .collect::<HashSet<(usize, usize)>>();
+        for attacker in af.argument_set().iter() {
+            for attacked in af.argument_set().iter() {
+                let attack_lit = encoder.attack_to_lit(af, attacker.label(), attacked.label());
+                let ids = (attacker.id(), attacked.id());
+                if !attacks.contains(&ids) {
+                    solver.add_clause(vec![attack_lit.negate()]);
+                    continue;
+                }
+                let endpoint_lits = [presence_lits[ids.0], presence_lits[ids.1]];
+                endpoint_lits
+                    .iter()
+                    .flatten()
+                    .for_each(|l| solver.add_clause(vec![attack_lit.negate(), *l]));
+                if self.iaf.is_uncertain_attack_ids(ids.0, ids.1) {
+                    self.elements.push(CompletionElement {
+                        kind: CompletionElementKind::Attack(ids.0, ids.1),
+                        lit: attack_lit,
+                    });
+                } else {
+                    let mut cl = vec![attack_lit];
+                    endpoint_lits
+                        .iter()
+                        .flatten()
+                        .for_each(|l| cl.push(l.negate()));
+                    solver.add_clause(cl);
+                }
+            }
+        }
+    }
+
+    fn arg_id(&self, label: &T) -> usize {
+        self.iaf.argument_set().get_argument(label).unwrap().id()
+    }
+
+    fn solve(&self, assumptions: &[Literal]) -> Option<Assignment> {
+        self.solver
+            .borrow_mut()
+            .solve_under_assumptions(assumptions)
+            .unwrap_model()
+    }
+
+    /// Checks whether an argument is credulously accepted in some completion.
+    ///
+    /// # Panics
+    ///
+    /// If the provided argument does not belong to the argument set, this function panics.
+    pub fn is_possibly_credulously_accepted(&mut self, arg: &T) -> bool {
+        let id = self.arg_id(arg);
+        self.solve(&[self.membership_lits[id].unwrap()]).is_some()
+    }
+
+    /// Checks whether an argument is skeptically accepted in each completion.
+    ///
+    /// # Panics
+    ///
+    /// If the provided argument does not belong to the argument set, this function panics.
+    pub fn is_necessarily_skeptically_accepted(&mut self, arg: &T) -> bool {
+        let id = self.arg_id(arg);
+        !self.iaf.is_uncertain_argument_id(id)
+            && self.solve(&[self.arg_lits[id].unwrap().negate()]).is_none()
+    }
+
+    /// Checks whether an argument is credulously accepted in each completion.
+    ///
+    /// # Panics
+    ///
+    /// If the provided argument does not belong to the argument set, this function panics.
+    pub fn is_necessarily_credulously_accepted(&mut self, arg: &T) -> bool {
+        let id = self.arg_id(arg);
+        if self.iaf.is_uncertain_argument_id(id) {
+            return false;
+        }
+        let certificate_fn = match self.semantics {
+            Semantics::CO => Self::admissibility_certificate,
+            Semantics::ST => Self::stability_certificate,
+            _ => unreachable!(),
+        };
+        !self.exists_completion_without_model(&[], &[self.arg_lits[id].unwrap()], certificate_fn)
+    }
+
+    /// Checks whether an argument is skeptically accepted in some completion.
+    ///
+    /// # Panics
+    ///
+    /// If the provided argument does not belong to the argument set, this function panics.
+    pub fn is_possibly_skeptically_accepted(&mut self, arg: &T) -> bool {
+        let id = self.arg_id(arg);
+        let completion_units = self.arg_elements[id]
+            .map(|i| vec![completion_lit(i, true)])
+            .unwrap_or_default();
+        let certificate_fn = match self.semantics {
+            Semantics::CO => Self::completion_certificate,
+            Semantics::ST => Self::stability_certificate,
+            _ => unreachable!(),
+        };
+        self.exists_completion_without_model(
+            &completion_units,
+            &[self.arg_lits[id].unwrap().negate()],
+            certificate_fn,
+        )
+    }
+
+    /// Checks whether a set of arguments is an extension of some completion.
+    ///
+    /// # Panics
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function panics.
+    pub fn is_possible_extension(&mut self, args: &[&T]) -> bool {
+        let in_set = self.in_set(args);
+        let assumptions = self
+            .iaf
+            .argument_set()
+            .iter()
+            .map(|a| {
+                let lit = self.membership_lits[a.id()].unwrap();
+                if in_set[a.id()] {
+                    lit
+                } else {
+                    lit.negate()
+                }
+            })
+            .collect::<Vec<Literal>>();
+        self.solve(&assumptions).is_some()
+    }
+
+    /// Checks whether a set of arguments is an extension of each completion.
+    ///
+    /// # Panics
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function panics.
+    pub fn is_necessary_extension(&mut self, args: &[&T]) -> bool {
+        let in_set = self.in_set(args);
+        let af = self.iaf.af();
+        if af
+            .argument_set()
+            .iter()
+            .any(|a| in_set[a.id()] && self.iaf.is_uncertain_argument_id(a.id()))
+        {
+            return false;
+        }
+        if af
+            .iter_attacks()
+            .any(|att| in_set[att.attacker().id()] && in_set[att.attacked().id()])
+        {
+            return false;
+        }
+        let is_certain_attack =
+            |b: &Argument<T>, a: &Argument<T>| !self.iaf.is_uncertain_attack_ids(b.id(), a.id());
+        let surely_attacked_by_set = |arg: &Argument<T>| {
+            af.iter_attacks_to(arg).any(|att| {
+                in_set[att.attacker().id()] && is_certain_attack(att.attacker(), att.attacked())
+            })
+        };
+        match self.semantics {
+            Semantics::ST => af
+                .argument_set()
+                .iter()
+                .all(|a| in_set[a.id()] || surely_attacked_by_set(a)),
+            Semantics::CO => {
+                let admissible = af.iter_attacks().all(|att| {
+                    !in_set[att.attacked().id()] || surely_attacked_by_set(att.attacker())
+                });
+                // in the completion which is the most favorable to the defense of an argument,
+                // its attackers are the certain ones, and all the possible attacks from the set are present
+                admissible
+                    && af.argument_set().iter().all(|a| {
+                        in_set[a.id()]
+                            || af.iter_attacks_to(a).any(|att| {
+                                let b = att.attacker();
+                                is_certain_attack(b, a)
+                                    && (b.id() == a.id()
+                                        || !self.iaf.is_uncertain_argument_id(b.id()))
+                                    && !af.iter_attacks_to(b).any(|counter| {
+                                        in_set[counter.attacker().id()]
+                                            && (b.id() != a.id()
+                                                || is_certain_attack(counter.attacker(), b))
+                                    })
+                            })
+                    })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn in_set(&self, args: &[&T]) -> Vec<bool> {
+        let mut in_set = vec![false; self.arg_lits.len()];
+        args.iter().for_each(|a| in_set[self.arg_id(a)] = true);
+        in_set
+    }
+
+    // Searches for a completion in which the encoding has no model under the provided assumptions.
+    // The completion literals restrict the completions under consideration.
+    // When a model is found for a completion, the certificate function returns completion literals
+    // ensuring the model still gives a witness; the completions satisfying them are discarded.
+    fn exists_completion_without_model(
+        &self,
+        completion_units: &[Literal],
+        assumptions: &[Literal],
+        certificate_fn: CertificateFn<'a, T>,
+    ) -> bool {
+        let mut completion_solver = (self.solver_factory)();
+        completion_solver.reserve(self.elements.len());
+        for (i, element) in self.elements.iter().enumerate() {
+            if let CompletionElementKind::Attack(attacker, attacked) = element.kind {
+                [attacker, attacked]
+                    .iter()
+                    .filter_map(|id| self.arg_elements[*id])
+                    .for_each(|j| {
+                        completion_solver
+                            .add_clause(vec![completion_lit(i, false), completion_lit(j, true)])
+                    });
+            }
+        }
+        completion_units
+            .iter()
+            .for_each(|l| completion_solver.add_clause(vec![*l]));
+        while let Some(completion) = completion_solver.solve().unwrap_model() {
+            let completion = (0..self.elements.len())
+                .map(|i| completion.value_of(i + 1) == Some(true))
+                .collect::<Vec<bool>>();
+            let mut full_assumptions = self
+                .elements
+                .iter()
+                .zip(completion.iter())
+                .map(|(e, b)| if *b { e.lit } else { e.lit.negate() })
+                .collect::<Vec<Literal>>();
+            full_assumptions.extend_from_slice(assumptions);
+            match self.solve(&full_assumptions) {
+                None => return true,
+                Some(model) => {
+                    let certificate = certificate_fn(self, &completion, &model);
+                    if certificate.is_empty() {
+                        return false;
+                    }
+                    completion_solver.add_clause(certificate.iter().map(|l| l.negate()).collect());
+                }
+            }
+        }
+        false
+    }
+
+    fn is_present(&self, completion: &[bool], arg_id: usize) -> bool {
+        self.arg_elements[arg_id]
+            .map(|i| completion[i])
+            .unwrap_or(true)
+    }
+
+    // Returns the literals setting the presence of the uncertain arguments as in the completion.
+    fn argument_certificate(&self, completion: &[bool]) -> Vec<Literal> {
+        self.elements
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| matches!(e.kind, CompletionElementKind::Argument))
+            .map(|(i, _)| completion_lit(i, completion[i]))
+            .collect()
+    }
+
+    // Returns the completion literals ensuring the extension given by the model is still conflict-free,
+    // and that each present argument outside the extension it attacks is still attacked.
+    // If the strict flag is set, an argument not attacked by the extension must not attack it.
+    fn certificate(&self, completion: &[bool], model: &Assignment, strict: bool) -> Vec<Literal> {
+        let af = self.iaf.af();
+        let in_ext = |id: usize| {
+            self.is_present(completion, id)
+                && model.value_of(self.arg_lits[id].unwrap().var()) == Some(true)
+        };
+        let mut lits = self.argument_certificate(completion);
+        let mut attack_elements = vec![];
+        let mut attacked_by_ext = vec![false; self.arg_lits.len()];
+        for (i, element) in self.elements.iter().enumerate() {
+            if let CompletionElementKind::Attack(attacker, attacked) = element.kind {
+                attack_elements.push((i, attacker, attacked));
+                if in_ext(attacker) && in_ext(attacked) {
+                    lits.push(completion_lit(i, false));
+                }
+            }
+        }
+        for arg in af.argument_set().iter() {
+            if !self.is_present(completion, arg.id()) || in_ext(arg.id()) {
+                continue;
+            }
+            let certain_attack = af.iter_attacks_to(arg).any(|att| {
+                in_ext(att.attacker().id())
+                    && !self
+                        .iaf
+                        .is_uncertain_attack_ids(att.attacker().id(), arg.id())
+            });
+            if certain_attack {
+                attacked_by_ext[arg.id()] = true;
+                continue;
+            }
+            if let Some((i, _, _)) = attack_elements
+                .iter()
+                .find(|(i, b, a)| *a == arg.id() && in_ext(*b) && completion[*i])
+            {
+                attacked_by_ext[arg.id()] = true;
+                lits.push(completion_lit(*i, true));
+            }
+        }
+        if strict {
+            for (i, b, a) in attack_elements.iter() {
+                if self.is_present(completion, *b)
+                    && !in_ext(*b)
+                    && !attacked_by_ext[*b]
+                    && in_ext(*a)
+                {
+                    lits.push(completion_lit(*i, false));
+                }
+            }
+        }
+        lits
+    }
+
+    // A certificate for a stable extension.
+    fn stability_certificate(&self, completion: &[bool], model: &Assignment) -> Vec<Literal> {
+        self.certificate(completion, model, false)
+    }
+
+    // A certificate for an admissible set, given by the complete extension of the model.
+    fn admissibility_certificate(&self, completion: &[bool], model: &Assignment) -> Vec<Literal> {
+        self.certificate(completion, model, true)
+    }
+
+    // A certificate made of the whole completion.
+    fn completion_certificate(&self, completion: &[bool], _model: &Assignment) -> Vec<Literal> {
+        (0..self.elements.len())
+            .map(|i| completion_lit(i, completion[i]))
+            .collect()
+    }
+}
+
+type CertificateFn<'a, T> =
+    fn(&IncompleteFrameworkSolver<'a, T>, &[bool], &Assignment) -> Vec<Literal>;
+
+// The literal of the completion solver stating the presence (or absence) of an element.
+fn completion_lit(element_index: usize, present: bool) -> Literal {
+    let lit = Literal::from((element_index + 1) as isize);
+    if present {
+        lit
+    } else {
+        lit.negate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aa::ArgumentSet;
+
+    fn iaf_with_uncertain_defense() -> IncompleteFramework<&'static str> {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d"]);
+        let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+        iaf.set_uncertain_argument(&"c").unwrap();
+        iaf.new_attack(&"b", &"a").unwrap();
+        iaf.new_attack(&"c", &"b").unwrap();
+        iaf.new_uncertain_attack(&"d", &"c").unwrap();
+        iaf
+    }
+
+    #[test]
+    fn test_acceptance() {
+        let iaf = iaf_with_uncertain_defense();
+        for semantics in [Semantics::CO, Semantics::ST] {
+            let mut solver = IncompleteFrameworkSolver::new(&iaf, semantics).unwrap();
+            assert!(solver.is_possibly_credulously_accepted(&"a"));
+            assert!(!solver.is_necessarily_credulously_accepted(&"a"));
+            assert!(solver.is_possibly_skeptically_accepted(&"a"));
+            assert!(!solver.is_necessarily_skeptically_accepted(&"a"));
+            assert!(solver.is_possibly_credulously_accepted(&"b"));
+            assert!(solver.is_possibly_credulously_accepted(&"c"));
+            assert!(!solver.is_necessarily_credulously_accepted(&"c"));
+            assert!(solver.is_necessarily_skeptically_accepted(&"d"));
+        }
+    }
+
+    #[test]
+    fn test_necessary_credulous_acceptance() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+        let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+        iaf.new_attack(&"a", &"b").unwrap();
+        iaf.new_attack(&"b", &"a").unwrap();
+        iaf.new_uncertain_attack(&"b", &"b").unwrap();
+        for semantics in [Semantics::CO, Semantics::ST] {
+            let mut solver = IncompleteFrameworkSolver::new(&iaf, semantics).unwrap();
+            assert!(solver.is_necessarily_credulously_accepted(&"a"));
+            assert!(!solver.is_necessarily_skeptically_accepted(&"a"));
+            assert_eq!(
+                semantics == Semantics::ST,
+                solver.is_possibly_skeptically_accepted(&"a")
+            );
+            assert!(!solver.is_necessarily_credulously_accepted(&"b"));
+            assert!(!solver.is_possibly_skeptically_accepted(&"b"));
+        }
+    }
+
+    #[test]
+    fn test_verification() {
+        let iaf = iaf_with_uncertain_defense();
+        let mut solver = IncompleteFrameworkSolver::new(&iaf, Semantics::CO).unwrap();
+        assert!(solver.is_possible_extension(&[&"a", &"c", &"d"]));
+        assert!(solver.is_possible_extension(&[&"b", &"d"]));
+        assert!(!solver.is_possible_extension(&[&"a", &"d"]));
+        assert!(!solver.is_possible_extension(&[&"d"]));
+        assert!(!solver.is_necessary_extension(&[&"b", &"d"]));
+        let mut solver = IncompleteFrameworkSolver::new(&iaf, Semantics::ST).unwrap();
+        assert!(solver.is_possible_extension(&[&"b", &"d"]));
+        assert!(!solver.is_possible_extension(&[&"d"]));
+        assert!(!solver.is_necessary_extension(&[&"b", &"d"]));
+    }
+
+    #[test]
+    fn test_necessary_extension() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut iaf = IncompleteFramework::new_with_argument_set(arguments);
+        iaf.set_uncertain_argument(&"b").unwrap();
+        iaf.new_attack(&"a", &"b").unwrap();
+        iaf.new_uncertain_attack(&"b", &"c").unwrap();
+        for semantics in [Semantics::CO, Semantics::ST] {
+            let mut solver = IncompleteFrameworkSolver::new(&iaf, semantics).unwrap();
+            assert!(solver.is_necessary_extension(&[&"a", &"c"]));
+            assert!(!solver.is_necessary_extension(&[&"a"]));
+            assert!(!solver.is_necessary_extension(&[&"a", &"b"]));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_semantics() {
+        let iaf = iaf_with_uncertain_defense();
+        for semantics in [Semantics::GR, Semantics::PR] {
+            assert_eq!(
+                "unsupported semantics for IAFs: ".to_string() + semantics.as_ref(),
+                IncompleteFrameworkSolver::new(&iaf, semantics)
+                    .err()
+                    .unwrap()
+                    .to_string()
+            );
+        }
+    }
+}
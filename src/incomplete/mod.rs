@@ -0,0 +1,7 @@
+//! Incomplete argumentation frameworks, in which some arguments and attacks are uncertain, and their solvers.
+
+mod incomplete_framework;
+pub use incomplete_framework::IncompleteFramework;
+
+mod incomplete_framework_solver;
+pub use incomplete_framework_solver::IncompleteFrameworkSolver;
@@ -0,0 +1,198 @@
+use super::{
+    aspartix_reader::{self, AspartixLine, ARG_AND_SPACE_PATTERN},
+    warning_result::WarningResult,
+    WarningHandler,
+};
+use crate::{
+    aa::{Argument, ArgumentSet},
+    bipolar::BipolarFramework,
+};
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::io::Read;
+
+lazy_static! {
+    static ref SUPPORT_LINE_PATTERN: Regex =
+        Regex::new(r"^\s*support\([^,]+,[^)]+\).\s*$").unwrap();
+    static ref SUPPORT_LINE_ARG_NAMES_PATTERN: Regex = Regex::new(&format!(
+        r"^\s*support\(({}),({})\).\s*$",
+        ARG_AND_SPACE_PATTERN, ARG_AND_SPACE_PATTERN,
+    ))
+    .unwrap();
+}
+
+fn try_read_support_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
+where
+    T: AsRef<str>,
+{
+    if SUPPORT_LINE_PATTERN.is_match(l.as_ref()) {
+        let captures = SUPPORT_LINE_ARG_NAMES_PATTERN.captures(l.as_ref());
+        match captures {
+            Some(c) => Ok(Some(
+                aspartix_reader::captured_arg(&c, 1).zip(aspartix_reader::captured_arg(&c, 2)),
+            )),
+            None => Err(anyhow!("invalid argument names in {}", l.as_ref().trim())),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// A reader for bipolar frameworks encoded with the Aspartix format.
+///
+/// This object is used to read a [`BipolarFramework`] encoded using the Aspartix format extended with a `support` predicate.
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`String`].
+///
+/// The `att` and `support` lines may appear in any order, but they must follow the argument declarations.
+///
+/// # Aspartix format for bipolar frameworks
+///
+/// The following content defines a bipolar framework with three arguments labelled `a`, `b` and `c`,
+/// in which `a` supports `b` and `c` attacks `b`.
+///
+/// ```text
+/// arg(a).
+/// arg(b).
+/// arg(c).
+/// support(a,b).
+/// att(c,b).
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::io::AspartixBipolarReader;
+/// # use crustabri::bipolar::BipolarFramework;
+/// fn read_baf_from_str(s: &str) -> BipolarFramework<String> {
+///     let reader = AspartixBipolarReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid Aspartix bipolar framework")
+/// }
+/// # read_baf_from_str("arg(a).\narg(b).\nsupport(a,b).");
+/// ```
+#[derive(Default)]
+pub struct AspartixBipolarReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl AspartixBipolarReader {
+    /// Reads a bipolar framework.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<BipolarFramework<String>> {
+        let mut arg_labels = vec![];
+        let mut baf = None;
+        aspartix_reader::read_lines(
+            reader,
+            &self.warning_handlers,
+            "an attack or a support",
+            |_, line, warning_consumer| {
+                let l = match line {
+                    AspartixLine::Argument(a) => {
+                        arg_labels.push(a);
+                        return Ok(true);
+                    }
+                    AspartixLine::Other(l) => l,
+                };
+                if let Some(result) = aspartix_reader::try_read_att_line(l)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    baf.get_or_insert_with(|| new_baf(&arg_labels))
+                        .new_attack(&a, &b)?;
+                    return Ok(true);
+                }
+                if let Some(result) = try_read_support_line(l)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    baf.get_or_insert_with(|| new_baf(&arg_labels))
+                        .new_support(&a, &b)?;
+                    return Ok(true);
+                }
+                Ok(false)
+            },
+        )?;
+        Ok(baf.unwrap_or_else(|| new_baf(&arg_labels)))
+    }
+
+    /// Returns the argument which label corresponds to the provided string.
+    ///
+    /// If no argument matches the label, an error is returned.
+    pub fn read_arg_from_str<'a>(
+        &self,
+        baf: &'a BipolarFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        baf.argument_set().get_argument(&arg.to_string())
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+fn new_baf(arg_labels: &[String]) -> BipolarFramework<String> {
+    BipolarFramework::new_with_argument_set(ArgumentSet::new_with_labels(arg_labels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn test_ok() {
+        let instance = "arg(a).\narg(b).\narg(c).\nsupport(a,b).\natt(c,b).\nsupport(c,a).\n";
+        let baf = AspartixBipolarReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(3, baf.n_arguments());
+        assert_eq!(1, baf.n_attacks());
+        assert_eq!(
+            vec!["a -> b", "c -> a"],
+            baf.iter_supports()
+                .map(|s| format!("{} -> {}", s.supporter(), s.supported()))
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn test_no_support() {
+        let baf = AspartixBipolarReader::default()
+            .read(&mut "arg(a).\narg(b).\n".as_bytes())
+            .unwrap();
+        assert_eq!(2, baf.n_arguments());
+        assert_eq!(0, baf.n_supports());
+    }
+
+    #[test]
+    fn test_warning() {
+        let warnings = Rc::new(RefCell::new(vec![]));
+        let warnings_clone = Rc::clone(&warnings);
+        let mut reader = AspartixBipolarReader::default();
+        reader.add_warning_handler(Box::new(move |i, w| {
+            warnings_clone.borrow_mut().push((i, w))
+        }));
+        let instance = "arg(a).\narg(b).\nsupport(a, b).\n";
+        let baf = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(1, baf.n_supports());
+        assert_eq!(1, warnings.borrow().len());
+        assert_eq!(3, warnings.borrow()[0].0);
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "arg(a).\nsupport(a,b).\n",
+            "arg(a).\nsupport(a,).\n",
+            "arg(a).\nsupport(a,a).\narg(b).\n",
+            "arg(a).\nsupports(a,a).\n",
+        ] {
+            assert!(
+                AspartixBipolarReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use super::{
+    aspartix_reader::{self, AspartixLine},
+    warning_result::WarningResult,
+    WarningHandler,
+};
+use crate::{
+    aa::{Argument, ArgumentSet},
+    incomplete::IncompleteFramework,
+};
+use anyhow::Result;
+use std::io::Read;
+
+/// A reader for IAFs encoded with the Aspartix format.
+///
+/// This object is used to read an [`IncompleteFramework`] encoded using the Aspartix format,
+/// in which the uncertain arguments and attacks are declared by prefixing their line with a question mark.
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`String`].
+///
+/// As in the Aspartix format for AFs, the argument declarations must precede the attacks.
+///
+/// # Aspartix format for IAFs
+///
+/// The following content defines an IAF with a certain argument `a`, an uncertain argument `b`,
+/// a certain attack from `a` to `b` and an uncertain attack from `b` to `a`.
+///
+/// ```text
+/// arg(a).
+/// ?arg(b).
+/// att(a,b).
+/// ?att(b,a).
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::io::AspartixIncompleteReader;
+/// # use crustabri::incomplete::IncompleteFramework;
+/// fn read_iaf_from_str(s: &str) -> IncompleteFramework<String> {
+///     let reader = AspartixIncompleteReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid Aspartix IAF")
+/// }
+/// # read_iaf_from_str("arg(a).\n?arg(b).\n?att(a,b).");
+/// ```
+#[derive(Default)]
+pub struct AspartixIncompleteReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl AspartixIncompleteReader {
+    /// Reads an IAF.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<IncompleteFramework<String>> {
+        let mut arg_labels = vec![];
+        let mut iaf = None;
+        aspartix_reader::read_lines_with_arg_line_reader(
+            reader,
+            &self.warning_handlers,
+            "an attack",
+            try_read_arg_line,
+            |_, line, warning_consumer| {
+                let l = match line {
+                    AspartixLine::Argument(a) => {
+                        arg_labels.push(a);
+                        return Ok(true);
+                    }
+                    AspartixLine::Other(l) => l,
+                };
+                let (uncertain, content) = split_uncertainty_marker(l);
+                if let Some(result) = aspartix_reader::try_read_att_line(content)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    let framework = iaf.get_or_insert_with(|| new_iaf(&arg_labels));
+                    if uncertain {
+                        framework.new_uncertain_attack(&a, &b)?;
+                    } else {
+                        framework.new_attack(&a, &b)?;
+                    }
+                    return Ok(true);
+                }
+                Ok(false)
+            },
+        )?;
+        Ok(iaf.unwrap_or_else(|| new_iaf(&arg_labels)))
+    }
+
+    /// Returns the argument which label corresponds to the provided string.
+    ///
+    /// If no argument matches the label, an error is returned.
+    pub fn read_arg_from_str<'a>(
+        &self,
+        iaf: &'a IncompleteFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        iaf.argument_set().get_argument(&arg.to_string())
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+fn split_uncertainty_marker(l: &str) -> (bool, &str) {
+    match l.trim_start().strip_prefix('?') {
+        Some(content) => (true, content),
+        None => (false, l),
+    }
+}
+
+fn try_read_arg_line(l: &str) -> Result<Option<WarningResult<(String, bool), String>>> {
+    let (uncertain, content) = split_uncertainty_marker(l);
+    Ok(aspartix_reader::try_read_arg_line(content)?.map(|a| a.zip(WarningResult::Ok(uncertain))))
+}
+
+fn new_iaf(arg_labels: &[(String, bool)]) -> IncompleteFramework<String> {
+    let labels = arg_labels
+        .iter()
+        .map(|(l, _)| l.clone())
+        .collect::<Vec<String>>();
+    let mut iaf = IncompleteFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+    arg_labels
+        .iter()
+        .filter(|(_, uncertain)| *uncertain)
+        .for_each(|(l, _)| iaf.set_uncertain_argument(l).unwrap());
+    iaf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ok() {
+        let instance = "arg(a).\n?arg(b).\n arg(c).\natt(a,b).\n?att(b,a).\n ?att(c,c).\n";
+        let iaf = AspartixIncompleteReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(3, iaf.n_arguments());
+        assert_eq!(1, iaf.n_uncertain_arguments());
+        let b = iaf.argument_set().get_argument(&"b".to_string()).unwrap();
+        assert!(iaf.is_uncertain_argument(b));
+        assert_eq!(3, iaf.n_attacks());
+        assert_eq!(2, iaf.n_uncertain_attacks());
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "arg(a).\n?att(a,b).\n",
+            "arg(a).\n??arg(b).\n",
+            "arg(a).\n?foo(a).\n",
+            "arg(a).\n?att(a,).\n",
+            "arg(a).\n?att(a,a).\n?arg(b).\n",
+        ] {
+            assert!(
+                AspartixIncompleteReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use super::{
+    aspartix_reader::{self, AspartixLine, ARG_AND_SPACE_PATTERN},
+    warning_result::WarningResult,
+    WarningHandler,
+};
+use crate::{
+    aa::{Argument, ArgumentSet},
+    preferences::PafFramework,
+};
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::io::Read;
+
+lazy_static! {
+    static ref PREF_LINE_PATTERN: Regex = Regex::new(r"^\s*pref\([^,]+,[^)]+\).\s*$").unwrap();
+    static ref PREF_LINE_ARG_NAMES_PATTERN: Regex = Regex::new(&format!(
+        r"^\s*pref\(({}),({})\).\s*$",
+        ARG_AND_SPACE_PATTERN, ARG_AND_SPACE_PATTERN,
+    ))
+    .unwrap();
+}
+
+fn try_read_pref_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
+where
+    T: AsRef<str>,
+{
+    if PREF_LINE_PATTERN.is_match(l.as_ref()) {
+        let captures = PREF_LINE_ARG_NAMES_PATTERN.captures(l.as_ref());
+        match captures {
+            Some(c) => Ok(Some(
+                aspartix_reader::captured_arg(&c, 1).zip(aspartix_reader::captured_arg(&c, 2)),
+            )),
+            None => Err(anyhow!("invalid argument names in {}", l.as_ref().trim())),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// A reader for PAFs encoded with the Aspartix format.
+///
+/// This object is used to read a [`PafFramework`] encoded using the Aspartix format extended with a `pref` predicate,
+/// where `pref(a,b)` states that `a` is strictly preferred to `b`.
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`String`].
+///
+/// The `att` and `pref` lines may appear in any order, but they must follow the argument declarations.
+///
+/// # Aspartix format for PAFs
+///
+/// The following content defines a PAF with two arguments labelled `a` and `b` which attack each other,
+/// `b` being preferred to `a`.
+///
+/// ```text
+/// arg(a).
+/// arg(b).
+/// att(a,b).
+/// att(b,a).
+/// pref(b,a).
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::io::AspartixPafReader;
+/// # use crustabri::preferences::PafFramework;
+/// fn read_paf_from_str(s: &str) -> PafFramework<String> {
+///     let reader = AspartixPafReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid Aspartix PAF")
+/// }
+/// # read_paf_from_str("arg(a).\narg(b).\npref(a,b).");
+/// ```
+#[derive(Default)]
+pub struct AspartixPafReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl AspartixPafReader {
+    /// Reads a PAF.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<PafFramework<String>> {
+        let mut arg_labels = vec![];
+        let mut paf = None;
+        aspartix_reader::read_lines(
+            reader,
+            &self.warning_handlers,
+            "an attack or a preference",
+            |_, line, warning_consumer| {
+                let l = match line {
+                    AspartixLine::Argument(a) => {
+                        arg_labels.push(a);
+                        return Ok(true);
+                    }
+                    AspartixLine::Other(l) => l,
+                };
+                if let Some(result) = aspartix_reader::try_read_att_line(l)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    paf.get_or_insert_with(|| new_paf(&arg_labels))
+                        .new_attack(&a, &b)?;
+                    return Ok(true);
+                }
+                if let Some(result) = try_read_pref_line(l)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    paf.get_or_insert_with(|| new_paf(&arg_labels))
+                        .new_preference(&a, &b)?;
+                    return Ok(true);
+                }
+                Ok(false)
+            },
+        )?;
+        Ok(paf.unwrap_or_else(|| new_paf(&arg_labels)))
+    }
+
+    /// Returns the argument which label corresponds to the provided string.
+    ///
+    /// If no argument matches the label, an error is returned.
+    pub fn read_arg_from_str<'a>(
+        &self,
+        paf: &'a PafFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        paf.argument_set().get_argument(&arg.to_string())
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+fn new_paf(arg_labels: &[String]) -> PafFramework<String> {
+    PafFramework::new_with_argument_set(ArgumentSet::new_with_labels(arg_labels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preferences::PreferenceReduction;
+
+    #[test]
+    fn test_ok() {
+        let instance = "arg(a).\narg(b).\npref(b,a).\natt(a,b).\natt(b,a).\n";
+        let paf = AspartixPafReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(2, paf.n_arguments());
+        assert_eq!(2, paf.n_attacks());
+        assert_eq!(1, paf.n_preferences());
+        assert_eq!(1, paf.reduce(PreferenceReduction::Removal).n_attacks());
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "arg(a).\npref(a,b).\n",
+            "arg(a).\npref(a,a).\n",
+            "arg(a).\narg(b).\npref(a,).\n",
+            "arg(a).\narg(b).\npref(a,b).\narg(c).\n",
+            "arg(a).\narg(b).\npref(a,b).\npref(b,a).\n",
+        ] {
+            assert!(
+                AspartixPafReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+}
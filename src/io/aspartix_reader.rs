@@ -5,7 +5,7 @@ use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 use std::io::{BufRead, BufReader, Read};
 
-const ARG_AND_SPACE_PATTERN: &str = r"\s*[_[:alpha:]][_[:alpha:]\d]*\s*";
+pub(crate) const ARG_AND_SPACE_PATTERN: &str = r"\s*[_[:alpha:]][_[:alpha:]\d]*\s*";
 
 lazy_static! {
     static ref ARG_LINE_PATTERN: Regex = Regex::new(r"^\s*arg\([^)]+\).\s*$").unwrap();
@@ -21,7 +21,7 @@ lazy_static! {
 
 const DEFAULT_ARG_LABELS_CAP: usize = 1 << 10;
 
-fn captured_arg(c: &Captures, i: usize) -> WarningResult<String, String> {
+pub(crate) fn captured_arg(c: &Captures, i: usize) -> WarningResult<String, String> {
     let str_arg = c.get(i).unwrap().as_str();
     let trimmed_str_arg = str_arg.trim().to_string();
     if trimmed_str_arg.len() == str_arg.len() {
@@ -34,7 +34,7 @@ fn captured_arg(c: &Captures, i: usize) -> WarningResult<String, String> {
     }
 }
 
-fn try_read_arg_line<T>(l: T) -> Result<Option<WarningResult<String, String>>>
+pub(crate) fn try_read_arg_line<T>(l: T) -> Result<Option<WarningResult<String, String>>>
 where
     T: AsRef<str>,
 {
@@ -49,7 +49,7 @@ where
     }
 }
 
-fn try_read_att_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
+pub(crate) fn try_read_att_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
 where
     T: AsRef<str>,
 {
@@ -64,6 +64,88 @@ where
     }
 }
 
+pub(crate) type ArgLineReader<A> = fn(&str) -> Result<Option<WarningResult<A, String>>>;
+
+// A line read by one of the `read_lines` functions.
+pub(crate) enum AspartixLine<'a, A> {
+    // An argument declaration, given by the value returned by the argument line reader.
+    Argument(A),
+    // Any other line, which may follow the argument declarations.
+    Other(&'a str),
+}
+
+// Reads an instance given in the Aspartix format or in one of its extensions, line by line.
+//
+// Blank lines are ignored; the other ones are given to the callback, along with their index and a function consuming the warnings they raise.
+// The argument declarations must come before all the other lines, described by `other_lines` in the error message raised otherwise.
+// The callback returns `false` if it does not recognize a line, in which case a syntax error is returned.
+pub(crate) fn read_lines<F>(
+    reader: &mut dyn Read,
+    warning_handlers: &[WarningHandler],
+    other_lines: &str,
+    line_fn: F,
+) -> Result<()>
+where
+    F: FnMut(usize, AspartixLine<String>, &dyn Fn(Vec<String>)) -> Result<bool>,
+{
+    read_lines_with_arg_line_reader(
+        reader,
+        warning_handlers,
+        other_lines,
+        |l| try_read_arg_line(l),
+        line_fn,
+    )
+}
+
+// Same as `read_lines`, but the argument declarations are recognized by the provided reader.
+pub(crate) fn read_lines_with_arg_line_reader<A, F>(
+    reader: &mut dyn Read,
+    warning_handlers: &[WarningHandler],
+    other_lines: &str,
+    arg_line_reader: ArgLineReader<A>,
+    mut line_fn: F,
+) -> Result<()>
+where
+    F: FnMut(usize, AspartixLine<A>, &dyn Fn(Vec<String>)) -> Result<bool>,
+{
+    let mut read_other_lines = false;
+    let br = BufReader::new(reader);
+    for (i, line) in br.lines().enumerate() {
+        let context = || format!("while reading line with index {}", i);
+        let warning_consumer = |warnings: Vec<String>| {
+            for w in warnings.iter() {
+                warning_handlers
+                    .iter()
+                    .for_each(|h| (h)(1 + i, w.to_string()));
+            }
+        };
+        let l = &line.with_context(context)?;
+        if l.trim().is_empty() {
+            continue;
+        }
+        let aspartix_line = match (arg_line_reader)(l).with_context(context)? {
+            Some(a) => {
+                if read_other_lines {
+                    return Err(anyhow!(
+                        "found an argument declaration after {}",
+                        other_lines
+                    ))
+                    .with_context(context);
+                }
+                AspartixLine::Argument(a.consume_warnings(warning_consumer))
+            }
+            None => {
+                read_other_lines = true;
+                AspartixLine::Other(l)
+            }
+        };
+        if !(line_fn)(i, aspartix_line, &warning_consumer).with_context(context)? {
+            return Err(anyhow!("syntax error in line \"{}\"", l)).with_context(context);
+        }
+    }
+    Ok(())
+}
+
 /// A reader for the Aspartix format.
 ///
 /// This object is used to read an [`AAFramework`] encoded using the Aspartix input format, as defined on [the Aspartix website](https://www.dbai.tuwien.ac.at/research/argumentation/aspartix/dung.html).
@@ -102,43 +184,29 @@ impl InstanceReader<String> for AspartixReader {
     fn read(&self, reader: &mut dyn Read) -> Result<AAFramework<String>> {
         let mut arg_labels = Vec::with_capacity(DEFAULT_ARG_LABELS_CAP);
         let mut af = None;
-        let br = BufReader::new(reader);
-        for (i, line) in br.lines().enumerate() {
-            let context = || format!("while reading line with index {}", i);
-            let warning_consumer = |warnings: Vec<String>| {
-                for w in warnings.iter() {
-                    self.warning_handlers
-                        .iter()
-                        .for_each(|h| (h)(1 + i, w.to_string()));
+        read_lines(
+            reader,
+            &self.warning_handlers,
+            "an attack",
+            |_, line, warning_consumer| {
+                match line {
+                    AspartixLine::Argument(a) => arg_labels.push(a),
+                    AspartixLine::Other(l) => match try_read_att_line(l)? {
+                        Some(result) => {
+                            let (a, b) = result.consume_warnings(warning_consumer);
+                            af.get_or_insert_with(|| {
+                                AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(
+                                    &arg_labels,
+                                ))
+                            })
+                            .new_attack(&a, &b)?;
+                        }
+                        None => return Ok(false),
+                    },
                 }
-            };
-            let l = &line.with_context(context)?;
-            if l.trim().is_empty() {
-                continue;
-            }
-            if let Some(a) = try_read_arg_line(l).with_context(context)? {
-                if af.is_some() {
-                    return Err(anyhow!("found an argument declaration after an attack"))
-                        .with_context(context);
-                }
-                arg_labels.push(a.consume_warnings(warning_consumer));
-                continue;
-            }
-            if let Some(result) = try_read_att_line(l).with_context(context)? {
-                let (a, b) = result.consume_warnings(warning_consumer);
-                if af.is_none() {
-                    af = Some(AAFramework::new_with_argument_set(
-                        ArgumentSet::new_with_labels(&arg_labels),
-                    ));
-                }
-                af.as_mut()
-                    .unwrap()
-                    .new_attack(&a, &b)
-                    .with_context(context)?;
-                continue;
-            }
-            return Err(anyhow!("syntax error in line \"{}\"", l)).with_context(context);
-        }
+                Ok(true)
+            },
+        )?;
         match af {
             Some(a) => Ok(a),
             None => Ok(AAFramework::new_with_argument_set(
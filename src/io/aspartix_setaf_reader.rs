@@ -0,0 +1,247 @@
+use super::{
+    aspartix_reader::{self, AspartixLine, ARG_AND_SPACE_PATTERN},
+    warning_result::WarningResult,
+    WarningHandler,
+};
+use crate::{
+    aa::{Argument, ArgumentSet},
+    setaf::SetafFramework,
+};
+use anyhow::{anyhow, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{collections::HashMap, io::Read};
+
+lazy_static! {
+    static ref MEM_LINE_PATTERN: Regex = Regex::new(r"^\s*mem\([^,]+,[^)]+\).\s*$").unwrap();
+    static ref MEM_LINE_NAMES_PATTERN: Regex = Regex::new(&format!(
+        r"^\s*mem\(({}),({})\).\s*$",
+        ARG_AND_SPACE_PATTERN, ARG_AND_SPACE_PATTERN,
+    ))
+    .unwrap();
+}
+
+fn try_read_mem_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
+where
+    T: AsRef<str>,
+{
+    if MEM_LINE_PATTERN.is_match(l.as_ref()) {
+        let captures = MEM_LINE_NAMES_PATTERN.captures(l.as_ref());
+        match captures {
+            Some(c) => Ok(Some(
+                aspartix_reader::captured_arg(&c, 1).zip(aspartix_reader::captured_arg(&c, 2)),
+            )),
+            None => Err(anyhow!("invalid names in {}", l.as_ref().trim())),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+struct AttackData {
+    line_index: usize,
+    attacked: Option<String>,
+    attackers: Vec<String>,
+}
+
+// Returns the data of an attack, registering the attack if it is encountered for the first time.
+fn attack_data<'a>(
+    attacks: &'a mut HashMap<String, AttackData>,
+    attack_names: &mut Vec<String>,
+    name: String,
+    line_index: usize,
+) -> &'a mut AttackData {
+    attacks.entry(name.clone()).or_insert_with(|| {
+        attack_names.push(name);
+        AttackData {
+            line_index,
+            ..Default::default()
+        }
+    })
+}
+
+/// A reader for SETAFs encoded with the Aspartix format.
+///
+/// This object is used to read a [`SetafFramework`] encoded using the extension of the Aspartix input format to collective attacks.
+/// Each attack is given a name, used to declare its target with an `att` predicate and its attackers with `mem` predicates.
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`String`].
+///
+/// The `att` and `mem` lines may appear in any order, but they must follow the argument declarations.
+///
+/// # Aspartix format for SETAFs
+///
+/// The following content defines a SETAF with three arguments labelled `a`, `b` and `c` and two attacks:
+/// `a` and `b` collectively attack `c`, and `c` attacks `a`.
+///
+/// ```text
+/// arg(a).
+/// arg(b).
+/// arg(c).
+/// att(r1,c).
+/// mem(r1,a).
+/// mem(r1,b).
+/// att(r2,a).
+/// mem(r2,c).
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::io::AspartixSetafReader;
+/// # use crustabri::setaf::SetafFramework;
+/// fn read_setaf_from_str(s: &str) -> SetafFramework<String> {
+///     let reader = AspartixSetafReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid Aspartix SETAF")
+/// }
+/// # read_setaf_from_str("arg(a).\natt(r,a).\nmem(r,a).");
+/// ```
+#[derive(Default)]
+pub struct AspartixSetafReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl AspartixSetafReader {
+    /// Reads a SETAF.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<SetafFramework<String>> {
+        let mut arg_labels = vec![];
+        let mut attack_names = vec![];
+        let mut attacks: HashMap<String, AttackData> = HashMap::new();
+        aspartix_reader::read_lines(
+            reader,
+            &self.warning_handlers,
+            "an attack",
+            |i, line, warning_consumer| {
+                let l = match line {
+                    AspartixLine::Argument(a) => {
+                        arg_labels.push(a);
+                        return Ok(true);
+                    }
+                    AspartixLine::Other(l) => l,
+                };
+                if let Some(result) = aspartix_reader::try_read_att_line(l)? {
+                    let (name, attacked) = result.consume_warnings(warning_consumer);
+                    let data = attack_data(&mut attacks, &mut attack_names, name.clone(), i);
+                    match &data.attacked {
+                        Some(a) if a != &attacked => {
+                            return Err(anyhow!("attack {} has two targets", name))
+                        }
+                        _ => data.attacked = Some(attacked),
+                    }
+                    return Ok(true);
+                }
+                if let Some(result) = try_read_mem_line(l)? {
+                    let (name, attacker) = result.consume_warnings(warning_consumer);
+                    attack_data(&mut attacks, &mut attack_names, name, i)
+                        .attackers
+                        .push(attacker);
+                    return Ok(true);
+                }
+                Ok(false)
+            },
+        )?;
+        let mut setaf = SetafFramework::new_with_argument_set(ArgumentSet::new_with_labels(
+            arg_labels.as_slice(),
+        ));
+        for name in attack_names {
+            let data = attacks.remove(&name).unwrap();
+            let context = || format!("while reading line with index {}", data.line_index);
+            let attacked = data
+                .attacked
+                .as_ref()
+                .ok_or_else(|| anyhow!("attack {} has no target", name))
+                .with_context(context)?;
+            let attackers = data.attackers.iter().collect::<Vec<&String>>();
+            if attackers.is_empty() {
+                return Err(anyhow!("attack {} has no attackers", name)).with_context(context);
+            }
+            setaf
+                .new_attack(&attackers, attacked)
+                .with_context(context)?;
+        }
+        Ok(setaf)
+    }
+
+    /// Returns the argument which label corresponds to the provided string.
+    ///
+    /// If no argument matches the label, an error is returned.
+    pub fn read_arg_from_str<'a>(
+        &self,
+        setaf: &'a SetafFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        setaf.argument_set().get_argument(&arg.to_string())
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn attacks_as_strings(setaf: &SetafFramework<String>) -> Vec<String> {
+        setaf
+            .iter_attacks()
+            .map(|att| {
+                let attackers = att
+                    .attackers()
+                    .iter()
+                    .map(|a| a.to_string())
+                    .collect::<Vec<String>>();
+                format!("{} -> {}", attackers.join(" "), att.attacked())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ok() {
+        let instance = "arg(a).\narg(b).\narg(c).\natt(r1,c).\nmem(r1,a).\nmem(r1,b).\nmem(r2,c).\natt(r2,a).\n";
+        let setaf = AspartixSetafReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(3, setaf.n_arguments());
+        assert_eq!(vec!["a b -> c", "c -> a"], attacks_as_strings(&setaf));
+    }
+
+    #[test]
+    fn test_warning() {
+        let warnings = Rc::new(RefCell::new(vec![]));
+        let warnings_clone = Rc::clone(&warnings);
+        let mut reader = AspartixSetafReader::default();
+        reader.add_warning_handler(Box::new(move |i, w| {
+            warnings_clone.borrow_mut().push((i, w))
+        }));
+        let instance = "arg(a).\natt(r, a).\nmem(r,a).\n";
+        let setaf = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(vec!["a -> a"], attacks_as_strings(&setaf));
+        assert_eq!(1, warnings.borrow().len());
+        assert_eq!(2, warnings.borrow()[0].0);
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "arg(a).\natt(r,a).\n",
+            "arg(a).\nmem(r,a).\n",
+            "arg(a).\natt(r,a).\natt(r,b).\nmem(r,a).\n",
+            "arg(a).\natt(r,a).\nmem(r,b).\n",
+            "arg(a).\natt(r,a).\nmem(r,a).\narg(b).\n",
+            "arg(a).\nfoo(r,a).\n",
+        ] {
+            assert!(
+                AspartixSetafReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+}
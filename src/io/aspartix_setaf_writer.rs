@@ -0,0 +1,83 @@
+use crate::{setaf::SetafFramework, utils::LabelType};
+use anyhow::{Context, Result};
+use std::io::Write;
+
+/// A writer for SETAFs using the Aspartix format.
+///
+/// This object is able to write a [`SetafFramework`] using the extension of the Aspartix format to collective attacks,
+/// as described in the [`AspartixSetafReader`](super::AspartixSetafReader) documentation.
+/// The attacks are named `r1`, `r2`, and so on.
+///
+/// # Example
+///
+/// The following example retrieves a SETAF and writes it to the standard output using the Aspartix format.
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::io::AspartixSetafWriter;
+/// # use crustabri::setaf::SetafFramework;
+/// # use crustabri::utils::LabelType;
+/// # use anyhow::Result;
+/// fn write_setaf_to_stdout<T: LabelType>(setaf: &SetafFramework<T>) -> Result<()> {
+///     let writer = AspartixSetafWriter::default();
+///     writer.write_framework(&setaf, &mut std::io::stdout())
+/// }
+/// # write_setaf_to_stdout(&SetafFramework::new_with_argument_set(ArgumentSet::new_with_labels(&[] as &[String])));
+/// ```
+#[derive(Default)]
+pub struct AspartixSetafWriter;
+
+impl AspartixSetafWriter {
+    /// Writes a SETAF using the Aspartix format to the provided writer.
+    pub fn write_framework<T>(
+        &self,
+        framework: &SetafFramework<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        let context = "while writing a framework";
+        for arg in framework.argument_set().iter() {
+            writeln!(writer, "arg({}).", arg).context(context)?;
+        }
+        for (i, attack) in framework.iter_attacks().enumerate() {
+            writeln!(writer, "att(r{},{}).", i + 1, attack.attacked()).context(context)?;
+            for attacker in attack.attackers() {
+                writeln!(writer, "mem(r{},{}).", i + 1, attacker).context(context)?;
+            }
+        }
+        writer.flush().context(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aa::ArgumentSet, io::AspartixSetafReader};
+    use std::io::BufWriter;
+
+    #[test]
+    fn test_write_setaf() {
+        let labels = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let mut setaf =
+            SetafFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        setaf
+            .new_attack(&[&labels[0], &labels[1]], &labels[2])
+            .unwrap();
+        setaf.new_attack(&[&labels[2]], &labels[0]).unwrap();
+        let mut buffer = BufWriter::new(Vec::new());
+        AspartixSetafWriter
+            .write_framework(&setaf, &mut buffer)
+            .unwrap();
+        let content = String::from_utf8(buffer.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            "arg(a).\narg(b).\narg(c).\natt(r1,c).\nmem(r1,a).\nmem(r1,b).\natt(r2,a).\nmem(r2,c).\n",
+            content
+        );
+        let read = AspartixSetafReader::default()
+            .read(&mut content.as_bytes())
+            .unwrap();
+        assert_eq!(2, read.n_attacks());
+    }
+}
@@ -0,0 +1,203 @@
+use super::{
+    aspartix_reader::{self, AspartixLine, ARG_AND_SPACE_PATTERN},
+    warning_result::WarningResult,
+    WarningHandler,
+};
+use crate::{
+    aa::{Argument, ArgumentSet},
+    preferences::VafFramework,
+};
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::io::Read;
+
+lazy_static! {
+    static ref VAL_LINE_PATTERN: Regex = Regex::new(r"^\s*val\([^,]+,[^)]+\).\s*$").unwrap();
+    static ref VAL_LINE_NAMES_PATTERN: Regex = Regex::new(&format!(
+        r"^\s*val\(({}),({})\).\s*$",
+        ARG_AND_SPACE_PATTERN, ARG_AND_SPACE_PATTERN,
+    ))
+    .unwrap();
+}
+
+fn try_read_val_line<T>(l: T) -> Result<Option<WarningResult<(String, String), String>>>
+where
+    T: AsRef<str>,
+{
+    if VAL_LINE_PATTERN.is_match(l.as_ref()) {
+        let captures = VAL_LINE_NAMES_PATTERN.captures(l.as_ref());
+        match captures {
+            Some(c) => Ok(Some(
+                aspartix_reader::captured_arg(&c, 1).zip(aspartix_reader::captured_arg(&c, 2)),
+            )),
+            None => Err(anyhow!("invalid names in {}", l.as_ref().trim())),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// A reader for VAFs encoded with the Aspartix format.
+///
+/// This object is used to read a [`VafFramework`] encoded using the Aspartix format extended with a `val` predicate,
+/// where `val(a,v)` states that the value of `a` is `v`.
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`String`].
+///
+/// The `att` and `val` lines may appear in any order, but they must follow the argument declarations.
+/// The audience of the returned framework prefers the values in the order they appear in the input.
+/// A warning is raised for each argument with no value.
+///
+/// # Aspartix format for VAFs
+///
+/// The following content defines a VAF with two arguments labelled `a` and `b` which attack each other,
+/// `a` promoting the value `life` and `b` promoting the value `property`.
+///
+/// ```text
+/// arg(a).
+/// arg(b).
+/// att(a,b).
+/// att(b,a).
+/// val(a,life).
+/// val(b,property).
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::io::AspartixVafReader;
+/// # use crustabri::preferences::VafFramework;
+/// fn read_vaf_from_str(s: &str) -> VafFramework<String> {
+///     let reader = AspartixVafReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid Aspartix VAF")
+/// }
+/// # read_vaf_from_str("arg(a).\nval(a,life).");
+/// ```
+#[derive(Default)]
+pub struct AspartixVafReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl AspartixVafReader {
+    /// Reads a VAF.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<VafFramework<String>> {
+        let mut arg_labels = vec![];
+        let mut vaf = None;
+        aspartix_reader::read_lines(
+            reader,
+            &self.warning_handlers,
+            "an attack or a value",
+            |i, line, warning_consumer| {
+                let l = match line {
+                    AspartixLine::Argument(a) => {
+                        arg_labels.push((i, a));
+                        return Ok(true);
+                    }
+                    AspartixLine::Other(l) => l,
+                };
+                if let Some(result) = aspartix_reader::try_read_att_line(l)? {
+                    let (a, b) = result.consume_warnings(warning_consumer);
+                    vaf.get_or_insert_with(|| new_vaf(&arg_labels))
+                        .new_attack(&a, &b)?;
+                    return Ok(true);
+                }
+                if let Some(result) = try_read_val_line(l)? {
+                    let (a, v) = result.consume_warnings(warning_consumer);
+                    vaf.get_or_insert_with(|| new_vaf(&arg_labels))
+                        .set_value(&a, &v)?;
+                    return Ok(true);
+                }
+                Ok(false)
+            },
+        )?;
+        let vaf = vaf.unwrap_or_else(|| new_vaf(&arg_labels));
+        for (i, label) in arg_labels.iter() {
+            let arg = vaf.argument_set().get_argument(label).unwrap();
+            if vaf.value_of(arg).is_none() {
+                self.warning_handlers
+                    .iter()
+                    .for_each(|h| (h)(1 + i, format!("argument {} has no value", label)));
+            }
+        }
+        Ok(vaf)
+    }
+
+    /// Returns the argument which label corresponds to the provided string.
+    ///
+    /// If no argument matches the label, an error is returned.
+    pub fn read_arg_from_str<'a>(
+        &self,
+        vaf: &'a VafFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        vaf.argument_set().get_argument(&arg.to_string())
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+fn new_vaf(arg_labels: &[(usize, String)]) -> VafFramework<String> {
+    let labels = arg_labels
+        .iter()
+        .map(|(_, l)| l.clone())
+        .collect::<Vec<String>>();
+    VafFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn test_ok() {
+        let instance = "arg(a).\narg(b).\nval(b,property).\natt(a,b).\natt(b,a).\nval(a,life).\n";
+        let vaf = AspartixVafReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(2, vaf.n_arguments());
+        assert_eq!(2, vaf.n_attacks());
+        assert_eq!(vec!["property", "life"], vaf.audience());
+        let a = vaf.argument_set().get_argument(&"a".to_string()).unwrap();
+        assert_eq!(Some("life"), vaf.value_of(a));
+    }
+
+    #[test]
+    fn test_missing_value() {
+        let warnings = Rc::new(RefCell::new(vec![]));
+        let warnings_clone = Rc::clone(&warnings);
+        let mut reader = AspartixVafReader::default();
+        reader.add_warning_handler(Box::new(move |i, w| {
+            warnings_clone.borrow_mut().push((i, w))
+        }));
+        let instance = "arg(a).\narg(b).\nval(a,life).\n";
+        reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![(2, "argument b has no value".to_string())],
+            *warnings.borrow()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "arg(a).\nval(b,life).\n",
+            "arg(a).\nval(a,life).\nval(a,property).\n",
+            "arg(a).\nval(a,).\n",
+            "arg(a).\nval(a,life).\narg(b).\n",
+        ] {
+            assert!(
+                AspartixVafReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+}
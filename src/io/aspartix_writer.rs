@@ -1,6 +1,6 @@
 use super::ResponseWriter;
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling, LabellingValue},
     utils::LabelType,
 };
 use anyhow::{Context, Result};
@@ -10,6 +10,7 @@ use std::io::Write;
 ///
 /// More precisely, the answers to argumentation problems are written this way:
 ///   * extension: the list of argument labels, splitted by spaces
+///   * labelling: the list of the arguments in the `in(a)`, `out(a)` or `undec(a)` form, splitted by spaces
 ///   * absence of extension: `NO`
 ///   * acceptance status: `YES` and `NO`
 ///
@@ -111,6 +112,30 @@ impl ResponseWriter<String> for AspartixWriter {
         writer.flush().context(context)
     }
 
+    fn write_single_labelling(
+        &self,
+        writer: &mut dyn Write,
+        labelling: &Labelling<String>,
+    ) -> Result<()> {
+        let context = "while writing a labelling";
+        let mut first = true;
+        labelling.iter().try_for_each(|(arg, value)| {
+            let value_str = match value {
+                LabellingValue::In => "in",
+                LabellingValue::Out => "out",
+                LabellingValue::Undec => "undec",
+            };
+            if first {
+                first = false;
+                write!(writer, "{}({})", value_str, arg).context(context)
+            } else {
+                write!(writer, " {}({})", value_str, arg).context(context)
+            }
+        })?;
+        writeln!(writer).context(context)?;
+        writer.flush().context(context)
+    }
+
     fn write_acceptance_status(
         &self,
         writer: &mut dyn Write,
@@ -124,6 +149,7 @@ impl ResponseWriter<String> for AspartixWriter {
 mod tests {
     use super::*;
     use crate::aa::ArgumentSet;
+    use num_bigint::BigUint;
     use std::io::BufWriter;
 
     #[test]
@@ -134,7 +160,7 @@ mod tests {
         framework.new_attack(&arg_names[0], &arg_names[0]).unwrap();
         framework.new_attack(&arg_names[1], &arg_names[2]).unwrap();
         let mut buffer = BufWriter::new(Vec::new());
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         writer.write_framework(&framework, &mut buffer).unwrap();
         assert_eq!(
             "arg(a).\narg(b).\narg(c).\natt(a,a).\natt(b,c).\n",
@@ -146,7 +172,7 @@ mod tests {
     fn test_write_single_extension() {
         let arg_names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
         let args = ArgumentSet::new_with_labels(&arg_names);
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer = BufWriter::new(Vec::new());
         writer
             .write_single_extension(
@@ -162,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_write_empty_extension() {
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer = BufWriter::new(Vec::new());
         writer
             .write_single_extension(&mut buffer, &[] as &[&Argument<String>])
@@ -173,9 +199,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_write_single_labelling() {
+        let arg_names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let args = ArgumentSet::new_with_labels(&arg_names);
+        let mut framework = AAFramework::new_with_argument_set(args);
+        framework.new_attack(&arg_names[0], &arg_names[1]).unwrap();
+        framework.new_attack(&arg_names[2], &arg_names[2]).unwrap();
+        let labelling = Labelling::new_from_extension(&framework, &framework.grounded_extension());
+        let writer = AspartixWriter;
+        let mut buffer = BufWriter::new(Vec::new());
+        writer
+            .write_single_labelling(&mut buffer, &labelling)
+            .unwrap();
+        assert_eq!(
+            "in(a) out(b) undec(c)\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
+
     #[test]
     fn test_write_no_extension() {
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_no_extension(&mut buffer).unwrap();
         assert_eq!(
@@ -186,7 +231,7 @@ mod tests {
 
     #[test]
     fn test_write_acceptance_status_yes() {
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_acceptance_status(&mut buffer, true).unwrap();
         assert_eq!(
@@ -197,7 +242,7 @@ mod tests {
 
     #[test]
     fn test_write_acceptance_status_no() {
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_acceptance_status(&mut buffer, false).unwrap();
         assert_eq!(
@@ -205,4 +250,17 @@ mod tests {
             String::from_utf8(buffer.into_inner().unwrap()).unwrap()
         );
     }
+
+    #[test]
+    fn test_write_extension_count() {
+        let writer = AspartixWriter;
+        let mut buffer = BufWriter::new(Vec::new());
+        writer
+            .write_extension_count(&mut buffer, &(BigUint::from(1_usize) << 70))
+            .unwrap();
+        assert_eq!(
+            "1180591620717411303424\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
 }
@@ -0,0 +1,170 @@
+use crate::{
+    aa::{AAFramework, Argument, Labelling, LabellingValue},
+    utils::LabelType,
+};
+use anyhow::{Context, Result};
+use std::io::Write;
+
+/// A writer rendering argumentation frameworks using the Graphviz DOT language.
+///
+/// Arguments are written as nodes and attacks as directed edges.
+/// The arguments can be coloured according to a [`Labelling`], or to an extension from which a labelling is built:
+/// `IN` arguments are filled in green, `OUT` arguments in red and `UNDEC` arguments in grey.
+///
+/// # Example
+///
+/// The following example writes an AF to the standard output, highlighting its grounded extension.
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet};
+/// # use crustabri::io::DotWriter;
+/// let args = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"c", &"c").unwrap();
+/// let writer = DotWriter::default();
+/// writer
+///     .write_framework_with_extension(&af, &af.grounded_extension(), &mut std::io::stdout())
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct DotWriter;
+
+impl DotWriter {
+    /// Writes a framework using the DOT language to the provided writer.
+    pub fn write_framework<T>(
+        &self,
+        framework: &AAFramework<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        write_dot(framework, None, writer)
+    }
+
+    /// Writes a framework using the DOT language to the provided writer, colouring the arguments according to a labelling.
+    ///
+    /// # Panics
+    ///
+    /// The labelling must be built on the provided framework.
+    /// If it is not the case, this function may panic.
+    pub fn write_framework_with_labelling<T>(
+        &self,
+        framework: &AAFramework<T>,
+        labelling: &Labelling<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        write_dot(framework, Some(labelling), writer)
+    }
+
+    /// Writes a framework using the DOT language to the provided writer, colouring the arguments according to the labelling of an extension.
+    ///
+    /// See [`Labelling::new_from_extension`] for the way the labelling is built.
+    ///
+    /// # Panics
+    ///
+    /// The arguments of the extension must belong to the AF.
+    /// If it is not the case, this function may panic.
+    pub fn write_framework_with_extension<T>(
+        &self,
+        framework: &AAFramework<T>,
+        extension: &[&Argument<T>],
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        let labelling = Labelling::new_from_extension(framework, extension);
+        write_dot(framework, Some(&labelling), writer)
+    }
+}
+
+fn write_dot<T>(
+    framework: &AAFramework<T>,
+    labelling: Option<&Labelling<T>>,
+    writer: &mut dyn Write,
+) -> Result<()>
+where
+    T: LabelType,
+{
+    let context = "while writing a DOT graph";
+    writeln!(writer, "digraph af {{").context(context)?;
+    for arg in framework.argument_set().iter() {
+        write!(writer, "    {}", node_id(arg)).context(context)?;
+        if let Some(l) = labelling {
+            let color = match l.value_of(arg) {
+                LabellingValue::In => "green",
+                LabellingValue::Out => "red",
+                LabellingValue::Undec => "grey",
+            };
+            write!(writer, " [style=filled, fillcolor={}]", color).context(context)?;
+        }
+        writeln!(writer, ";").context(context)?;
+    }
+    for attack in framework.iter_attacks() {
+        writeln!(
+            writer,
+            "    {} -> {};",
+            node_id(attack.attacker()),
+            node_id(attack.attacked())
+        )
+        .context(context)?;
+    }
+    writeln!(writer, "}}").context(context)?;
+    writer.flush().context(context)
+}
+
+fn node_id<T>(arg: &Argument<T>) -> String
+where
+    T: LabelType,
+{
+    format!(
+        "\"{}\"",
+        arg.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aa::ArgumentSet;
+    use std::io::BufWriter;
+
+    fn framework() -> AAFramework<String> {
+        let arg_names = vec!["a".to_string(), "b".to_string(), "c\"".to_string()];
+        let args = ArgumentSet::new_with_labels(&arg_names);
+        let mut framework = AAFramework::new_with_argument_set(args);
+        framework.new_attack(&arg_names[0], &arg_names[1]).unwrap();
+        framework.new_attack(&arg_names[2], &arg_names[2]).unwrap();
+        framework
+    }
+
+    #[test]
+    fn test_write_framework() {
+        let mut buffer = BufWriter::new(Vec::new());
+        DotWriter
+            .write_framework(&framework(), &mut buffer)
+            .unwrap();
+        assert_eq!(
+            "digraph af {\n    \"a\";\n    \"b\";\n    \"c\\\"\";\n    \"a\" -> \"b\";\n    \"c\\\"\" -> \"c\\\"\";\n}\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_framework_with_extension() {
+        let af = framework();
+        let mut buffer = BufWriter::new(Vec::new());
+        DotWriter
+            .write_framework_with_extension(&af, &af.grounded_extension(), &mut buffer)
+            .unwrap();
+        let output = String::from_utf8(buffer.into_inner().unwrap()).unwrap();
+        assert!(output.contains("    \"a\" [style=filled, fillcolor=green];\n"));
+        assert!(output.contains("    \"b\" [style=filled, fillcolor=red];\n"));
+        assert!(output.contains("    \"c\\\"\" [style=filled, fillcolor=grey];\n"));
+    }
+}
@@ -0,0 +1,280 @@
+use super::{iccma23_reader::read_preamble, WarningHandler};
+use crate::aba::{AbaFramework, Atom};
+use anyhow::{anyhow, Context, Result};
+use std::io::{BufRead, BufReader, Read};
+
+/// A reader for the ICCMA 2023 format dedicated to flat ABA frameworks.
+///
+/// This object is used to read an [`AbaFramework`] encoded using the ICCMA 2023 input format for ABA, as defined on [the competition website](https://iccma2023.github.io/rules.html).
+/// The [`LabelType`](crate::utils::LabelType) of the returned frameworks is [`usize`].
+///
+/// Declaring an assumption twice produces a warning, as well as an assumption with no contrary.
+///
+/// # ICCMA 2023 ABA format
+///
+/// The following content defines an ABA framework with six atoms (given by the indexes `1` to `6`).
+/// The atoms `1` and `2` are assumptions, with contraries `5` and `6`.
+/// The atom `5` is derived from `2` and `3`, the atom `3` is a fact, and the atom `6` is derived from `1`.
+///
+/// ```text
+/// p aba 6
+/// a 1
+/// a 2
+/// c 1 5
+/// c 2 6
+/// r 5 2 3
+/// r 3
+/// r 6 1
+/// ```
+#[derive(Default)]
+pub struct Iccma23AbaReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+enum AbaLine {
+    Assumption(usize),
+    Contrary(usize, usize),
+    Rule(usize, Vec<usize>),
+}
+
+impl Iccma23AbaReader {
+    /// Reads an ABA framework.
+    ///
+    /// In case warnings are raised, the handlers are triggered.
+    pub fn read(&self, reader: &mut dyn Read) -> Result<AbaFramework<usize>> {
+        let br = BufReader::new(reader);
+        let mut n_atoms = None;
+        let mut found_empty_lines = false;
+        let mut aba_lines = vec![];
+        for (i, line) in br.lines().enumerate() {
+            let context = || format!("while reading line with index {}", i);
+            let l = line.with_context(context)?;
+            if l.starts_with('#') {
+                continue;
+            }
+            if l.is_empty() {
+                found_empty_lines = true;
+                continue;
+            }
+            if found_empty_lines {
+                return Err(anyhow!("got content after an empty line")).with_context(context);
+            }
+            let words = l.split_whitespace().collect::<Vec<&str>>();
+            match n_atoms {
+                None => n_atoms = Some(read_preamble(&words, "aba").with_context(context)?),
+                Some(n) => aba_lines.push((i, read_aba_line(&words, n).with_context(context)?)),
+            }
+        }
+        let n_atoms = n_atoms.ok_or_else(|| anyhow!("missing preamble"))?;
+        self.build_framework(n_atoms, aba_lines)
+    }
+
+    fn build_framework(
+        &self,
+        n_atoms: usize,
+        aba_lines: Vec<(usize, AbaLine)>,
+    ) -> Result<AbaFramework<usize>> {
+        let mut aba = AbaFramework::new_with_atoms(&(1..=n_atoms).collect::<Vec<usize>>());
+        let warn = |i: usize, w: String| {
+            self.warning_handlers
+                .iter()
+                .for_each(|h| (h)(1 + i, w.clone()))
+        };
+        let mut declaration_lines = vec![None; n_atoms];
+        for (i, l) in aba_lines.iter() {
+            if let AbaLine::Assumption(a) = l {
+                if declaration_lines[a - 1].is_some() {
+                    warn(*i, format!("assumption {} is declared twice", a));
+                    continue;
+                }
+                declaration_lines[a - 1] = Some(*i);
+                aba.new_assumption(a)
+                    .with_context(|| format!("while reading line with index {}", i))?;
+            }
+        }
+        for (i, l) in aba_lines.iter() {
+            if let AbaLine::Contrary(a, c) = l {
+                aba.set_contrary(a, c)
+                    .with_context(|| format!("while reading line with index {}", i))?;
+            }
+        }
+        for (i, l) in aba_lines.iter() {
+            if let AbaLine::Rule(head, body) = l {
+                aba.new_rule(head, &body.iter().collect::<Vec<&usize>>())
+                    .with_context(|| format!("while reading line with index {}", i))?;
+            }
+        }
+        aba.iter_assumptions()
+            .filter(|a| aba.contrary_of(a).is_none())
+            .for_each(|a| {
+                warn(
+                    declaration_lines[a.id()].unwrap(),
+                    format!("assumption {} has no contrary", a.label()),
+                )
+            });
+        Ok(aba)
+    }
+
+    /// Returns the atom which label corresponds to the provided string.
+    ///
+    /// The atom is searched among the atoms of the provided ABA framework.
+    /// If no atom matches the label, an error is returned.
+    pub fn read_atom_from_str<'a>(
+        &self,
+        aba: &'a AbaFramework<usize>,
+        atom: &str,
+    ) -> Result<&'a Atom<usize>> {
+        match atom.parse::<usize>() {
+            Ok(n) if n > 0 && n <= aba.n_atoms() => Ok(aba.atom_set().get_label_by_id(n - 1)),
+            _ => Err(anyhow!("unknown atom: {}", atom)),
+        }
+    }
+
+    /// Adds a new handler for warnings.
+    pub fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+fn read_aba_line(words: &[&str], n_atoms: usize) -> Result<AbaLine> {
+    let read_atom = |word: &str| match word.parse::<isize>() {
+        Ok(n) if n >= 1 && (n as usize) <= n_atoms => Ok(n as usize),
+        _ => Err(anyhow!(r#"invalid atom index "{}""#, word)),
+    };
+    let expect_n_words = |n: usize| {
+        if words.len() == n {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                r#"error in "{}" line; expected {} words, got {}"#,
+                words[0],
+                n,
+                words.len()
+            ))
+        }
+    };
+    match words[0] {
+        "a" => {
+            expect_n_words(2)?;
+            Ok(AbaLine::Assumption(read_atom(words[1])?))
+        }
+        "c" => {
+            expect_n_words(3)?;
+            Ok(AbaLine::Contrary(
+                read_atom(words[1])?,
+                read_atom(words[2])?,
+            ))
+        }
+        "r" => {
+            if words.len() < 2 {
+                return Err(anyhow!(r#"error in "r" line; missing rule head"#));
+            }
+            Ok(AbaLine::Rule(
+                read_atom(words[1])?,
+                words[2..]
+                    .iter()
+                    .map(|w| read_atom(w))
+                    .collect::<Result<Vec<usize>>>()?,
+            ))
+        }
+        _ => Err(anyhow!(r#"unexpected line kind "{}""#, words[0])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn read_with_warnings(instance: &str) -> (Result<AbaFramework<usize>>, Vec<(usize, String)>) {
+        let warnings = Rc::new(RefCell::new(vec![]));
+        let warnings_clone = Rc::clone(&warnings);
+        let closure = Box::new(move |i, w| warnings_clone.borrow_mut().push((i, w)));
+        let mut reader = Iccma23AbaReader::default();
+        reader.add_warning_handler(closure);
+        let result = reader.read(&mut instance.as_bytes());
+        let warnings = warnings.borrow().clone();
+        (result, warnings)
+    }
+
+    #[test]
+    fn test_ok() {
+        let instance = "p aba 6\n# comment\na 1\na 2\nc 1 5\nc 2 6\nr 5 2 3\nr 3\nr 6 1\n";
+        let (result, warnings) = read_with_warnings(instance);
+        let aba = result.unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(6, aba.n_atoms());
+        assert_eq!(2, aba.n_assumptions());
+        assert_eq!(3, aba.n_rules());
+        let atom = |n| aba.atom_set().get_label(&n).unwrap();
+        assert_eq!(Some(atom(5)), aba.contrary_of(atom(1)));
+        assert_eq!(
+            vec![
+                (atom(5), vec![atom(2), atom(3)]),
+                (atom(3), vec![]),
+                (atom(6), vec![atom(1)])
+            ],
+            aba.iter_rules().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_lines_in_any_order() {
+        let instance = "p aba 3\nr 3 1\nc 1 2\na 1\n";
+        let aba = Iccma23AbaReader::default()
+            .read(&mut instance.as_bytes())
+            .unwrap();
+        assert_eq!(1, aba.n_assumptions());
+        assert_eq!(1, aba.n_rules());
+    }
+
+    #[test]
+    fn test_warnings() {
+        let instance = "p aba 3\na 1\na 1\na 2\nc 1 3\n";
+        let (result, warnings) = read_with_warnings(instance);
+        assert!(result.is_ok());
+        assert_eq!(
+            vec![
+                (3, "assumption 1 is declared twice".to_string()),
+                (4, "assumption 2 has no contrary".to_string())
+            ],
+            warnings
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for instance in [
+            "p af 3\na 1\n",
+            "a 1\n",
+            "",
+            "p aba 3\na 4\n",
+            "p aba 3\na 1 2\n",
+            "p aba 3\nc 1\n",
+            "p aba 3\nr\n",
+            "p aba 3\nx 1\n",
+            "p aba 3\n\na 1\n",
+            "p aba 3\nc 1 2\n",
+            "p aba 3\na 1\nr 1 2\n",
+        ] {
+            assert!(
+                Iccma23AbaReader::default()
+                    .read(&mut instance.as_bytes())
+                    .is_err(),
+                "{:?}",
+                instance
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_atom_from_str() {
+        let aba = Iccma23AbaReader::default()
+            .read(&mut "p aba 2\na 1\n".as_bytes())
+            .unwrap();
+        let reader = Iccma23AbaReader::default();
+        assert_eq!(2, *reader.read_atom_from_str(&aba, "2").unwrap().label());
+        assert!(reader.read_atom_from_str(&aba, "0").is_err());
+        assert!(reader.read_atom_from_str(&aba, "3").is_err());
+    }
+}
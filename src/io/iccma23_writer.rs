@@ -1,5 +1,8 @@
 use super::ResponseWriter;
-use crate::aa::Argument;
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    utils::LabelType,
+};
 use anyhow::{Context, Result};
 use std::io::Write;
 
@@ -7,11 +10,91 @@ use std::io::Write;
 ///
 /// More precisely, the answers to argumentation problems are written this way:
 ///   * extension: the letter `w`, followed by a space and the list of argument labels, splitted by spaces
+///   * labelling: three lines beginning by the letters `w`, `o` and `u` followed by the list of the `IN`, `OUT` and `UNDEC` arguments respectively
 ///   * absence of extension: `NO`
 ///   * acceptance status: `YES` and `NO`
+///
+/// In addition, this object is able to write an [`AAFramework`] using the ICCMA 2023 input format, as defined on [the competition website](https://iccma2023.github.io/rules.html).
+/// Since this format only allows arguments to be identified by integers, the arguments are numbered from `1` following their order in the [`ArgumentSet`](crate::aa::ArgumentSet).
+/// The correspondence between these indexes and the argument labels can be written by [`write_label_mapping`](Self::write_label_mapping).
+///
+/// # Example
+///
+/// The following example writes an AF with labelled arguments to the standard output using the ICCMA 2023 format.
+///
+/// ```
+/// # use crustabri::aa::AAFramework;
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::io::Iccma23Writer;
+/// let args = ArgumentSet::new_with_labels(&["a", "b"]);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// let writer = Iccma23Writer::default();
+/// writer.write_framework(&af, &mut std::io::stdout()).unwrap(); // "p af 2" and "1 2"
+/// writer.write_label_mapping(&af, &mut std::io::stdout()).unwrap(); // "1 a" and "2 b"
+/// ```
 #[derive(Default)]
 pub struct Iccma23Writer;
 
+impl Iccma23Writer {
+    /// Writes a framework using the ICCMA 2023 format to the provided writer.
+    ///
+    /// Arguments are numbered from `1` following their order in the argument set of the framework.
+    pub fn write_framework<T>(
+        &self,
+        framework: &AAFramework<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        let context = "while writing a framework";
+        let indexes = argument_indexes(framework);
+        writeln!(writer, "p af {}", framework.n_arguments()).context(context)?;
+        for attack in framework.iter_attacks() {
+            writeln!(
+                writer,
+                "{} {}",
+                indexes[attack.attacker().id()],
+                indexes[attack.attacked().id()]
+            )
+            .context(context)?;
+        }
+        writer.flush().context(context)
+    }
+
+    /// Writes the correspondence between the indexes used by [`write_framework`](Self::write_framework) and the argument labels.
+    ///
+    /// Each line contains an index followed by a space and the label of the corresponding argument.
+    pub fn write_label_mapping<T>(
+        &self,
+        framework: &AAFramework<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        let context = "while writing a label mapping";
+        for (i, arg) in framework.argument_set().iter().enumerate() {
+            writeln!(writer, "{} {}", i + 1, arg).context(context)?;
+        }
+        writer.flush().context(context)
+    }
+}
+
+fn argument_indexes<T>(framework: &AAFramework<T>) -> Vec<usize>
+where
+    T: LabelType,
+{
+    let mut indexes = vec![0; framework.max_argument_id().map(|id| id + 1).unwrap_or(0)];
+    framework
+        .argument_set()
+        .iter()
+        .enumerate()
+        .for_each(|(i, arg)| indexes[arg.id()] = i + 1);
+    indexes
+}
+
 impl ResponseWriter<usize> for Iccma23Writer {
     fn write_no_extension(&self, writer: &mut dyn Write) -> Result<()> {
         super::specs::write_no_extension(writer)
@@ -31,6 +114,25 @@ impl ResponseWriter<usize> for Iccma23Writer {
         writer.flush().context(context)
     }
 
+    fn write_single_labelling(
+        &self,
+        writer: &mut dyn Write,
+        labelling: &Labelling<usize>,
+    ) -> Result<()> {
+        let context = "while writing a labelling";
+        for (prefix, args) in [
+            ("w", labelling.in_arguments()),
+            ("o", labelling.out_arguments()),
+            ("u", labelling.undec_arguments()),
+        ] {
+            write!(writer, "{}", prefix).context(context)?;
+            args.iter()
+                .try_for_each(|arg| write!(writer, " {}", arg).context(context))?;
+            writeln!(writer).context(context)?;
+        }
+        writer.flush().context(context)
+    }
+
     fn write_acceptance_status(
         &self,
         writer: &mut dyn Write,
@@ -43,14 +145,15 @@ impl ResponseWriter<usize> for Iccma23Writer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aa::ArgumentSet;
+    use crate::aa::{AAFramework, ArgumentSet};
+    use num_bigint::BigUint;
     use std::io::BufWriter;
 
     #[test]
     fn test_write_single_extension() {
         let arg_names = vec![0, 1, 2];
         let args = ArgumentSet::new_with_labels(&arg_names);
-        let writer = Iccma23Writer::default();
+        let writer = Iccma23Writer;
         let mut buffer = BufWriter::new(Vec::new());
         writer
             .write_single_extension(&mut buffer, &args.iter().collect::<Vec<&Argument<usize>>>())
@@ -63,7 +166,7 @@ mod tests {
 
     #[test]
     fn test_write_empty_extension() {
-        let writer = Iccma23Writer::default();
+        let writer = Iccma23Writer;
         let mut buffer = BufWriter::new(Vec::new());
         writer
             .write_single_extension(&mut buffer, &[] as &[&Argument<usize>])
@@ -74,9 +177,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_write_single_labelling() {
+        let arg_names = vec![1, 2, 3, 4];
+        let args = ArgumentSet::new_with_labels(&arg_names);
+        let mut framework = AAFramework::new_with_argument_set(args);
+        framework.new_attack(&1, &2).unwrap();
+        framework.new_attack(&1, &3).unwrap();
+        framework.new_attack(&4, &4).unwrap();
+        let labelling = Labelling::new_from_extension(&framework, &framework.grounded_extension());
+        let writer = Iccma23Writer;
+        let mut buffer = BufWriter::new(Vec::new());
+        writer
+            .write_single_labelling(&mut buffer, &labelling)
+            .unwrap();
+        assert_eq!(
+            "w 1\no 2 3\nu 4\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
+
     #[test]
     fn test_write_no_extension() {
-        let writer = Iccma23Writer::default();
+        let writer = Iccma23Writer;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_no_extension(&mut buffer).unwrap();
         assert_eq!(
@@ -87,7 +210,7 @@ mod tests {
 
     #[test]
     fn test_write_acceptance_status_yes() {
-        let writer = Iccma23Writer::default();
+        let writer = Iccma23Writer;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_acceptance_status(&mut buffer, true).unwrap();
         assert_eq!(
@@ -98,7 +221,7 @@ mod tests {
 
     #[test]
     fn test_write_acceptance_status_no() {
-        let writer = Iccma23Writer::default();
+        let writer = Iccma23Writer;
         let mut buffer = BufWriter::new(Vec::new());
         writer.write_acceptance_status(&mut buffer, false).unwrap();
         assert_eq!(
@@ -106,4 +229,40 @@ mod tests {
             String::from_utf8(buffer.into_inner().unwrap()).unwrap()
         );
     }
+
+    #[test]
+    fn test_write_framework() {
+        let arg_names = vec!["a", "b", "c", "d"];
+        let args = ArgumentSet::new_with_labels(&arg_names);
+        let mut framework = AAFramework::new_with_argument_set(args);
+        framework.new_attack(&"a", &"b").unwrap();
+        framework.new_attack(&"d", &"d").unwrap();
+        framework.remove_argument(&"c").unwrap();
+        let writer = Iccma23Writer;
+        let mut buffer = BufWriter::new(Vec::new());
+        writer.write_framework(&framework, &mut buffer).unwrap();
+        assert_eq!(
+            "p af 3\n1 2\n3 3\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+        let mut buffer = BufWriter::new(Vec::new());
+        writer.write_label_mapping(&framework, &mut buffer).unwrap();
+        assert_eq!(
+            "1 a\n2 b\n3 d\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_extension_count() {
+        let writer = Iccma23Writer;
+        let mut buffer = BufWriter::new(Vec::new());
+        writer
+            .write_extension_count(&mut buffer, &(BigUint::from(1_usize) << 70))
+            .unwrap();
+        assert_eq!(
+            "1180591620717411303424\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
 }
@@ -1,11 +1,35 @@
 //! Objects used to read and write Argumentation frameworks and answers to problems.
 
-mod aspartix_reader;
+mod aspartix_bipolar_reader;
+pub use aspartix_bipolar_reader::AspartixBipolarReader;
+
+mod aspartix_incomplete_reader;
+pub use aspartix_incomplete_reader::AspartixIncompleteReader;
+
+mod aspartix_paf_reader;
+pub use aspartix_paf_reader::AspartixPafReader;
+
+pub(crate) mod aspartix_reader;
 pub use aspartix_reader::AspartixReader;
 
+mod aspartix_setaf_reader;
+pub use aspartix_setaf_reader::AspartixSetafReader;
+
+mod aspartix_setaf_writer;
+pub use aspartix_setaf_writer::AspartixSetafWriter;
+
+mod aspartix_vaf_reader;
+pub use aspartix_vaf_reader::AspartixVafReader;
+
 mod aspartix_writer;
 pub use aspartix_writer::AspartixWriter;
 
+mod dot_writer;
+pub use dot_writer::DotWriter;
+
+mod iccma23_aba_reader;
+pub use iccma23_aba_reader::Iccma23AbaReader;
+
 pub(crate) mod iccma23_reader;
 pub use iccma23_reader::Iccma23Reader;
 
@@ -17,4 +41,10 @@ pub use specs::InstanceReader;
 pub use specs::ResponseWriter;
 pub use specs::WarningHandler;
 
+mod tgf_reader;
+pub use tgf_reader::TgfReader;
+
+mod tgf_writer;
+pub use tgf_writer::TgfWriter;
+
 mod warning_result;
@@ -1,8 +1,9 @@
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
     utils::LabelType,
 };
 use anyhow::{Context, Result};
+use num_bigint::BigUint;
 use std::io::{Read, Write};
 
 /// The type of callback functions to call when warnings are raised while parsing an AF.
@@ -87,6 +88,20 @@ where
         extension: &[&Argument<T>],
     ) -> Result<()>;
 
+    /// Writes a single labelling.
+    ///
+    /// Such answer may be written by a solver seeking extensions when the status of the arguments out of the extension is required.
+    /// The absence of labelling must be written using [write_no_extension](Self::write_no_extension).
+    ///
+    /// The default implementation writes the arguments labelled `IN` using [write_single_extension](Self::write_single_extension).
+    fn write_single_labelling(
+        &self,
+        writer: &mut dyn Write,
+        labelling: &Labelling<T>,
+    ) -> Result<()> {
+        self.write_single_extension(writer, &labelling.in_arguments())
+    }
+
     /// Writes an acceptance status.
     ///
     /// Such answer may be written by a solver checking the credulous or the skeptical acceptance of an argument.
@@ -95,6 +110,15 @@ where
         writer: &mut dyn Write,
         acceptance_status: bool,
     ) -> Result<()>;
+
+    /// Writes a number of extensions.
+    ///
+    /// Such answer may be written by a solver counting the extensions.
+    ///
+    /// The default implementation writes the number in decimal notation on its own line.
+    fn write_extension_count(&self, writer: &mut dyn Write, count: &BigUint) -> Result<()> {
+        write_extension_count(writer, count)
+    }
 }
 
 pub(crate) fn write_no_extension(writer: &mut dyn Write) -> Result<()> {
@@ -111,3 +135,58 @@ pub(crate) fn write_acceptance_status(
     writeln!(writer, "{}", if acceptance_status { "YES" } else { "NO" }).context(context)?;
     writer.flush().context(context)
 }
+
+pub(crate) fn write_extension_count(writer: &mut dyn Write, count: &BigUint) -> Result<()> {
+    let context = "while writing an extension count";
+    writeln!(writer, "{}", count).context(context)?;
+    writer.flush().context(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aa::{AAFramework, ArgumentSet};
+    use std::io::BufWriter;
+
+    struct ExtensionOnlyWriter;
+
+    impl ResponseWriter<&'static str> for ExtensionOnlyWriter {
+        fn write_no_extension(&self, writer: &mut dyn Write) -> Result<()> {
+            write_no_extension(writer)
+        }
+
+        fn write_single_extension(
+            &self,
+            writer: &mut dyn Write,
+            extension: &[&Argument<&'static str>],
+        ) -> Result<()> {
+            let labels = extension.iter().map(|a| *a.label()).collect::<Vec<&str>>();
+            writeln!(writer, "{}", labels.join(" ")).context("while writing an extension")
+        }
+
+        fn write_acceptance_status(
+            &self,
+            writer: &mut dyn Write,
+            acceptance_status: bool,
+        ) -> Result<()> {
+            write_acceptance_status(writer, acceptance_status)
+        }
+    }
+
+    #[test]
+    fn test_default_labelling() {
+        let mut af =
+            AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&["a", "b", "c"]));
+        af.new_attack(&"a", &"b").unwrap();
+        af.new_attack(&"c", &"c").unwrap();
+        let labelling = Labelling::new_from_extension(&af, &af.grounded_extension());
+        let mut buffer = BufWriter::new(Vec::new());
+        ExtensionOnlyWriter
+            .write_single_labelling(&mut buffer, &labelling)
+            .unwrap();
+        assert_eq!(
+            "a\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        );
+    }
+}
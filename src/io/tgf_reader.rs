@@ -0,0 +1,204 @@
+use super::{InstanceReader, WarningHandler};
+use crate::aa::{AAFramework, Argument, ArgumentSet};
+use anyhow::{anyhow, Context, Result};
+use std::io::{BufRead, BufReader, Read};
+
+/// A reader for the Trivial Graph Format (TGF).
+///
+/// This object is used to read an [`AAFramework`] encoded using the Trivial Graph Format, as used by the ICCMA 2015 to 2019 competitions and tools like ConArg.
+/// The [`LabelType`](crate::utils::LabelType) of the returned argument frameworks is [`String`].
+///
+/// # Trivial Graph Format
+///
+/// A TGF file begins with the argument declarations, one per line, followed by a line containing only a `#` character and the attack declarations, one per line.
+/// An attack declaration is made of the names of the attacking and the attacked arguments, splitted by spaces.
+///
+/// The following content defines an Argumentation Framework with three arguments labelled `a`, `b` and `c` and three attacks (`a` and `b` attack each other and `c` attacks `b`).
+///
+/// ```text
+/// a
+/// b
+/// c
+/// #
+/// a b
+/// b a
+/// c b
+/// ```
+///
+/// The TGF format allows nodes and edges to be followed by a label.
+/// Such labels have no meaning in argumentation frameworks; they are ignored, and a warning is raised.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::AAFramework;
+/// # use crustabri::io::{TgfReader, InstanceReader};
+/// fn read_af_from_str(s: &str) -> AAFramework<String> {
+///     let reader = TgfReader::default();
+///     reader.read(&mut s.as_bytes()).expect("invalid TGF AF")
+/// }
+/// # read_af_from_str("a\n#\n");
+/// ```
+#[derive(Default)]
+pub struct TgfReader {
+    warning_handlers: Vec<WarningHandler>,
+}
+
+impl InstanceReader<String> for TgfReader {
+    fn read(&self, reader: &mut dyn Read) -> Result<AAFramework<String>> {
+        let mut argument_set = ArgumentSet::new_with_labels(&[]);
+        let mut af: Option<AAFramework<String>> = None;
+        let br = BufReader::new(reader);
+        for (i, line) in br.lines().enumerate() {
+            let context = || format!("while reading line with index {}", i);
+            let warn = |w: &str| {
+                self.warning_handlers
+                    .iter()
+                    .for_each(|h| (h)(1 + i, w.to_string()))
+            };
+            let l = &line.with_context(context)?;
+            if l.trim().is_empty() {
+                continue;
+            }
+            let words = l.split_whitespace().collect::<Vec<&str>>();
+            if words[0] == "#" {
+                if words.len() > 1 || af.is_some() {
+                    return Err(anyhow!("syntax error in line \"{}\"", l)).with_context(context);
+                }
+                af = Some(AAFramework::new_with_argument_set(std::mem::replace(
+                    &mut argument_set,
+                    ArgumentSet::new_with_labels(&[]),
+                )));
+                continue;
+            }
+            match af.as_mut() {
+                None => {
+                    if words.len() > 1 {
+                        warn("ignoring the label of the argument");
+                    }
+                    let label = words[0].to_string();
+                    if argument_set.get_argument(&label).is_ok() {
+                        return Err(anyhow!(r#"argument "{}" is declared twice"#, label))
+                            .with_context(context);
+                    }
+                    argument_set.new_argument(label);
+                }
+                Some(framework) => {
+                    if words.len() < 2 {
+                        return Err(anyhow!("syntax error in line \"{}\"", l))
+                            .with_context(context);
+                    }
+                    if words.len() > 2 {
+                        warn("ignoring the label of the attack");
+                    }
+                    framework
+                        .new_attack(&words[0].to_string(), &words[1].to_string())
+                        .with_context(context)?;
+                }
+            }
+        }
+        match af {
+            Some(a) => Ok(a),
+            None => Ok(AAFramework::new_with_argument_set(argument_set)),
+        }
+    }
+
+    fn read_arg_from_str<'a>(
+        &self,
+        af: &'a AAFramework<String>,
+        arg: &str,
+    ) -> Result<&'a Argument<String>> {
+        af.argument_set().get_argument(&arg.to_string())
+    }
+
+    fn add_warning_handler(&mut self, h: WarningHandler) {
+        self.warning_handlers.push(h);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn str_args(af: &AAFramework<String>) -> Vec<String> {
+        af.argument_set().iter().map(|s| format!("{}", s)).collect()
+    }
+
+    fn str_attacks(af: &AAFramework<String>) -> Vec<String> {
+        af.iter_attacks()
+            .map(|a| format!("({},{})", a.attacker(), a.attacked()))
+            .collect()
+    }
+
+    fn read_with_warnings(instance: &str) -> (Result<AAFramework<String>>, Vec<(usize, String)>) {
+        let warnings = Rc::new(RefCell::new(vec![]));
+        let warnings_clone = Rc::clone(&warnings);
+        let closure = Box::new(move |i, w| warnings_clone.borrow_mut().push((i, w)));
+        let mut reader = TgfReader::default();
+        reader.add_warning_handler(closure);
+        let result = reader.read(&mut instance.as_bytes());
+        let warnings = warnings.borrow().clone();
+        (result, warnings)
+    }
+
+    #[test]
+    fn test_read_ok() {
+        let instance = "a\nb\n\n#\na b\n";
+        let af = TgfReader::default().read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(vec!["a".to_string(), "b".to_string()], str_args(&af));
+        assert_eq!(vec!["(a,b)".to_string()], str_attacks(&af));
+    }
+
+    #[test]
+    fn test_read_empty() {
+        let instance = "\n";
+        let af = TgfReader::default().read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(vec![] as Vec<String>, str_args(&af));
+        assert_eq!(vec![] as Vec<String>, str_attacks(&af));
+    }
+
+    #[test]
+    fn test_read_no_attack_section() {
+        let instance = "a\nb\n";
+        let af = TgfReader::default().read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(2, af.n_arguments());
+        assert_eq!(0, af.n_attacks());
+    }
+
+    #[test]
+    fn test_read_unknown_arg_in_att() {
+        let instance = "a\nb\n#\na c\n";
+        assert!(TgfReader::default().read(&mut instance.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_read_syntax_errors() {
+        for instance in ["a\n#\na\n", "a\n#\n#\n", "a\n# a\n", "a\na\n#\n"] {
+            assert!(TgfReader::default().read(&mut instance.as_bytes()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_read_warn_labels() {
+        let (result, warnings) = read_with_warnings("a first\nb\n#\na b attack\n");
+        let af = result.unwrap();
+        assert_eq!(vec!["a".to_string(), "b".to_string()], str_args(&af));
+        assert_eq!(
+            vec![
+                (1, "ignoring the label of the argument".to_string()),
+                (4, "ignoring the label of the attack".to_string())
+            ],
+            warnings
+        );
+    }
+
+    #[test]
+    fn test_read_arg_from_str() {
+        let instance = "a\n#\na a\n";
+        let reader = TgfReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert!(reader.read_arg_from_str(&af, "a").is_ok());
+        assert!(reader.read_arg_from_str(&af, "b").is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{aa::AAFramework, utils::LabelType};
+use anyhow::{Context, Result};
+use std::io::Write;
+
+/// A writer for the Trivial Graph Format (TGF).
+///
+/// This object is able to write an [`AAFramework`] using the Trivial Graph Format, as described in the [`TgfReader`](super::TgfReader) documentation.
+///
+/// # Example
+///
+/// The following example retrieves an AF and writes it to the standard output using the Trivial Graph Format.
+///
+/// ```
+/// # use crustabri::aa::AAFramework;
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::io::TgfWriter;
+/// # use crustabri::utils::LabelType;
+/// # use anyhow::Result;
+/// fn write_af_to_stdout<T: LabelType>(af: &AAFramework<T>) -> Result<()> {
+///     let writer = TgfWriter::default();
+///     writer.write_framework(&af, &mut std::io::stdout())
+/// }
+/// # write_af_to_stdout(&AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&[] as &[String])));
+/// ```
+#[derive(Default)]
+pub struct TgfWriter;
+
+impl TgfWriter {
+    /// Writes a framework using the Trivial Graph Format to the provided writer.
+    pub fn write_framework<T>(
+        &self,
+        framework: &AAFramework<T>,
+        writer: &mut dyn Write,
+    ) -> Result<()>
+    where
+        T: LabelType,
+    {
+        let context = "while writing a framework";
+        for arg in framework.argument_set().iter() {
+            writeln!(writer, "{}", arg).context(context)?;
+        }
+        writeln!(writer, "#").context(context)?;
+        for attack in framework.iter_attacks() {
+            writeln!(writer, "{} {}", attack.attacker(), attack.attacked()).context(context)?;
+        }
+        writer.flush().context(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aa::ArgumentSet;
+    use std::io::BufWriter;
+
+    #[test]
+    fn test_write_af() {
+        let arg_names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let args = ArgumentSet::new_with_labels(&arg_names);
+        let mut framework = AAFramework::new_with_argument_set(args);
+        framework.new_attack(&arg_names[0], &arg_names[0]).unwrap();
+        framework.new_attack(&arg_names[1], &arg_names[2]).unwrap();
+        let mut buffer = BufWriter::new(Vec::new());
+        TgfWriter.write_framework(&framework, &mut buffer).unwrap();
+        assert_eq!(
+            "a\nb\nc\n#\na a\nb c\n",
+            String::from_utf8(buffer.into_inner().unwrap()).unwrap()
+        )
+    }
+}
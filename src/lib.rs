@@ -25,14 +25,30 @@
 
 pub mod aa;
 
+pub mod aba;
+
+pub mod bipolar;
+
 pub mod dynamics;
 
 pub mod encodings;
 
+pub mod generators;
+
+pub mod incomplete;
+
 pub mod io;
 
+pub mod preferences;
+
+pub mod principles;
+
+pub mod ranking;
+
 pub mod solvers;
 
 pub mod sat;
 
+pub mod setaf;
+
 pub mod utils;
@@ -0,0 +1,11 @@
+//! Preference-based and value-based argumentation frameworks, and the reductions turning them into AFs.
+
+mod paf_framework;
+pub use paf_framework::PafFramework;
+
+mod preference_reduction;
+pub use preference_reduction::PreferenceReduction;
+
+mod vaf_framework;
+pub use vaf_framework::SkepticalAcceptanceComputerFactoryFn;
+pub use vaf_framework::VafFramework;
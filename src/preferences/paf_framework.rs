@@ -0,0 +1,249 @@
+use super::PreferenceReduction;
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet, Attack},
+    utils::LabelType,
+};
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashMap, HashSet};
+
+/// A Preference-based Argumentation framework (PAF).
+///
+/// PAFs extend [`AAFramework`] objects with a strict preference relation between arguments.
+/// The AF under consideration is given by a [`PreferenceReduction`], which handles the attacks to preferred arguments;
+/// it can then be handled by any of the solvers dedicated to AFs.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::preferences::{PafFramework, PreferenceReduction};
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+/// let mut paf = PafFramework::new_with_argument_set(arguments);
+/// paf.new_attack(&"a", &"b").unwrap();
+/// paf.new_preference(&"b", &"a").unwrap();
+/// assert_eq!(0, paf.reduce(PreferenceReduction::Removal).n_attacks());
+/// ```
+#[derive(Debug)]
+pub struct PafFramework<T>
+where
+    T: LabelType,
+{
+    af: AAFramework<T>,
+    preferences: Vec<(usize, usize)>,
+    preference_set: HashSet<(usize, usize)>,
+    less_preferred: HashMap<usize, Vec<usize>>,
+}
+
+impl<T> PafFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds a PAF with its argument set, and neither attacks nor preferences.
+    pub fn new_with_argument_set(arguments: ArgumentSet<T>) -> Self {
+        Self::new_from_af(AAFramework::new_with_argument_set(arguments))
+    }
+
+    /// Builds a PAF from an AF, with no preferences.
+    pub fn new_from_af(af: AAFramework<T>) -> Self {
+        PafFramework {
+            af,
+            preferences: vec![],
+            preference_set: HashSet::new(),
+            less_preferred: HashMap::new(),
+        }
+    }
+
+    /// Returns the AF made of the arguments and the attacks of this framework.
+    pub fn af(&self) -> &AAFramework<T> {
+        &self.af
+    }
+
+    /// Returns the argument set of this framework.
+    pub fn argument_set(&self) -> &ArgumentSet<T> {
+        self.af.argument_set()
+    }
+
+    /// Returns the number of arguments in this framework.
+    pub fn n_arguments(&self) -> usize {
+        self.af.n_arguments()
+    }
+
+    /// Returns the number of attacks in this framework.
+    pub fn n_attacks(&self) -> usize {
+        self.af.n_attacks()
+    }
+
+    /// Returns the number of preferences in this framework.
+    pub fn n_preferences(&self) -> usize {
+        self.preferences.len()
+    }
+
+    /// Adds an attack given the labels of the attacker and the attacked arguments.
+    ///
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_attack(&mut self, from: &T, to: &T) -> Result<()> {
+        self.af.new_attack(from, to)
+    }
+
+    /// Adds a strict preference of the first argument over the second one, given their labels.
+    ///
+    /// The preferences are taken as they are given; in particular, they are not closed by transitivity.
+    /// Adding a preference which already exists leaves the PAF unchanged.
+    /// If one of the arguments does not belong to this framework, or if both labels are the same, an error is returned.
+    /// An error is also returned if the second argument is already preferred to the first one, directly or by transitivity,
+    /// since the preference relation would not be a strict order anymore.
+    pub fn new_preference(&mut self, preferred: &T, other: &T) -> Result<()> {
+        let context = || {
+            format!(
+                "cannot add a preference of {:?} over {:?}",
+                preferred, other
+            )
+        };
+        let preferred_id = self
+            .argument_set()
+            .get_argument(preferred)
+            .with_context(context)?
+            .id();
+        let other_id = self
+            .argument_set()
+            .get_argument(other)
+            .with_context(context)?
+            .id();
+        if preferred_id == other_id {
+            return Err(anyhow!("an argument cannot be preferred to itself")).with_context(context);
+        }
+        if self.is_transitively_preferred(other_id, preferred_id) {
+            return Err(anyhow!(
+                "the preferences would be cyclic, since {:?} is already preferred to {:?}",
+                other,
+                preferred
+            ))
+            .with_context(context);
+        }
+        if self.preference_set.insert((preferred_id, other_id)) {
+            self.preferences.push((preferred_id, other_id));
+            self.less_preferred
+                .entry(preferred_id)
+                .or_default()
+                .push(other_id);
+        }
+        Ok(())
+    }
+
+    fn is_transitively_preferred(&self, preferred_id: usize, other_id: usize) -> bool {
+        let mut seen = HashSet::from([preferred_id]);
+        let mut to_visit = vec![preferred_id];
+        while let Some(id) = to_visit.pop() {
+            for &next in self.less_preferred.get(&id).into_iter().flatten() {
+                if next == other_id {
+                    return true;
+                }
+                if seen.insert(next) {
+                    to_visit.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if the first argument is strictly preferred to the second one.
+    pub fn is_preferred(&self, preferred: &Argument<T>, other: &Argument<T>) -> bool {
+        self.preference_set.contains(&(preferred.id(), other.id()))
+    }
+
+    /// Provides an iterator to the attacks.
+    pub fn iter_attacks(&self) -> impl Iterator<Item = Attack<'_, T>> + '_ {
+        self.af.iter_attacks()
+    }
+
+    /// Provides an iterator to the preferences, given as couples of a preferred argument and the argument it is preferred to.
+    pub fn iter_preferences(&self) -> impl Iterator<Item = (&Argument<T>, &Argument<T>)> + '_ {
+        self.preferences.iter().map(|(a, b)| {
+            (
+                self.argument_set().get_argument_by_id(*a),
+                self.argument_set().get_argument_by_id(*b),
+            )
+        })
+    }
+
+    /// Builds the AF given by a reduction.
+    ///
+    /// The arguments of the returned AF have the same labels as the ones of this framework.
+    pub fn reduce(&self, reduction: PreferenceReduction) -> AAFramework<T> {
+        reduction.reduce(&self.af, |a, b| self.preference_set.contains(&(a, b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attack_labels(af: &AAFramework<&'static str>) -> Vec<(&'static str, &'static str)> {
+        let mut attacks = af
+            .iter_attacks()
+            .map(|att| (*att.attacker().label(), *att.attacked().label()))
+            .collect::<Vec<_>>();
+        attacks.sort_unstable();
+        attacks
+    }
+
+    // a and b attack each other, c attacks d, and b and d are preferred to a and c
+    fn paf() -> PafFramework<&'static str> {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d"]);
+        let mut paf = PafFramework::new_with_argument_set(arguments);
+        paf.new_attack(&"a", &"b").unwrap();
+        paf.new_attack(&"b", &"a").unwrap();
+        paf.new_attack(&"c", &"d").unwrap();
+        paf.new_preference(&"b", &"a").unwrap();
+        paf.new_preference(&"d", &"c").unwrap();
+        paf
+    }
+
+    #[test]
+    fn test_new_preference() {
+        let mut paf = paf();
+        paf.new_preference(&"b", &"a").unwrap();
+        assert_eq!(2, paf.n_preferences());
+        assert!(paf.new_preference(&"a", &"a").is_err());
+        assert!(paf.new_preference(&"a", &"e").is_err());
+        assert_eq!(2, paf.n_preferences());
+        let a = paf.argument_set().get_argument(&"a").unwrap();
+        let b = paf.argument_set().get_argument(&"b").unwrap();
+        assert!(paf.is_preferred(b, a));
+        assert!(!paf.is_preferred(a, b));
+    }
+
+    #[test]
+    fn test_new_preference_cycle() {
+        let mut paf = paf();
+        assert!(paf.new_preference(&"a", &"b").is_err());
+        paf.new_preference(&"c", &"b").unwrap();
+        assert!(paf.new_preference(&"a", &"c").is_err());
+        paf.new_preference(&"d", &"b").unwrap();
+        assert_eq!(4, paf.n_preferences());
+        let a = paf.argument_set().get_argument(&"a").unwrap();
+        let c = paf.argument_set().get_argument(&"c").unwrap();
+        assert!(!paf.is_preferred(a, c));
+    }
+
+    #[test]
+    fn test_reductions() {
+        let paf = paf();
+        assert_eq!(
+            vec![("b", "a")],
+            attack_labels(&paf.reduce(PreferenceReduction::Removal))
+        );
+        assert_eq!(
+            vec![("b", "a"), ("d", "c")],
+            attack_labels(&paf.reduce(PreferenceReduction::Reversal))
+        );
+        assert_eq!(
+            vec![("b", "a"), ("c", "d")],
+            attack_labels(&paf.reduce(PreferenceReduction::SymmetricRemoval))
+        );
+        assert_eq!(
+            vec![("b", "a"), ("c", "d"), ("d", "c")],
+            attack_labels(&paf.reduce(PreferenceReduction::Symmetrization))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    aa::{AAFramework, ArgumentSet},
+    utils::LabelType,
+};
+use std::collections::HashSet;
+
+/// The reductions turning the attacks of a framework with preferences into the attacks of an AF.
+///
+/// An attack from `a` to `b` is critical if `b` is strictly preferred to `a`.
+/// Non-critical attacks are kept by all the reductions; they differ in the way critical attacks are handled.
+/// The last two reductions are the ones introduced by Kaci and van der Torre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreferenceReduction {
+    /// Critical attacks are removed.
+    Removal,
+    /// Critical attacks are reversed.
+    Reversal,
+    /// Critical attacks are removed if the preferred argument attacks back, and kept otherwise.
+    SymmetricRemoval,
+    /// Critical attacks are removed if the preferred argument attacks back, and made symmetric otherwise.
+    Symmetrization,
+}
+
+impl PreferenceReduction {
+    /// Builds the AF given by this reduction.
+    ///
+    /// The preference relation is given by a function returning `true` if the argument with the first identifier is strictly preferred to the one with the second identifier.
+    /// The arguments of the returned AF have the same labels as the ones of the provided AF.
+    pub(crate) fn reduce<T, F>(&self, af: &AAFramework<T>, is_preferred: F) -> AAFramework<T>
+    where
+        T: LabelType,
+        F: Fn(usize, usize) -> bool,
+    {
+        let attacks = af
+            .iter_attacks()
+            .map(|att| (att.attacker().id(), att.attacked().id()))
+            .collect::<HashSet<(usize, usize)>>();
+        let labels = af
+            .argument_set()
+            .iter()
+            .map(|a| a.label().clone())
+            .collect::<Vec<T>>();
+        let mut reduced = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        for att in af.iter_attacks() {
+            let (a, b) = (att.attacker(), att.attacked());
+            let critical = is_preferred(b.id(), a.id());
+            let symmetric = attacks.contains(&(b.id(), a.id()));
+            let (keep, reverse) = match self {
+                PreferenceReduction::Removal => (!critical, false),
+                PreferenceReduction::Reversal => (!critical, critical),
+                PreferenceReduction::SymmetricRemoval => (!critical || !symmetric, false),
+                PreferenceReduction::Symmetrization => (!critical || !symmetric, critical),
+            };
+            if keep {
+                reduced.new_attack(a.label(), b.label()).unwrap();
+            }
+            if reverse {
+                reduced.new_attack(b.label(), a.label()).unwrap();
+            }
+        }
+        reduced
+    }
+}
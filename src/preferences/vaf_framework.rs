@@ -0,0 +1,424 @@
+use super::PreferenceReduction;
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet, Attack},
+    solvers::SkepticalAcceptanceComputer,
+    utils::LabelType,
+};
+use anyhow::{anyhow, Context, Result};
+
+/// The type of the functions building a skeptical acceptance solver for a semantics on an AF.
+///
+/// Such functions are given to the subjective and objective acceptance queries of [`VafFramework`] objects,
+/// since they involve the AFs given by each audience.
+pub type SkepticalAcceptanceComputerFactoryFn<T> =
+    dyn for<'b> Fn(&'b AAFramework<T>) -> Box<dyn SkepticalAcceptanceComputer<T> + 'b>;
+
+/// A Value-based Argumentation framework (VAF).
+///
+/// VAFs extend [`AAFramework`] objects by assigning values to arguments.
+/// An audience is a total order on these values, which induces a preference relation between arguments:
+/// an argument is preferred to another one if the audience prefers its value to the value of the other argument.
+/// Arguments with no value are not compared to the other ones.
+///
+/// The AF under consideration for the audience of the framework is given by a [`PreferenceReduction`].
+/// Subjective and objective acceptance consider all the possible audiences.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::preferences::{PreferenceReduction, VafFramework};
+/// # use crustabri::aa::AAFramework;
+/// # use crustabri::solvers::{PreferredSemanticsSolver, SkepticalAcceptanceComputer};
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b"]);
+/// let mut vaf = VafFramework::new_with_argument_set(arguments);
+/// vaf.new_attack(&"a", &"b").unwrap();
+/// vaf.new_attack(&"b", &"a").unwrap();
+/// vaf.set_value(&"a", "life").unwrap();
+/// vaf.set_value(&"b", "property").unwrap();
+/// assert_eq!(vec!["life", "property"], vaf.audience());
+/// assert_eq!(1, vaf.reduce(PreferenceReduction::Removal).n_attacks());
+/// fn factory<'a>(
+///     af: &'a AAFramework<&'static str>,
+/// ) -> Box<dyn SkepticalAcceptanceComputer<&'static str> + 'a> {
+///     Box::new(PreferredSemanticsSolver::new(af))
+/// }
+/// assert!(vaf.is_subjectively_accepted(&"b", PreferenceReduction::Removal, &factory).unwrap());
+/// assert!(!vaf.is_objectively_accepted(&"b", PreferenceReduction::Removal, &factory).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct VafFramework<T>
+where
+    T: LabelType,
+{
+    af: AAFramework<T>,
+    values: Vec<String>,
+    arg_values: Vec<Option<usize>>,
+    audience: Vec<usize>,
+}
+
+impl<T> VafFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds a VAF with its argument set, and neither attacks nor values.
+    pub fn new_with_argument_set(arguments: ArgumentSet<T>) -> Self {
+        Self::new_from_af(AAFramework::new_with_argument_set(arguments))
+    }
+
+    /// Builds a VAF from an AF, with no values.
+    pub fn new_from_af(af: AAFramework<T>) -> Self {
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        VafFramework {
+            af,
+            values: vec![],
+            arg_values: vec![None; n],
+            audience: vec![],
+        }
+    }
+
+    /// Returns the AF made of the arguments and the attacks of this framework.
+    pub fn af(&self) -> &AAFramework<T> {
+        &self.af
+    }
+
+    /// Returns the argument set of this framework.
+    pub fn argument_set(&self) -> &ArgumentSet<T> {
+        self.af.argument_set()
+    }
+
+    /// Returns the number of arguments in this framework.
+    pub fn n_arguments(&self) -> usize {
+        self.af.n_arguments()
+    }
+
+    /// Returns the number of attacks in this framework.
+    pub fn n_attacks(&self) -> usize {
+        self.af.n_attacks()
+    }
+
+    /// Adds an attack given the labels of the attacker and the attacked arguments.
+    ///
+    /// If one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_attack(&mut self, from: &T, to: &T) -> Result<()> {
+        self.af.new_attack(from, to)
+    }
+
+    /// Provides an iterator to the attacks.
+    pub fn iter_attacks(&self) -> impl Iterator<Item = Attack<'_, T>> + '_ {
+        self.af.iter_attacks()
+    }
+
+    /// Sets the value of an argument given its label.
+    ///
+    /// Values that were not used before are added to the audience, as the least preferred ones.
+    /// If the argument does not belong to this framework, or if it already has another value, an error is returned.
+    pub fn set_value(&mut self, arg: &T, value: &str) -> Result<()> {
+        let context = || format!("cannot set the value of {:?} to {:?}", arg, value);
+        let arg_id = self
+            .argument_set()
+            .get_argument(arg)
+            .with_context(context)?
+            .id();
+        let value_index = match self.values.iter().position(|v| v == value) {
+            Some(i) => i,
+            None => {
+                self.values.push(value.to_string());
+                self.audience.push(self.values.len() - 1);
+                self.values.len() - 1
+            }
+        };
+        match self.arg_values[arg_id] {
+            Some(i) if i != value_index => Err(anyhow!(
+                "the argument already has the value {:?}",
+                self.values[i]
+            ))
+            .with_context(context),
+            _ => {
+                self.arg_values[arg_id] = Some(value_index);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the value of an argument, if any.
+    pub fn value_of(&self, arg: &Argument<T>) -> Option<&str> {
+        self.arg_values[arg.id()].map(|i| self.values[i].as_str())
+    }
+
+    /// Returns the values, in the order they were introduced.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Returns the audience of this framework, from the most preferred value to the least preferred one.
+    pub fn audience(&self) -> Vec<&str> {
+        self.audience
+            .iter()
+            .map(|i| self.values[*i].as_str())
+            .collect()
+    }
+
+    /// Sets the audience of this framework, given from the most preferred value to the least preferred one.
+    ///
+    /// If the provided values are not a permutation of the values of this framework, an error is returned.
+    pub fn set_audience(&mut self, audience: &[&str]) -> Result<()> {
+        let context = || format!("cannot set the audience to {:?}", audience);
+        let indices = audience
+            .iter()
+            .map(|v| {
+                self.values
+                    .iter()
+                    .position(|w| w == v)
+                    .ok_or_else(|| anyhow!("unknown value {:?}", v))
+            })
+            .collect::<Result<Vec<usize>>>()
+            .with_context(context)?;
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        if sorted.len() != self.values.len() || indices.len() != self.values.len() {
+            return Err(anyhow!("each value must appear exactly once")).with_context(context);
+        }
+        self.audience = indices;
+        Ok(())
+    }
+
+    /// Builds the AF given by a reduction, using the audience of this framework.
+    ///
+    /// The arguments of the returned AF have the same labels as the ones of this framework.
+    pub fn reduce(&self, reduction: PreferenceReduction) -> AAFramework<T> {
+        self.reduce_for_audience(reduction, &self.audience)
+    }
+
+    fn reduce_for_audience(
+        &self,
+        reduction: PreferenceReduction,
+        audience: &[usize],
+    ) -> AAFramework<T> {
+        let mut ranks = vec![0; audience.len()];
+        audience
+            .iter()
+            .enumerate()
+            .for_each(|(rank, v)| ranks[*v] = rank);
+        reduction.reduce(&self.af, |a, b| {
+            match (self.arg_values[a], self.arg_values[b]) {
+                (Some(va), Some(vb)) => ranks[va] < ranks[vb],
+                _ => false,
+            }
+        })
+    }
+
+    /// Checks whether an argument is subjectively accepted.
+    ///
+    /// An argument is subjectively accepted if there is an audience for which it is skeptically accepted in the AF given by the reduction.
+    /// The semantics is given by a function building skeptical acceptance solvers (see [`SkepticalAcceptanceComputerFactoryFn`]).
+    /// The usual definition of VAFs uses the removal reduction and the preferred semantics.
+    ///
+    /// Since all the audiences are considered, this function is intended for frameworks with a few values.
+    /// If the argument does not belong to this framework, an error is returned.
+    pub fn is_subjectively_accepted(
+        &self,
+        arg: &T,
+        reduction: PreferenceReduction,
+        solver_factory: &SkepticalAcceptanceComputerFactoryFn<T>,
+    ) -> Result<bool> {
+        self.argument_set().get_argument(arg)?;
+        let all_rejected = for_each_audience(self.values.len(), |audience| {
+            let af = self.reduce_for_audience(reduction, audience);
+            let mut solver = (solver_factory)(&af);
+            !solver.is_skeptically_accepted(arg)
+        });
+        Ok(!all_rejected)
+    }
+
+    /// Checks whether an argument is objectively accepted.
+    ///
+    /// An argument is objectively accepted if it is skeptically accepted in the AFs given by the reduction for all the audiences.
+    /// The semantics is given by a function building skeptical acceptance solvers (see [`SkepticalAcceptanceComputerFactoryFn`]).
+    /// The usual definition of VAFs uses the removal reduction and the preferred semantics.
+    ///
+    /// Since all the audiences are considered, this function is intended for frameworks with a few values.
+    /// If the argument does not belong to this framework, an error is returned.
+    pub fn is_objectively_accepted(
+        &self,
+        arg: &T,
+        reduction: PreferenceReduction,
+        solver_factory: &SkepticalAcceptanceComputerFactoryFn<T>,
+    ) -> Result<bool> {
+        self.argument_set().get_argument(arg)?;
+        Ok(for_each_audience(self.values.len(), |audience| {
+            let af = self.reduce_for_audience(reduction, audience);
+            let mut solver = (solver_factory)(&af);
+            solver.is_skeptically_accepted(arg)
+        }))
+    }
+}
+
+// Calls the function on each permutation of the values (using Heap's algorithm) while it returns true.
+// Returns false iff the function returned false.
+fn for_each_audience<F>(n_values: usize, mut f: F) -> bool
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    let mut audience = (0..n_values).collect::<Vec<usize>>();
+    if !f(&audience) {
+        return false;
+    }
+    let mut counters = vec![0; n_values];
+    let mut i = 1;
+    while i < n_values {
+        if counters[i] < i {
+            if i % 2 == 0 {
+                audience.swap(0, i);
+            } else {
+                audience.swap(counters[i], i);
+            }
+            if !f(&audience) {
+                return false;
+            }
+            counters[i] += 1;
+            i = 1;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{GroundedSemanticsSolver, PreferredSemanticsSolver};
+
+    fn preferred_factory<'a>(
+        af: &'a AAFramework<&'static str>,
+    ) -> Box<dyn SkepticalAcceptanceComputer<&'static str> + 'a> {
+        Box::new(PreferredSemanticsSolver::new(af))
+    }
+
+    fn grounded_factory<'a>(
+        af: &'a AAFramework<&'static str>,
+    ) -> Box<dyn SkepticalAcceptanceComputer<&'static str> + 'a> {
+        Box::new(GroundedSemanticsSolver::new(af))
+    }
+
+    // a, b and c form an odd cycle; a and c promote v1 and b promotes v2
+    fn vaf() -> VafFramework<&'static str> {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut vaf = VafFramework::new_with_argument_set(arguments);
+        vaf.new_attack(&"a", &"b").unwrap();
+        vaf.new_attack(&"b", &"c").unwrap();
+        vaf.new_attack(&"c", &"a").unwrap();
+        vaf.set_value(&"a", "v1").unwrap();
+        vaf.set_value(&"b", "v2").unwrap();
+        vaf.set_value(&"c", "v1").unwrap();
+        vaf
+    }
+
+    #[test]
+    fn test_set_value() {
+        let mut vaf = vaf();
+        vaf.set_value(&"a", "v1").unwrap();
+        assert!(vaf.set_value(&"a", "v2").is_err());
+        assert!(vaf.set_value(&"d", "v1").is_err());
+        assert_eq!(&["v1", "v2"], vaf.values());
+        let b = vaf.argument_set().get_argument(&"b").unwrap();
+        assert_eq!(Some("v2"), vaf.value_of(b));
+    }
+
+    #[test]
+    fn test_set_audience() {
+        let mut vaf = vaf();
+        assert_eq!(vec!["v1", "v2"], vaf.audience());
+        vaf.set_audience(&["v2", "v1"]).unwrap();
+        assert_eq!(vec!["v2", "v1"], vaf.audience());
+        assert!(vaf.set_audience(&["v2"]).is_err());
+        assert!(vaf.set_audience(&["v2", "v2"]).is_err());
+        assert!(vaf.set_audience(&["v2", "v1", "v3"]).is_err());
+        assert_eq!(vec!["v2", "v1"], vaf.audience());
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut vaf = vaf();
+        // v1 > v2: the attack from b to c is removed
+        assert_eq!(2, vaf.reduce(PreferenceReduction::Removal).n_attacks());
+        vaf.set_audience(&["v2", "v1"]).unwrap();
+        // v2 > v1: the attack from a to b is removed
+        let af = vaf.reduce(PreferenceReduction::Removal);
+        assert_eq!(2, af.n_attacks());
+        let mut grounded = af
+            .grounded_extension()
+            .iter()
+            .map(|a| *a.label())
+            .collect::<Vec<&str>>();
+        grounded.sort_unstable();
+        assert_eq!(vec!["a", "b"], grounded);
+    }
+
+    #[test]
+    fn test_subjective_and_objective_acceptance() {
+        let vaf = vaf();
+        let reduction = PreferenceReduction::Removal;
+        // v1 > v2: {b, c} is accepted; v2 > v1: {a, b} is accepted
+        assert!(vaf
+            .is_objectively_accepted(&"b", reduction, &preferred_factory)
+            .unwrap());
+        for arg in ["a", "c"] {
+            assert!(vaf
+                .is_subjectively_accepted(&arg, reduction, &preferred_factory)
+                .unwrap());
+            assert!(!vaf
+                .is_objectively_accepted(&arg, reduction, &preferred_factory)
+                .unwrap());
+        }
+        assert!(vaf
+            .is_subjectively_accepted(&"d", reduction, &preferred_factory)
+            .is_err());
+    }
+
+    #[test]
+    fn test_objective_acceptance() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut vaf = VafFramework::new_with_argument_set(arguments);
+        vaf.new_attack(&"a", &"b").unwrap();
+        vaf.new_attack(&"b", &"c").unwrap();
+        vaf.set_value(&"a", "v1").unwrap();
+        vaf.set_value(&"b", "v2").unwrap();
+        vaf.set_value(&"c", "v1").unwrap();
+        let reduction = PreferenceReduction::Removal;
+        // v1 > v2: {a, c} is accepted; v2 > v1: {a, b} is accepted
+        assert!(vaf
+            .is_objectively_accepted(&"a", reduction, &grounded_factory)
+            .unwrap());
+        for arg in ["b", "c"] {
+            assert!(vaf
+                .is_subjectively_accepted(&arg, reduction, &grounded_factory)
+                .unwrap());
+            assert!(!vaf
+                .is_objectively_accepted(&arg, reduction, &grounded_factory)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_for_each_audience() {
+        let mut audiences = vec![];
+        assert!(for_each_audience(3, |a| {
+            audiences.push(a.to_vec());
+            true
+        }));
+        audiences.sort_unstable();
+        audiences.dedup();
+        assert_eq!(6, audiences.len());
+        let mut n = 0;
+        assert!(!for_each_audience(3, |_| {
+            n += 1;
+            n < 2
+        }));
+        assert_eq!(2, n);
+        assert!(for_each_audience(0, |a| a.is_empty()));
+    }
+}
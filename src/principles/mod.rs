@@ -0,0 +1,9 @@
+//! Checks of the principles (or properties) satisfied by the semantics on concrete frameworks.
+//!
+//! Each principle is a variant of [Principle], which can be checked on an AF given a function building solvers for a semantics.
+//! When the principle does not hold, a [Counterexample] is returned.
+
+mod principle_checks;
+pub use principle_checks::Counterexample;
+pub use principle_checks::ExtensionEnumeratorFactoryFn;
+pub use principle_checks::Principle;
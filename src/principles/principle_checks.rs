@@ -0,0 +1,447 @@
+use crate::{
+    aa::{AAFramework, Argument},
+    solvers::ExtensionEnumerator,
+    utils::{self, LabelType},
+};
+use strum_macros::{AsRefStr, EnumIter};
+
+/// The type of the functions building a solver for a semantics on an AF.
+///
+/// Such functions are given to the principle checks, since some principles involve the extensions of sub-frameworks.
+pub type ExtensionEnumeratorFactoryFn<T> =
+    dyn for<'b> Fn(&'b AAFramework<T>) -> Box<dyn ExtensionEnumerator<T> + 'b>;
+
+/// A counterexample showing that a semantics does not satisfy a principle on an AF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Counterexample<'a, T>
+where
+    T: LabelType,
+{
+    /// An extension of the AF for which the principle does not hold.
+    Extension(Vec<&'a Argument<T>>),
+    /// A set of arguments the principle requires to be an extension of the AF, while it is not.
+    ///
+    /// For directionality, the set is an extension of an unattacked sub-framework that is not the restriction of an extension of the AF.
+    MissingExtension(Vec<&'a Argument<T>>),
+}
+
+/// The principles (or properties) that can be checked for a semantics on an AF.
+///
+/// Checking a principle requires a function building solvers for the semantics under consideration (see [ExtensionEnumeratorFactoryFn]).
+/// Since the checks enumerate the extensions of the AF (and of some of its sub-frameworks), they are intended for small AFs.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet};
+/// # use crustabri::principles::{Counterexample, Principle};
+/// # use crustabri::solvers::{CompleteSemanticsSolver, PreferredSemanticsSolver};
+/// let arg_labels = vec!["a", "b"];
+/// let args = ArgumentSet::new_with_labels(&arg_labels);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"b", &"a").unwrap();
+/// assert!(Principle::IMaximality
+///     .check(&af, &|af| Box::new(PreferredSemanticsSolver::new(af)))
+///     .is_none());
+/// assert_eq!(
+///     Some(Counterexample::Extension(vec![])),
+///     Principle::IMaximality.check(&af, &|af| Box::new(CompleteSemanticsSolver::new(af))),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AsRefStr, EnumIter)]
+pub enum Principle {
+    /// Each extension is conflict-free.
+    ConflictFreeness,
+    /// Each extension is admissible.
+    Admissibility,
+    /// Each extension contains the arguments it defends.
+    Reinstatement,
+    /// No extension is strictly included in another one.
+    IMaximality,
+    /// For each unattacked set of arguments `U`, the extensions of the restriction of the AF to `U` are the restrictions of its extensions to `U`.
+    ///
+    /// The unattacked sets under consideration are, for each strongly connected component, the union of this component and its ancestors.
+    Directionality,
+    /// The extensions are the sets built by the SCC-recursive decomposition of the AF, using the semantics itself on each strongly connected component.
+    ///
+    /// The components are considered in a topological order.
+    /// Given the part `E` of a set already computed for the previous components,
+    /// the arguments of the current component that are attacked by `E` are discarded,
+    /// and the attackers from the previous components which are neither in `E` nor attacked by `E` are kept as self-attacking arguments,
+    /// preventing the remaining arguments they attack from being defended against them.
+    /// The parts of the set for this component are the intersections of the component and the extensions of this sub-framework.
+    SccRecursiveness,
+}
+
+impl Principle {
+    /// Checks whether the semantics satisfies this principle on the AF.
+    ///
+    /// The semantics is given by a function building solvers (see [ExtensionEnumeratorFactoryFn]).
+    /// If the principle holds, [Option::None] is returned.
+    /// Otherwise, a counterexample is returned.
+    pub fn check<'a, T>(
+        &self,
+        af: &'a AAFramework<T>,
+        solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+    ) -> Option<Counterexample<'a, T>>
+    where
+        T: LabelType,
+    {
+        match self {
+            Principle::ConflictFreeness => {
+                check_each_extension(af, solver_factory, |af, ext| is_conflict_free(af, ext))
+            }
+            Principle::Admissibility => check_each_extension(af, solver_factory, |af, ext| {
+                is_conflict_free(af, ext) && defended_args(af, ext).1
+            }),
+            Principle::Reinstatement => check_each_extension(af, solver_factory, |af, ext| {
+                let (defended, _) = defended_args(af, ext);
+                let mut in_ext = vec![false; defended.len()];
+                ext.iter().for_each(|a| in_ext[a.id()] = true);
+                defended
+                    .iter()
+                    .enumerate()
+                    .all(|(id, defended)| !defended || in_ext[id])
+            }),
+            Principle::IMaximality => check_i_maximality(af, solver_factory),
+            Principle::Directionality => check_directionality(af, solver_factory),
+            Principle::SccRecursiveness => check_scc_recursiveness(af, solver_factory),
+        }
+    }
+}
+
+fn collect_extensions<'a, T>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+) -> Vec<Vec<&'a Argument<T>>>
+where
+    T: LabelType,
+{
+    let mut extensions = Vec::new();
+    let mut solver = (solver_factory)(af);
+    solver.enumerate_extensions(&mut |ext| {
+        extensions.push(
+            ext.iter()
+                .map(|a| af.argument_set().get_argument_by_id(a.id()))
+                .collect(),
+        );
+        true
+    });
+    extensions
+}
+
+fn check_each_extension<'a, T, F>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+    property: F,
+) -> Option<Counterexample<'a, T>>
+where
+    T: LabelType,
+    F: Fn(&AAFramework<T>, &[&Argument<T>]) -> bool,
+{
+    collect_extensions(af, solver_factory)
+        .into_iter()
+        .find(|ext| !property(af, ext))
+        .map(Counterexample::Extension)
+}
+
+fn is_conflict_free<T>(af: &AAFramework<T>, set: &[&Argument<T>]) -> bool
+where
+    T: LabelType,
+{
+    let mut in_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+    set.iter().for_each(|a| in_set[a.id()] = true);
+    set.iter().all(|a| {
+        af.iter_attacks_to(a)
+            .all(|att| !in_set[att.attacker().id()])
+    })
+}
+
+// Returns the flags of the arguments defended by the set (indexed by their ids), and whether the set defends all its arguments.
+fn defended_args<T>(af: &AAFramework<T>, set: &[&Argument<T>]) -> (Vec<bool>, bool)
+where
+    T: LabelType,
+{
+    let mut attacked_by_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+    set.iter().for_each(|a| {
+        af.iter_attacks_from(a)
+            .for_each(|att| attacked_by_set[att.attacked().id()] = true)
+    });
+    let mut defended = vec![false; attacked_by_set.len()];
+    af.argument_set().iter().for_each(|a| {
+        defended[a.id()] = af
+            .iter_attacks_to(a)
+            .all(|att| attacked_by_set[att.attacker().id()])
+    });
+    let defends_itself = set.iter().all(|a| defended[a.id()]);
+    (defended, defends_itself)
+}
+
+fn check_i_maximality<'a, T>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+) -> Option<Counterexample<'a, T>>
+where
+    T: LabelType,
+{
+    let extensions = collect_extensions(af, solver_factory);
+    extensions
+        .iter()
+        .find(|ext| {
+            extensions
+                .iter()
+                .any(|other| other.len() > ext.len() && ext.iter().all(|a| other.contains(a)))
+        })
+        .map(|ext| Counterexample::Extension(ext.clone()))
+}
+
+fn check_directionality<'a, T>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+) -> Option<Counterexample<'a, T>>
+where
+    T: LabelType,
+{
+    let extensions = collect_extensions(af, solver_factory);
+    for scc in utils::strongly_connected_components(af) {
+        let in_unattacked = utils::ancestors(af, &scc);
+        let unattacked = af
+            .argument_set()
+            .iter()
+            .filter(|a| in_unattacked[a.id()])
+            .collect::<Vec<&Argument<T>>>();
+        if unattacked.len() == af.n_arguments() {
+            continue;
+        }
+        let restricted_af = utils::restricted_framework(af, &unattacked);
+        let restricted_extensions = collect_extensions(&restricted_af, solver_factory)
+            .iter()
+            .map(|ext| to_init_args(af, ext))
+            .collect::<Vec<Vec<&Argument<T>>>>();
+        let projections = extensions
+            .iter()
+            .map(|ext| {
+                ext.iter()
+                    .filter(|a| in_unattacked[a.id()])
+                    .copied()
+                    .collect()
+            })
+            .collect::<Vec<Vec<&Argument<T>>>>();
+        for (ext, projection) in extensions.iter().zip(projections.iter()) {
+            if !restricted_extensions
+                .iter()
+                .any(|r| is_same_set(r, projection))
+            {
+                return Some(Counterexample::Extension(ext.clone()));
+            }
+        }
+        for restricted_ext in restricted_extensions.iter() {
+            if !projections.iter().any(|p| is_same_set(p, restricted_ext)) {
+                return Some(Counterexample::MissingExtension(restricted_ext.clone()));
+            }
+        }
+    }
+    None
+}
+
+fn check_scc_recursiveness<'a, T>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+) -> Option<Counterexample<'a, T>>
+where
+    T: LabelType,
+{
+    let extensions = collect_extensions(af, solver_factory);
+    let sccs = utils::strongly_connected_components(af);
+    let mut decomposed = Vec::new();
+    decompose_from_scc(af, solver_factory, &sccs, &mut Vec::new(), &mut decomposed);
+    if let Some(ext) = extensions
+        .iter()
+        .find(|ext| !decomposed.iter().any(|d| is_same_set(d, ext)))
+    {
+        return Some(Counterexample::Extension(ext.clone()));
+    }
+    decomposed
+        .into_iter()
+        .find(|d| !extensions.iter().any(|ext| is_same_set(d, ext)))
+        .map(Counterexample::MissingExtension)
+}
+
+fn decompose_from_scc<'a, T>(
+    af: &'a AAFramework<T>,
+    solver_factory: &ExtensionEnumeratorFactoryFn<T>,
+    sccs: &[Vec<&'a Argument<T>>],
+    current: &mut Vec<&'a Argument<T>>,
+    results: &mut Vec<Vec<&'a Argument<T>>>,
+) where
+    T: LabelType,
+{
+    if sccs.is_empty() {
+        results.push(current.clone());
+        return;
+    }
+    let n = 1 + af.max_argument_id().unwrap_or_default();
+    let mut in_current = vec![false; n];
+    current.iter().for_each(|a| in_current[a.id()] = true);
+    let mut attacked_by_current = vec![false; n];
+    current.iter().for_each(|a| {
+        af.iter_attacks_from(a)
+            .for_each(|att| attacked_by_current[att.attacked().id()] = true)
+    });
+    let mut in_scc = vec![false; n];
+    sccs[0].iter().for_each(|a| in_scc[a.id()] = true);
+    let undefeated = sccs[0]
+        .iter()
+        .filter(|a| !attacked_by_current[a.id()])
+        .copied()
+        .collect::<Vec<&Argument<T>>>();
+    let mut guards = Vec::new();
+    let mut is_guard = vec![false; n];
+    undefeated.iter().for_each(|a| {
+        af.iter_attacks_to(a).for_each(|att| {
+            let attacker = att.attacker();
+            if !in_scc[attacker.id()]
+                && !in_current[attacker.id()]
+                && !attacked_by_current[attacker.id()]
+                && !is_guard[attacker.id()]
+            {
+                is_guard[attacker.id()] = true;
+                guards.push(attacker);
+            }
+        })
+    });
+    let mut sub_af_args = undefeated.clone();
+    sub_af_args.append(&mut guards.clone());
+    let mut sub_af = utils::restricted_framework(af, &sub_af_args);
+    for guard in guards.iter() {
+        if !af
+            .iter_attacks_to(guard)
+            .any(|att| att.attacker().id() == guard.id())
+        {
+            sub_af.new_attack(guard.label(), guard.label()).unwrap();
+        }
+    }
+    let local_parts = collect_extensions(&sub_af, solver_factory)
+        .iter()
+        .map(|ext| {
+            to_init_args(af, ext)
+                .into_iter()
+                .filter(|a| in_scc[a.id()])
+                .collect()
+        })
+        .collect::<Vec<Vec<&Argument<T>>>>();
+    for local_part in local_parts {
+        let current_len = current.len();
+        current.extend(local_part);
+        decompose_from_scc(af, solver_factory, &sccs[1..], current, results);
+        current.truncate(current_len);
+    }
+}
+
+fn to_init_args<'a, T>(af: &'a AAFramework<T>, args: &[&Argument<T>]) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    args.iter()
+        .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+        .collect()
+}
+
+fn is_same_set<T>(set0: &[&Argument<T>], set1: &[&Argument<T>]) -> bool
+where
+    T: LabelType,
+{
+    set0.len() == set1.len() && set0.iter().all(|a| set1.iter().any(|b| a.id() == b.id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aa::ArgumentSet,
+        solvers::{
+            Cf2SemanticsSolver, CompleteSemanticsSolver, NaiveSemanticsSolver,
+            PreferredSemanticsSolver, StableSemanticsSolver,
+        },
+    };
+    use strum::IntoEnumIterator;
+
+    fn labels_of<'a>(ext: &[&Argument<&'a str>]) -> Vec<&'a str> {
+        let mut labels = ext.iter().map(|a| *a.label()).collect::<Vec<&str>>();
+        labels.sort_unstable();
+        labels
+    }
+
+    fn build_af<'a>(labels: &[&'a str], attacks: &[(&'a str, &'a str)]) -> AAFramework<&'a str> {
+        let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(labels));
+        for (from, to) in attacks {
+            af.new_attack(from, to).unwrap();
+        }
+        af
+    }
+
+    #[test]
+    fn test_complete_is_not_i_maximal() {
+        let af = build_af(&["a", "b"], &[("a", "b"), ("b", "a")]);
+        match Principle::IMaximality.check(&af, &|af| Box::new(CompleteSemanticsSolver::new(af))) {
+            Some(Counterexample::Extension(ext)) => assert!(ext.is_empty()),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_stable_is_not_directional() {
+        let af = build_af(&["a", "b"], &[("b", "b")]);
+        match Principle::Directionality.check(&af, &|af| Box::new(StableSemanticsSolver::new(af))) {
+            Some(Counterexample::MissingExtension(ext)) => assert_eq!(vec!["a"], labels_of(&ext)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_cf2_is_not_admissible() {
+        let af = build_af(&["a", "b", "c"], &[("a", "b"), ("b", "c"), ("c", "a")]);
+        let factory: &ExtensionEnumeratorFactoryFn<&str> =
+            &|af| Box::new(Cf2SemanticsSolver::new(af));
+        assert!(Principle::ConflictFreeness.check(&af, factory).is_none());
+        match Principle::Admissibility.check(&af, factory) {
+            Some(Counterexample::Extension(ext)) => assert_eq!(1, ext.len()),
+            _ => panic!(),
+        }
+        assert!(Principle::SccRecursiveness.check(&af, factory).is_none());
+    }
+
+    #[test]
+    fn test_naive_does_not_satisfy_reinstatement() {
+        let af = build_af(&["a", "b"], &[("a", "b")]);
+        match Principle::Reinstatement.check(&af, &|af| Box::new(NaiveSemanticsSolver::new(af))) {
+            Some(Counterexample::Extension(ext)) => assert_eq!(vec!["b"], labels_of(&ext)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_preferred_satisfies_all_principles() {
+        let af = build_af(
+            &["a", "b", "c", "d"],
+            &[
+                ("a", "b"),
+                ("b", "a"),
+                ("a", "c"),
+                ("b", "c"),
+                ("c", "d"),
+                ("d", "c"),
+            ],
+        );
+        for principle in Principle::iter() {
+            assert!(
+                principle
+                    .check(&af, &|af| Box::new(PreferredSemanticsSolver::new(af)))
+                    .is_none(),
+                "{}",
+                principle.as_ref()
+            );
+        }
+    }
+}
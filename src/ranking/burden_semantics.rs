@@ -0,0 +1,161 @@
+use super::{
+    gradual_semantics::{DEFAULT_MAX_ITERATIONS, DEFAULT_TOLERANCE},
+    specs::{self, RankingComputer},
+    Ranking,
+};
+use crate::{aa::AAFramework, utils::LabelType};
+
+/// A computer for the Burden-based ranking semantics.
+///
+/// This semantics was introduced by Amgoud and Ben-Naim.
+/// The burden numbers of an argument are defined step by step:
+/// at step `0`, all the arguments have a burden of `1`;
+/// at step `i`, the burden of an argument is `1 + s`, where `s` is the sum of the inverses of the burdens of its attackers at step `i - 1`.
+/// Arguments are compared using the lexicographical order on their sequences of burden numbers (starting at step `1`):
+/// the lower the burden numbers, the stronger the argument.
+///
+/// The sequences are computed until the difference between two successive steps is not greater than the tolerance or the iteration limit is reached.
+/// The value associated with an argument is its sequence of burden numbers;
+/// burden numbers are compared up to the tolerance to build the preorder.
+pub struct BurdenRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    tolerance: f64,
+    max_iterations: usize,
+}
+
+impl<'a, T> BurdenRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new computer for the Burden-based semantics.
+    ///
+    /// The tolerance is set to `1e-9` and the iteration limit to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::ranking::{RankingComputer, BurdenRankingComputer};
+    /// fn print_strongest<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut computer = BurdenRankingComputer::new(af);
+    ///     let ranking = computer.compute_ranking();
+    ///     if let Some(class) = ranking.preorder().first() {
+    ///         println!("the strongest arguments are {:?}", class);
+    ///     }
+    /// }
+    /// # print_strongest::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self {
+            af,
+            tolerance: DEFAULT_TOLERANCE,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    /// Sets the convergence tolerance.
+    ///
+    /// # Panics
+    ///
+    /// The tolerance must be strictly positive.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        assert!(tolerance > 0., "the tolerance must be strictly positive");
+        self.tolerance = tolerance;
+    }
+
+    /// Sets the maximal number of iterations, that is the maximal length of the sequences of burden numbers.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+}
+
+impl<T> RankingComputer<T> for BurdenRankingComputer<'_, T>
+where
+    T: LabelType,
+{
+    type Value = Vec<f64>;
+
+    fn compute_ranking(&mut self) -> Ranking<'_, T, Vec<f64>> {
+        let af = self.af;
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        let mut sequences = vec![Vec::new(); n];
+        let mut previous = vec![1.; n];
+        let mut current = vec![1.; n];
+        for _ in 0..self.max_iterations {
+            let mut max_diff: f64 = 0.;
+            for arg in af.argument_set().iter() {
+                let burden = 1.
+                    + af.iter_attacks_to(arg)
+                        .map(|att| 1. / previous[att.attacker().id()])
+                        .sum::<f64>();
+                max_diff = max_diff.max((burden - previous[arg.id()]).abs());
+                current[arg.id()] = burden;
+                sequences[arg.id()].push(burden);
+            }
+            std::mem::swap(&mut previous, &mut current);
+            if max_diff <= self.tolerance {
+                break;
+            }
+        }
+        let tolerance = self.tolerance;
+        Ranking::new_with_key(af, sequences, |seq| {
+            seq.iter()
+                .map(|b| specs::quantize(*b, tolerance))
+                .collect::<Vec<i64>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    #[test]
+    fn test_burden() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a3,a2).
+        att(a0,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let arg = |l: &str| af.argument_set().get_argument(&l.to_string()).unwrap();
+        let mut computer = BurdenRankingComputer::new(&af);
+        let ranking = computer.compute_ranking();
+        assert_eq!(&[1., 1., 1.], ranking.value_of(arg("a0")).as_slice());
+        assert_eq!(&[2., 2., 2.], ranking.value_of(arg("a1")).as_slice());
+        assert_eq!(&[3., 2., 2.], ranking.value_of(arg("a2")).as_slice());
+        assert_eq!(0, ranking.rank_of(arg("a0")));
+        assert_eq!(1, ranking.rank_of(arg("a1")));
+        assert_eq!(1, ranking.rank_of(arg("a3")));
+        assert_eq!(2, ranking.rank_of(arg("a2")));
+    }
+
+    #[test]
+    fn test_burden_iteration_limit() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut computer = BurdenRankingComputer::new(&af);
+        computer.set_max_iterations(3);
+        let ranking = computer.compute_ranking();
+        assert!(ranking.values().iter().all(|seq| seq.len() == 3));
+        assert_eq!(2, ranking.preorder().len());
+    }
+}
@@ -0,0 +1,413 @@
+use super::{
+    specs::{self, RankingComputer},
+    Ranking,
+};
+use crate::{
+    aa::{AAFramework, Argument},
+    utils::LabelType,
+};
+use std::cmp::Reverse;
+
+pub(crate) const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+pub(crate) const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+const DEFAULT_DAMPING_FACTOR: f64 = 0.98;
+
+macro_rules! gradual_ranking_computer {
+    ($computer_ident:ident, $sem_name:literal, $update_fn:ident, $sem_doc:literal) => {
+        #[doc = concat!(" A computer for the ", $sem_name, " ranking-based semantics.")]
+        ///
+        #[doc = $sem_doc]
+        ///
+        /// The strengths are computed iteratively, starting from a strength of `1` for each argument,
+        /// until the difference between two successive steps is not greater than the tolerance or the iteration limit is reached.
+        /// The higher the strength, the stronger the argument; strengths are compared up to the tolerance to build the preorder.
+        pub struct $computer_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            af: &'a AAFramework<T>,
+            tolerance: f64,
+            max_iterations: usize,
+        }
+
+        impl<'a, T> $computer_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            /// Builds a new computer for this semantics.
+            ///
+            /// The tolerance is set to `1e-9` and the iteration limit to `1000`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            #[doc = concat!(" # use crustabri::ranking::{RankingComputer, ", stringify!($computer_ident), "};")]
+            /// fn print_strongest<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut computer = ", stringify!($computer_ident), "::new(af);")]
+            ///     let ranking = computer.compute_ranking();
+            ///     if let Some(class) = ranking.preorder().first() {
+            ///         println!("the strongest arguments are {:?}", class);
+            ///     }
+            /// }
+            /// # print_strongest::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new(af: &'a AAFramework<T>) -> Self {
+                Self {
+                    af,
+                    tolerance: DEFAULT_TOLERANCE,
+                    max_iterations: DEFAULT_MAX_ITERATIONS,
+                }
+            }
+
+            /// Sets the convergence tolerance.
+            ///
+            /// # Panics
+            ///
+            /// The tolerance must be strictly positive.
+            pub fn set_tolerance(&mut self, tolerance: f64) {
+                assert!(tolerance > 0., "the tolerance must be strictly positive");
+                self.tolerance = tolerance;
+            }
+
+            /// Sets the maximal number of iterations.
+            pub fn set_max_iterations(&mut self, max_iterations: usize) {
+                self.max_iterations = max_iterations;
+            }
+        }
+
+        impl<T> RankingComputer<T> for $computer_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            type Value = f64;
+
+            fn compute_ranking(&mut self) -> Ranking<'_, T, f64> {
+                let values =
+                    compute_fixpoint(self.af, self.tolerance, self.max_iterations, $update_fn);
+                let tolerance = self.tolerance;
+                Ranking::new_with_key(self.af, values, |v| {
+                    Reverse(specs::quantize(*v, tolerance))
+                })
+            }
+        }
+    };
+}
+
+gradual_ranking_computer!(
+    HCategoriserRankingComputer,
+    "h-categoriser",
+    h_categoriser_update,
+    " The strength of an argument is `1 / (1 + s)`, where `s` is the sum of the strengths of its attackers (Besnard and Hunter)."
+);
+
+gradual_ranking_computer!(
+    MaxBasedRankingComputer,
+    "max-based",
+    max_based_update,
+    " The strength of an argument is `1 / (1 + m)`, where `m` is the maximal strength of its attackers, or `0` if it is unattacked (Amgoud, Ben-Naim, Doder and Vesic)."
+);
+
+gradual_ranking_computer!(
+    CardBasedRankingComputer,
+    "card-based",
+    card_based_update,
+    " The strength of an argument is `1 / (1 + n + s / n)`, where `n` is its number of attackers and `s` the sum of their strengths, or `1` if it is unattacked (Amgoud, Ben-Naim, Doder and Vesic)."
+);
+
+fn h_categoriser_update<T>(af: &AAFramework<T>, values: &[f64], arg: &Argument<T>) -> f64
+where
+    T: LabelType,
+{
+    let sum = af
+        .iter_attacks_to(arg)
+        .map(|att| values[att.attacker().id()])
+        .sum::<f64>();
+    1. / (1. + sum)
+}
+
+fn max_based_update<T>(af: &AAFramework<T>, values: &[f64], arg: &Argument<T>) -> f64
+where
+    T: LabelType,
+{
+    let max = af
+        .iter_attacks_to(arg)
+        .map(|att| values[att.attacker().id()])
+        .fold(0., f64::max);
+    1. / (1. + max)
+}
+
+fn card_based_update<T>(af: &AAFramework<T>, values: &[f64], arg: &Argument<T>) -> f64
+where
+    T: LabelType,
+{
+    let (n_attackers, sum) = af.iter_attacks_to(arg).fold((0_usize, 0.), |(n, s), att| {
+        (n + 1, s + values[att.attacker().id()])
+    });
+    if n_attackers == 0 {
+        1.
+    } else {
+        1. / (1. + n_attackers as f64 + sum / n_attackers as f64)
+    }
+}
+
+/// A computer for the counting ranking-based semantics.
+///
+/// This semantics was introduced by Pu, Zhang, Luo and Luo.
+/// The strength of an argument counts the attack and defense paths ending at it,
+/// the contribution of each path being weighted according to its length by a damping factor.
+/// It is the fixpoint of the function setting the strength of an argument to `1 - (d / n) * s`,
+/// where `d` is the damping factor, `n` the maximal number of attackers of an argument, and `s` the sum of the strengths of its attackers.
+///
+/// The strengths are computed iteratively, starting from a strength of `1` for each argument,
+/// until the difference between two successive steps is not greater than the tolerance or the iteration limit is reached.
+/// The higher the strength, the stronger the argument; strengths are compared up to the tolerance to build the preorder.
+pub struct CountingRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    tolerance: f64,
+    max_iterations: usize,
+    damping_factor: f64,
+}
+
+impl<'a, T> CountingRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new computer for the counting semantics.
+    ///
+    /// The tolerance is set to `1e-9`, the iteration limit to `1000`, and the damping factor to `0.98`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::ranking::{RankingComputer, CountingRankingComputer};
+    /// fn print_strongest<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut computer = CountingRankingComputer::new(af);
+    ///     computer.set_damping_factor(0.9);
+    ///     let ranking = computer.compute_ranking();
+    ///     if let Some(class) = ranking.preorder().first() {
+    ///         println!("the strongest arguments are {:?}", class);
+    ///     }
+    /// }
+    /// # print_strongest::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self {
+            af,
+            tolerance: DEFAULT_TOLERANCE,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            damping_factor: DEFAULT_DAMPING_FACTOR,
+        }
+    }
+
+    /// Sets the convergence tolerance.
+    ///
+    /// # Panics
+    ///
+    /// The tolerance must be strictly positive.
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        assert!(tolerance > 0., "the tolerance must be strictly positive");
+        self.tolerance = tolerance;
+    }
+
+    /// Sets the maximal number of iterations.
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    /// Sets the damping factor.
+    ///
+    /// # Panics
+    ///
+    /// The damping factor must be in the open interval `(0, 1)`.
+    pub fn set_damping_factor(&mut self, damping_factor: f64) {
+        assert!(
+            damping_factor > 0. && damping_factor < 1.,
+            "the damping factor must be in (0, 1)"
+        );
+        self.damping_factor = damping_factor;
+    }
+}
+
+impl<T> RankingComputer<T> for CountingRankingComputer<'_, T>
+where
+    T: LabelType,
+{
+    type Value = f64;
+
+    fn compute_ranking(&mut self) -> Ranking<'_, T, f64> {
+        let af = self.af;
+        let max_in_degree = af
+            .argument_set()
+            .iter()
+            .map(|arg| af.iter_attacks_to(arg).count())
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let factor = self.damping_factor / max_in_degree as f64;
+        let values = compute_fixpoint(
+            af,
+            self.tolerance,
+            self.max_iterations,
+            |af, values, arg| {
+                1. - factor
+                    * af.iter_attacks_to(arg)
+                        .map(|att| values[att.attacker().id()])
+                        .sum::<f64>()
+            },
+        );
+        let tolerance = self.tolerance;
+        Ranking::new_with_key(af, values, |v| Reverse(specs::quantize(*v, tolerance)))
+    }
+}
+
+fn compute_fixpoint<T, F>(
+    af: &AAFramework<T>,
+    tolerance: f64,
+    max_iterations: usize,
+    update_fn: F,
+) -> Vec<f64>
+where
+    T: LabelType,
+    F: Fn(&AAFramework<T>, &[f64], &Argument<T>) -> f64,
+{
+    let mut values = vec![1.; af.max_argument_id().map(|id| id + 1).unwrap_or_default()];
+    let mut next_values = values.clone();
+    for _ in 0..max_iterations {
+        let mut max_diff: f64 = 0.;
+        for arg in af.argument_set().iter() {
+            let v = update_fn(af, &values, arg);
+            max_diff = max_diff.max((v - values[arg.id()]).abs());
+            next_values[arg.id()] = v;
+        }
+        std::mem::swap(&mut values, &mut next_values);
+        if max_diff <= tolerance {
+            break;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn preorder_labels(ranking: &Ranking<String, f64>) -> Vec<Vec<String>> {
+        ranking
+            .preorder()
+            .iter()
+            .map(|class| {
+                let mut labels = class
+                    .iter()
+                    .map(|a| a.label().to_string())
+                    .collect::<Vec<String>>();
+                labels.sort_unstable();
+                labels
+            })
+            .collect()
+    }
+
+    fn read_af(instance: &str) -> AAFramework<String> {
+        let reader = AspartixReader::default();
+        reader.read(&mut instance.as_bytes()).unwrap()
+    }
+
+    const INSTANCE: &str = r#"
+    arg(a0).
+    arg(a1).
+    arg(a2).
+    arg(a3).
+    arg(a4).
+    att(a0,a1).
+    att(a1,a2).
+    att(a3,a2).
+    att(a4,a3).
+    att(a3,a4).
+    "#;
+
+    #[test]
+    fn test_h_categoriser() {
+        let af = read_af(INSTANCE);
+        let mut computer = HCategoriserRankingComputer::new(&af);
+        let ranking = computer.compute_ranking();
+        let value =
+            |l: &str| *ranking.value_of(af.argument_set().get_argument(&l.to_string()).unwrap());
+        assert!((value("a0") - 1.).abs() < 1e-6);
+        assert!((value("a1") - 0.5).abs() < 1e-6);
+        let golden = (5_f64.sqrt() - 1.) / 2.;
+        assert!((value("a3") - golden).abs() < 1e-6);
+        assert!((value("a2") - 1. / (1.5 + golden)).abs() < 1e-6);
+        assert_eq!(
+            vec![
+                vec!["a0".to_string()],
+                vec!["a3".to_string(), "a4".to_string()],
+                vec!["a1".to_string()],
+                vec!["a2".to_string()],
+            ],
+            preorder_labels(&ranking)
+        );
+    }
+
+    #[test]
+    fn test_max_based_and_card_based() {
+        let af = read_af(INSTANCE);
+        let a2 = af.argument_set().get_argument(&"a2".to_string()).unwrap();
+        let a1 = af.argument_set().get_argument(&"a1".to_string()).unwrap();
+        let mut computer = MaxBasedRankingComputer::new(&af);
+        let ranking = computer.compute_ranking();
+        let golden = (5_f64.sqrt() - 1.) / 2.;
+        assert!((ranking.value_of(a2) - 1. / (1. + golden)).abs() < 1e-6);
+        assert_eq!(std::cmp::Ordering::Greater, ranking.compare(a2, a1));
+        let mut computer = CardBasedRankingComputer::new(&af);
+        let ranking = computer.compute_ranking();
+        assert!((ranking.value_of(a1) - 1. / 3.).abs() < 1e-6);
+        assert_eq!(std::cmp::Ordering::Less, ranking.compare(a2, a1));
+    }
+
+    #[test]
+    fn test_counting() {
+        let af = read_af(INSTANCE);
+        let mut computer = CountingRankingComputer::new(&af);
+        computer.set_damping_factor(0.9);
+        let ranking = computer.compute_ranking();
+        let value =
+            |l: &str| *ranking.value_of(af.argument_set().get_argument(&l.to_string()).unwrap());
+        assert!((value("a0") - 1.).abs() < 1e-6);
+        assert!((value("a1") - 0.55).abs() < 1e-6);
+        assert_eq!(vec!["a0".to_string()], preorder_labels(&ranking)[0]);
+    }
+
+    #[test]
+    fn test_iteration_limit() {
+        let af = read_af(INSTANCE);
+        let mut computer = HCategoriserRankingComputer::new(&af);
+        computer.set_max_iterations(1);
+        let ranking = computer.compute_ranking();
+        assert!(ranking.values().iter().all(|v| (v - 0.5).abs() < 1e-6
+            || (v - 1.).abs() < 1e-6
+            || (v - 1. / 3.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_empty_af() {
+        let af = AAFramework::<usize>::default();
+        let mut computer = CountingRankingComputer::new(&af);
+        assert!(computer.compute_ranking().preorder().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the tolerance must be strictly positive")]
+    fn test_null_tolerance() {
+        let af = AAFramework::<usize>::default();
+        HCategoriserRankingComputer::new(&af).set_tolerance(0.);
+    }
+}
@@ -0,0 +1,21 @@
+//! Ranking-based semantics, computing the strength of the arguments instead of their acceptance.
+//!
+//! Each semantics is implemented by a computer implementing the [RankingComputer] trait.
+//! The result is a [Ranking], giving the values computed for the arguments and the total preorder they induce.
+
+mod burden_semantics;
+pub use burden_semantics::BurdenRankingComputer;
+
+mod gradual_semantics;
+pub use gradual_semantics::CardBasedRankingComputer;
+pub use gradual_semantics::CountingRankingComputer;
+pub use gradual_semantics::HCategoriserRankingComputer;
+pub use gradual_semantics::MaxBasedRankingComputer;
+
+mod specs;
+pub use specs::Ranking;
+pub use specs::RankingComputer;
+
+mod tuples_semantics;
+pub use tuples_semantics::TupledValue;
+pub use tuples_semantics::TuplesRankingComputer;
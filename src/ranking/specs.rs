@@ -0,0 +1,136 @@
+use crate::{
+    aa::{AAFramework, Argument},
+    utils::LabelType,
+};
+use std::cmp::Ordering;
+
+/// A trait for objects able to rank the arguments of an AF.
+pub trait RankingComputer<T>
+where
+    T: LabelType,
+{
+    /// The type of the values computed for the arguments.
+    type Value;
+
+    /// Computes the ranking of the arguments.
+    fn compute_ranking(&mut self) -> Ranking<'_, T, Self::Value>;
+}
+
+/// The result of a ranking-based semantics.
+///
+/// It gives access both to the values computed for the arguments, indexed by their identifiers,
+/// and to the total preorder these values induce on the arguments.
+/// The preorder is given as a sequence of equivalence classes, from the strongest arguments to the weakest ones.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet};
+/// # use crustabri::ranking::{HCategoriserRankingComputer, RankingComputer};
+/// let arg_labels = vec!["a", "b", "c"];
+/// let args = ArgumentSet::new_with_labels(&arg_labels);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"b", &"c").unwrap();
+/// let mut computer = HCategoriserRankingComputer::new(&af);
+/// let ranking = computer.compute_ranking();
+/// let labels = ranking
+///     .preorder()
+///     .iter()
+///     .map(|class| class.iter().map(|arg| *arg.label()).collect())
+///     .collect::<Vec<Vec<&str>>>();
+/// assert_eq!(vec![vec!["a"], vec!["c"], vec!["b"]], labels);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ranking<'a, T, V>
+where
+    T: LabelType,
+{
+    values: Vec<V>,
+    ranks: Vec<usize>,
+    classes: Vec<Vec<&'a Argument<T>>>,
+}
+
+impl<'a, T, V> Ranking<'a, T, V>
+where
+    T: LabelType,
+{
+    /// Builds a ranking given the argument values and a function mapping values to keys.
+    ///
+    /// Arguments with smaller keys are stronger, and arguments with equal keys are equivalent.
+    pub(crate) fn new_with_key<K, F>(af: &'a AAFramework<T>, values: Vec<V>, key_fn: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&V) -> K,
+    {
+        let mut keyed = af
+            .argument_set()
+            .iter()
+            .map(|arg| (key_fn(&values[arg.id()]), arg))
+            .collect::<Vec<(K, &Argument<T>)>>();
+        keyed.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+        let mut ranks = vec![0; values.len()];
+        let mut classes: Vec<Vec<&Argument<T>>> = Vec::new();
+        for (i, (key, arg)) in keyed.iter().enumerate() {
+            if i == 0 || keyed[i - 1].0.cmp(key) != Ordering::Equal {
+                classes.push(vec![]);
+            }
+            ranks[arg.id()] = classes.len() - 1;
+            classes.last_mut().unwrap().push(arg);
+        }
+        Self {
+            values,
+            ranks,
+            classes,
+        }
+    }
+
+    /// Returns the values computed for the arguments, indexed by the argument identifiers.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns the value computed for an argument.
+    ///
+    /// # Panics
+    ///
+    /// The argument must belong to the AF.
+    /// If it is not the case, this function may panic.
+    pub fn value_of(&self, arg: &Argument<T>) -> &V {
+        &self.values[arg.id()]
+    }
+
+    /// Returns the equivalence classes of the preorder, from the strongest arguments to the weakest ones.
+    pub fn preorder(&self) -> &[Vec<&'a Argument<T>>] {
+        &self.classes
+    }
+
+    /// Returns the index of the equivalence class of an argument in the preorder.
+    ///
+    /// The strongest arguments have rank `0`.
+    ///
+    /// # Panics
+    ///
+    /// The argument must belong to the AF.
+    /// If it is not the case, this function may panic.
+    pub fn rank_of(&self, arg: &Argument<T>) -> usize {
+        self.ranks[arg.id()]
+    }
+
+    /// Compares the strength of two arguments.
+    ///
+    /// [Ordering::Greater] is returned if the first argument is strictly stronger than the second one.
+    ///
+    /// # Panics
+    ///
+    /// The arguments must belong to the AF.
+    /// If it is not the case, this function may panic.
+    pub fn compare(&self, arg0: &Argument<T>, arg1: &Argument<T>) -> Ordering {
+        self.rank_of(arg1).cmp(&self.rank_of(arg0))
+    }
+}
+
+/// Maps a floating point value to an integer key, such that values are compared up to the tolerance.
+pub(crate) fn quantize(value: f64, tolerance: f64) -> i64 {
+    (value / tolerance).round() as i64
+}
@@ -0,0 +1,287 @@
+use super::{specs::RankingComputer, Ranking};
+use crate::{aa::AAFramework, utils::LabelType};
+use std::cmp::{Ordering, Reverse};
+
+const DEFAULT_MAX_BRANCH_LENGTH: usize = 100;
+
+/// The tupled value of an argument, as defined by the Tuples* semantics.
+///
+/// A branch of an argument is a path of attacks ending at this argument and starting at an unattacked argument.
+/// Branches of even length are defense branches, while branches of odd length are attack branches.
+/// The tupled value records the number of branches of each length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TupledValue {
+    branch_counts: Vec<u64>,
+}
+
+impl TupledValue {
+    /// Returns the number of branches of each length, indexed by the length.
+    ///
+    /// Even indices correspond to defense branches, while odd indices correspond to attack branches.
+    /// Counts that overflow are saturated.
+    pub fn branch_counts(&self) -> &[u64] {
+        &self.branch_counts
+    }
+
+    /// Returns the number of defense branches.
+    pub fn n_defense_branches(&self) -> u64 {
+        self.count_branches(0)
+    }
+
+    /// Returns the number of attack branches.
+    pub fn n_attack_branches(&self) -> u64 {
+        self.count_branches(1)
+    }
+
+    fn count_branches(&self, first_index: usize) -> u64 {
+        self.branch_counts
+            .iter()
+            .skip(first_index)
+            .step_by(2)
+            .fold(0_u64, |acc, n| acc.saturating_add(*n))
+    }
+
+    fn parity_counts(&self, first_index: usize) -> BranchLengths {
+        BranchLengths(
+            self.branch_counts
+                .iter()
+                .enumerate()
+                .map(|(i, n)| if i % 2 == first_index { *n } else { 0 })
+                .collect(),
+        )
+    }
+}
+
+// The sorted tuple of the lengths of some branches, given as a number of branches per length.
+//
+// The order is the lexicographical order on the sorted tuples.
+#[derive(PartialEq, Eq)]
+struct BranchLengths(Vec<u64>);
+
+impl BranchLengths {
+    fn has_branches_after(&self, length: usize) -> bool {
+        self.0.iter().skip(length + 1).any(|n| *n > 0)
+    }
+}
+
+impl PartialOrd for BranchLengths {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BranchLengths {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let max_len = usize::max(self.0.len(), other.0.len());
+        for length in 0..max_len {
+            let n0 = self.0.get(length).copied().unwrap_or_default();
+            let n1 = other.0.get(length).copied().unwrap_or_default();
+            match n0.cmp(&n1) {
+                Ordering::Equal => continue,
+                Ordering::Less => {
+                    return if self.has_branches_after(length) {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Less
+                    }
+                }
+                Ordering::Greater => {
+                    return if other.has_branches_after(length) {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                }
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+/// A computer for the Tuples* ranking-based semantics.
+///
+/// This semantics was introduced by Cayrol and Lagasquie-Schiex.
+/// The value of an argument is its tupled value (see [TupledValue]), made of the lengths of its defense branches and of its attack branches.
+/// The comparison of the tupled values follows the intuition of the semantics:
+/// an argument is stronger when it has more defense branches and fewer attack branches,
+/// when its defense branches are shorter, and when its attack branches are longer.
+/// In order to get a total preorder, these criteria are applied in sequence:
+/// first, the difference between the number of defense branches and the number of attack branches is compared (the higher, the stronger);
+/// then, the sorted tuples of defense branch lengths are compared lexicographically (the lower, the stronger);
+/// finally, the sorted tuples of attack branch lengths are compared lexicographically (the higher, the stronger).
+///
+/// In presence of cycles, arguments may have infinitely many branches.
+/// Branches are thus only considered up to a maximal length.
+pub struct TuplesRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    max_branch_length: usize,
+}
+
+impl<'a, T> TuplesRankingComputer<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new computer for the Tuples* semantics.
+    ///
+    /// The maximal length of the considered branches is set to `100`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::ranking::{RankingComputer, TuplesRankingComputer};
+    /// fn print_strongest<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut computer = TuplesRankingComputer::new(af);
+    ///     let ranking = computer.compute_ranking();
+    ///     if let Some(class) = ranking.preorder().first() {
+    ///         println!("the strongest arguments are {:?}", class);
+    ///     }
+    /// }
+    /// # print_strongest::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self {
+            af,
+            max_branch_length: DEFAULT_MAX_BRANCH_LENGTH,
+        }
+    }
+
+    /// Sets the maximal length of the considered branches.
+    ///
+    /// Each additional unit of length requires an iteration over the attacks.
+    pub fn set_max_branch_length(&mut self, max_branch_length: usize) {
+        self.max_branch_length = max_branch_length;
+    }
+}
+
+impl<T> RankingComputer<T> for TuplesRankingComputer<'_, T>
+where
+    T: LabelType,
+{
+    type Value = TupledValue;
+
+    fn compute_ranking(&mut self) -> Ranking<'_, T, TupledValue> {
+        let af = self.af;
+        let n = af.max_argument_id().map(|id| id + 1).unwrap_or_default();
+        let mut branch_counts = vec![vec![]; n];
+        let mut previous = vec![0_u64; n];
+        for arg in af.argument_set().iter() {
+            if af.iter_attacks_to(arg).next().is_none() {
+                previous[arg.id()] = 1;
+            }
+            branch_counts[arg.id()].push(previous[arg.id()]);
+        }
+        let mut current = vec![0_u64; n];
+        for _ in 0..self.max_branch_length {
+            if previous.iter().all(|c| *c == 0) {
+                break;
+            }
+            for arg in af.argument_set().iter() {
+                current[arg.id()] = af.iter_attacks_to(arg).fold(0_u64, |acc, att| {
+                    acc.saturating_add(previous[att.attacker().id()])
+                });
+                branch_counts[arg.id()].push(current[arg.id()]);
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+        let values = branch_counts
+            .into_iter()
+            .map(|mut counts| {
+                while counts.last() == Some(&0) {
+                    counts.pop();
+                }
+                TupledValue {
+                    branch_counts: counts,
+                }
+            })
+            .collect();
+        Ranking::new_with_key(af, values, |v: &TupledValue| {
+            (
+                Reverse(v.n_defense_branches() as i128 - v.n_attack_branches() as i128),
+                v.parity_counts(0),
+                Reverse(v.parity_counts(1)),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    #[test]
+    fn test_tupled_values() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a3,a2).
+        att(a4,a2).
+        att(a0,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let arg = |l: &str| af.argument_set().get_argument(&l.to_string()).unwrap();
+        let mut computer = TuplesRankingComputer::new(&af);
+        let ranking = computer.compute_ranking();
+        assert_eq!(&[1], ranking.value_of(arg("a0")).branch_counts());
+        assert_eq!(&[0, 1], ranking.value_of(arg("a1")).branch_counts());
+        assert_eq!(&[0, 1, 2], ranking.value_of(arg("a2")).branch_counts());
+        assert_eq!(2, ranking.value_of(arg("a2")).n_defense_branches());
+        assert_eq!(1, ranking.value_of(arg("a2")).n_attack_branches());
+        assert_eq!(
+            vec![vec!["a0", "a3"], vec!["a2"], vec!["a1", "a4"]],
+            ranking
+                .preorder()
+                .iter()
+                .map(|class| {
+                    let mut labels = class
+                        .iter()
+                        .map(|a| a.label().as_str())
+                        .collect::<Vec<&str>>();
+                    labels.sort_unstable();
+                    labels
+                })
+                .collect::<Vec<Vec<&str>>>()
+        );
+    }
+
+    #[test]
+    fn test_branch_lengths_order() {
+        let shorter = BranchLengths(vec![0, 0, 1]);
+        let longer = BranchLengths(vec![0, 0, 0, 0, 1]);
+        let more = BranchLengths(vec![0, 0, 1, 0, 1]);
+        assert_eq!(Ordering::Less, shorter.cmp(&longer));
+        assert_eq!(Ordering::Less, shorter.cmp(&more));
+        assert_eq!(Ordering::Less, more.cmp(&longer));
+    }
+
+    #[test]
+    fn test_cycle_is_bounded() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut computer = TuplesRankingComputer::new(&af);
+        computer.set_max_branch_length(10);
+        let ranking = computer.compute_ranking();
+        let a1 = af.argument_set().get_argument(&"a1".to_string()).unwrap();
+        assert_eq!(10, ranking.value_of(a1).branch_counts().len());
+        assert_eq!(5, ranking.value_of(a1).n_attack_branches());
+    }
+}
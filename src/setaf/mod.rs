@@ -0,0 +1,8 @@
+//! Argumentation frameworks with collective attacks (SETAFs) and the solvers dedicated to them.
+
+mod setaf_framework;
+pub use setaf_framework::SetafAttack;
+pub use setaf_framework::SetafFramework;
+
+mod setaf_solver;
+pub use setaf_solver::SetafSolver;
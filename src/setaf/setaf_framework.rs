@@ -0,0 +1,297 @@
+use crate::{
+    aa::{AAFramework, Argument, ArgumentSet},
+    utils::LabelType,
+};
+use anyhow::{anyhow, Context, Result};
+
+/// An Argumentation framework with collective attacks (SETAF).
+///
+/// SETAFs generalize [`AAFramework`] objects by allowing attacks from a nonempty set of arguments to a single argument.
+/// A set of arguments attacks an argument if it contains all the attackers of an attack targeting it.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::ArgumentSet;
+/// # use crustabri::setaf::SetafFramework;
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+/// let mut setaf = SetafFramework::new_with_argument_set(arguments);
+/// setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+/// setaf.new_attack(&[&"c"], &"a").unwrap();
+/// assert_eq!(2, setaf.n_attacks());
+/// assert!(setaf.new_attack(&[], &"a").is_err());
+/// ```
+#[derive(Default, Debug)]
+pub struct SetafFramework<T>
+where
+    T: LabelType,
+{
+    arguments: ArgumentSet<T>,
+    attacks: Vec<(Vec<usize>, usize)>,
+    attacks_to: Vec<Vec<usize>>,
+}
+
+/// A collective attack, represented by the set of its attackers and the attacked argument.
+///
+/// Attacks are built by [`SetafFramework`] objects.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::setaf::SetafFramework;
+/// # use crustabri::utils::LabelType;
+/// fn print_setaf_attacks<T>(setaf: &SetafFramework<T>) where T: LabelType {
+///     setaf.iter_attacks().for_each(|att| {
+///         let attackers = att.attackers().iter().map(|a| a.to_string()).collect::<Vec<_>>();
+///         println!("{{{}}} attacks {}", attackers.join(", "), att.attacked())
+///     });
+/// }
+/// ```
+pub struct SetafAttack<'a, T>(Vec<&'a Argument<T>>, &'a Argument<T>)
+where
+    T: LabelType;
+
+impl<'a, T> SetafAttack<'a, T>
+where
+    T: LabelType,
+{
+    /// Returns the attackers, sorted by their identifiers.
+    pub fn attackers(&self) -> &[&'a Argument<T>] {
+        &self.0
+    }
+
+    /// Returns the attacked argument.
+    pub fn attacked(&self) -> &'a Argument<T> {
+        self.1
+    }
+}
+
+impl<T> SetafFramework<T>
+where
+    T: LabelType,
+{
+    /// Builds a SETAF with its argument set and no attacks.
+    pub fn new_with_argument_set(arguments: ArgumentSet<T>) -> Self {
+        let n = arguments.max_id().map(|id| id + 1).unwrap_or_default();
+        SetafFramework {
+            arguments,
+            attacks: vec![],
+            attacks_to: vec![vec![]; n],
+        }
+    }
+
+    /// Builds a SETAF from an AF, translating each attack into a collective attack with a single attacker.
+    pub fn new_from_af(af: &AAFramework<T>) -> Self {
+        let labels = af
+            .argument_set()
+            .iter()
+            .map(|a| a.label().clone())
+            .collect::<Vec<T>>();
+        let mut setaf = Self::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        af.iter_attacks().for_each(|att| {
+            setaf
+                .new_attack(&[att.attacker().label()], att.attacked().label())
+                .unwrap()
+        });
+        setaf
+    }
+
+    /// Adds a collective attack given the labels of the attackers and the label of the attacked argument.
+    ///
+    /// Repeated attackers are considered once, and adding an attack which already exists leaves the SETAF unchanged.
+    /// If the set of attackers is empty or if one of the arguments does not belong to this framework, an error is returned.
+    pub fn new_attack(&mut self, from: &[&T], to: &T) -> Result<()> {
+        let context = || format!("cannot add an attack from {:?} to {:?}", from, to);
+        if from.is_empty() {
+            return Err(anyhow!("the set of attackers is empty")).with_context(context);
+        }
+        let mut attacker_ids = from
+            .iter()
+            .map(|a| self.arguments.get_argument(a).map(|arg| arg.id()))
+            .collect::<Result<Vec<usize>>>()
+            .with_context(context)?;
+        attacker_ids.sort_unstable();
+        attacker_ids.dedup();
+        let attacked_id = self.arguments.get_argument(to).with_context(context)?.id();
+        if !self.attacks_to[attacked_id]
+            .iter()
+            .any(|i| self.attacks[*i].0 == attacker_ids)
+        {
+            self.attacks.push((attacker_ids, attacked_id));
+            self.attacks_to[attacked_id].push(self.attacks.len() - 1);
+        }
+        Ok(())
+    }
+
+    /// Returns the argument set of this framework.
+    pub fn argument_set(&self) -> &ArgumentSet<T> {
+        &self.arguments
+    }
+
+    /// Returns the number of arguments in this framework.
+    pub fn n_arguments(&self) -> usize {
+        self.arguments.len()
+    }
+
+    /// Returns the number of attacks in this framework.
+    pub fn n_attacks(&self) -> usize {
+        self.attacks.len()
+    }
+
+    /// Provides an iterator to the attacks.
+    pub fn iter_attacks(&self) -> impl Iterator<Item = SetafAttack<'_, T>> + '_ {
+        self.attacks.iter().map(|att| self.build_attack(att))
+    }
+
+    /// Provides an iterator to the attacks that have the given argument as attacked.
+    pub fn iter_attacks_to(
+        &self,
+        arg: &Argument<T>,
+    ) -> impl Iterator<Item = SetafAttack<'_, T>> + '_ {
+        self.attacks_to[arg.id()]
+            .iter()
+            .map(|i| self.build_attack(&self.attacks[*i]))
+    }
+
+    fn build_attack(&self, attack: &(Vec<usize>, usize)) -> SetafAttack<'_, T> {
+        SetafAttack(
+            attack
+                .0
+                .iter()
+                .map(|id| self.arguments.get_argument_by_id(*id))
+                .collect(),
+            self.arguments.get_argument_by_id(attack.1),
+        )
+    }
+
+    pub(crate) fn attacks(&self) -> &[(Vec<usize>, usize)] {
+        &self.attacks
+    }
+
+    pub(crate) fn attack_indices_to(&self, arg_id: usize) -> &[usize] {
+        &self.attacks_to[arg_id]
+    }
+
+    /// Computes the grounded extension of the SETAF.
+    ///
+    /// The grounded extension is the least fixpoint of the characteristic function,
+    /// which maps a set of arguments to the set of the arguments it defends.
+    /// It is computed in time polynomial in the size of the framework.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::ArgumentSet;
+    /// # use crustabri::setaf::SetafFramework;
+    /// let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d"]);
+    /// let mut setaf = SetafFramework::new_with_argument_set(arguments);
+    /// setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+    /// setaf.new_attack(&[&"c"], &"d").unwrap();
+    /// let grounded_labels = setaf.grounded_extension()
+    ///     .iter()
+    ///     .map(|a| *a.label())
+    ///     .collect::<Vec<&str>>();
+    /// assert_eq!(vec!["a", "b", "d"], grounded_labels)
+    /// ```
+    pub fn grounded_extension(&self) -> Vec<&Argument<T>> {
+        let n = self.attacks_to.len();
+        let mut accepted = vec![false; n];
+        let mut defeated = vec![false; n];
+        loop {
+            let mut changed = false;
+            for arg in self.arguments.iter() {
+                if !accepted[arg.id()]
+                    && self.attacks_to[arg.id()]
+                        .iter()
+                        .all(|i| self.attacks[*i].0.iter().any(|b| defeated[*b]))
+                {
+                    accepted[arg.id()] = true;
+                    changed = true;
+                }
+            }
+            for (attackers, attacked) in self.attacks.iter() {
+                if attackers.iter().all(|b| accepted[*b]) {
+                    defeated[*attacked] = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        self.arguments.iter().filter(|a| accepted[a.id()]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_attack() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut setaf = SetafFramework::new_with_argument_set(arguments);
+        setaf.new_attack(&[&"b", &"a", &"b"], &"c").unwrap();
+        setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+        assert_eq!(1, setaf.n_attacks());
+        let att = setaf.iter_attacks().next().unwrap();
+        assert_eq!(
+            vec!["a", "b"],
+            att.attackers()
+                .iter()
+                .map(|a| *a.label())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!("c", *att.attacked().label());
+        assert!(setaf.new_attack(&[&"a", &"d"], &"c").is_err());
+        assert!(setaf.new_attack(&[&"a"], &"d").is_err());
+        assert_eq!(1, setaf.n_attacks());
+    }
+
+    #[test]
+    fn test_iter_attacks_to() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+        let mut setaf = SetafFramework::new_with_argument_set(arguments);
+        setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+        setaf.new_attack(&[&"c"], &"c").unwrap();
+        setaf.new_attack(&[&"c"], &"a").unwrap();
+        let c = setaf.argument_set().get_argument(&"c").unwrap();
+        assert_eq!(2, setaf.iter_attacks_to(c).count());
+        let b = setaf.argument_set().get_argument(&"b").unwrap();
+        assert_eq!(0, setaf.iter_attacks_to(b).count());
+    }
+
+    #[test]
+    fn test_grounded_extension_with_collective_defense() {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d", "e"]);
+        let mut setaf = SetafFramework::new_with_argument_set(arguments);
+        setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+        setaf.new_attack(&[&"c", &"d"], &"e").unwrap();
+        setaf.new_attack(&[&"e"], &"d").unwrap();
+        assert_eq!(
+            vec!["a", "b", "e"],
+            setaf
+                .grounded_extension()
+                .iter()
+                .map(|a| *a.label())
+                .collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn test_new_from_af() {
+        let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&["a", "b"]));
+        af.new_attack(&"a", &"b").unwrap();
+        af.new_attack(&"b", &"b").unwrap();
+        let setaf = SetafFramework::new_from_af(&af);
+        assert_eq!(2, setaf.n_arguments());
+        assert_eq!(2, setaf.n_attacks());
+        assert_eq!(
+            vec!["a"],
+            setaf
+                .grounded_extension()
+                .iter()
+                .map(|a| *a.label())
+                .collect::<Vec<&str>>()
+        );
+    }
+}
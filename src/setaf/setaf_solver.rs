@@ -0,0 +1,415 @@
+use super::SetafFramework;
+use crate::{
+    aa::{Argument, Semantics},
+    encodings::{setaf_constraints_encoder, SetafConstraintsEncoder},
+    sat::{self, Assignment, Literal, SatSolver, SatSolverFactoryFn},
+    solvers::{CredulousAcceptanceComputer, SingleExtensionComputer, SkepticalAcceptanceComputer},
+    utils::LabelType,
+};
+use anyhow::{anyhow, Result};
+
+type AcceptanceResult<'a, T> = (bool, Option<Vec<&'a Argument<T>>>);
+
+/// A SAT-based solver for SETAFs under the grounded, complete, preferred, stable, semi-stable and stage semantics.
+///
+/// The grounded extension is computed by [`SetafFramework::grounded_extension`].
+/// The problems related to the other semantics are solved using the encodings given by the [`setaf_constraints_encoder`] module:
+/// the complete semantics is used for the preferred and the semi-stable semantics, and conflict-freeness for the stage semantics.
+/// Maximal extensions (wrt. set inclusion for the preferred semantics, and wrt. their range for the semi-stable and stage semantics)
+/// are computed by iteratively increasing a candidate extension.
+///
+/// When a certificate is needed, an extension is given, as with the AF solvers.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{ArgumentSet, Semantics};
+/// # use crustabri::setaf::{SetafFramework, SetafSolver};
+/// # use crustabri::solvers::{CredulousAcceptanceComputer, SkepticalAcceptanceComputer};
+/// let arguments = ArgumentSet::new_with_labels(&["a", "b", "c"]);
+/// let mut setaf = SetafFramework::new_with_argument_set(arguments);
+/// setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+/// setaf.new_attack(&[&"c"], &"a").unwrap();
+/// let mut solver = SetafSolver::new(&setaf, Semantics::PR).unwrap();
+/// assert!(solver.is_credulously_accepted(&"c"));
+/// assert!(solver.is_skeptically_accepted(&"b"));
+/// assert!(!solver.are_all_credulously_accepted(&[&"a", &"c"]));
+/// ```
+pub struct SetafSolver<'a, T>
+where
+    T: LabelType,
+{
+    setaf: &'a SetafFramework<T>,
+    semantics: Semantics,
+    solver_factory: Box<SatSolverFactoryFn>,
+}
+
+impl<'a, T> SetafSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// The semantics must be the grounded, the complete, the preferred, the stable, the semi-stable or the stage one.
+    /// If it is not the case, an error is returned.
+    pub fn new(setaf: &'a SetafFramework<T>, semantics: Semantics) -> Result<Self> {
+        Self::new_with_sat_solver_factory(setaf, semantics, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new SAT based solver for the provided semantics.
+    ///
+    /// The SAT solvers are created by the provided factory.
+    ///
+    /// The semantics must be the grounded, the complete, the preferred, the stable, the semi-stable or the stage one.
+    /// If it is not the case, an error is returned.
+    pub fn new_with_sat_solver_factory(
+        setaf: &'a SetafFramework<T>,
+        semantics: Semantics,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Result<Self> {
+        if !matches!(
+            semantics,
+            Semantics::GR
+                | Semantics::CO
+                | Semantics::PR
+                | Semantics::ST
+                | Semantics::SST
+                | Semantics::STG
+        ) {
+            return Err(anyhow!(
+                "unsupported semantics for SETAFs: {}",
+                semantics.as_ref()
+            ));
+        }
+        Ok(Self {
+            setaf,
+            semantics,
+            solver_factory,
+        })
+    }
+
+    fn encoder(&self) -> Box<dyn SetafConstraintsEncoder<T>> {
+        match self.semantics {
+            Semantics::CO | Semantics::PR | Semantics::SST => {
+                Box::new(setaf_constraints_encoder::new_for_complete_semantics())
+            }
+            Semantics::ST => Box::new(setaf_constraints_encoder::new_for_stable_semantics()),
+            Semantics::STG => Box::new(setaf_constraints_encoder::new_for_conflict_freeness()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn args_of(&self, labels: &[&T]) -> Vec<&'a Argument<T>> {
+        labels
+            .iter()
+            .map(|l| self.setaf.argument_set().get_argument(l).unwrap())
+            .collect()
+    }
+
+    // Searches for an extension satisfying the provided clauses.
+    fn find_extension(&self, clauses: Vec<Vec<Literal>>) -> Option<Vec<&'a Argument<T>>> {
+        let encoder = self.encoder();
+        let mut solver = (self.solver_factory)();
+        encoder.encode_constraints(self.setaf, solver.as_mut());
+        clauses.into_iter().for_each(|cl| solver.add_clause(cl));
+        solver
+            .solve()
+            .unwrap_model()
+            .map(|model| encoder.assignment_to_extension(&model, self.setaf))
+    }
+
+    // Searches for a maximal extension satisfying the provided clauses.
+    //
+    // The maximality is checked among all the extensions, not only the ones satisfying the clauses.
+    // Candidates which are not maximal are discarded, together with all the extensions they dominate.
+    fn find_maximal_extension(&self, clauses: Vec<Vec<Literal>>) -> Option<Vec<&'a Argument<T>>> {
+        let encoder = self.encoder();
+        let mut solver = (self.solver_factory)();
+        encoder.encode_constraints_and_range(self.setaf, solver.as_mut());
+        let n_args = self
+            .setaf
+            .argument_set()
+            .max_id()
+            .map(|id| id + 1)
+            .unwrap_or(0);
+        let targets = if self.semantics == Semantics::PR {
+            (1..=n_args).collect::<Vec<usize>>()
+        } else {
+            let first = encoder.first_range_var(n_args);
+            (first..first + n_args).collect::<Vec<usize>>()
+        };
+        let constraint_selector = new_selector(solver.as_mut());
+        clauses.into_iter().for_each(|mut cl| {
+            cl.push(constraint_selector.negate());
+            solver.add_clause(cl)
+        });
+        loop {
+            let model = maximize(solver.as_mut(), &[constraint_selector], &targets)?;
+            let (in_targets, out_targets) = split_targets(&model, &targets);
+            let selector = new_selector(solver.as_mut());
+            let mut increase_cl = out_targets.clone();
+            increase_cl.push(selector.negate());
+            solver.add_clause(increase_cl);
+            let mut assumptions = in_targets;
+            assumptions.push(selector);
+            let is_maximal = solver
+                .solve_under_assumptions(&assumptions)
+                .unwrap_model()
+                .is_none();
+            solver.add_clause(vec![selector.negate()]);
+            if is_maximal {
+                return Some(encoder.assignment_to_extension(&model, self.setaf));
+            }
+            solver.add_clause(out_targets);
+        }
+    }
+
+    fn check_credulous_acceptance(
+        &self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> AcceptanceResult<'a, T> {
+        let args = self.args_of(args);
+        if matches!(self.semantics, Semantics::GR) {
+            let grounded = self.setaf.grounded_extension();
+            let status = if conjunctive {
+                args.iter().all(|a| grounded.contains(a))
+            } else {
+                args.iter().any(|a| grounded.contains(a))
+            };
+            return if status {
+                (true, Some(grounded))
+            } else {
+                (false, None)
+            };
+        }
+        let encoder = self.encoder();
+        let lits = args.iter().map(|a| encoder.arg_to_lit(a));
+        let clauses = if conjunctive {
+            lits.map(|l| vec![l]).collect()
+        } else {
+            vec![lits.collect()]
+        };
+        let extension = match self.semantics {
+            Semantics::CO | Semantics::ST => self.find_extension(clauses),
+            Semantics::PR | Semantics::SST | Semantics::STG => self.find_maximal_extension(clauses),
+            _ => unreachable!(),
+        };
+        (extension.is_some(), extension)
+    }
+}
+
+fn new_selector(solver: &mut dyn SatSolver) -> Literal {
+    let selector = Literal::from(1 + solver.n_vars() as isize);
+    solver.reserve(solver.n_vars() + 1);
+    selector
+}
+
+// Computes an extension which cannot be increased wrt. the targets under the provided assumptions.
+fn maximize(
+    solver: &mut dyn SatSolver,
+    assumptions: &[Literal],
+    targets: &[usize],
+) -> Option<Assignment> {
+    let mut model = solver.solve_under_assumptions(assumptions).unwrap_model()?;
+    loop {
+        let (in_targets, out_targets) = split_targets(&model, targets);
+        if out_targets.is_empty() {
+            return Some(model);
+        }
+        let selector = new_selector(solver);
+        let mut increase_cl = out_targets;
+        increase_cl.push(selector.negate());
+        solver.add_clause(increase_cl);
+        let mut all_assumptions = assumptions.to_vec();
+        all_assumptions.extend(in_targets);
+        all_assumptions.push(selector);
+        let next_model = solver
+            .solve_under_assumptions(&all_assumptions)
+            .unwrap_model();
+        solver.add_clause(vec![selector.negate()]);
+        match next_model {
+            Some(m) => model = m,
+            None => return Some(model),
+        }
+    }
+}
+
+fn split_targets(model: &Assignment, targets: &[usize]) -> (Vec<Literal>, Vec<Literal>) {
+    let (in_targets, out_targets): (Vec<usize>, Vec<usize>) = targets
+        .iter()
+        .partition(|v| model.value_of(**v).unwrap_or(false));
+    let to_lits = |vars: Vec<usize>| {
+        vars.into_iter()
+            .map(|v| Literal::from(v as isize))
+            .collect::<Vec<Literal>>()
+    };
+    (to_lits(in_targets), to_lits(out_targets))
+}
+
+impl<T> SingleExtensionComputer<T> for SetafSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        match self.semantics {
+            Semantics::GR | Semantics::CO => Some(self.setaf.grounded_extension()),
+            Semantics::ST => self.find_extension(vec![]),
+            Semantics::PR | Semantics::SST | Semantics::STG => self.find_maximal_extension(vec![]),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for SetafSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_credulously_accepted_with_certificate(args).0
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(args, false)
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(args, true)
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for SetafSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_skeptically_accepted_with_certificate(args).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = self.args_of(args);
+        if matches!(self.semantics, Semantics::GR | Semantics::CO) {
+            let grounded = self.setaf.grounded_extension();
+            return if args.iter().any(|a| grounded.contains(a)) {
+                (true, None)
+            } else {
+                (false, Some(grounded))
+            };
+        }
+        let encoder = self.encoder();
+        let clauses = args
+            .iter()
+            .map(|a| vec![encoder.arg_to_lit(a).negate()])
+            .collect();
+        let counterexample = match self.semantics {
+            Semantics::ST => self.find_extension(clauses),
+            Semantics::PR | Semantics::SST | Semantics::STG => self.find_maximal_extension(clauses),
+            _ => unreachable!(),
+        };
+        (counterexample.is_none(), counterexample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aa::ArgumentSet;
+
+    // a and b collectively attack c, c attacks a, d attacks itself, and b and c collectively attack d
+    fn setaf() -> SetafFramework<&'static str> {
+        let arguments = ArgumentSet::new_with_labels(&["a", "b", "c", "d"]);
+        let mut setaf = SetafFramework::new_with_argument_set(arguments);
+        setaf.new_attack(&[&"a", &"b"], &"c").unwrap();
+        setaf.new_attack(&[&"c"], &"a").unwrap();
+        setaf.new_attack(&[&"d"], &"d").unwrap();
+        setaf.new_attack(&[&"b", &"c"], &"d").unwrap();
+        setaf
+    }
+
+    fn labels(ext: Vec<&Argument<&'static str>>) -> Vec<&'static str> {
+        ext.iter().map(|a| *a.label()).collect()
+    }
+
+    #[test]
+    fn test_compute_one_extension() {
+        let setaf = setaf();
+        for semantics in [Semantics::GR, Semantics::CO] {
+            let mut solver = SetafSolver::new(&setaf, semantics).unwrap();
+            assert_eq!(vec!["b"], labels(solver.compute_one_extension().unwrap()));
+        }
+        for semantics in [Semantics::ST, Semantics::SST, Semantics::STG] {
+            let mut solver = SetafSolver::new(&setaf, semantics).unwrap();
+            assert_eq!(
+                vec!["b", "c"],
+                labels(solver.compute_one_extension().unwrap())
+            );
+        }
+        let ext = labels(
+            SetafSolver::new(&setaf, Semantics::PR)
+                .unwrap()
+                .compute_one_extension()
+                .unwrap(),
+        );
+        assert!(ext == vec!["a", "b"] || ext == vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_credulous_acceptance() {
+        let setaf = setaf();
+        let expected = [
+            (Semantics::GR, false),
+            (Semantics::CO, true),
+            (Semantics::PR, true),
+            (Semantics::ST, false),
+            (Semantics::SST, false),
+            (Semantics::STG, false),
+        ];
+        for (semantics, a_accepted) in expected {
+            let mut solver = SetafSolver::new(&setaf, semantics).unwrap();
+            assert_eq!(a_accepted, solver.is_credulously_accepted(&"a"));
+            assert!(!solver.is_credulously_accepted(&"d"));
+            assert!(solver.are_credulously_accepted(&[&"b", &"d"]));
+            assert!(!solver.are_all_credulously_accepted(&[&"a", &"c"]));
+        }
+    }
+
+    #[test]
+    fn test_skeptical_acceptance() {
+        let setaf = setaf();
+        let expected = [
+            (Semantics::GR, false),
+            (Semantics::CO, false),
+            (Semantics::PR, false),
+            (Semantics::ST, true),
+            (Semantics::SST, true),
+            (Semantics::STG, true),
+        ];
+        for (semantics, c_accepted) in expected {
+            let mut solver = SetafSolver::new(&setaf, semantics).unwrap();
+            assert!(solver.is_skeptically_accepted(&"b"));
+            let (status, certificate) = solver.is_skeptically_accepted_with_certificate(&"c");
+            assert_eq!(c_accepted, status);
+            assert_eq!(c_accepted, certificate.is_none());
+            assert_eq!(
+                !matches!(semantics, Semantics::GR | Semantics::CO),
+                solver.are_skeptically_accepted(&[&"a", &"c"])
+            );
+        }
+    }
+
+    #[test]
+    fn test_unsupported_semantics() {
+        assert!(SetafSolver::new(&setaf(), Semantics::ID).is_err());
+    }
+}
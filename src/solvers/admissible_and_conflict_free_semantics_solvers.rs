@@ -0,0 +1,794 @@
+use super::{
+    extension_enumeration, extension_existence, extension_verification::CandidateExtension,
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, SingleExtensionComputer, SingleLabellingComputer,
+    SkepticalAcceptanceComputer, UniqueExtensionChecker, VerifyExtension,
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
+    sat::{self, Literal, SatSolverFactoryFn},
+    utils::{ConnectedComponentsComputer, Label, LabelType},
+};
+use num_bigint::BigUint;
+
+macro_rules! empty_set_semantics_solver {
+    ($solver_ident:ident, $sem_name:literal, $constraints_encoder:expr, $check_fn:ident) => {
+        #[doc = concat!(" A SAT-based solver for the ", $sem_name, " semantics.")]
+        ///
+        /// Computing an extension and checking the credulous acceptance of arguments both resume to a single call to a SAT solver.
+        /// The certificate provided in case arguments are credulously accepted is an extension containing one of them.
+        ///
+        /// Since the empty set is always an extension wrt. this semantics, no argument is skeptically accepted.
+        /// The certificate provided for skeptical acceptance queries is thus the empty extension.
+        ///
+        /// Checking whether a set of arguments is an extension ([VerifyExtension]) is done in polynomial time.
+        /// The existence of a non-empty extension ([NonEmptyExtensionChecker]) is checked with a single SAT call per connected component.
+        /// The uniqueness of the extension ([UniqueExtensionChecker]) holds iff the empty set is the only extension.
+        pub struct $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            helper: EmptySetSemanticsHelper<'a, T>,
+        }
+
+        impl<'a, T> $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new(af);")]
+            ///     let ext = solver.compute_one_extension().unwrap();
+            ///     println!("found an extension: {:?}", ext);
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            pub fn new(af: &'a AAFramework<T>) -> Self
+            where
+                T: LabelType,
+            {
+                Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat::CadicalSolver;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory(")]
+            ///         af,
+            ///         Box::new(|| Box::new(CadicalSolver::default()))
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            ///     println!("found an extension: {:?}", ext);
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            pub fn new_with_sat_solver_factory(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self {
+                    helper: EmptySetSemanticsHelper::new(af, solver_factory, $constraints_encoder),
+                }
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat;
+            /// # use crustabri::encodings::aux_var_constraints_encoder;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory_and_constraints_encoder(")]
+            ///         af,
+            ///         Box::new(|| sat::default_solver()),
+            ///         Box::new(aux_var_constraints_encoder::new_for_admissibility()),
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            ///     println!("found an extension: {:?}", ext);
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            pub fn new_with_sat_solver_factory_and_constraints_encoder(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+                constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self {
+                    helper: EmptySetSemanticsHelper::new(af, solver_factory, constraints_encoder),
+                }
+            }
+        }
+
+        impl<T> SingleExtensionComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+                self.helper.compute_one_extension()
+            }
+        }
+
+        impl<T> SingleLabellingComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+                let af = self.helper.af;
+                self.compute_one_extension()
+                    .map(|ext| Labelling::new_from_extension(af, &ext))
+            }
+        }
+
+        impl<T> ExtensionEnumerator<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+                self.helper.enumerate_extensions(callback)
+            }
+        }
+
+        impl<T> ExtensionCounter<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn count_extensions(&mut self) -> BigUint {
+                self.helper.count_extensions()
+            }
+        }
+
+        impl<T> NonEmptyExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_nonempty_extension_with_certificate(
+                &mut self,
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.has_nonempty_extension_with_certificate()
+            }
+        }
+
+        impl<T> UniqueExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+                self.helper.has_unique_extension_with_certificate()
+            }
+        }
+
+        impl<T> VerifyExtension<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn verify_extension_with_reason(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+                let reason = CandidateExtension::new(self.helper.af, args).$check_fn();
+                (reason.is_none(), reason)
+            }
+        }
+
+        impl<T> CredulousAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_credulous_acceptance_with_certificate(args, false).0
+            }
+
+            fn are_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_credulous_acceptance_with_certificate(args, false)
+            }
+
+            fn are_all_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_credulous_acceptance_with_certificate(args, true)
+            }
+        }
+
+        impl<T> SkepticalAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.are_skeptically_accepted_with_certificate(args).0
+            }
+
+            fn are_skeptically_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.are_skeptically_accepted_with_certificate(args)
+            }
+        }
+    };
+}
+
+empty_set_semantics_solver!(
+    AdmissibleSemanticsSolver,
+    "admissible",
+    Box::new(aux_var_constraints_encoder::new_for_admissibility()),
+    check_admissibility
+);
+
+empty_set_semantics_solver!(
+    ConflictFreeSemanticsSolver,
+    "conflict-free",
+    Box::new(aux_var_constraints_encoder::new_for_conflict_freeness()),
+    check_conflict_freeness
+);
+
+struct EmptySetSemanticsHelper<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+}
+
+impl<'a, T> EmptySetSemanticsHelper<'a, T>
+where
+    T: LabelType,
+{
+    fn new(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+        constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+    ) -> Self {
+        EmptySetSemanticsHelper {
+            af,
+            solver_factory,
+            constraints_encoder,
+        }
+    }
+
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        let mut merged = Vec::new();
+        for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
+            let mut solver = (self.solver_factory)();
+            self.constraints_encoder
+                .encode_constraints(&cc_af, solver.as_mut());
+            let model = solver.solve().unwrap_model().unwrap();
+            self.constraints_encoder
+                .assignment_to_extension(&model, &cc_af)
+                .iter()
+                .for_each(|cc_arg| {
+                    merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                });
+        }
+        Some(merged)
+    }
+
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_enumeration::enumerate_extensions(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+            callback,
+        );
+    }
+
+    fn compute_nonempty_extension_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_existence::compute_nonempty_extension_if_any(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+        )
+    }
+
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        extension_existence::check_nonempty_extension_by_connected_components(
+            self.af,
+            &mut |cc_af| self.compute_nonempty_extension_in_cc(cc_af),
+        )
+    }
+
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+
+    fn check_credulous_acceptance_with_certificate(
+        &mut self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+        let cc_af = cc_computer.merged_connected_components_of(&args);
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(&cc_af, solver.as_mut());
+        let arg_lits = args
+            .iter()
+            .map(|a| {
+                self.constraints_encoder
+                    .arg_to_lit(cc_af.argument_set().get_argument(a.label()).unwrap())
+            })
+            .collect::<Vec<Literal>>();
+        let assumptions = if conjunctive {
+            arg_lits
+        } else {
+            solver.add_clause(arg_lits);
+            vec![]
+        };
+        match solver.solve_under_assumptions(&assumptions).unwrap_model() {
+            Some(model) => {
+                let cc_ext = self
+                    .constraints_encoder
+                    .assignment_to_extension(&model, &cc_af);
+                let ext = cc_ext
+                    .iter()
+                    .map(|cc_arg| self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                    .collect();
+                (true, Some(ext))
+            }
+            None => (false, None),
+        }
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        args.iter().for_each(|a| {
+            self.af.argument_set().get_argument(a).unwrap();
+        });
+        (false, Some(vec![]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encodings::exp_constraints_encoder,
+        io::{AspartixReader, InstanceReader},
+    };
+
+    #[test]
+    fn test_admissible_compute_one() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        let ext = solver
+            .compute_one_extension()
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        assert!(!ext.contains(&"a1".to_string()));
+    }
+
+    #[test]
+    fn test_admissible_credulous_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a3).
+        att(a3,a2).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert!(solver.is_credulously_accepted(&"a0".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a1".to_string()));
+        assert!(solver.is_credulously_accepted(&"a2".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a3".to_string()));
+        let mut cert = solver
+            .is_credulously_accepted_with_certificate(&"a2".to_string())
+            .1
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        cert.sort_unstable();
+        assert_eq!(vec!["a0", "a2"], cert);
+        assert_eq!(
+            (false, None),
+            solver.is_credulously_accepted_with_certificate(&"a3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_admissible_conj_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a3).
+        att(a3,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(solver.are_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        let mut cert = solver
+            .are_all_credulously_accepted_with_certificate(&[&"a0".to_string(), &"a3".to_string()])
+            .1
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        cert.sort_unstable();
+        assert_eq!(vec!["a0", "a3"], cert);
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string()]));
+        assert!(solver.are_all_skeptically_accepted(&[]));
+    }
+
+    #[test]
+    fn test_admissible_skeptical_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert!(!solver.is_skeptically_accepted(&"a0".to_string()));
+        assert_eq!(
+            (false, Some(vec![])),
+            solver.is_skeptically_accepted_with_certificate(&"a0".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_admissible_skeptical_acceptance_unknown_arg() {
+        let instance = r#"
+        arg(a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        solver.is_skeptically_accepted(&"a1".to_string());
+    }
+
+    macro_rules! test_conflict_freeness_for_encoder {
+        ($encoder:expr, $suffix:literal) => {
+            paste::item! {
+    #[test]
+    fn [< test_conflict_free_credulous_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(solver.is_credulously_accepted(&"a0".to_string()));
+        assert!(solver.is_credulously_accepted(&"a1".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a2".to_string()));
+        assert!(solver.are_credulously_accepted(&[&"a1".to_string(), &"a2".to_string()]));
+        assert!(!solver.is_skeptically_accepted(&"a0".to_string()));
+    }
+
+    #[test]
+    fn [< test_conflict_free_compute_one_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(solver.compute_one_extension().unwrap().len() <= 1);
+    }
+            }
+        };
+    }
+
+    test_conflict_freeness_for_encoder!(
+        aux_var_constraints_encoder::new_for_conflict_freeness(),
+        "auxvar"
+    );
+    test_conflict_freeness_for_encoder!(
+        exp_constraints_encoder::new_for_conflict_freeness(),
+        "exp"
+    );
+
+    #[test]
+    fn test_admissible_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(
+            vec![
+                vec![] as Vec<&str>,
+                vec!["a0"],
+                vec!["a0", "a2"],
+                vec!["a0", "a2", "a3"],
+                vec!["a0", "a3"],
+                vec!["a1"],
+                vec!["a1", "a3"],
+                vec!["a3"]
+            ],
+            extensions
+        );
+    }
+
+    #[test]
+    fn test_conflict_freeness_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(
+            vec![
+                vec![] as Vec<&str>,
+                vec!["a0"],
+                vec!["a0", "a2"],
+                vec!["a0", "a2", "a3"],
+                vec!["a0", "a3"],
+                vec!["a1"],
+                vec!["a1", "a3"],
+                vec!["a2"],
+                vec!["a2", "a3"],
+                vec!["a3"]
+            ],
+            extensions
+        );
+    }
+
+    #[test]
+    fn test_admissible_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(8_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_conflict_freeness_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(10_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_admissible_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[]));
+        assert!(solver.verify_extension(&[&"a0".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a2".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::UndefendedArgument(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_conflict_freeness_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a2".to_string()]));
+        let (result, reason) =
+            solver.verify_extension_with_reason(&[&"a1".to_string(), &"a2".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::ConflictingAttack(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_admissible_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a1).
+        att(a2,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = AdmissibleSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (true, vec![vec![]]),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
+
+    #[test]
+    fn test_conflict_freeness_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a1,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ConflictFreeSemanticsSolver::new(&af);
+        assert_eq!(
+            (
+                true,
+                Some(vec![af
+                    .argument_set()
+                    .get_argument(&"a0".to_string())
+                    .unwrap()])
+            ),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert!(!solver.has_unique_extension());
+    }
+}
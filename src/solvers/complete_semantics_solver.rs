@@ -1,4 +1,9 @@
-use super::specs::CredulousAcceptanceComputer;
+use super::extension_verification::CandidateExtension;
+use super::specs::{
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, UniqueExtensionChecker, VerifyExtension,
+};
+use super::{extension_enumeration, extension_existence};
 use crate::aa::{AAFramework, Argument};
 use crate::encodings::{aux_var_constraints_encoder, ConstraintsEncoder};
 use crate::sat::Literal;
@@ -7,6 +12,7 @@ use crate::{
     sat::{self, SatSolverFactoryFn},
     utils::ConnectedComponentsComputer,
 };
+use num_bigint::BigUint;
 
 /// A SAT-based solver for the complete semantics.
 ///
@@ -17,6 +23,10 @@ use crate::{
 /// of an argument as they can be computed in an efficient way by a [GroundedSemanticsSolver](super::GroundedSemanticsSolver).
 ///
 /// The implementation of the [CredulousAcceptanceComputer] problems relies on a single call to a SAT solver.
+/// The complete extensions can also be enumerated and counted through the [ExtensionEnumerator] and [ExtensionCounter] interfaces.
+/// Checking whether a set of arguments is a complete extension ([VerifyExtension]) does not require any call to the SAT solver.
+/// The existence of a non-empty extension ([NonEmptyExtensionChecker]) is checked with a single SAT call per connected component,
+/// while the uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
 /// The certificate provided in case an argument is credulously accepted is a complete extension containing the argument.
 pub struct CompleteSemanticsSolver<'a, T>
 where
@@ -139,6 +149,148 @@ where
             constraints_encoder,
         }
     }
+
+    fn check_credulous_acceptance_with_certificate(
+        &self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+        let reduced_af = cc_computer.merged_connected_components_of(&args);
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(&reduced_af, solver.as_mut());
+        let arg_lits = args
+            .iter()
+            .map(|a| {
+                self.constraints_encoder
+                    .arg_to_lit(reduced_af.argument_set().get_argument(a.label()).unwrap())
+            })
+            .collect::<Vec<Literal>>();
+        let model = if conjunctive {
+            solver.solve_under_assumptions(&arg_lits).unwrap_model()
+        } else {
+            arg_lits
+                .iter()
+                .find_map(|l| solver.solve_under_assumptions(&[*l]).unwrap_model())
+        };
+        match model {
+            Some(model) => {
+                let cc_ext = self
+                    .constraints_encoder
+                    .assignment_to_extension(&model, &reduced_af);
+                let mut merged = cc_ext
+                    .iter()
+                    .map(|cc_arg| self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                    .collect::<Vec<&Argument<T>>>();
+                while let Some(other_cc_af) = cc_computer.next_connected_component() {
+                    let other_cc_grounded = other_cc_af.grounded_extension();
+                    other_cc_grounded
+                        .iter()
+                        .map(|a| self.af.argument_set().get_argument(a.label()).unwrap())
+                        .for_each(|a| merged.push(a));
+                }
+                (true, Some(merged))
+            }
+            None => (false, None),
+        }
+    }
+
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_enumeration::enumerate_extensions(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+            callback,
+        );
+    }
+
+    fn compute_nonempty_extension_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_existence::compute_nonempty_extension_if_any(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+        )
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for CompleteSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+}
+
+impl<T> ExtensionCounter<T> for CompleteSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for CompleteSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        extension_existence::check_nonempty_extension_by_connected_components(
+            self.af,
+            &mut |cc_af| self.compute_nonempty_extension_in_cc(cc_af),
+        )
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for CompleteSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> VerifyExtension<T> for CompleteSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let reason = CandidateExtension::new(self.af, args).check_completeness();
+        (reason.is_none(), reason)
+    }
 }
 
 impl<T> CredulousAcceptanceComputer<T> for CompleteSemanticsSolver<'_, T>
@@ -177,42 +329,14 @@ where
         &mut self,
         args: &[&T],
     ) -> (bool, Option<Vec<&Argument<T>>>) {
-        let args = args
-            .iter()
-            .map(|a| self.af.argument_set().get_argument(a).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
-        let reduced_af = cc_computer.merged_connected_components_of(&args);
-        let mut solver = (self.solver_factory)();
-        self.constraints_encoder
-            .encode_constraints(&reduced_af, solver.as_mut());
-        let assumptions = args
-            .iter()
-            .map(|a| {
-                self.constraints_encoder
-                    .arg_to_lit(reduced_af.argument_set().get_argument(a.label()).unwrap())
-            })
-            .collect::<Vec<Literal>>();
-        match solver.solve_under_assumptions(&assumptions).unwrap_model() {
-            Some(model) => {
-                let cc_ext = self
-                    .constraints_encoder
-                    .assignment_to_extension(&model, &reduced_af);
-                let mut merged = cc_ext
-                    .iter()
-                    .map(|cc_arg| self.af.argument_set().get_argument(cc_arg.label()).unwrap())
-                    .collect::<Vec<&Argument<T>>>();
-                while let Some(other_cc_af) = cc_computer.next_connected_component() {
-                    let other_cc_grounded = other_cc_af.grounded_extension();
-                    other_cc_grounded
-                        .iter()
-                        .map(|a| self.af.argument_set().get_argument(a.label()).unwrap())
-                        .for_each(|a| merged.push(a));
-                }
-                (true, Some(merged))
-            }
-            None => (false, None),
-        }
+        self.check_credulous_acceptance_with_certificate(args, false)
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance_with_certificate(args, true)
     }
 }
 
@@ -389,6 +513,40 @@ mod tests {
         assert!(solver.are_credulously_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(solver.are_credulously_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn [< test_conj_credulous_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a3).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a2).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver =
+            CompleteSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&"a0".to_string(), &"a3".to_string()]);
+        assert!(status);
+        let mut labels = certificate.unwrap().iter().map(|a| a.label().as_str()).collect::<Vec<&str>>();
+        labels.sort_unstable();
+        assert_eq!(vec!["a0", "a3"], labels);
+        assert_eq!((false, None), solver.are_all_credulously_accepted_with_certificate(&[&"a1".to_string(), &"a3".to_string()]));
+        let (status, certificate) = solver.are_credulously_accepted_with_certificate(&[&"a1".to_string(), &"a2".to_string()]);
+        assert!(status);
+        assert!(certificate.unwrap().iter().any(|a| a.label() == "a2"));
+    }
     }
     };
     }
@@ -399,4 +557,122 @@ mod tests {
     );
     test_for_encoder!(exp_constraints_encoder::new_for_complete_semantics(), "exp");
     test_for_encoder!(HybridCompleteConstraintsEncoder::default(), "hybrid");
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = CompleteSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(
+            vec![vec!["a0", "a2", "a3"], vec!["a1", "a3"], vec!["a3"]],
+            extensions
+        );
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = CompleteSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(3_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = CompleteSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a1".to_string(), &"a3".to_string()]));
+        assert!(solver.verify_extension(&[&"a3".to_string()]));
+        let (result, reason) =
+            solver.verify_extension_with_reason(&[&"a0".to_string(), &"a3".to_string()]);
+        assert!(!result);
+        assert_eq!(
+            Some(NonExtensionReason::MissingDefendedArgument(
+                af.argument_set().get_argument(&"a2".to_string()).unwrap()
+            )),
+            reason
+        );
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = CompleteSemanticsSolver::new(&af);
+        let (result, certificate) = solver.has_nonempty_extension_with_certificate();
+        assert!(result);
+        assert_eq!(1, certificate.unwrap().len());
+        let (result, certificate) = solver.has_unique_extension_with_certificate();
+        assert!(!result);
+        assert_eq!(2, certificate.len());
+        assert_ne!(certificate[0], certificate[1]);
+    }
+
+    #[test]
+    fn test_unique_empty_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a0).
+        att(a1,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = CompleteSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (true, vec![vec![]]),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
 }
@@ -0,0 +1,170 @@
+use crate::{
+    aa::{AAFramework, Argument},
+    encodings::ConstraintsEncoder,
+    sat::{Literal, SatSolver},
+    utils::{ConnectedComponentsComputer, LabelType},
+};
+use num_bigint::BigUint;
+use permutator::CartesianProduct;
+
+/// The type of functions used to enumerate the extensions of a connected component.
+pub(crate) type ConnectedComponentEnumeratorFn<'a, T> =
+    dyn FnMut(&AAFramework<T>, &mut dyn FnMut(&[&Argument<T>]) -> bool) + 'a;
+
+/// Enumerates the extensions of an AF by combining the extensions of its connected components.
+///
+/// The extensions of all the connected components but the first one are stored,
+/// while the ones of the first connected component are enumerated on the fly.
+pub(crate) fn enumerate_extensions_by_connected_components<T>(
+    af: &AAFramework<T>,
+    cc_enumerator: &mut ConnectedComponentEnumeratorFn<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) where
+    T: LabelType,
+{
+    let mut cc_iterator = ConnectedComponentsComputer::iter_connected_components(af);
+    let first_cc_af = match cc_iterator.next() {
+        Some(cc_af) => cc_af,
+        None => {
+            callback(&[]);
+            return;
+        }
+    };
+    let mut other_cc_extensions = Vec::new();
+    for cc_af in cc_iterator {
+        let mut cc_extensions = Vec::new();
+        cc_enumerator(&cc_af, &mut |cc_ext| {
+            cc_extensions.push(
+                cc_ext
+                    .iter()
+                    .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+                    .collect::<Vec<&Argument<T>>>(),
+            );
+            true
+        });
+        if cc_extensions.is_empty() {
+            return;
+        }
+        other_cc_extensions.push(cc_extensions);
+    }
+    let other_cc_extensions_refs = other_cc_extensions
+        .iter()
+        .map(|v| v.as_slice())
+        .collect::<Vec<&[Vec<&Argument<T>>]>>();
+    cc_enumerator(&first_cc_af, &mut |cc_ext| {
+        let ext = cc_ext
+            .iter()
+            .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+            .collect::<Vec<&Argument<T>>>();
+        if other_cc_extensions_refs.is_empty() {
+            return callback(&ext);
+        }
+        for other_exts in other_cc_extensions_refs.cart_prod() {
+            let mut merged = ext.clone();
+            other_exts
+                .iter()
+                .for_each(|other_ext| merged.extend_from_slice(other_ext));
+            if !callback(&merged) {
+                return false;
+            }
+        }
+        true
+    });
+}
+
+/// Counts the extensions of an AF by multiplying the number of extensions of its connected components.
+///
+/// The extensions of the connected components are enumerated but never stored.
+pub(crate) fn count_extensions_by_connected_components<T>(
+    af: &AAFramework<T>,
+    cc_enumerator: &mut ConnectedComponentEnumeratorFn<T>,
+) -> BigUint
+where
+    T: LabelType,
+{
+    let mut n_extensions = BigUint::from(1_usize);
+    for cc_af in ConnectedComponentsComputer::iter_connected_components(af) {
+        let mut n_cc_extensions = 0_usize;
+        cc_enumerator(&cc_af, &mut |_| {
+            n_cc_extensions += 1;
+            true
+        });
+        if n_cc_extensions == 0 {
+            return BigUint::from(0_usize);
+        }
+        n_extensions *= n_cc_extensions;
+    }
+    n_extensions
+}
+
+/// Enumerates the extensions given by the models of a SAT solver in which the constraints have already been encoded.
+///
+/// After each extension, a clause is added to the solver to prevent it from being found again.
+/// Thus, the encoding must ensure that each extension is given by a single model (wrt. the argument variables).
+///
+/// Returns `false` iff the enumeration was stopped by the callback function.
+pub(crate) fn enumerate_extensions_under_assumptions<T>(
+    af: &AAFramework<T>,
+    solver: &mut dyn SatSolver,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    assumptions: &[Literal],
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) -> bool
+where
+    T: LabelType,
+{
+    loop {
+        let model = match solver.solve_under_assumptions(assumptions).unwrap_model() {
+            Some(m) => m,
+            None => return true,
+        };
+        let extension = constraints_encoder.assignment_to_extension(&model, af);
+        if !callback(&extension) {
+            return false;
+        }
+        if af.n_arguments() == 0 {
+            return true;
+        }
+        solver.add_clause(blocking_clause(af, constraints_encoder, &extension));
+    }
+}
+
+/// Enumerates the extensions given by the models of a SAT solver in which the constraints have already been encoded.
+///
+/// See [enumerate_extensions_under_assumptions] for more information.
+pub(crate) fn enumerate_extensions<T>(
+    af: &AAFramework<T>,
+    solver: &mut dyn SatSolver,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) -> bool
+where
+    T: LabelType,
+{
+    enumerate_extensions_under_assumptions(af, solver, constraints_encoder, &[], callback)
+}
+
+fn blocking_clause<T>(
+    af: &AAFramework<T>,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    extension: &[&Argument<T>],
+) -> Vec<Literal>
+where
+    T: LabelType,
+{
+    let mut in_extension = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+    extension
+        .iter()
+        .for_each(|arg| in_extension[arg.id()] = true);
+    af.argument_set()
+        .iter()
+        .map(|arg| {
+            let lit = constraints_encoder.arg_to_lit(arg);
+            if in_extension[arg.id()] {
+                lit.negate()
+            } else {
+                lit
+            }
+        })
+        .collect()
+}
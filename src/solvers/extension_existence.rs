@@ -0,0 +1,121 @@
+use super::extension_enumeration::ConnectedComponentEnumeratorFn;
+use crate::{
+    aa::{AAFramework, Argument},
+    encodings::ConstraintsEncoder,
+    sat::{Literal, SatSolver},
+    utils::{ConnectedComponentsComputer, LabelType},
+};
+
+/// The type of functions used to compute an extension of a connected component, preferably a non-empty one.
+///
+/// Such functions must return a non-empty extension if one exists, any extension otherwise,
+/// and `None` iff the connected component admits no extension.
+pub(crate) type ConnectedComponentExtensionFinderFn<'a, T> =
+    dyn FnMut(&AAFramework<T>) -> Option<Vec<&Argument<T>>> + 'a;
+
+/// Checks the existence of a non-empty extension by combining extensions of the connected components.
+///
+/// An extension is computed for each connected component (see [ConnectedComponentExtensionFinderFn]);
+/// their union is returned as a certificate if it is not empty.
+pub(crate) fn check_nonempty_extension_by_connected_components<'a, T>(
+    af: &'a AAFramework<T>,
+    cc_finder: &mut ConnectedComponentExtensionFinderFn<T>,
+) -> (bool, Option<Vec<&'a Argument<T>>>)
+where
+    T: LabelType,
+{
+    let mut merged = Vec::new();
+    for cc_af in ConnectedComponentsComputer::iter_connected_components(af) {
+        match cc_finder(&cc_af) {
+            Some(cc_ext) => cc_ext
+                .iter()
+                .for_each(|a| merged.push(af.argument_set().get_argument(a.label()).unwrap())),
+            None => return (false, None),
+        }
+    }
+    if merged.is_empty() {
+        (false, None)
+    } else {
+        (true, Some(merged))
+    }
+}
+
+/// Checks that an AF admits a single extension by enumerating at most two extensions of each connected component.
+///
+/// In case the AF admits several extensions, two of them differing on a single connected component are returned.
+pub(crate) fn check_uniqueness_by_connected_components<'a, T>(
+    af: &'a AAFramework<T>,
+    cc_enumerator: &mut ConnectedComponentEnumeratorFn<T>,
+) -> (bool, Vec<Vec<&'a Argument<T>>>)
+where
+    T: LabelType,
+{
+    let mut first_cc_extensions = Vec::new();
+    let mut other_cc_extension = None;
+    for (i, cc_af) in ConnectedComponentsComputer::iter_connected_components(af).enumerate() {
+        let max_cc_extensions = if other_cc_extension.is_none() { 2 } else { 1 };
+        let mut cc_extensions = Vec::with_capacity(max_cc_extensions);
+        cc_enumerator(&cc_af, &mut |cc_ext| {
+            cc_extensions.push(
+                cc_ext
+                    .iter()
+                    .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+                    .collect::<Vec<&Argument<T>>>(),
+            );
+            cc_extensions.len() < max_cc_extensions
+        });
+        let mut cc_extensions_iter = cc_extensions.into_iter();
+        match cc_extensions_iter.next() {
+            Some(ext) => first_cc_extensions.push(ext),
+            None => return (false, vec![]),
+        }
+        if other_cc_extension.is_none() {
+            other_cc_extension = cc_extensions_iter.next().map(|ext| (i, ext));
+        }
+    }
+    let first_extension = first_cc_extensions.concat();
+    match other_cc_extension {
+        None => (true, vec![first_extension]),
+        Some((i, ext)) => {
+            first_cc_extensions[i] = ext;
+            (false, vec![first_extension, first_cc_extensions.concat()])
+        }
+    }
+}
+
+/// Computes an extension using a SAT solver in which the constraints have already been encoded.
+///
+/// The extension is non-empty if such an extension exists.
+/// `None` is returned iff the AF admits no extension.
+pub(crate) fn compute_nonempty_extension_if_any<'a, T>(
+    af: &'a AAFramework<T>,
+    solver: &mut dyn SatSolver,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+) -> Option<Vec<&'a Argument<T>>>
+where
+    T: LabelType,
+{
+    let selector = Literal::from(1 + solver.n_vars() as isize);
+    let mut clause = nonempty_extension_clause(af, constraints_encoder);
+    clause.push(selector.negate());
+    solver.add_clause(clause);
+    solver
+        .solve_under_assumptions(&[selector])
+        .unwrap_model()
+        .or_else(|| solver.solve().unwrap_model())
+        .map(|model| constraints_encoder.assignment_to_extension(&model, af))
+}
+
+/// Returns a clause which is satisfied iff at least one argument of the AF is in the extension.
+pub(crate) fn nonempty_extension_clause<T>(
+    af: &AAFramework<T>,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+) -> Vec<Literal>
+where
+    T: LabelType,
+{
+    af.argument_set()
+        .iter()
+        .map(|arg| constraints_encoder.arg_to_lit(arg))
+        .collect()
+}
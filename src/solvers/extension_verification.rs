@@ -0,0 +1,370 @@
+use super::{strong_admissibility, NonExtensionReason};
+use crate::{
+    aa::{AAFramework, Argument},
+    encodings::ConstraintsEncoder,
+    sat::{Literal, SatSolverFactoryFn},
+    utils::{ConnectedComponentsComputer, LabelType},
+};
+
+/// A set of arguments which must be checked to be an extension.
+///
+/// The arguments attacked by the set are computed at creation time to speed up the checks.
+pub(crate) struct CandidateExtension<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    args: Vec<&'a Argument<T>>,
+    in_set: Vec<bool>,
+    attacked_by_set: Vec<bool>,
+}
+
+impl<'a, T> CandidateExtension<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a candidate extension given the labels of its arguments.
+    ///
+    /// # Panics
+    ///
+    /// If one of the labels does not belong to the AF, this function panics.
+    pub(crate) fn new(af: &'a AAFramework<T>, labels: &[&T]) -> Self {
+        let mut in_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        let mut attacked_by_set = vec![false; in_set.len()];
+        let mut args = Vec::with_capacity(labels.len());
+        labels.iter().for_each(|l| {
+            let arg = af.argument_set().get_argument(l).unwrap();
+            if in_set[arg.id()] {
+                return;
+            }
+            in_set[arg.id()] = true;
+            af.iter_attacks_from(arg)
+                .for_each(|att| attacked_by_set[att.attacked().id()] = true);
+            args.push(arg);
+        });
+        Self {
+            af,
+            args,
+            in_set,
+            attacked_by_set,
+        }
+    }
+
+    /// Returns the arguments of the set.
+    pub(crate) fn args(&self) -> &[&'a Argument<T>] {
+        &self.args
+    }
+
+    /// Returns `true` iff this set and the provided one contain the same arguments.
+    pub(crate) fn is_equal_to(&self, other: &[&Argument<T>]) -> bool {
+        let mut n_common = 0;
+        for arg in other {
+            if !self.in_set[arg.id()] {
+                return false;
+            }
+            n_common += 1;
+        }
+        n_common == self.args.len()
+    }
+
+    /// Looks for an attack between two arguments of the set.
+    pub(crate) fn check_conflict_freeness(&self) -> Option<NonExtensionReason<'a, T>> {
+        self.args.iter().find_map(|arg| {
+            self.af
+                .iter_attacks_to(arg)
+                .find(|att| self.in_set[att.attacker().id()])
+                .map(|att| NonExtensionReason::ConflictingAttack(att.attacker(), att.attacked()))
+        })
+    }
+
+    /// Checks the conflict-freeness, and then looks for an argument of the set which is not defended by it.
+    pub(crate) fn check_admissibility(&self) -> Option<NonExtensionReason<'a, T>> {
+        self.check_conflict_freeness().or_else(|| {
+            self.args.iter().find_map(|arg| {
+                self.af
+                    .iter_attacks_to(arg)
+                    .find(|att| !self.attacked_by_set[att.attacker().id()])
+                    .map(|att| NonExtensionReason::UndefendedArgument(arg, att.attacker()))
+            })
+        })
+    }
+
+    /// Checks the admissibility, and then looks for arguments of the set which are not strongly defended by it.
+    pub(crate) fn check_strong_admissibility(&self) -> Option<NonExtensionReason<'a, T>> {
+        self.check_admissibility().or_else(|| {
+            let unjustified = strong_admissibility::unjustified_arguments(self.af, &self.args);
+            if unjustified.is_empty() {
+                None
+            } else {
+                Some(NonExtensionReason::CircularDefense(unjustified))
+            }
+        })
+    }
+
+    /// Checks the admissibility, and then looks for an argument defended by the set which is not in it.
+    pub(crate) fn check_completeness(&self) -> Option<NonExtensionReason<'a, T>> {
+        self.check_admissibility().or_else(|| {
+            self.af
+                .argument_set()
+                .iter()
+                .filter(|arg| !self.in_set[arg.id()])
+                .find(|arg| {
+                    self.af
+                        .iter_attacks_to(arg)
+                        .all(|att| self.attacked_by_set[att.attacker().id()])
+                })
+                .map(NonExtensionReason::MissingDefendedArgument)
+        })
+    }
+
+    /// Checks the conflict-freeness, and then looks for an argument which is neither in the set nor attacked by it.
+    pub(crate) fn check_stability(&self) -> Option<NonExtensionReason<'a, T>> {
+        self.check_conflict_freeness().or_else(|| {
+            self.af
+                .argument_set()
+                .iter()
+                .find(|arg| !self.in_set[arg.id()] && !self.attacked_by_set[arg.id()])
+                .map(NonExtensionReason::UnattackedArgument)
+        })
+    }
+
+    /// Looks for an extension which is strictly larger than this set.
+    ///
+    /// The extensions are the ones described by the constraints encoder.
+    /// If `wrt_range` is `true`, the extension must have a range that strictly includes the one of this set;
+    /// otherwise, the extension must strictly include this set.
+    ///
+    /// The computation is made connected component by connected component.
+    /// In case such a larger extension is found for a connected component,
+    /// the returned extension is made of it and of the arguments of the set that belong to the other connected components.
+    pub(crate) fn find_larger_extension(
+        &self,
+        solver_factory: &SatSolverFactoryFn,
+        constraints_encoder: &dyn ConstraintsEncoder<T>,
+        wrt_range: bool,
+    ) -> Option<Vec<&'a Argument<T>>> {
+        for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
+            let cc_in_set = cc_af
+                .argument_set()
+                .iter()
+                .map(|cc_arg| {
+                    self.in_set[self
+                        .af
+                        .argument_set()
+                        .get_argument(cc_arg.label())
+                        .unwrap()
+                        .id()]
+                })
+                .collect::<Vec<bool>>();
+            let mut solver = solver_factory();
+            let (mut assumptions, mut clause) = if wrt_range {
+                constraints_encoder.encode_constraints_and_range(&cc_af, solver.as_mut());
+                let first_range_var = constraints_encoder.first_range_var(cc_af.n_arguments());
+                let mut cc_in_range = cc_in_set.clone();
+                cc_af.argument_set().iter().for_each(|cc_arg| {
+                    if cc_in_set[cc_arg.id()] {
+                        cc_af
+                            .iter_attacks_from(cc_arg)
+                            .for_each(|att| cc_in_range[att.attacked().id()] = true);
+                    }
+                });
+                split_literals(&cc_in_range, |i| {
+                    Literal::from((first_range_var + i) as isize)
+                })
+            } else {
+                constraints_encoder.encode_constraints(&cc_af, solver.as_mut());
+                split_literals(&cc_in_set, |i| {
+                    constraints_encoder.arg_to_lit(cc_af.argument_set().get_argument_by_id(i))
+                })
+            };
+            if clause.is_empty() {
+                continue;
+            }
+            let selector = Literal::from(1 + solver.n_vars() as isize);
+            clause.push(selector.negate());
+            solver.add_clause(clause);
+            assumptions.push(selector);
+            let result = solver.solve_under_assumptions(&assumptions).unwrap_model();
+            solver.add_clause(vec![selector.negate()]);
+            if let Some(model) = result {
+                let mut larger = self
+                    .args
+                    .iter()
+                    .filter(|arg| cc_af.argument_set().get_argument(arg.label()).is_err())
+                    .copied()
+                    .collect::<Vec<&'a Argument<T>>>();
+                constraints_encoder
+                    .assignment_to_extension(&model, &cc_af)
+                    .iter()
+                    .for_each(|cc_arg| {
+                        larger.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                    });
+                return Some(larger);
+            }
+        }
+        None
+    }
+}
+
+// Returns the literals of the elements set to true (as positive literals) and the ones of the elements set to false (as positive literals too).
+fn split_literals<F>(flags: &[bool], lit_fn: F) -> (Vec<Literal>, Vec<Literal>)
+where
+    F: Fn(usize) -> Literal,
+{
+    let mut set = Vec::with_capacity(flags.len());
+    let mut unset = Vec::with_capacity(flags.len());
+    flags.iter().enumerate().for_each(|(i, b)| {
+        if *b {
+            set.push(lit_fn(i));
+        } else {
+            unset.push(lit_fn(i));
+        }
+    });
+    (set, unset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encodings::aux_var_constraints_encoder,
+        io::{AspartixReader, InstanceReader},
+        sat,
+    };
+
+    fn read_af(instance: &str) -> AAFramework<String> {
+        let reader = AspartixReader::default();
+        reader.read(&mut instance.as_bytes()).unwrap()
+    }
+
+    fn labels<'a>(reason: &NonExtensionReason<'a, String>) -> Vec<&'a str> {
+        reason
+            .involved_arguments()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect()
+    }
+
+    const INSTANCE: &str = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a3,a3).
+        "#;
+
+    #[test]
+    fn test_conflict() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(&af, &[&"a0".to_string(), &"a1".to_string()]);
+        let reason = candidate.check_conflict_freeness().unwrap();
+        assert!(matches!(
+            reason,
+            NonExtensionReason::ConflictingAttack(_, _)
+        ));
+        assert!(labels(&reason) == ["a0", "a1"] || labels(&reason) == ["a1", "a0"]);
+    }
+
+    #[test]
+    fn test_undefended() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(&af, &[&"a2".to_string()]);
+        assert!(candidate.check_conflict_freeness().is_none());
+        let reason = candidate.check_admissibility().unwrap();
+        assert!(matches!(
+            reason,
+            NonExtensionReason::UndefendedArgument(_, _)
+        ));
+        assert_eq!(vec!["a2", "a1"], labels(&reason));
+    }
+
+    #[test]
+    fn test_circular_defense() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(&af, &[&"a0".to_string(), &"a2".to_string()]);
+        assert!(candidate.check_admissibility().is_none());
+        let reason = candidate.check_strong_admissibility().unwrap();
+        assert!(matches!(reason, NonExtensionReason::CircularDefense(_)));
+        assert_eq!(vec!["a0", "a2"], labels(&reason));
+        let candidate = CandidateExtension::new(&af, &[]);
+        assert!(candidate.check_strong_admissibility().is_none());
+    }
+
+    #[test]
+    fn test_missing_defended() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(&af, &[&"a0".to_string()]);
+        assert!(candidate.check_admissibility().is_none());
+        let reason = candidate.check_completeness().unwrap();
+        assert_eq!(
+            NonExtensionReason::MissingDefendedArgument(
+                af.argument_set().get_argument(&"a2".to_string()).unwrap()
+            ),
+            reason
+        );
+    }
+
+    #[test]
+    fn test_unattacked() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(&af, &[&"a0".to_string(), &"a2".to_string()]);
+        assert!(candidate.check_completeness().is_none());
+        let reason = candidate.check_stability().unwrap();
+        assert_eq!(vec!["a3"], labels(&reason));
+    }
+
+    #[test]
+    fn test_equality() {
+        let af = read_af(INSTANCE);
+        let candidate = CandidateExtension::new(
+            &af,
+            &[&"a0".to_string(), &"a2".to_string(), &"a0".to_string()],
+        );
+        let a0 = af.argument_set().get_argument(&"a0".to_string()).unwrap();
+        let a2 = af.argument_set().get_argument(&"a2".to_string()).unwrap();
+        assert_eq!(2, candidate.args().len());
+        assert!(candidate.is_equal_to(&[a2, a0]));
+        assert!(!candidate.is_equal_to(&[a0]));
+    }
+
+    #[test]
+    fn test_larger_extension() {
+        let af = read_af(INSTANCE);
+        let encoder = aux_var_constraints_encoder::new_for_complete_semantics();
+        let candidate = CandidateExtension::new(&af, &[] as &[&String]);
+        let mut larger = candidate
+            .find_larger_extension(&|| sat::default_solver(), &encoder, false)
+            .unwrap()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        larger.sort_unstable();
+        assert!(larger == ["a0", "a2"] || larger == ["a1"]);
+        let candidate = CandidateExtension::new(&af, &[&"a0".to_string(), &"a2".to_string()]);
+        assert!(candidate
+            .find_larger_extension(&|| sat::default_solver(), &encoder, false)
+            .is_none());
+    }
+
+    #[test]
+    fn test_larger_range_extension() {
+        let af = read_af(INSTANCE);
+        let encoder = aux_var_constraints_encoder::new_for_complete_semantics();
+        let candidate = CandidateExtension::new(&af, &[] as &[&String]);
+        let mut larger = candidate
+            .find_larger_extension(&|| sat::default_solver(), &encoder, true)
+            .unwrap()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        larger.sort_unstable();
+        assert!(larger == ["a0", "a2"] || larger == ["a1"]);
+        let candidate = CandidateExtension::new(&af, &[&"a1".to_string()]);
+        assert!(candidate
+            .find_larger_extension(&|| sat::default_solver(), &encoder, true)
+            .is_none());
+    }
+}
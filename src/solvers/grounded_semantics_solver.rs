@@ -1,23 +1,41 @@
-use super::{CredulousAcceptanceComputer, SingleExtensionComputer, SkepticalAcceptanceComputer};
+use super::{
+    extension_verification::CandidateExtension, strong_admissibility, CredulousAcceptanceComputer,
+    ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker, NonExtensionReason,
+    SingleExtensionComputer, SingleLabellingComputer, SkepticalAcceptanceComputer,
+    UniqueExtensionChecker, VerifyExtension,
+};
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
+    sat::SatSolverFactoryFn,
     utils::LabelType,
 };
+use num_bigint::BigUint;
 
 /// A solver used to solve queries for the grounded semantics.
 ///
 /// The (unique) grounded extension is the minimal complete extension (see [CompleteSemanticsSolver](crate::solvers::CompleteSemanticsSolver) for more information).
 /// It is computed in time polynomial in the size of the framework.
 ///
-/// This solver implements [SingleExtensionComputer] and both [CredulousAcceptanceComputer] and [SkepticalAcceptanceComputer] interfaces.
-/// In these three cases, the computation resumes to the (polynomial time) computation of the grounded extension.
+/// This solver implements [SingleExtensionComputer], [ExtensionEnumerator] and both [CredulousAcceptanceComputer] and [SkepticalAcceptanceComputer] interfaces.
+/// In these four cases, the computation resumes to the (polynomial time) computation of the grounded extension.
 ///
 /// When a certificate is provided, the certificate is the grounded extension itself.
+/// On large frameworks, this certificate may be too large to be used as an explanation;
+/// solvers built with [new_with_minimum_certificates](Self::new_with_minimum_certificates) rather give
+/// a strongly admissible set of minimum size containing the accepted argument(s) for credulous acceptance queries
+/// (see [StronglyAdmissibleSemanticsSolver](crate::solvers::StronglyAdmissibleSemanticsSolver) for more information).
+///
+/// Since the grounded extension is unique, checking the existence of a non-empty extension ([NonEmptyExtensionChecker])
+/// and the uniqueness of the extension ([UniqueExtensionChecker]) are immediate once it is computed.
+///
+/// When checking a set of arguments with [VerifyExtension], the set is first checked to be a complete extension;
+/// if it is, but it differs from the grounded extension, the latter is given as the reason.
 pub struct GroundedSemanticsSolver<'a, T>
 where
     T: LabelType,
 {
     af: &'a AAFramework<T>,
+    certificate_solver_factory: Option<Box<SatSolverFactoryFn>>,
 }
 
 impl<'a, T> GroundedSemanticsSolver<'a, T>
@@ -40,7 +58,78 @@ where
     /// # search_one_extension::<usize>(&AAFramework::default());
     /// ```
     pub fn new(af: &'a AAFramework<T>) -> Self {
-        Self { af }
+        Self {
+            af,
+            certificate_solver_factory: None,
+        }
+    }
+
+    /// Builds a new solver dedicated to the grounded semantics, providing minimum certificates for credulous acceptance.
+    ///
+    /// The certificates are strongly admissible sets of minimum size containing the accepted argument(s), instead of the whole grounded extension.
+    /// Computing such sets is NP-hard; the SAT solver used to this end is given through the solver factory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat;
+    /// # use crustabri::solvers::{CredulousAcceptanceComputer, GroundedSemanticsSolver};
+    /// fn explain_acceptance<T>(af: &AAFramework<T>, arg: &T) where T: LabelType {
+    ///     let mut solver = GroundedSemanticsSolver::new_with_minimum_certificates(
+    ///         af,
+    ///         Box::new(|| sat::default_solver()),
+    ///     );
+    ///     if let (true, Some(certificate)) = solver.is_credulously_accepted_with_certificate(arg) {
+    ///         println!("{} is accepted thanks to {:?}", arg, certificate);
+    ///     }
+    /// }
+    /// # let mut af = AAFramework::default();
+    /// # af.new_argument(0);
+    /// # explain_acceptance::<usize>(&af, &0);
+    /// ```
+    pub fn new_with_minimum_certificates(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Self {
+        Self {
+            af,
+            certificate_solver_factory: Some(solver_factory),
+        }
+    }
+
+    fn check_credulous_acceptance_with_certificate(
+        &mut self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Argument<T>>>();
+        if let Some(solver_factory) = &self.certificate_solver_factory {
+            return match strong_admissibility::minimum_strongly_admissible_set(
+                self.af,
+                &args,
+                conjunctive,
+                solver_factory.as_ref(),
+            ) {
+                Some(set) => (true, Some(set)),
+                None => (false, None),
+            };
+        }
+        let ext = self.af.grounded_extension();
+        let is_accepted = if conjunctive {
+            args.iter().all(|a| ext.contains(a))
+        } else {
+            args.iter().any(|a| ext.contains(a))
+        };
+        if is_accepted {
+            (true, Some(ext))
+        } else {
+            (false, None)
+        }
     }
 }
 
@@ -53,27 +142,107 @@ where
     }
 }
 
+impl<T> SingleLabellingComputer<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        callback(&self.af.grounded_extension());
+    }
+}
+
+impl<T> ExtensionCounter<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        BigUint::from(1_usize)
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        let ext = self.af.grounded_extension();
+        if ext.is_empty() {
+            (false, None)
+        } else {
+            (true, Some(ext))
+        }
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        (true, vec![self.af.grounded_extension()])
+    }
+}
+
+impl<T> VerifyExtension<T> for GroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let candidate = CandidateExtension::new(self.af, args);
+        let reason = candidate.check_completeness().or_else(|| {
+            let grounded = self.af.grounded_extension();
+            if candidate.is_equal_to(&grounded) {
+                None
+            } else {
+                Some(NonExtensionReason::DistinctExtension(grounded))
+            }
+        });
+        (reason.is_none(), reason)
+    }
+}
+
 impl<T> CredulousAcceptanceComputer<T> for GroundedSemanticsSolver<'_, T>
 where
     T: LabelType,
 {
     fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
-        self.are_credulously_accepted_with_certificate(args).0
+        let ext = self.af.grounded_extension();
+        args.iter()
+            .any(|a| ext.contains(&self.af.argument_set().get_argument(a).unwrap()))
     }
 
     fn are_credulously_accepted_with_certificate(
         &mut self,
         args: &[&T],
     ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance_with_certificate(args, false)
+    }
+
+    fn are_all_credulously_accepted(&mut self, args: &[&T]) -> bool {
         let ext = self.af.grounded_extension();
-        if args
-            .iter()
-            .any(|a| ext.contains(&self.af.argument_set().get_argument(a).unwrap()))
-        {
-            (true, Some(ext))
-        } else {
-            (false, None)
-        }
+        args.iter()
+            .all(|a| ext.contains(&self.af.argument_set().get_argument(a).unwrap()))
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance_with_certificate(args, true)
     }
 }
 
@@ -104,7 +273,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::io::{AspartixReader, InstanceReader};
+    use crate::{
+        io::{AspartixReader, InstanceReader},
+        sat,
+    };
 
     #[test]
     fn test_grounded_solver() {
@@ -165,6 +337,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_minimum_certificates() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        arg(a5).
+        att(a0,a3).
+        att(a1,a4).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a5).
+        att(a4,a5).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let certificate = |solver: &mut GroundedSemanticsSolver<String>, conjunctive: bool| {
+            let args = [&"a5".to_string(), &"a1".to_string()];
+            let (status, certificate) = if conjunctive {
+                solver.are_all_credulously_accepted_with_certificate(&args)
+            } else {
+                solver.are_credulously_accepted_with_certificate(&args[..1])
+            };
+            assert!(status);
+            let mut labels = certificate
+                .unwrap()
+                .iter()
+                .map(|a| a.label().clone())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            labels
+        };
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        assert_eq!(
+            vec!["a0", "a1", "a2", "a5"],
+            certificate(&mut solver, false)
+        );
+        let mut solver = GroundedSemanticsSolver::new_with_minimum_certificates(
+            &af,
+            Box::new(sat::default_solver),
+        );
+        assert_eq!(vec!["a2", "a5"], certificate(&mut solver, false));
+        let conj_certificate = certificate(&mut solver, true);
+        assert_eq!(3, conj_certificate.len());
+        assert!(conj_certificate.contains(&"a1".to_string()));
+        assert_eq!(
+            (false, None),
+            solver.is_credulously_accepted_with_certificate(&"a3".to_string())
+        );
+    }
+
     #[test]
     fn test_disj_credulous_acceptance() {
         let instance = r#"
@@ -226,4 +451,173 @@ mod tests {
         assert!(!solver.are_skeptically_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(!solver.are_skeptically_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a3"]], extensions);
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(1_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a3".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[
+            &"a0".to_string(),
+            &"a2".to_string(),
+            &"a3".to_string(),
+        ]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::DistinctExtension(_))
+        ));
+        let (result, reason) = solver.verify_extension_with_reason(&[]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::MissingDefendedArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (true, vec![vec![]]),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
+
+    #[test]
+    fn test_labelling() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a2,a3).
+        att(a3,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        let labelling = solver.compute_one_labelling().unwrap();
+        assert_eq!(
+            vec!["a0"],
+            labelling
+                .in_arguments()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(
+            vec!["a1"],
+            labelling
+                .out_arguments()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(
+            vec!["a2", "a3"],
+            labelling
+                .undec_arguments()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn test_conj_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = GroundedSemanticsSolver::new(&af);
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a1".to_string()]));
+        assert!(solver.are_credulously_accepted(&[&"a0".to_string(), &"a1".to_string()]));
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert_eq!(
+            2,
+            solver
+                .are_all_skeptically_accepted_with_certificate(&[
+                    &"a0".to_string(),
+                    &"a1".to_string()
+                ])
+                .1
+                .unwrap()
+                .len()
+        );
+    }
 }
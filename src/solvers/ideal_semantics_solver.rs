@@ -1,215 +1,483 @@
 use super::{
+    extension_verification::CandidateExtension,
     maximal_extension_computer::{self},
-    CredulousAcceptanceComputer, PreferredSemanticsSolver, SingleExtensionComputer,
-    SkepticalAcceptanceComputer,
+    maximal_range_semantics_solvers, CredulousAcceptanceComputer, ExtensionCounter,
+    ExtensionEnumerator, NonEmptyExtensionChecker, NonExtensionReason, PreferredSemanticsSolver,
+    SingleExtensionComputer, SingleLabellingComputer, SkepticalAcceptanceComputer,
+    UniqueExtensionChecker, VerifyExtension,
 };
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
     encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
     sat,
     sat::{Literal, SatSolver, SatSolverFactoryFn},
     utils::{self, ConnectedComponentsComputer, Label, LabelType},
 };
+use num_bigint::BigUint;
 use std::{cell::RefCell, rc::Rc};
 
-/// A SAT-based solver for the ideal semantics.
+/// The type of functions used to enumerate the extensions whose intersection bounds the single extension.
 ///
-/// A definition of the ideal semantics is given in the [tracks definition](https://iccma2023.github.io/tracks.html) of ICCMA'23 competition.
-///
-/// For both acceptance queries and extension computation, this solver relies on successive calls to a SAT solver making the computation reach the second level of the polynomial hierarchy.
-///
-/// The certificates for the acceptance queries are extensions.
-pub struct IdealSemanticsSolver<'a, T>
-where
-    T: LabelType,
-{
-    af: &'a AAFramework<T>,
-    solver_factory: Box<SatSolverFactoryFn>,
-    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
-}
+/// The SAT solver given as parameter must contain the constraints of the encoder when the function returns.
+type BaseEnumeratorFn<T> = fn(
+    &AAFramework<T>,
+    &SatSolverFactoryFn,
+    Rc<RefCell<Box<dyn SatSolver>>>,
+    &dyn ConstraintsEncoder<T>,
+    &mut dyn FnMut(&[&Argument<T>]) -> bool,
+);
 
-impl<'a, T> IdealSemanticsSolver<'a, T>
-where
-    T: LabelType,
-{
-    /// Builds a new SAT based solver for the ideal semantics.
-    ///
-    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use crustabri::aa::{AAFramework};
-    /// # use crustabri::utils::LabelType;
-    /// # use crustabri::solvers::{SingleExtensionComputer, IdealSemanticsSolver};
-    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
-    ///     let mut solver = IdealSemanticsSolver::new(af);
-    ///     let ext = solver.compute_one_extension().unwrap();
-    ///     println!("found the ideal extension: {:?}", ext);
-    /// }
-    /// # search_one_extension::<usize>(&AAFramework::default());
-    /// ```
-    pub fn new(af: &'a AAFramework<T>) -> Self {
-        Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
-    }
+macro_rules! single_extension_solver {
+    ($solver_ident:ident, $sem_name:literal, $base_enumerator:expr, $solver_doc:literal) => {
+        #[doc = concat!(" A SAT-based solver for the ", $sem_name, " semantics.")]
+        ///
+        #[doc = $solver_doc]
+        ///
+        /// For both acceptance queries and extension computation, this solver relies on successive calls to a SAT solver making the computation reach the second level of the polynomial hierarchy.
+        ///
+        /// The certificates for the acceptance queries are extensions.
+        ///
+        #[doc = concat!(" Since the ", $sem_name, " extension is unique, checking the existence of a non-empty extension ([NonEmptyExtensionChecker])")]
+        /// and the uniqueness of the extension ([UniqueExtensionChecker]) resume to its computation.
+        ///
+        /// When checking a set of arguments with [VerifyExtension], the set is first checked to be admissible;
+        #[doc = concat!(" if it is, the ", $sem_name, " extension is computed and given as the reason in case it differs from the set.")]
+        pub struct $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            af: &'a AAFramework<T>,
+            solver_factory: Box<SatSolverFactoryFn>,
+            constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+        }
+
+        impl<'a, T> $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            #[doc = concat!(" Builds a new SAT based solver for the ", $sem_name, " semantics.")]
+            ///
+            /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new(af);")]
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found the ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new(af: &'a AAFramework<T>) -> Self {
+                Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+            }
+
+            #[doc = concat!(" Builds a new SAT based solver for the ", $sem_name, " semantics.")]
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat::CadicalSolver;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory(")]
+            ///         af,
+            ///         Box::new(|| Box::new(CadicalSolver::default())),
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found the ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+            ) -> Self {
+                Self {
+                    af,
+                    solver_factory,
+                    constraints_encoder: Box::new(
+                        aux_var_constraints_encoder::new_for_complete_semantics(),
+                    ),
+                }
+            }
+
+            #[doc = concat!(" Builds a new SAT based solver for the ", $sem_name, " semantics.")]
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat;
+            /// # use crustabri::encodings;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory_and_constraints_encoder(")]
+            ///         af,
+            ///         Box::new(|| sat::default_solver()),
+            ///         encodings::new_default_complete_constraints_encoder(),
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found the ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory_and_constraints_encoder(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+                constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self {
+                    af,
+                    solver_factory,
+                    constraints_encoder,
+                }
+            }
+
+            fn compute_one_extension_for_cc<'b>(
+                &self,
+                cc_af: &'b AAFramework<T>,
+            ) -> Vec<&'b Argument<T>> {
+                let grounded = utils::grounded_extension(cc_af);
+                let solver = Rc::new(RefCell::new((self.solver_factory)()));
+                let (in_all, n_in_all, n_base) =
+                    self.compute_in_all_extensions_for_cc(cc_af, &grounded, Rc::clone(&solver));
+                if n_in_all == grounded.len() {
+                    return grounded;
+                }
+                if n_base == 1 {
+                    return single_base_extension(cc_af, in_all);
+                }
+                compute_maximal_with_allowed(
+                    cc_af,
+                    solver,
+                    in_all,
+                    self.constraints_encoder.as_ref(),
+                )
+            }
+
+            // The number of base extensions may be incorrect if n_in_all is equal to the size of the grounded extension.
+            fn compute_in_all_extensions_for_cc<'b>(
+                &self,
+                cc_af: &'b AAFramework<T>,
+                grounded: &[&'b Label<T>],
+                solver: Rc<RefCell<Box<dyn SatSolver>>>,
+            ) -> (Vec<bool>, usize, usize) {
+                let mut in_all = vec![true; cc_af.n_arguments()];
+                let mut n_in_all = 0;
+                let mut n_base = 0;
+                let base_enumerator: BaseEnumeratorFn<T> = $base_enumerator;
+                base_enumerator(
+                    cc_af,
+                    self.solver_factory.as_ref(),
+                    Rc::clone(&solver),
+                    self.constraints_encoder.as_ref(),
+                    &mut |ext| {
+                        n_base += 1;
+                        let mut new_in_all = vec![false; cc_af.n_arguments()];
+                        n_in_all = 0;
+                        ext.iter().for_each(|a| {
+                            if in_all[a.id()] {
+                                new_in_all[a.id()] = true;
+                                n_in_all += 1;
+                            }
+                        });
+                        in_all = new_in_all;
+                        n_in_all != grounded.len()
+                    },
+                );
+                (in_all, n_in_all, n_base)
+            }
 
-    /// Builds a new SAT based solver for the ideal semantics.
-    ///
-    /// The SAT solver to use in given through the solver factory.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use crustabri::aa::{AAFramework};
-    /// # use crustabri::utils::LabelType;
-    /// # use crustabri::sat::CadicalSolver;
-    /// # use crustabri::solvers::{SingleExtensionComputer, IdealSemanticsSolver};
-    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
-    ///     let mut solver = IdealSemanticsSolver::new_with_sat_solver_factory(
-    ///         af,
-    ///         Box::new(|| Box::new(CadicalSolver::default())),
-    ///     );
-    ///     let ext = solver.compute_one_extension().unwrap();
-    ///     println!("found the ideal extension: {:?}", ext);
-    /// }
-    /// # search_one_extension::<usize>(&AAFramework::default());
-    /// ```
-    pub fn new_with_sat_solver_factory(
-        af: &'a AAFramework<T>,
-        solver_factory: Box<SatSolverFactoryFn>,
-    ) -> Self {
-        Self {
-            af,
-            solver_factory,
-            constraints_encoder: Box::new(aux_var_constraints_encoder::new_for_complete_semantics()),
+            fn check_credulous_acceptance_for_cc<'b>(
+                &self,
+                cc_af: &'b AAFramework<T>,
+                cc_args: &[&'b Argument<T>],
+            ) -> (bool, Option<Vec<&'b Argument<T>>>) {
+                let grounded = utils::grounded_extension(cc_af);
+                let solver = Rc::new(RefCell::new((self.solver_factory)()));
+                let (in_all, n_in_all, n_base) =
+                    self.compute_in_all_extensions_for_cc(cc_af, &grounded, Rc::clone(&solver));
+                if cc_args.iter().all(|a| !in_all[a.id()]) {
+                    return (false, None);
+                }
+                let result = |ext: Vec<&'b Argument<T>>| {
+                    if cc_args.iter().any(|a| ext.contains(a)) {
+                        (true, Some(ext))
+                    } else {
+                        (false, None)
+                    }
+                };
+                if n_in_all == grounded.len() {
+                    return result(grounded);
+                }
+                if n_base == 1 {
+                    let ext = single_base_extension(cc_af, in_all);
+                    return result(ext);
+                }
+                let ext = compute_maximal_with_allowed(
+                    cc_af,
+                    solver,
+                    in_all,
+                    self.constraints_encoder.as_ref(),
+                );
+                result(ext)
+            }
         }
-    }
 
-    /// Builds a new SAT based solver for the ideal semantics.
-    ///
-    /// The SAT solver to use in given through the solver factory.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use crustabri::aa::{AAFramework};
-    /// # use crustabri::utils::LabelType;
-    /// # use crustabri::sat;
-    /// # use crustabri::encodings;
-    /// # use crustabri::solvers::{SingleExtensionComputer, IdealSemanticsSolver};
-    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
-    ///     let mut solver = IdealSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
-    ///         af,
-    ///         Box::new(|| sat::default_solver()),
-    ///         encodings::new_default_complete_constraints_encoder(),
-    ///     );
-    ///     let ext = solver.compute_one_extension().unwrap();
-    ///     println!("found the ideal extension: {:?}", ext);
-    /// }
-    /// # search_one_extension::<usize>(&AAFramework::default());
-    /// ```
-    pub fn new_with_sat_solver_factory_and_constraints_encoder(
-        af: &'a AAFramework<T>,
-        solver_factory: Box<SatSolverFactoryFn>,
-        constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
-    ) -> Self
-    where
-        T: LabelType,
-    {
-        Self {
-            af,
-            solver_factory,
-            constraints_encoder,
+        impl<T> SingleExtensionComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+                let mut merged = Vec::new();
+                for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
+                    let local_ext = self.compute_one_extension_for_cc(&cc_af);
+                    for cc_arg in local_ext {
+                        merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                    }
+                }
+                Some(merged)
+            }
         }
-    }
 
-    fn compute_one_extension_for_cc<'b>(&self, cc_af: &'b AAFramework<T>) -> Vec<&'b Argument<T>> {
-        let grounded = utils::grounded_extension(cc_af);
-        let solver = Rc::new(RefCell::new((self.solver_factory)()));
-        let (in_all, n_in_all, n_preferred) =
-            self.compute_in_all_extensions_for_cc(cc_af, &grounded, Rc::clone(&solver));
-        if n_in_all == grounded.len() {
-            return grounded;
+        impl<T> SingleLabellingComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+                let af = self.af;
+                self.compute_one_extension()
+                    .map(|ext| Labelling::new_from_extension(af, &ext))
+            }
         }
-        if n_preferred == 1 {
-            return single_preferred(cc_af, in_all);
+
+        impl<T> ExtensionEnumerator<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+                callback(&self.compute_one_extension().unwrap());
+            }
         }
-        compute_maximal_with_allowed(cc_af, solver, in_all, self.constraints_encoder.as_ref())
-    }
 
-    // The number of preferred extensions may be incorrect if n_in_all is equal to the size of the grounded extension.
-    fn compute_in_all_extensions_for_cc<'b>(
-        &self,
-        cc_af: &'b AAFramework<T>,
-        grounded: &[&'b Label<T>],
-        solver: Rc<RefCell<Box<dyn SatSolver>>>,
-    ) -> (Vec<bool>, usize, usize) {
-        let mut in_all = vec![true; cc_af.n_arguments()];
-        let mut n_in_all = 0;
-        let mut n_preferred = 0;
-        PreferredSemanticsSolver::enumerate_extensions(
-            cc_af,
-            Rc::clone(&solver),
-            self.constraints_encoder.as_ref(),
-            &mut |ext| {
-                n_preferred += 1;
-                let mut new_in_all = vec![false; cc_af.n_arguments()];
-                n_in_all = 0;
-                ext.iter().for_each(|a| {
-                    if in_all[a.id()] {
-                        new_in_all[a.id()] = true;
-                        n_in_all += 1;
-                    }
-                });
-                in_all = new_in_all;
-                n_in_all != grounded.len()
-            },
-        );
-        (in_all, n_in_all, n_preferred)
-    }
+        impl<T> ExtensionCounter<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn count_extensions(&mut self) -> BigUint {
+                BigUint::from(1_usize)
+            }
+        }
 
-    fn check_credulous_acceptance_for_cc<'b>(
-        &self,
-        cc_af: &'b AAFramework<T>,
-        cc_args: &[&'b Argument<T>],
-    ) -> (bool, Option<Vec<&'b Argument<T>>>) {
-        let grounded = utils::grounded_extension(cc_af);
-        let solver = Rc::new(RefCell::new((self.solver_factory)()));
-        let (in_all, n_in_all, n_preferred) =
-            self.compute_in_all_extensions_for_cc(cc_af, &grounded, Rc::clone(&solver));
-        if cc_args.iter().all(|a| !in_all[a.id()]) {
-            return (false, None);
+        impl<T> NonEmptyExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_nonempty_extension_with_certificate(
+                &mut self,
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                let ext = self.compute_one_extension().unwrap();
+                if ext.is_empty() {
+                    (false, None)
+                } else {
+                    (true, Some(ext))
+                }
+            }
         }
-        let result = |ext: Vec<&'b Argument<T>>| {
-            if cc_args.iter().any(|a| ext.contains(a)) {
-                (true, Some(ext))
-            } else {
-                (false, None)
+
+        impl<T> UniqueExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+                (true, vec![self.compute_one_extension().unwrap()])
             }
-        };
-        if n_in_all == grounded.len() {
-            return result(grounded);
         }
-        if n_preferred == 1 {
-            let ext = single_preferred(cc_af, in_all);
-            return result(ext);
+
+        impl<T> VerifyExtension<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn verify_extension_with_reason(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+                let candidate = CandidateExtension::new(self.af, args);
+                if let Some(reason) = candidate.check_admissibility() {
+                    return (false, Some(reason));
+                }
+                let ext = self.compute_one_extension().unwrap();
+                if candidate.is_equal_to(&ext) {
+                    (true, None)
+                } else {
+                    (false, Some(NonExtensionReason::DistinctExtension(ext)))
+                }
+            }
         }
-        let ideal_ext =
-            compute_maximal_with_allowed(cc_af, solver, in_all, self.constraints_encoder.as_ref());
-        result(ideal_ext)
-    }
+
+        impl<T> CredulousAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+                let args = args
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+                let cc_af = cc_computer.merged_connected_components_of(&args);
+                let cc_args = args
+                    .iter()
+                    .map(|a| cc_af.argument_set().get_argument(a.label()).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                self.check_credulous_acceptance_for_cc(&cc_af, &cc_args).0
+            }
+
+            fn are_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                let args = args
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+                let cc_af = cc_computer.merged_connected_components_of(&args);
+                let cc_args = args
+                    .iter()
+                    .map(|a| cc_af.argument_set().get_argument(a.label()).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                let cc_ext = match self.check_credulous_acceptance_for_cc(&cc_af, &cc_args) {
+                    (true, Some(ext)) => ext,
+                    _ => return (false, None),
+                };
+                let mut merged = Vec::new();
+                cc_ext
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a.label()).unwrap())
+                    .for_each(|a| merged.push(a));
+                while let Some(other_cc_af) = cc_computer.next_connected_component() {
+                    for cc_arg in self.compute_one_extension_for_cc(&other_cc_af) {
+                        merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+                    }
+                }
+                (true, Some(merged))
+            }
+
+            fn are_all_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                let args = args
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                let ext = self.compute_one_extension().unwrap();
+                if args.iter().all(|a| ext.contains(a)) {
+                    (true, Some(ext))
+                } else {
+                    (false, None)
+                }
+            }
+        }
+
+        impl<T> SkepticalAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+                self.are_credulously_accepted(args)
+            }
+
+            fn are_skeptically_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                let args = args
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a).unwrap())
+                    .collect::<Vec<&Label<T>>>();
+                let ext = self.compute_one_extension().unwrap();
+                if args.iter().any(|a| ext.contains(a)) {
+                    (true, None)
+                } else {
+                    (false, Some(ext))
+                }
+            }
+        }
+    };
+}
+
+single_extension_solver!(
+    IdealSemanticsSolver,
+    "ideal",
+    enumerate_preferred_extensions,
+    " A definition of the ideal semantics is given in the [tracks definition](https://iccma2023.github.io/tracks.html) of ICCMA'23 competition."
+);
+
+single_extension_solver!(
+    EagerSemanticsSolver,
+    "eager",
+    enumerate_semi_stable_extensions,
+    " The eager extension of an Argumentation Framework is its maximal (wrt. set inclusion) admissible set included in all its semi-stable extensions."
+);
+
+fn enumerate_preferred_extensions<T>(
+    af: &AAFramework<T>,
+    _solver_factory: &SatSolverFactoryFn,
+    solver: Rc<RefCell<Box<dyn SatSolver>>>,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) where
+    T: LabelType,
+{
+    PreferredSemanticsSolver::enumerate_extensions(af, solver, constraints_encoder, callback)
+}
+
+// The semi-stable extensions are enumerated with their own SAT solver, since the range variables are not needed afterwards.
+fn enumerate_semi_stable_extensions<T>(
+    af: &AAFramework<T>,
+    solver_factory: &SatSolverFactoryFn,
+    solver: Rc<RefCell<Box<dyn SatSolver>>>,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) where
+    T: LabelType,
+{
+    constraints_encoder.encode_constraints(af, solver.borrow_mut().as_mut());
+    maximal_range_semantics_solvers::enumerate_maximal_range_extensions(
+        af,
+        solver_factory,
+        constraints_encoder,
+        callback,
+    )
 }
 
 fn compute_maximal_with_allowed<'a, T>(
     cc_af: &'a AAFramework<T>,
     solver: Rc<RefCell<Box<dyn SatSolver>>>,
-    in_all_preferred: Vec<bool>,
+    in_all_base_extensions: Vec<bool>,
     constraints_encoder: &dyn ConstraintsEncoder<T>,
 ) -> Vec<&'a Argument<T>>
 where
     T: LabelType,
 {
-    let assumptions = in_all_preferred
+    let assumptions = in_all_base_extensions
         .iter()
         .enumerate()
         .filter_map(|(i, b)| match *b {
@@ -230,11 +498,14 @@ where
     computer.compute_maximal()
 }
 
-fn single_preferred<T>(cc_af: &AAFramework<T>, in_all_preferred: Vec<bool>) -> Vec<&Argument<T>>
+fn single_base_extension<T>(
+    cc_af: &AAFramework<T>,
+    in_all_base_extensions: Vec<bool>,
+) -> Vec<&Argument<T>>
 where
     T: LabelType,
 {
-    in_all_preferred
+    in_all_base_extensions
         .iter()
         .enumerate()
         .filter_map(|(i, b)| {
@@ -247,100 +518,6 @@ where
         .collect()
 }
 
-impl<T> SingleExtensionComputer<T> for IdealSemanticsSolver<'_, T>
-where
-    T: LabelType,
-{
-    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
-        let mut merged = Vec::new();
-        for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
-            let mut solver = (self.solver_factory)();
-            self.constraints_encoder
-                .encode_constraints(&cc_af, solver.as_mut());
-            let local_ext = self.compute_one_extension_for_cc(&cc_af);
-            for cc_arg in local_ext {
-                merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
-            }
-        }
-        Some(merged)
-    }
-}
-
-impl<T> CredulousAcceptanceComputer<T> for IdealSemanticsSolver<'_, T>
-where
-    T: LabelType,
-{
-    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
-        let args = args
-            .iter()
-            .map(|a| self.af.argument_set().get_argument(a).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
-        let cc_af = cc_computer.merged_connected_components_of(&args);
-        let cc_args = args
-            .iter()
-            .map(|a| cc_af.argument_set().get_argument(a.label()).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        self.check_credulous_acceptance_for_cc(&cc_af, &cc_args).0
-    }
-
-    fn are_credulously_accepted_with_certificate(
-        &mut self,
-        args: &[&T],
-    ) -> (bool, Option<Vec<&Argument<T>>>) {
-        let args = args
-            .iter()
-            .map(|a| self.af.argument_set().get_argument(a).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
-        let cc_af = cc_computer.merged_connected_components_of(&args);
-        let cc_args = args
-            .iter()
-            .map(|a| cc_af.argument_set().get_argument(a.label()).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        let cc_ext = match self.check_credulous_acceptance_for_cc(&cc_af, &cc_args) {
-            (true, Some(ext)) => ext,
-            _ => return (false, None),
-        };
-        let mut merged = Vec::new();
-        cc_ext
-            .iter()
-            .map(|a| self.af.argument_set().get_argument(a.label()).unwrap())
-            .for_each(|a| merged.push(a));
-        while let Some(other_cc_af) = cc_computer.next_connected_component() {
-            for cc_arg in self.compute_one_extension_for_cc(&other_cc_af) {
-                merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
-            }
-        }
-        (true, Some(merged))
-    }
-}
-
-impl<T> SkepticalAcceptanceComputer<T> for IdealSemanticsSolver<'_, T>
-where
-    T: LabelType,
-{
-    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
-        self.are_credulously_accepted(args)
-    }
-
-    fn are_skeptically_accepted_with_certificate(
-        &mut self,
-        args: &[&T],
-    ) -> (bool, Option<Vec<&Argument<T>>>) {
-        let args = args
-            .iter()
-            .map(|a| self.af.argument_set().get_argument(a).unwrap())
-            .collect::<Vec<&Label<T>>>();
-        let ext = self.compute_one_extension().unwrap();
-        if args.iter().any(|a| ext.contains(a)) {
-            (true, None)
-        } else {
-            (false, Some(ext))
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -667,4 +844,200 @@ mod tests {
     );
     test_for_encoder!(exp_constraints_encoder::new_for_complete_semantics(), "exp");
     test_for_encoder!(HybridCompleteConstraintsEncoder::default(), "hybrid");
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = IdealSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a3"]], extensions);
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = IdealSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(1_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = IdealSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a3".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a2".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::UndefendedArgument(_, _))
+        ));
+        let (result, reason) = solver.verify_extension_with_reason(&[]);
+        assert!(!result);
+        assert_eq!(
+            Some(NonExtensionReason::DistinctExtension(vec![af
+                .argument_set()
+                .get_argument(&"a3".to_string())
+                .unwrap()])),
+            reason
+        );
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = IdealSemanticsSolver::new(&af);
+        assert_eq!(
+            (
+                true,
+                Some(vec![af
+                    .argument_set()
+                    .get_argument(&"a0".to_string())
+                    .unwrap()])
+            ),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert!(solver.has_unique_extension());
+    }
+
+    #[test]
+    fn test_conj_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a3).
+        att(a3,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = IdealSemanticsSolver::new(&af);
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a3".to_string()]));
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+    }
+
+    #[test]
+    fn test_eager_differs_from_ideal() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut ideal_solver = IdealSemanticsSolver::new(&af);
+        assert!(ideal_solver.compute_one_extension().unwrap().is_empty());
+        let mut eager_solver =
+            EagerSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                &af,
+                Box::new(|| sat::default_solver()),
+                Box::new(exp_constraints_encoder::new_for_complete_semantics()),
+            );
+        let mut ext = eager_solver
+            .compute_one_extension()
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        ext.sort_unstable();
+        assert_eq!(vec!["a1", "a3"], ext);
+        assert!(eager_solver.verify_extension(&[&"a3".to_string(), &"a1".to_string()]));
+        assert!(!eager_solver.verify_extension(&[&"a0".to_string()]));
+    }
+
+    #[test]
+    fn test_eager_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        att(a3,a4).
+        att(a4,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = EagerSemanticsSolver::new(&af);
+        let (accepted, certificate) =
+            solver.is_credulously_accepted_with_certificate(&"a1".to_string());
+        assert!(accepted);
+        assert_eq!(
+            vec!["a1"],
+            certificate
+                .unwrap()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert!(!solver.is_credulously_accepted(&"a0".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a4".to_string()));
+        let (accepted, certificate) =
+            solver.is_skeptically_accepted_with_certificate(&"a4".to_string());
+        assert!(!accepted);
+        assert_eq!(1, certificate.unwrap().len());
+    }
 }
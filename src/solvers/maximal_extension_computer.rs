@@ -168,7 +168,7 @@ where
         self.state = MaximalExtensionComputerState::JustDiscarded;
     }
 
-    pub(crate) fn state_data(&mut self) -> MaximalExtensionComputerStateData<T> {
+    pub(crate) fn state_data(&mut self) -> MaximalExtensionComputerStateData<'_, T> {
         MaximalExtensionComputerStateData {
             af: self.af,
             current_arg_set: self.current_extension.as_ref().unwrap(),
@@ -1,19 +1,24 @@
 use super::{
+    extension_enumeration, extension_existence,
+    extension_verification::CandidateExtension,
     maximal_extension_computer::{
         MaximalExtensionComputer, MaximalExtensionComputerState, MaximalExtensionComputerStateData,
     },
-    CredulousAcceptanceComputer, SingleExtensionComputer, SkepticalAcceptanceComputer,
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, SingleExtensionComputer, SingleLabellingComputer,
+    SkepticalAcceptanceComputer, UniqueExtensionChecker, VerifyExtension,
 };
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
     encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
     sat::{self, Literal, SatSolver, SatSolverFactoryFn},
     utils::{ConnectedComponentsComputer, Label, LabelType},
 };
+use num_bigint::BigUint;
 use std::{cell::RefCell, rc::Rc};
 
 macro_rules! maximal_range_solver {
-    ($solver_ident:ident, $sem_name:literal, $constraints_encoder:expr) => {
+    ($solver_ident:ident, $sem_name:literal, $constraints_encoder:expr, $check_fn:ident) => {
         #[doc = concat!(" A SAT-based solver for the ", $sem_name, " semantics.")]
         ///
         /// A definition of the extensions wrt. this semantics is given in the [tracks definition](https://iccma2023.github.io/tracks.html) of ICCMA'23 competition.
@@ -21,6 +26,14 @@ macro_rules! maximal_range_solver {
         /// For both acceptance queries and extension computation, this solver relies on successive calls to a SAT solver making the computation reach the second level of the polynomial hierarchy.
         ///
         /// The certificates for the acceptance queries are extensions.
+        ///
+        /// Checking whether a set of arguments is an extension ([VerifyExtension]) requires a single SAT call per connected component
+        /// to ensure no extension with a strictly larger range exists.
+        /// If such an extension exists, it is given as the reason.
+        ///
+        /// The existence of a non-empty extension ([NonEmptyExtensionChecker]) is checked by a single search for an extension with a maximal range
+        /// per connected component, in which the empty set is forbidden.
+        /// The uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
         pub struct $solver_ident<'a, T>
         where
             T: LabelType,
@@ -140,6 +153,77 @@ macro_rules! maximal_range_solver {
             }
         }
 
+        impl<T> SingleLabellingComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+                let af = self.helper.af;
+                self.compute_one_extension()
+                    .map(|ext| Labelling::new_from_extension(af, &ext))
+            }
+        }
+
+        impl<T> ExtensionEnumerator<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+                self.helper.enumerate_extensions(callback)
+            }
+        }
+
+        impl<T> ExtensionCounter<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn count_extensions(&mut self) -> BigUint {
+                self.helper.count_extensions()
+            }
+        }
+
+        impl<T> NonEmptyExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_nonempty_extension_with_certificate(
+                &mut self,
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.has_nonempty_extension_with_certificate()
+            }
+        }
+
+        impl<T> UniqueExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+                self.helper.has_unique_extension_with_certificate()
+            }
+        }
+
+        impl<T> VerifyExtension<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn verify_extension_with_reason(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+                let candidate = CandidateExtension::new(self.helper.af, args);
+                let reason = candidate.$check_fn().or_else(|| {
+                    candidate
+                        .find_larger_extension(
+                            self.helper.solver_factory.as_ref(),
+                            self.helper.constraints_encoder.as_ref(),
+                            true,
+                        )
+                        .map(NonExtensionReason::LargerExtension)
+                });
+                (reason.is_none(), reason)
+            }
+        }
+
         impl<T> CredulousAcceptanceComputer<T> for $solver_ident<'_, T>
         where
             T: LabelType,
@@ -154,6 +238,13 @@ macro_rules! maximal_range_solver {
             ) -> (bool, Option<Vec<&Argument<T>>>) {
                 self.helper.are_credulously_accepted_with_certificate(args)
             }
+
+            fn are_all_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.are_all_credulously_accepted_with_certificate(args)
+            }
         }
 
         impl<T> SkepticalAcceptanceComputer<T> for $solver_ident<'_, T>
@@ -177,13 +268,15 @@ macro_rules! maximal_range_solver {
 maximal_range_solver!(
     SemiStableSemanticsSolver,
     "semi-stable",
-    Box::new(aux_var_constraints_encoder::new_for_complete_semantics())
+    Box::new(aux_var_constraints_encoder::new_for_complete_semantics()),
+    check_completeness
 );
 
 maximal_range_solver!(
     StageSemanticsSolver,
     "stage",
-    Box::new(aux_var_constraints_encoder::new_for_conflict_freeness())
+    Box::new(aux_var_constraints_encoder::new_for_conflict_freeness()),
+    check_conflict_freeness
 );
 
 pub(crate) struct MaximalRangeSemanticsHelper<'a, T>
@@ -226,6 +319,67 @@ where
         Some(merged)
     }
 
+    pub fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+
+    pub fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+
+    pub fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        extension_existence::check_nonempty_extension_by_connected_components(
+            self.af,
+            &mut |cc_af| self.compute_nonempty_extension_in_cc(cc_af),
+        )
+    }
+
+    pub fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+
+    // The empty set has an empty range, so an extension with a maximal range among the non-empty ones has a maximal range among all the extensions.
+    fn compute_nonempty_extension_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let solver = Rc::new(RefCell::new((self.solver_factory)()));
+        self.constraints_encoder
+            .encode_constraints_and_range(cc_af, solver.borrow_mut().as_mut());
+        solver
+            .borrow_mut()
+            .add_clause(extension_existence::nonempty_extension_clause(
+                cc_af,
+                self.constraints_encoder.as_ref(),
+            ));
+        let computer =
+            new_maximal_extension_computer(cc_af, solver, self.constraints_encoder.as_ref());
+        Some(computer.compute_maximal())
+    }
+
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        enumerate_maximal_range_extensions(
+            cc_af,
+            self.solver_factory.as_ref(),
+            self.constraints_encoder.as_ref(),
+            callback,
+        )
+    }
+
     pub fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
         let args = args
             .iter()
@@ -233,7 +387,7 @@ where
             .collect::<Vec<&Label<T>>>();
         let mut cc_computer = ConnectedComponentsComputer::new(self.af);
         let cc_af = cc_computer.merged_connected_components_of(&args);
-        self.check_acceptance_in_cc(&cc_af, &args, true).0
+        self.check_acceptance_in_cc(&cc_af, &args, true, false).0
     }
 
     pub fn are_credulously_accepted_with_certificate(
@@ -244,7 +398,18 @@ where
             .iter()
             .map(|a| self.af.argument_set().get_argument(a).unwrap())
             .collect::<Vec<&Label<T>>>();
-        self.check_acceptance_with_certificate(&args, true)
+        self.check_acceptance_with_certificate(&args, true, false)
+    }
+
+    pub fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        self.check_acceptance_with_certificate(&args, true, true)
     }
 
     pub fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
@@ -254,7 +419,7 @@ where
             .collect::<Vec<&Label<T>>>();
         let mut cc_computer = ConnectedComponentsComputer::new(self.af);
         let cc_af = cc_computer.merged_connected_components_of(&args);
-        self.check_acceptance_in_cc(&cc_af, &args, false).0
+        self.check_acceptance_in_cc(&cc_af, &args, false, false).0
     }
 
     pub fn are_skeptically_accepted_with_certificate(
@@ -265,18 +430,19 @@ where
             .iter()
             .map(|a| self.af.argument_set().get_argument(a).unwrap())
             .collect::<Vec<&Label<T>>>();
-        self.check_acceptance_with_certificate(&args, false)
+        self.check_acceptance_with_certificate(&args, false, false)
     }
 
     fn check_acceptance_with_certificate(
         &mut self,
         args: &[&Argument<T>],
         is_credulous_acceptance: bool,
+        conjunctive: bool,
     ) -> (bool, Option<Vec<&Argument<T>>>) {
         let mut cc_computer = ConnectedComponentsComputer::new(self.af);
         let cc_af = cc_computer.merged_connected_components_of(args);
         let mut merged = Vec::new();
-        match self.check_acceptance_in_cc(&cc_af, args, is_credulous_acceptance) {
+        match self.check_acceptance_in_cc(&cc_af, args, is_credulous_acceptance, conjunctive) {
             (_, None) => return (!is_credulous_acceptance, None),
             (_, Some(cc_ext)) => {
                 cc_ext
@@ -294,6 +460,7 @@ where
         cc_af: &'b AAFramework<T>,
         args: &[&'a Argument<T>],
         is_credulous_acceptance: bool,
+        conjunctive: bool,
     ) -> (bool, Option<Vec<&'b Argument<T>>>) {
         let cc_args = args
             .iter()
@@ -313,11 +480,14 @@ where
                 MaximalExtensionComputerState::Maximal => {
                     let fn_data = computer.state_data();
                     let ext = fn_data.current_arg_set;
-                    if (is_credulous_acceptance
-                        && cc_args.iter().any(|cc_arg| ext.contains(cc_arg)))
-                        || (!is_credulous_acceptance
-                            && cc_args.iter().all(|cc_arg| !ext.contains(cc_arg)))
-                    {
+                    let is_answer = if !is_credulous_acceptance {
+                        cc_args.iter().all(|cc_arg| !ext.contains(cc_arg))
+                    } else if conjunctive {
+                        cc_args.iter().all(|cc_arg| ext.contains(cc_arg))
+                    } else {
+                        cc_args.iter().any(|cc_arg| ext.contains(cc_arg))
+                    };
+                    if is_answer {
                         return (
                             is_credulous_acceptance,
                             Some(
@@ -335,7 +505,11 @@ where
                     assumptions.append(&mut not_in_range);
                     assumptions.push(fn_data.selector);
                     let mut opt_selector = None;
-                    if is_credulous_acceptance {
+                    if is_credulous_acceptance && conjunctive {
+                        cc_args
+                            .iter()
+                            .for_each(|a| assumptions.push(self.constraints_encoder.arg_to_lit(a)))
+                    } else if is_credulous_acceptance {
                         let selector = Literal::from(1 + solver.borrow().n_vars() as isize);
                         let clause = cc_args
                             .iter()
@@ -354,10 +528,8 @@ where
                         .borrow_mut()
                         .solve_under_assumptions(&assumptions)
                         .unwrap_model();
-                    if is_credulous_acceptance {
-                        solver
-                            .borrow_mut()
-                            .add_clause(vec![opt_selector.unwrap().negate()]);
+                    if let Some(selector) = opt_selector {
+                        solver.borrow_mut().add_clause(vec![selector.negate()]);
                     }
                     if let Some(model) = result {
                         return (
@@ -398,6 +570,46 @@ where
     }
 }
 
+/// Enumerates the extensions of an AF with a maximal range among the ones described by the constraints encoder.
+///
+/// Each time a maximal range is found, all the extensions sharing this range are enumerated.
+/// The enumeration stops as soon as the callback function returns `false`.
+pub(crate) fn enumerate_maximal_range_extensions<T>(
+    af: &AAFramework<T>,
+    solver_factory: &SatSolverFactoryFn,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) where
+    T: LabelType,
+{
+    let solver = Rc::new(RefCell::new(solver_factory()));
+    constraints_encoder.encode_constraints_and_range(af, solver.borrow_mut().as_mut());
+    let mut computer = new_maximal_extension_computer(af, Rc::clone(&solver), constraints_encoder);
+    loop {
+        computer.compute_next();
+        match computer.state() {
+            MaximalExtensionComputerState::Maximal => {
+                let fn_data = computer.state_data();
+                let (mut assumptions, mut not_in_range) = split_in_range(&fn_data);
+                not_in_range.iter_mut().for_each(|l| *l = l.negate());
+                assumptions.append(&mut not_in_range);
+                assumptions.push(fn_data.selector);
+                if !extension_enumeration::enumerate_extensions_under_assumptions(
+                    af,
+                    solver.borrow_mut().as_mut(),
+                    constraints_encoder,
+                    &assumptions,
+                    callback,
+                ) {
+                    break;
+                }
+            }
+            MaximalExtensionComputerState::None => break,
+            _ => {}
+        }
+    }
+}
+
 fn new_maximal_extension_computer<'a, 'b, T>(
     af: &'a AAFramework<T>,
     solver: Rc<RefCell<Box<dyn SatSolver>>>,
@@ -747,6 +959,40 @@ mod tests {
         assert!(!solver.are_skeptically_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(!solver.are_skeptically_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn [< test_semi_stable_conj_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a3).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a2).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver =
+            SemiStableSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&"a0".to_string(), &"a3".to_string()]);
+        assert!(status);
+        let certificate = certificate.unwrap();
+        assert!(["a0", "a3"].iter().all(|l| certificate.iter().any(|a| a.label() == l)));
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string()]));
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        let (status, certificate) = solver.are_all_skeptically_accepted_with_certificate(&[&"a0".to_string(), &"a2".to_string()]);
+        assert!(!status);
+        assert!(certificate.unwrap().iter().all(|a| a.label() != "a2"));
+    }
     }
     };
     }
@@ -1005,6 +1251,40 @@ mod tests {
         assert!(!solver.are_skeptically_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(!solver.are_skeptically_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn [< test_stage_conj_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a3).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a2).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver =
+            StageSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        let (status, certificate) = solver.are_all_credulously_accepted_with_certificate(&[&"a0".to_string(), &"a3".to_string()]);
+        assert!(status);
+        let certificate = certificate.unwrap();
+        assert!(["a0", "a3"].iter().all(|l| certificate.iter().any(|a| a.label() == l)));
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string()]));
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        let (status, certificate) = solver.are_all_skeptically_accepted_with_certificate(&[&"a0".to_string(), &"a2".to_string()]);
+        assert!(!status);
+        assert!(certificate.unwrap().iter().all(|a| a.label() != "a2"));
+    }
     }
     };
     }
@@ -1014,4 +1294,212 @@ mod tests {
         "auxvar"
     );
     test_for_encoder_stage!(exp_constraints_encoder::new_for_conflict_freeness(), "exp");
+
+    #[test]
+    fn test_semi_stable_enumerate_extensions_same_range() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = SemiStableSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0"], vec!["a1"]], extensions);
+    }
+
+    #[test]
+    fn test_stage_enumerate_extensions_same_range() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StageSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0"], vec!["a1"]], extensions);
+    }
+
+    #[test]
+    fn test_semi_stable_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = SemiStableSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(2_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_stage_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StageSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(2_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_semi_stable_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = SemiStableSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a1".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a0".to_string()]);
+        assert!(!result);
+        assert_eq!(
+            Some(NonExtensionReason::LargerExtension(vec![af
+                .argument_set()
+                .get_argument(&"a1".to_string())
+                .unwrap()])),
+            reason
+        );
+    }
+
+    #[test]
+    fn test_stage_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StageSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a1".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::LargerExtension(_))
+        ));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a0".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::LargerExtension(_))
+        ));
+    }
+
+    #[test]
+    fn test_semi_stable_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = SemiStableSemanticsSolver::new(&af);
+        assert_eq!(
+            (
+                true,
+                Some(vec![af
+                    .argument_set()
+                    .get_argument(&"a1".to_string())
+                    .unwrap()])
+            ),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert!(solver.has_unique_extension());
+        let instance = r#"
+        arg(a0).
+        att(a0,a0).
+        "#;
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = SemiStableSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert!(solver.has_unique_extension());
+    }
+
+    #[test]
+    fn test_stage_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StageSemanticsSolver::new(&af);
+        assert!(solver.has_nonempty_extension());
+        let (result, certificate) = solver.has_unique_extension_with_certificate();
+        assert!(!result);
+        assert_eq!(2, certificate.len());
+    }
 }
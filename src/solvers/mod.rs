@@ -1,12 +1,23 @@
 //! Solvers dedicated to problems related to Abstract Argumentation frameworks.
 
+mod admissible_and_conflict_free_semantics_solvers;
+pub use admissible_and_conflict_free_semantics_solvers::AdmissibleSemanticsSolver;
+pub use admissible_and_conflict_free_semantics_solvers::ConflictFreeSemanticsSolver;
+
 mod complete_semantics_solver;
 pub use complete_semantics_solver::CompleteSemanticsSolver;
 
+mod extension_enumeration;
+
+mod extension_existence;
+
+mod extension_verification;
+
 mod grounded_semantics_solver;
 pub use grounded_semantics_solver::GroundedSemanticsSolver;
 
 mod ideal_semantics_solver;
+pub use ideal_semantics_solver::EagerSemanticsSolver;
 pub use ideal_semantics_solver::IdealSemanticsSolver;
 
 pub(crate) mod maximal_extension_computer;
@@ -15,13 +26,43 @@ mod maximal_range_semantics_solvers;
 pub use maximal_range_semantics_solvers::SemiStableSemanticsSolver;
 pub use maximal_range_semantics_solvers::StageSemanticsSolver;
 
+mod naive_semantics_solver;
+pub use naive_semantics_solver::NaiveSemanticsSolver;
+
 mod preferred_semantics_solver;
 pub use preferred_semantics_solver::PreferredSemanticsSolver;
 
+pub mod reference;
+
+mod resolution_based_grounded_semantics_solver;
+pub use resolution_based_grounded_semantics_solver::ResolutionBasedGroundedSemanticsSolver;
+
+mod scc_recursive_semantics_solvers;
+pub use scc_recursive_semantics_solvers::Cf2SemanticsSolver;
+pub use scc_recursive_semantics_solvers::Stage2SemanticsSolver;
+
 mod specs;
 pub use specs::CredulousAcceptanceComputer;
+pub use specs::ExtensionCounter;
+pub use specs::ExtensionEnumerator;
+pub use specs::NonEmptyExtensionChecker;
+pub use specs::NonExtensionReason;
 pub use specs::SingleExtensionComputer;
+pub use specs::SingleLabellingComputer;
 pub use specs::SkepticalAcceptanceComputer;
+pub use specs::UniqueExtensionChecker;
+pub use specs::VerifyExtension;
 
 mod stable_semantics_solver;
 pub use stable_semantics_solver::StableSemanticsSolver;
+
+mod strong_admissibility;
+
+mod strongly_admissible_semantics_solver;
+pub use strongly_admissible_semantics_solver::StronglyAdmissibleSemanticsSolver;
+
+mod weak_admissibility_semantics_solvers;
+pub use weak_admissibility_semantics_solvers::WeaklyAdmissibleSemanticsSolver;
+pub use weak_admissibility_semantics_solvers::WeaklyCompleteSemanticsSolver;
+pub use weak_admissibility_semantics_solvers::WeaklyGroundedSemanticsSolver;
+pub use weak_admissibility_semantics_solvers::WeaklyPreferredSemanticsSolver;
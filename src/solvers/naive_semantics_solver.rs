@@ -0,0 +1,743 @@
+use super::{
+    extension_enumeration, extension_existence,
+    extension_verification::CandidateExtension,
+    maximal_extension_computer::{self, MaximalExtensionComputerState},
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, SingleExtensionComputer, SingleLabellingComputer,
+    SkepticalAcceptanceComputer, UniqueExtensionChecker, VerifyExtension,
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
+    sat::{self, SatSolverFactoryFn},
+    utils::{ConnectedComponentsComputer, Label, LabelType},
+};
+use num_bigint::BigUint;
+use std::{cell::RefCell, rc::Rc};
+
+/// A SAT-based solver for the naive semantics.
+///
+/// The naive extensions of an Argumentation Framework are its maximal (wrt. set inclusion) conflict-free sets.
+///
+/// An argument belongs to a naive extension if and only if it does not attack itself.
+/// Thus, checking the credulous acceptance is done in polynomial time.
+/// The certificate provided in this case is a naive extension containing one of the arguments under consideration.
+///
+/// Concerning the skeptical acceptance and the extension computation, this solver relies on successive calls to a SAT solver.
+/// The certificate provided in case an argument is not skeptically accepted is a naive extension that does not contain the argument.
+///
+/// Checking whether a set of arguments is a naive extension ([VerifyExtension]) is done in polynomial time.
+/// If the set is conflict-free but not maximal, a naive extension including it is given as the reason.
+///
+/// A non-empty naive extension exists iff an argument does not attack itself, so [NonEmptyExtensionChecker] is polynomial too.
+/// The uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
+pub struct NaiveSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+}
+
+impl<'a, T> NaiveSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new SAT based solver for the naive semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::solvers::{SingleExtensionComputer, NaiveSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = NaiveSemanticsSolver::new(af);
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found a naive extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new SAT based solver for the naive semantics.
+    ///
+    /// The SAT solver to use in given through the solver factory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat::CadicalSolver;
+    /// # use crustabri::solvers::{SingleExtensionComputer, NaiveSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = NaiveSemanticsSolver::new_with_sat_solver_factory(
+    ///         af,
+    ///         Box::new(|| Box::new(CadicalSolver::default())),
+    ///     );
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found a naive extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new_with_sat_solver_factory(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Self {
+        Self {
+            af,
+            solver_factory,
+            constraints_encoder: Box::new(aux_var_constraints_encoder::new_for_conflict_freeness()),
+        }
+    }
+
+    /// Builds a new SAT based solver for the naive semantics.
+    ///
+    /// The SAT solver to use in given through the solver factory.
+    /// The constraints encoder must be an encoder for conflict-freeness.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat;
+    /// # use crustabri::encodings;
+    /// # use crustabri::solvers::{SingleExtensionComputer, NaiveSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = NaiveSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+    ///         af,
+    ///         Box::new(|| sat::default_solver()),
+    ///         encodings::new_default_conflict_freeness_encoder(),
+    ///     );
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found a naive extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new_with_sat_solver_factory_and_constraints_encoder(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+        constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+    ) -> Self
+    where
+        T: LabelType,
+    {
+        Self {
+            af,
+            solver_factory,
+            constraints_encoder,
+        }
+    }
+
+    fn compute_one_extension_for_cc<'b>(&self, cc_af: &'b AAFramework<T>) -> Vec<&'b Argument<T>> {
+        let solver = Rc::new(RefCell::new((self.solver_factory)()));
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.borrow_mut().as_mut());
+        let computer = maximal_extension_computer::new_for_preferred_semantics(
+            cc_af,
+            solver,
+            self.constraints_encoder.as_ref(),
+        );
+        computer.compute_maximal()
+    }
+
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        enumerate_naive_extensions(
+            cc_af,
+            self.solver_factory.as_ref(),
+            self.constraints_encoder.as_ref(),
+            callback,
+        )
+    }
+
+    fn is_skeptically_accepted_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+        args: &[&'a Argument<T>],
+        allow_shortcut: bool,
+    ) -> (bool, Option<Vec<&'b Argument<T>>>) {
+        let cc_args = args
+            .iter()
+            .map(|a| cc_af.argument_set().get_argument(a.label()).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let solver = Rc::new(RefCell::new((self.solver_factory)()));
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.borrow_mut().as_mut());
+        let mut computer = maximal_extension_computer::new_for_preferred_semantics(
+            cc_af,
+            solver,
+            self.constraints_encoder.as_ref(),
+        );
+        loop {
+            computer.compute_next();
+            match computer.state() {
+                MaximalExtensionComputerState::Maximal
+                    if !cc_args
+                        .iter()
+                        .any(|cc_arg| computer.current().contains(cc_arg)) =>
+                {
+                    return (false, Some(computer.take_current()));
+                }
+                MaximalExtensionComputerState::Intermediate => {
+                    let current = computer.current();
+                    if cc_args.iter().any(|cc_arg| current.contains(cc_arg)) {
+                        computer.discard_current_search();
+                    } else if allow_shortcut
+                        && cc_args
+                            .iter()
+                            .all(|cc_arg| is_in_conflict_with(cc_af, cc_arg, current))
+                    {
+                        return (false, Some(computer.take_current()));
+                    }
+                }
+                MaximalExtensionComputerState::None => return (true, None),
+                _ => {}
+            }
+        }
+    }
+}
+
+pub(crate) fn is_self_attacking<T>(af: &AAFramework<T>, arg: &Argument<T>) -> bool
+where
+    T: LabelType,
+{
+    af.iter_attacks_from(arg)
+        .any(|att| att.attacked().id() == arg.id())
+}
+
+fn is_in_conflict_with<T>(af: &AAFramework<T>, arg: &Argument<T>, set: &[&Argument<T>]) -> bool
+where
+    T: LabelType,
+{
+    af.iter_attacks_to(arg)
+        .any(|att| set.contains(&att.attacker()))
+        || af
+            .iter_attacks_from(arg)
+            .any(|att| set.contains(&att.attacked()))
+}
+
+/// Enumerates the naive extensions of an AF.
+///
+/// The constraints encoder must be an encoder for conflict-freeness.
+/// The enumeration stops as soon as the callback function returns `false`.
+pub(crate) fn enumerate_naive_extensions<T>(
+    af: &AAFramework<T>,
+    solver_factory: &SatSolverFactoryFn,
+    constraints_encoder: &dyn ConstraintsEncoder<T>,
+    callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+) where
+    T: LabelType,
+{
+    let solver = Rc::new(RefCell::new(solver_factory()));
+    constraints_encoder.encode_constraints(af, solver.borrow_mut().as_mut());
+    let mut computer =
+        maximal_extension_computer::new_for_preferred_semantics(af, solver, constraints_encoder);
+    loop {
+        computer.compute_next();
+        match computer.state() {
+            MaximalExtensionComputerState::Maximal if !callback(computer.current()) => break,
+            MaximalExtensionComputerState::None => break,
+            _ => {}
+        }
+    }
+}
+
+// Greedily extends a conflict-free set into a naive extension.
+fn extend_to_naive<'a, T>(
+    af: &'a AAFramework<T>,
+    init: Vec<&'a Argument<T>>,
+) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    let mut blocked = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+    let mut ext = Vec::new();
+    init.into_iter()
+        .chain(af.argument_set().iter())
+        .for_each(|arg| {
+            if blocked[arg.id()] || is_self_attacking(af, arg) {
+                return;
+            }
+            blocked[arg.id()] = true;
+            af.iter_attacks_from(arg)
+                .for_each(|att| blocked[att.attacked().id()] = true);
+            af.iter_attacks_to(arg)
+                .for_each(|att| blocked[att.attacker().id()] = true);
+            ext.push(arg);
+        });
+    ext
+}
+
+impl<T> SingleExtensionComputer<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        let mut merged = Vec::new();
+        for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
+            for cc_arg in self.compute_one_extension_for_cc(&cc_af) {
+                merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+            }
+        }
+        Some(merged)
+    }
+}
+
+impl<T> SingleLabellingComputer<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+}
+
+impl<T> ExtensionCounter<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        match self
+            .af
+            .argument_set()
+            .iter()
+            .find(|a| !is_self_attacking(self.af, a))
+        {
+            Some(a) => (true, Some(extend_to_naive(self.af, vec![a]))),
+            None => (false, None),
+        }
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> VerifyExtension<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let candidate = CandidateExtension::new(self.af, args);
+        let reason = candidate.check_conflict_freeness().or_else(|| {
+            self.af
+                .argument_set()
+                .iter()
+                .find(|arg| {
+                    !candidate.args().contains(arg)
+                        && !is_self_attacking(self.af, arg)
+                        && !is_in_conflict_with(self.af, arg, candidate.args())
+                })
+                .map(|arg| {
+                    let mut init = candidate.args().to_vec();
+                    init.push(arg);
+                    NonExtensionReason::LargerExtension(extend_to_naive(self.af, init))
+                })
+        });
+        (reason.is_none(), reason)
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        args.iter()
+            .any(|a| !is_self_attacking(self.af, self.af.argument_set().get_argument(a).unwrap()))
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        match args.iter().find(|a| !is_self_attacking(self.af, a)) {
+            Some(a) => (true, Some(extend_to_naive(self.af, vec![a]))),
+            None => (false, None),
+        }
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        if args.iter().enumerate().any(|(i, a)| {
+            is_self_attacking(self.af, a) || is_in_conflict_with(self.af, a, &args[i + 1..])
+        }) {
+            (false, None)
+        } else {
+            (true, Some(extend_to_naive(self.af, args)))
+        }
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for NaiveSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+        let cc_af = cc_computer.merged_connected_components_of(&args);
+        self.is_skeptically_accepted_in_cc(&cc_af, &args, true).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let mut cc_computer = ConnectedComponentsComputer::new(self.af);
+        let cc_af = cc_computer.merged_connected_components_of(&args);
+        let mut merged = Vec::new();
+        match self.is_skeptically_accepted_in_cc(&cc_af, &args, false) {
+            (true, None) => return (true, None),
+            (false, Some(cc_ext)) => {
+                cc_ext
+                    .iter()
+                    .map(|a| self.af.argument_set().get_argument(a.label()).unwrap())
+                    .for_each(|a| merged.push(a));
+            }
+            _ => unreachable!(),
+        }
+        while let Some(other_cc_af) = cc_computer.next_connected_component() {
+            for cc_arg in self.compute_one_extension_for_cc(&other_cc_af) {
+                merged.push(self.af.argument_set().get_argument(cc_arg.label()).unwrap())
+            }
+        }
+        (false, Some(merged))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encodings::exp_constraints_encoder,
+        io::{AspartixReader, InstanceReader},
+    };
+
+    macro_rules! test_for_encoder {
+        ($encoder:expr, $suffix:literal) => {
+            paste::item! {
+    #[test]
+    fn [< test_compute_one_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        let ext = solver
+            .compute_one_extension()
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        assert!(vec!["a0"] == ext || vec!["a1"] == ext);
+    }
+
+    #[test]
+    fn [< test_compute_one_after_arg_removal_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        "#;
+        let reader = AspartixReader::default();
+        let mut af = reader.read(&mut instance.as_bytes()).unwrap();
+        af.remove_argument(&"a0".to_string()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        let ext = solver.compute_one_extension().unwrap();
+        assert_eq!(1, ext.len());
+        assert_eq!("a1", ext[0].label());
+    }
+
+    #[test]
+    fn [< test_skeptical_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a2).
+        att(a3,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(&af, Box::new(|| sat::default_solver()), Box::new($encoder));
+        assert!(!solver.is_skeptically_accepted(&"a0".to_string()));
+        assert!(!solver.is_skeptically_accepted(&"a1".to_string()));
+        assert!(!solver.is_skeptically_accepted(&"a2".to_string()));
+        assert!(solver.is_skeptically_accepted(&"a3".to_string()));
+        assert!(solver.are_skeptically_accepted(&[&"a0".to_string(), &"a1".to_string()]));
+        let mut cert = solver
+            .is_skeptically_accepted_with_certificate(&"a0".to_string())
+            .1
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        cert.sort_unstable();
+        assert_eq!(vec!["a1", "a3"], cert);
+        assert_eq!(
+            (true, None),
+            solver.is_skeptically_accepted_with_certificate(&"a3".to_string())
+        );
+    }
+            }
+        };
+    }
+
+    test_for_encoder!(
+        aux_var_constraints_encoder::new_for_conflict_freeness(),
+        "auxvar"
+    );
+    test_for_encoder!(exp_constraints_encoder::new_for_conflict_freeness(), "exp");
+
+    #[test]
+    fn test_credulous_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert!(solver.is_credulously_accepted(&"a0".to_string()));
+        assert!(solver.is_credulously_accepted(&"a1".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a2".to_string()));
+        assert_eq!(
+            &["a1"],
+            solver
+                .is_credulously_accepted_with_certificate(&"a1".to_string())
+                .1
+                .unwrap()
+                .iter()
+                .map(|a| a.label())
+                .cloned()
+                .collect::<Vec<String>>()
+                .as_slice()
+        );
+        assert_eq!(
+            (false, None),
+            solver.is_credulously_accepted_with_certificate(&"a2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_conj_credulous_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a1".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a1".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a3".to_string()]));
+        let mut cert = solver
+            .are_all_credulously_accepted_with_certificate(&[&"a2".to_string(), &"a0".to_string()])
+            .1
+            .unwrap()
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        cert.sort_unstable();
+        assert_eq!(vec!["a0", "a2"], cert);
+    }
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0", "a2", "a3"], vec!["a1", "a3"]], extensions);
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(2_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(solver.verify_extension(&[&"a1".to_string()]));
+        let (result, reason) =
+            solver.verify_extension_with_reason(&[&"a0".to_string(), &"a1".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::ConflictingAttack(_, _))
+        ));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a2".to_string()]);
+        assert!(!result);
+        let mut larger = reason
+            .unwrap()
+            .involved_arguments()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        larger.sort_unstable();
+        assert_eq!(vec!["a0", "a2"], larger);
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert!(solver.has_nonempty_extension());
+        let (result, certificate) = solver.has_unique_extension_with_certificate();
+        assert!(!result);
+        assert_eq!(2, certificate.len());
+        assert_ne!(certificate[0], certificate[1]);
+        let instance = r#"
+        arg(a0).
+        att(a0,a0).
+        "#;
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = NaiveSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (true, vec![vec![]]),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
+}
@@ -1,13 +1,18 @@
 use super::{
+    extension_enumeration, extension_existence,
+    extension_verification::CandidateExtension,
     maximal_extension_computer::{self, MaximalExtensionComputerState},
-    SingleExtensionComputer, SkepticalAcceptanceComputer,
+    ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker, NonExtensionReason,
+    SingleExtensionComputer, SingleLabellingComputer, SkepticalAcceptanceComputer,
+    UniqueExtensionChecker, VerifyExtension,
 };
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
     encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
     sat::{self, SatSolver, SatSolverFactoryFn},
     utils::{ConnectedComponentsComputer, Label, LabelType},
 };
+use num_bigint::BigUint;
 use std::{cell::RefCell, rc::Rc};
 
 /// A SAT-based solver for the preferred semantics.
@@ -18,6 +23,14 @@ use std::{cell::RefCell, rc::Rc};
 /// Concerning the skeptical acceptance and the extension computation, this solver relies on successive calls to a SAT solver making the computation reach the second level of the polynomial hierarchy.
 ///
 /// The certificate provided in case an argument is not skeptically accepted is a preferred extension that does not the argument.
+///
+/// Checking whether a set of arguments is a preferred extension ([VerifyExtension]) requires the set to be admissible
+/// and a single SAT call per connected component to ensure no strictly larger admissible set exists.
+/// If such a set exists, it is given as the reason.
+///
+/// The existence of a non-empty extension ([NonEmptyExtensionChecker]) is checked by a single search for a maximal extension per connected component,
+/// in which the empty set is forbidden.
+/// The uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
 pub struct PreferredSemanticsSolver<'a, T>
 where
     T: LabelType,
@@ -143,13 +156,12 @@ where
         loop {
             computer.compute_next();
             match computer.state() {
-                MaximalExtensionComputerState::Maximal => {
+                MaximalExtensionComputerState::Maximal
                     if !cc_args
                         .iter()
-                        .any(|cc_arg| computer.current().contains(cc_arg))
-                    {
-                        return (false, Some(computer.take_current()));
-                    }
+                        .any(|cc_arg| computer.current().contains(cc_arg)) =>
+                {
+                    return (false, Some(computer.take_current()));
                 }
                 MaximalExtensionComputerState::Intermediate => {
                     let current = computer.current();
@@ -174,6 +186,42 @@ where
         }
     }
 
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        PreferredSemanticsSolver::enumerate_extensions(
+            cc_af,
+            Rc::new(RefCell::new((self.solver_factory)())),
+            self.constraints_encoder.as_ref(),
+            callback,
+        )
+    }
+
+    // Since any set including a non-empty set is non-empty, the maximal extensions among the non-empty ones are preferred extensions.
+    // The search of a maximal extension starts from the grounded extension, so an empty extension is returned iff it is the only one.
+    fn compute_nonempty_extension_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let solver = Rc::new(RefCell::new((self.solver_factory)()));
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.borrow_mut().as_mut());
+        solver
+            .borrow_mut()
+            .add_clause(extension_existence::nonempty_extension_clause(
+                cc_af,
+                self.constraints_encoder.as_ref(),
+            ));
+        let computer = maximal_extension_computer::new_for_preferred_semantics(
+            cc_af,
+            solver,
+            self.constraints_encoder.as_ref(),
+        );
+        Some(computer.compute_maximal())
+    }
+
     pub(crate) fn enumerate_extensions(
         af: &AAFramework<T>,
         solver: Rc<RefCell<Box<dyn SatSolver>>>,
@@ -189,11 +237,7 @@ where
         loop {
             computer.compute_next();
             match computer.state() {
-                MaximalExtensionComputerState::Maximal => {
-                    if !callback(computer.current()) {
-                        break;
-                    }
-                }
+                MaximalExtensionComputerState::Maximal if !callback(computer.current()) => break,
                 MaximalExtensionComputerState::None => break,
                 _ => {}
             }
@@ -225,6 +269,88 @@ where
     }
 }
 
+impl<T> SingleLabellingComputer<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+}
+
+impl<T> ExtensionCounter<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        extension_existence::check_nonempty_extension_by_connected_components(
+            self.af,
+            &mut |cc_af| self.compute_nonempty_extension_in_cc(cc_af),
+        )
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> VerifyExtension<T> for PreferredSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let candidate = CandidateExtension::new(self.af, args);
+        let reason = candidate.check_admissibility().or_else(|| {
+            candidate
+                .find_larger_extension(
+                    self.solver_factory.as_ref(),
+                    self.constraints_encoder.as_ref(),
+                    false,
+                )
+                .map(NonExtensionReason::LargerExtension)
+        });
+        (reason.is_none(), reason)
+    }
+}
+
 impl<T> SkepticalAcceptanceComputer<T> for PreferredSemanticsSolver<'_, T>
 where
     T: LabelType,
@@ -667,6 +793,123 @@ mod tests {
         assert!(!solver.are_skeptically_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(!solver.are_skeptically_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn [< test_conj_skeptical_acceptance_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a3).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a2).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new(&af);
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string()]));
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        let (status, certificate) = solver.are_all_skeptically_accepted_with_certificate(&[&"a0".to_string(), &"a2".to_string()]);
+        assert!(!status);
+        assert!(certificate.unwrap().iter().all(|a| a.label() != "a2"));
+    }
+
+    #[test]
+    fn [< test_verify_extension_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+            &af,
+            Box::new(|| sat::default_solver()),
+            Box::new($encoder),
+        );
+        assert!(solver.verify_extension(&[&"a1".to_string(), &"a3".to_string()]));
+        assert!(solver.verify_extension(&[&"a0".to_string(), &"a2".to_string(), &"a3".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a2".to_string()]);
+        assert!(!result);
+        assert!(matches!(reason, Some(NonExtensionReason::UndefendedArgument(_, _))));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a0".to_string()]);
+        assert!(!result);
+        let mut larger = reason
+            .unwrap()
+            .involved_arguments()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        larger.sort_unstable();
+        assert_eq!(vec!["a0", "a2"], larger);
+    }
+
+    #[test]
+    fn [< test_nonempty_and_unique_extension_ $suffix >] () {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+            &af,
+            Box::new(|| sat::default_solver()),
+            Box::new($encoder),
+        );
+        let (result, certificate) = solver.has_nonempty_extension_with_certificate();
+        assert!(result);
+        assert_eq!(1, certificate.unwrap().len());
+        let (result, certificate) = solver.has_unique_extension_with_certificate();
+        assert!(!result);
+        assert_eq!(2, certificate.len());
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a1).
+        att(a1,a0).
+        "#;
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+            &af,
+            Box::new(|| sat::default_solver()),
+            Box::new($encoder),
+        );
+        assert!(solver.has_nonempty_extension());
+        assert!(solver.has_unique_extension());
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a1).
+        att(a0,a0).
+        "#;
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+            &af,
+            Box::new(|| sat::default_solver()),
+            Box::new($encoder),
+        );
+        assert_eq!((false, None), solver.has_nonempty_extension_with_certificate());
+        assert!(solver.has_unique_extension());
+    }
     }
     };
     }
@@ -677,4 +920,49 @@ mod tests {
     );
     test_for_encoder!(exp_constraints_encoder::new_for_complete_semantics(), "exp");
     test_for_encoder!(HybridCompleteConstraintsEncoder::default(), "hybrid");
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0", "a2", "a3"], vec!["a1", "a3"]], extensions);
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = PreferredSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(2_usize), solver.count_extensions());
+    }
 }
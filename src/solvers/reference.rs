@@ -0,0 +1,643 @@
+//! A brute-force solver for all the semantics, intended to be used as a reference when testing the SAT-based solvers.
+//!
+//! The [ReferenceSolver] computes the extensions by an exhaustive search among the sets of arguments, following the definitions of the semantics.
+//! Its computation time grows exponentially with the number of arguments, and it is thus intended for AFs of up to about 20 arguments.
+
+use super::{
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, SingleExtensionComputer,
+    SkepticalAcceptanceComputer,
+};
+use crate::{
+    aa::{AAFramework, Argument, Semantics},
+    utils::LabelType,
+};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+
+/// The maximal number of arguments an AF may have to be handled by a [ReferenceSolver].
+///
+/// Note that the solver is intended for far smaller AFs.
+pub const MAX_ARGUMENTS: usize = 64;
+
+/// A brute-force solver handling all the semantics.
+///
+/// The extensions are computed from the definitions of the semantics, by exhaustive enumerations of the sets of arguments.
+/// They are computed once for all, the first time they are needed.
+/// The solver is intended to be used as a reference for small AFs (up to about 20 arguments);
+/// AFs with resolutions involving a lot of mutual attacks are also expensive for the resolution-based grounded semantics.
+///
+/// The extensions are given in an arbitrary order, but this order does not change between calls.
+/// The certificates for credulous acceptance queries are extensions containing the arguments,
+/// while the certificates for skeptical acceptance queries are extensions that do not contain them.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet, Semantics};
+/// # use crustabri::solvers::reference::ReferenceSolver;
+/// # use crustabri::solvers::{ExtensionCounter, SkepticalAcceptanceComputer};
+/// let arg_labels = vec!["a", "b", "c"];
+/// let args = ArgumentSet::new_with_labels(&arg_labels);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"b", &"a").unwrap();
+/// af.new_attack(&"b", &"c").unwrap();
+/// let mut solver = ReferenceSolver::new(&af, Semantics::PR);
+/// assert_eq!(2_usize, solver.count_extensions().try_into().unwrap());
+/// assert!(!solver.is_skeptically_accepted(&"c"));
+/// ```
+pub struct ReferenceSolver<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    semantics: Semantics,
+    args: Vec<&'a Argument<T>>,
+    index_of: Vec<usize>,
+    extensions: Option<Vec<u64>>,
+}
+
+impl<'a, T> ReferenceSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new reference solver for the given semantics.
+    ///
+    /// # Panics
+    ///
+    /// The AF must not have more than [MAX_ARGUMENTS] arguments.
+    pub fn new(af: &'a AAFramework<T>, semantics: Semantics) -> Self {
+        assert!(
+            af.n_arguments() <= MAX_ARGUMENTS,
+            "the reference solver cannot handle more than {} arguments",
+            MAX_ARGUMENTS
+        );
+        let args = af.argument_set().iter().collect::<Vec<&Argument<T>>>();
+        let mut index_of = vec![usize::MAX; af.max_argument_id().map(|id| id + 1).unwrap_or(0)];
+        args.iter()
+            .enumerate()
+            .for_each(|(i, a)| index_of[a.id()] = i);
+        Self {
+            af,
+            semantics,
+            args,
+            index_of,
+            extensions: None,
+        }
+    }
+
+    fn extension_sets(&mut self) -> &[u64] {
+        if self.extensions.is_none() {
+            let mut attackers = vec![0; self.args.len()];
+            for arg in self.args.iter() {
+                for att in self.af.iter_attacks_to(arg) {
+                    attackers[self.index_of[arg.id()]] |= 1 << self.index_of[att.attacker().id()];
+                }
+            }
+            let mut bit_framework = BitFramework {
+                attackers,
+                weakly_admissible_unions: HashMap::new(),
+            };
+            self.extensions = Some(bit_framework.extensions(self.semantics));
+        }
+        self.extensions.as_ref().unwrap()
+    }
+
+    fn set_to_args(&self, set: u64) -> Vec<&'a Argument<T>> {
+        iter_bits(set).map(|i| self.args[i]).collect()
+    }
+
+    fn labels_to_set(&self, labels: &[&T]) -> u64 {
+        labels
+            .iter()
+            .map(|l| self.af.argument_set().get_argument(l).unwrap())
+            .fold(0, |acc, a| acc | 1 << self.index_of[a.id()])
+    }
+
+    fn find_extension<F>(&mut self, predicate: F) -> Option<Vec<&'a Argument<T>>>
+    where
+        F: Fn(u64) -> bool,
+    {
+        self.extension_sets()
+            .iter()
+            .find(|e| predicate(**e))
+            .copied()
+            .map(|e| self.set_to_args(e))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for ReferenceSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        let extensions = self.extension_sets().to_vec();
+        for ext in extensions {
+            if !callback(&self.set_to_args(ext)) {
+                break;
+            }
+        }
+    }
+}
+
+impl<T> ExtensionCounter<T> for ReferenceSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        BigUint::from(self.extension_sets().len())
+    }
+}
+
+impl<T> SingleExtensionComputer<T> for ReferenceSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        self.find_extension(|_| true)
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for ReferenceSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_credulously_accepted_with_certificate(args).0
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let set = self.labels_to_set(args);
+        let certificate = self.find_extension(|e| e & set != 0);
+        (certificate.is_some(), certificate)
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let set = self.labels_to_set(args);
+        let certificate = self.find_extension(|e| e & set == set);
+        (certificate.is_some(), certificate)
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for ReferenceSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_skeptically_accepted_with_certificate(args).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let set = self.labels_to_set(args);
+        let certificate = self.find_extension(|e| e & set == 0);
+        (certificate.is_none(), certificate)
+    }
+}
+
+// An AF in which the sets of arguments are represented by bitsets.
+//
+// The computations are made relatively to a universe, that is a set of arguments the AF is restricted to.
+struct BitFramework {
+    attackers: Vec<u64>,
+    weakly_admissible_unions: HashMap<u64, u64>,
+}
+
+impl BitFramework {
+    fn extensions(&mut self, semantics: Semantics) -> Vec<u64> {
+        let all = iter_bits(u64::MAX)
+            .take(self.attackers.len())
+            .fold(0, |acc, i| acc | 1 << i);
+        match semantics {
+            Semantics::GR => vec![grounded(&self.attackers, all)],
+            Semantics::CO => self.complete_sets(all),
+            Semantics::PR => maximal_sets(self.admissible_sets(all)),
+            Semantics::ST => self.stable_sets(all),
+            Semantics::SST => self.semi_stable_sets(all),
+            Semantics::STG => self.stage_sets(all),
+            Semantics::ID => {
+                let preferred = maximal_sets(self.admissible_sets(all));
+                vec![self.ideal_set(&preferred, all)]
+            }
+            Semantics::EG => {
+                let semi_stable = self.semi_stable_sets(all);
+                vec![self.ideal_set(&semi_stable, all)]
+            }
+            Semantics::AD => self.admissible_sets(all),
+            Semantics::CF => self.conflict_free_sets(all),
+            Semantics::NA => self.naive_sets(all),
+            Semantics::SA => self.strongly_admissible_sets(all),
+            Semantics::CF2 => self.scc_recursive_sets(all, Self::naive_sets),
+            Semantics::STG2 => self.scc_recursive_sets(all, Self::stage_sets),
+            Semantics::WAD => self.weakly_admissible_sets(all),
+            Semantics::WPR => maximal_sets(self.weakly_admissible_sets(all)),
+            Semantics::WCO => self.weakly_complete_sets(all),
+            Semantics::WGR => minimal_sets(self.weakly_complete_sets(all)),
+            Semantics::RGR => self.resolution_based_grounded_sets(all),
+        }
+    }
+
+    fn is_conflict_free(&self, set: u64) -> bool {
+        attacked_by(&self.attackers, set, set) == 0
+    }
+
+    fn range(&self, set: u64, universe: u64) -> u64 {
+        set | attacked_by(&self.attackers, set, universe)
+    }
+
+    fn conflict_free_sets(&self, universe: u64) -> Vec<u64> {
+        iter_subsets(universe)
+            .filter(|s| self.is_conflict_free(*s))
+            .collect()
+    }
+
+    fn naive_sets(&mut self, universe: u64) -> Vec<u64> {
+        maximal_sets(self.conflict_free_sets(universe))
+    }
+
+    fn stage_sets(&mut self, universe: u64) -> Vec<u64> {
+        let conflict_free = self.conflict_free_sets(universe);
+        self.maximal_range_sets(conflict_free, universe)
+    }
+
+    fn admissible_sets(&self, universe: u64) -> Vec<u64> {
+        iter_subsets(universe)
+            .filter(|s| {
+                self.is_conflict_free(*s) && *s & !defended_by(&self.attackers, *s, universe) == 0
+            })
+            .collect()
+    }
+
+    fn complete_sets(&self, universe: u64) -> Vec<u64> {
+        iter_subsets(universe)
+            .filter(|s| {
+                self.is_conflict_free(*s) && defended_by(&self.attackers, *s, universe) == *s
+            })
+            .collect()
+    }
+
+    fn stable_sets(&self, universe: u64) -> Vec<u64> {
+        iter_subsets(universe)
+            .filter(|s| self.is_conflict_free(*s) && self.range(*s, universe) == universe)
+            .collect()
+    }
+
+    fn semi_stable_sets(&self, universe: u64) -> Vec<u64> {
+        self.maximal_range_sets(self.complete_sets(universe), universe)
+    }
+
+    fn maximal_range_sets(&self, sets: Vec<u64>, universe: u64) -> Vec<u64> {
+        let ranges = sets
+            .iter()
+            .map(|s| self.range(*s, universe))
+            .collect::<Vec<u64>>();
+        sets.iter()
+            .zip(ranges.iter())
+            .filter(|(_, r)| !ranges.iter().any(|o| is_strict_subset(**r, *o)))
+            .map(|(s, _)| *s)
+            .collect()
+    }
+
+    // The union of the admissible sets included in all the provided sets.
+    fn ideal_set(&self, sets: &[u64], universe: u64) -> u64 {
+        let intersection = sets.iter().fold(universe, |acc, s| acc & s);
+        self.admissible_sets(universe)
+            .into_iter()
+            .filter(|s| s & !intersection == 0)
+            .fold(0, |acc, s| acc | s)
+    }
+
+    // A set is strongly admissible iff iterating the characteristic function restricted to the set from the empty set leads to it.
+    fn strongly_admissible_sets(&self, universe: u64) -> Vec<u64> {
+        iter_subsets(universe)
+            .filter(|s| {
+                let mut current = 0;
+                loop {
+                    let next = *s & defended_by(&self.attackers, current, universe);
+                    if next == current {
+                        return current == *s;
+                    }
+                    current = next;
+                }
+            })
+            .collect()
+    }
+
+    // The SCC-recursive scheme: on a single SCC, the base function is applied;
+    // otherwise, the extensions are built SCC by SCC, discarding the arguments attacked by the current extension.
+    fn scc_recursive_sets(
+        &mut self,
+        universe: u64,
+        base_fn: fn(&mut Self, u64) -> Vec<u64>,
+    ) -> Vec<u64> {
+        let sccs = self.strongly_connected_components(universe);
+        if sccs.len() <= 1 {
+            return base_fn(self, universe);
+        }
+        let mut results = vec![0];
+        for scc in sccs {
+            let mut next_results = vec![];
+            for current in results {
+                let undefeated = scc & !attacked_by(&self.attackers, current, scc);
+                for local in self.scc_recursive_sets(undefeated, base_fn) {
+                    next_results.push(current | local);
+                }
+            }
+            results = next_results;
+        }
+        results
+    }
+
+    // Returns the SCCs of the universe in a topological order.
+    fn strongly_connected_components(&self, universe: u64) -> Vec<u64> {
+        let mut reachable = vec![0; self.attackers.len()];
+        for i in iter_bits(universe) {
+            let mut current = 1 << i;
+            loop {
+                let next = current
+                    | iter_bits(universe)
+                        .filter(|j| self.attackers[*j] & current != 0)
+                        .fold(0, |acc, j| acc | 1 << j);
+                if next == current {
+                    break;
+                }
+                current = next;
+            }
+            reachable[i] = current;
+        }
+        let mut sccs = vec![];
+        let mut assigned = 0;
+        for i in iter_bits(universe) {
+            if assigned & (1 << i) != 0 {
+                continue;
+            }
+            let scc = iter_bits(reachable[i])
+                .filter(|j| reachable[*j] & (1 << i) != 0)
+                .fold(0, |acc, j| acc | 1 << j);
+            assigned |= scc;
+            sccs.push(scc);
+        }
+        let n_ancestors = |scc: u64| {
+            iter_bits(universe)
+                .filter(|k| reachable[*k] & scc != 0)
+                .count()
+        };
+        sccs.sort_by_key(|scc| n_ancestors(*scc));
+        sccs
+    }
+
+    fn weakly_admissible_sets(&mut self, universe: u64) -> Vec<u64> {
+        self.conflict_free_sets(universe)
+            .into_iter()
+            .filter(|s| self.is_weakly_admissible(*s, universe))
+            .collect()
+    }
+
+    fn is_weakly_admissible(&mut self, set: u64, universe: u64) -> bool {
+        if set == 0 {
+            return true;
+        }
+        let reduct = universe & !self.range(set, universe);
+        self.attackers_of(set, universe) & self.weakly_admissible_union(reduct) == 0
+    }
+
+    fn weakly_admissible_union(&mut self, universe: u64) -> u64 {
+        if let Some(union) = self.weakly_admissible_unions.get(&universe) {
+            return *union;
+        }
+        let union = self
+            .weakly_admissible_sets(universe)
+            .into_iter()
+            .fold(0, |acc, s| acc | s);
+        self.weakly_admissible_unions.insert(universe, union);
+        union
+    }
+
+    // A weakly admissible set E is weakly complete iff no strict superset X of E is weakly defended by E,
+    // that is each attacker of X is attacked by E, or is neither in E nor in a weakly admissible set of the reduct wrt. E,
+    // in which case X must be included in a weakly admissible set.
+    fn weakly_complete_sets(&mut self, universe: u64) -> Vec<u64> {
+        let weakly_admissible = self.weakly_admissible_sets(universe);
+        let mut result = vec![];
+        for set in weakly_admissible.iter() {
+            let attacked = attacked_by(&self.attackers, *set, universe);
+            let reduct = universe & !set & !attacked;
+            let weakly_defeated =
+                attacked | (universe & !set & !self.weakly_admissible_union(reduct));
+            let is_weakly_defended = |superset: u64| {
+                let attackers = self.attackers_of(superset, universe);
+                attackers & !weakly_defeated == 0
+                    && (attackers & !attacked == 0
+                        || weakly_admissible.iter().any(|w| superset & !w == 0))
+            };
+            if !iter_subsets(universe & !set)
+                .filter(|d| *d != 0)
+                .any(|d| is_weakly_defended(set | d))
+            {
+                result.push(*set);
+            }
+        }
+        result
+    }
+
+    fn resolution_based_grounded_sets(&self, universe: u64) -> Vec<u64> {
+        let mutual_attacks = iter_bits(universe)
+            .flat_map(|i| {
+                iter_bits(self.attackers[i] & universe)
+                    .filter(move |j| *j > i)
+                    .map(move |j| (i, j))
+            })
+            .filter(|(i, j)| self.attackers[*j] & (1 << i) != 0)
+            .collect::<Vec<(usize, usize)>>();
+        let mut grounded_extensions = vec![];
+        for resolution in 0..(1_u64 << mutual_attacks.len()) {
+            let mut attackers = self.attackers.clone();
+            for (k, (i, j)) in mutual_attacks.iter().enumerate() {
+                if resolution & (1 << k) == 0 {
+                    attackers[*i] &= !(1 << j);
+                } else {
+                    attackers[*j] &= !(1 << i);
+                }
+            }
+            let ext = grounded(&attackers, universe);
+            if !grounded_extensions.contains(&ext) {
+                grounded_extensions.push(ext);
+            }
+        }
+        minimal_sets(grounded_extensions)
+    }
+
+    fn attackers_of(&self, set: u64, universe: u64) -> u64 {
+        iter_bits(set).fold(0, |acc, i| acc | self.attackers[i]) & universe
+    }
+}
+
+fn iter_bits(set: u64) -> impl Iterator<Item = usize> {
+    (0..64).filter(move |i| set & (1 << i) != 0)
+}
+
+fn iter_subsets(universe: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(universe), move |s| {
+        if *s == 0 {
+            None
+        } else {
+            Some((s - 1) & universe)
+        }
+    })
+}
+
+fn attacked_by(attackers: &[u64], set: u64, universe: u64) -> u64 {
+    iter_bits(universe)
+        .filter(|i| attackers[*i] & set != 0)
+        .fold(0, |acc, i| acc | 1 << i)
+}
+
+fn defended_by(attackers: &[u64], set: u64, universe: u64) -> u64 {
+    let attacked = attacked_by(attackers, set, universe);
+    iter_bits(universe)
+        .filter(|i| attackers[*i] & universe & !attacked == 0)
+        .fold(0, |acc, i| acc | 1 << i)
+}
+
+fn grounded(attackers: &[u64], universe: u64) -> u64 {
+    let mut current = 0;
+    loop {
+        let next = defended_by(attackers, current, universe);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+fn is_strict_subset(set0: u64, set1: u64) -> bool {
+    set0 != set1 && set0 & !set1 == 0
+}
+
+fn maximal_sets(sets: Vec<u64>) -> Vec<u64> {
+    sets.iter()
+        .filter(|s| !sets.iter().any(|o| is_strict_subset(**s, *o)))
+        .copied()
+        .collect()
+}
+
+fn minimal_sets(sets: Vec<u64>) -> Vec<u64> {
+    sets.iter()
+        .filter(|s| !sets.iter().any(|o| is_strict_subset(*o, **s)))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn labels_of_extensions(af: &AAFramework<String>, semantics: Semantics) -> Vec<Vec<String>> {
+        let mut solver = ReferenceSolver::new(af, semantics);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().clone())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        extensions
+    }
+
+    #[test]
+    fn test_classical_semantics() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a3).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![vec![] as Vec<String>],
+            labels_of_extensions(&af, Semantics::GR)
+        );
+        assert_eq!(
+            vec![vec![], vec!["a0", "a2"], vec!["a1"]],
+            labels_of_extensions(&af, Semantics::CO)
+        );
+        assert_eq!(
+            vec![vec!["a0", "a2"], vec!["a1"]],
+            labels_of_extensions(&af, Semantics::PR)
+        );
+        assert_eq!(
+            vec![vec!["a0", "a2"]],
+            labels_of_extensions(&af, Semantics::ST)
+        );
+        assert_eq!(
+            vec![vec![] as Vec<String>],
+            labels_of_extensions(&af, Semantics::ID)
+        );
+    }
+
+    #[test]
+    fn test_scc_recursive_semantics() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![vec!["a0", "a3"], vec!["a1", "a3"], vec!["a2"]],
+            labels_of_extensions(&af, Semantics::CF2)
+        );
+        assert_eq!(
+            vec![vec![] as Vec<String>],
+            labels_of_extensions(&af, Semantics::PR)
+        );
+    }
+
+    #[test]
+    fn test_weak_and_resolution_based_semantics() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![vec!["a0"], vec!["a1"]],
+            labels_of_extensions(&af, Semantics::WPR)
+        );
+        assert_eq!(
+            vec![vec!["a0"], vec!["a1"]],
+            labels_of_extensions(&af, Semantics::RGR)
+        );
+    }
+}
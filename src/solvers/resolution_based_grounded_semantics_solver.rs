@@ -0,0 +1,616 @@
+use super::{
+    extension_verification::CandidateExtension,
+    specs::{
+        CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator,
+        NonEmptyExtensionChecker, NonExtensionReason, SingleExtensionComputer,
+        SingleLabellingComputer, SkepticalAcceptanceComputer, UniqueExtensionChecker,
+        VerifyExtension,
+    },
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    sat::{self, Assignment, Literal, SatSolver, SatSolverFactoryFn},
+    utils::{self, LabelType},
+};
+use num_bigint::BigUint;
+
+/// A SAT-based solver for the resolution-based grounded semantics (GR*).
+///
+/// A full resolution of an AF is obtained by removing exactly one of the two attacks of each pair of mutually attacking (distinct) arguments.
+/// The resolution-based grounded extensions are the minimal (wrt. set inclusion) sets among the grounded extensions of the full resolutions.
+/// This semantics was introduced by Baroni, Dunne and Giacomin.
+///
+/// Instead of enumerating the (exponentially many) resolutions, this solver relies on a SAT encoding in which the choices of resolution are variables.
+/// The models of the encoding are the pairs made of a resolution and a complete extension of this resolution;
+/// since the grounded extension of a resolution is included in its complete extensions,
+/// the minimal sets among these complete extensions are exactly the resolution-based grounded extensions.
+/// When a model is found, the grounded extension of the underlying resolution is computed without the SAT solver,
+/// and subsequent SAT calls look for a strictly smaller set until a minimal one is reached.
+///
+/// The certificates for the acceptance queries are extensions.
+pub struct ResolutionBasedGroundedSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+}
+
+impl<'a, T> ResolutionBasedGroundedSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new SAT based solver for the resolution-based grounded semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::solvers::{SingleExtensionComputer, ResolutionBasedGroundedSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = ResolutionBasedGroundedSemanticsSolver::new(af);
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found a resolution-based grounded extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new SAT based solver for the resolution-based grounded semantics.
+    ///
+    /// The SAT solver to use in given through the solver factory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat::CadicalSolver;
+    /// # use crustabri::solvers::{SingleExtensionComputer, ResolutionBasedGroundedSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = ResolutionBasedGroundedSemanticsSolver::new_with_sat_solver_factory(
+    ///         af,
+    ///         Box::new(|| Box::new(CadicalSolver::default())),
+    ///     );
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found a resolution-based grounded extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new_with_sat_solver_factory(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Self {
+        Self { af, solver_factory }
+    }
+
+    fn new_encoded_solver(&self) -> (Box<dyn SatSolver>, ResolutionEncoding) {
+        let mut solver = (self.solver_factory)();
+        let encoding = ResolutionEncoding::new(self.af, solver.as_mut());
+        (solver, encoding)
+    }
+
+    fn enumerate_extensions_internal(&self, callback: &mut dyn FnMut(&[&'a Argument<T>]) -> bool) {
+        let (mut solver, encoding) = self.new_encoded_solver();
+        while let Some(model) = solver.solve().unwrap_model() {
+            let mut current = encoding.grounded_extension_of_resolution(self.af, &model);
+            while let Some(smaller) = encoding.find_smaller_set(self.af, solver.as_mut(), &current)
+            {
+                current = smaller;
+            }
+            if !callback(&current) || current.is_empty() {
+                break;
+            }
+            solver.add_clause(
+                current
+                    .iter()
+                    .map(|a| encoding.arg_to_lit(a).negate())
+                    .collect(),
+            );
+        }
+    }
+
+    fn find_extension_with(
+        &self,
+        predicate: &mut dyn FnMut(&[&'a Argument<T>]) -> bool,
+    ) -> Option<Vec<&'a Argument<T>>> {
+        let mut found = None;
+        self.enumerate_extensions_internal(&mut |ext| {
+            if predicate(ext) {
+                found = Some(ext.to_vec());
+            }
+            found.is_none()
+        });
+        found
+    }
+
+    fn check_credulous_acceptance(
+        &self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        let args = self.labels_to_args(args);
+        let certificate = self.find_extension_with(&mut |ext| {
+            if conjunctive {
+                args.iter().all(|a| ext.contains(a))
+            } else {
+                args.iter().any(|a| ext.contains(a))
+            }
+        });
+        (certificate.is_some(), certificate)
+    }
+
+    fn labels_to_args(&self, labels: &[&T]) -> Vec<&'a Argument<T>> {
+        labels
+            .iter()
+            .map(|l| self.af.argument_set().get_argument(l).unwrap())
+            .collect()
+    }
+}
+
+impl<T> SingleExtensionComputer<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        self.find_extension_with(&mut |_| true)
+    }
+}
+
+impl<T> SingleLabellingComputer<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        self.enumerate_extensions_internal(callback)
+    }
+}
+
+impl<T> ExtensionCounter<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        let mut n_extensions = BigUint::from(0_usize);
+        self.enumerate_extensions_internal(&mut |_| {
+            n_extensions += 1_usize;
+            true
+        });
+        n_extensions
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        let certificate = self.find_extension_with(&mut |ext| !ext.is_empty());
+        (certificate.is_some(), certificate)
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        let mut extensions = Vec::with_capacity(2);
+        self.enumerate_extensions_internal(&mut |ext| {
+            extensions.push(ext.to_vec());
+            extensions.len() < 2
+        });
+        (extensions.len() == 1, extensions)
+    }
+}
+
+impl<T> VerifyExtension<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let candidate = CandidateExtension::new(self.af, args);
+        if let Some(reason) = candidate.check_completeness() {
+            return (false, Some(reason));
+        }
+        let (mut solver, encoding) = self.new_encoded_solver();
+        let mut in_set = vec![false; self.af.n_arguments()];
+        candidate.args().iter().for_each(|a| in_set[a.id()] = true);
+        let assumptions = self
+            .af
+            .argument_set()
+            .iter()
+            .map(|a| {
+                let lit = encoding.arg_to_lit(a);
+                if in_set[a.id()] {
+                    lit
+                } else {
+                    lit.negate()
+                }
+            })
+            .collect::<Vec<Literal>>();
+        if solver
+            .solve_under_assumptions(&assumptions)
+            .unwrap_model()
+            .is_none()
+        {
+            return (false, Some(NonExtensionReason::NoCompleteResolution));
+        }
+        match encoding.find_smaller_set(self.af, solver.as_mut(), candidate.args()) {
+            Some(mut smaller) => {
+                while let Some(s) = encoding.find_smaller_set(self.af, solver.as_mut(), &smaller) {
+                    smaller = s;
+                }
+                (false, Some(NonExtensionReason::SmallerExtension(smaller)))
+            }
+            None => (true, None),
+        }
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        self.check_credulous_acceptance(args, false).0
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(args, false)
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance(args, true)
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for ResolutionBasedGroundedSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_skeptically_accepted_with_certificate(args).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = self.labels_to_args(args);
+        let grounded = self.af.grounded_extension();
+        if args.iter().any(|a| grounded.contains(a)) {
+            return (true, None);
+        }
+        let certificate =
+            self.find_extension_with(&mut |ext| args.iter().all(|a| !ext.contains(a)));
+        (certificate.is_none(), certificate)
+    }
+}
+
+// The encoding of the pairs (R, E) where R is a full resolution and E a complete extension of R.
+//
+// The first variables are dedicated to the arguments (membership to E), followed by the ones indicating the arguments attacked by E in R.
+// Then comes a variable for each pair of mutual attacks, set to true iff the attack from the argument with the lower identifier is kept.
+struct ResolutionEncoding {
+    n_args: usize,
+    mutual_attack_vars: Vec<Vec<(usize, usize)>>,
+}
+
+impl ResolutionEncoding {
+    fn new<T>(af: &AAFramework<T>, solver: &mut dyn SatSolver) -> Self
+    where
+        T: LabelType,
+    {
+        let n_args = af.n_arguments();
+        let mut mutual_attack_vars = vec![vec![]; n_args];
+        let mut next_var = 1 + 2 * n_args;
+        af.iter_attacks().for_each(|att| {
+            let (a, b) = (att.attacker(), att.attacked());
+            if a.id() < b.id()
+                && af
+                    .iter_attacks_from(b)
+                    .any(|back| back.attacked().id() == a.id())
+            {
+                mutual_attack_vars[a.id()].push((b.id(), next_var));
+                next_var += 1;
+            }
+        });
+        let encoding = ResolutionEncoding {
+            n_args,
+            mutual_attack_vars,
+        };
+        encoding.encode(af, solver, next_var);
+        encoding
+    }
+
+    fn encode<T>(&self, af: &AAFramework<T>, solver: &mut dyn SatSolver, first_aux_var: usize)
+    where
+        T: LabelType,
+    {
+        let mut next_var = first_aux_var;
+        for arg in af.argument_set().iter() {
+            let arg_lit = self.arg_to_lit(arg);
+            let attacked_lit = self.attacked_lit(arg.id());
+            let mut attacked_reasons = vec![attacked_lit.negate()];
+            let mut undefended_reasons = vec![arg_lit];
+            for att in af.iter_attacks_to(arg) {
+                let attacker = att.attacker();
+                let attacker_lit = self.arg_to_lit(attacker);
+                let attacker_attacked_lit = self.attacked_lit(attacker.id());
+                solver.add_clause(vec![attacker_lit.negate(), arg_lit.negate()]);
+                match self.active_attack_lit(attacker.id(), arg.id()) {
+                    None => {
+                        solver.add_clause(vec![attacker_lit.negate(), attacked_lit]);
+                        solver.add_clause(vec![arg_lit.negate(), attacker_attacked_lit]);
+                        attacked_reasons.push(attacker_lit);
+                        undefended_reasons.push(attacker_attacked_lit.negate());
+                    }
+                    Some(active_lit) => {
+                        solver.add_clause(vec![
+                            attacker_lit.negate(),
+                            active_lit.negate(),
+                            attacked_lit,
+                        ]);
+                        solver.add_clause(vec![
+                            arg_lit.negate(),
+                            active_lit.negate(),
+                            attacker_attacked_lit,
+                        ]);
+                        let attacked_reason = Literal::from(next_var as isize);
+                        let undefended_reason = Literal::from(1 + next_var as isize);
+                        next_var += 2;
+                        solver.add_clause(vec![attacked_reason.negate(), attacker_lit]);
+                        solver.add_clause(vec![attacked_reason.negate(), active_lit]);
+                        solver.add_clause(vec![undefended_reason.negate(), active_lit]);
+                        solver.add_clause(vec![
+                            undefended_reason.negate(),
+                            attacker_attacked_lit.negate(),
+                        ]);
+                        attacked_reasons.push(attacked_reason);
+                        undefended_reasons.push(undefended_reason);
+                    }
+                }
+            }
+            solver.add_clause(attacked_reasons);
+            solver.add_clause(undefended_reasons);
+        }
+    }
+
+    fn arg_to_lit<T>(&self, arg: &Argument<T>) -> Literal
+    where
+        T: LabelType,
+    {
+        Literal::from(1 + arg.id() as isize)
+    }
+
+    fn attacked_lit(&self, arg_id: usize) -> Literal {
+        Literal::from((1 + self.n_args + arg_id) as isize)
+    }
+
+    // Returns the literal which is true iff the attack is kept in the resolution, or None if the attack is not a mutual one.
+    fn active_attack_lit(&self, attacker_id: usize, attacked_id: usize) -> Option<Literal> {
+        let (lower, upper) = if attacker_id < attacked_id {
+            (attacker_id, attacked_id)
+        } else {
+            (attacked_id, attacker_id)
+        };
+        self.mutual_attack_vars[lower]
+            .iter()
+            .find(|(other, _)| *other == upper)
+            .map(|(_, var)| {
+                let lit = Literal::from(*var as isize);
+                if attacker_id < attacked_id {
+                    lit
+                } else {
+                    lit.negate()
+                }
+            })
+    }
+
+    fn grounded_extension_of_resolution<'a, T>(
+        &self,
+        af: &'a AAFramework<T>,
+        model: &Assignment,
+    ) -> Vec<&'a Argument<T>>
+    where
+        T: LabelType,
+    {
+        let args = af.argument_set().iter().collect::<Vec<&Argument<T>>>();
+        let mut resolution = utils::restricted_framework(af, &args);
+        for (lower, mutual_attacks) in self.mutual_attack_vars.iter().enumerate() {
+            for (upper, var) in mutual_attacks {
+                let (lower_label, upper_label) = (
+                    af.argument_set().get_argument_by_id(lower).label(),
+                    af.argument_set().get_argument_by_id(*upper).label(),
+                );
+                if model.value_of(*var) == Some(true) {
+                    resolution.remove_attack(upper_label, lower_label).unwrap();
+                } else {
+                    resolution.remove_attack(lower_label, upper_label).unwrap();
+                }
+            }
+        }
+        utils::grounded_extension(&resolution)
+            .iter()
+            .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+            .collect()
+    }
+
+    // Looks for a set which is a complete extension of a resolution and is strictly included in the provided one.
+    //
+    // In case such a set exists, the grounded extension of the resolution is returned.
+    fn find_smaller_set<'a, T>(
+        &self,
+        af: &'a AAFramework<T>,
+        solver: &mut dyn SatSolver,
+        set: &[&Argument<T>],
+    ) -> Option<Vec<&'a Argument<T>>>
+    where
+        T: LabelType,
+    {
+        if set.is_empty() {
+            return None;
+        }
+        let mut in_set = vec![false; self.n_args];
+        set.iter().for_each(|a| in_set[a.id()] = true);
+        let selector = Literal::from(1 + solver.n_vars() as isize);
+        let mut clause = set
+            .iter()
+            .map(|a| self.arg_to_lit(a).negate())
+            .collect::<Vec<Literal>>();
+        clause.push(selector.negate());
+        solver.add_clause(clause);
+        let mut assumptions = af
+            .argument_set()
+            .iter()
+            .filter(|a| !in_set[a.id()])
+            .map(|a| self.arg_to_lit(a).negate())
+            .collect::<Vec<Literal>>();
+        assumptions.push(selector);
+        let result = solver.solve_under_assumptions(&assumptions).unwrap_model();
+        solver.add_clause(vec![selector.negate()]);
+        result.map(|model| self.grounded_extension_of_resolution(af, &model))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn labels<'a>(args: &[&'a Argument<String>]) -> Vec<&'a str> {
+        let mut labels = args
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        labels.sort_unstable();
+        labels
+    }
+
+    #[test]
+    fn test_mutual_attack_is_resolved() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a1,a2).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ResolutionBasedGroundedSemanticsSolver::new(&af);
+        let mut extensions = Vec::new();
+        solver.enumerate_extensions(&mut |ext| {
+            extensions.push(
+                labels(ext)
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<String>>(),
+            );
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0", "a3"], vec!["a1", "a3"]], extensions);
+        assert!(solver.is_skeptically_accepted(&"a3".to_string()));
+        assert!(!solver.is_skeptically_accepted(&"a0".to_string()));
+        let (status, certificate) =
+            solver.is_credulously_accepted_with_certificate(&"a1".to_string());
+        assert!(status);
+        assert_eq!(vec!["a1", "a3"], labels(&certificate.unwrap()));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a1".to_string()]));
+    }
+
+    #[test]
+    fn test_no_mutual_attack_is_grounded() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ResolutionBasedGroundedSemanticsSolver::new(&af);
+        assert_eq!(
+            vec!["a0", "a2"],
+            labels(&solver.compute_one_extension().unwrap())
+        );
+        assert!(solver.has_unique_extension());
+        assert_eq!(BigUint::from(1_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension_reasons() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a0,a2).
+        att(a2,a0).
+        att(a1,a2).
+        att(a2,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ResolutionBasedGroundedSemanticsSolver::new(&af);
+        assert_eq!((true, None), solver.verify_extension_with_reason(&[]));
+        assert_eq!(
+            (false, Some(NonExtensionReason::SmallerExtension(vec![]))),
+            solver.verify_extension_with_reason(&[&"a0".to_string()])
+        );
+        assert!(!solver.has_nonempty_extension());
+    }
+
+    #[test]
+    fn test_verify_not_complete_in_any_resolution() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = ResolutionBasedGroundedSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, Some(NonExtensionReason::NoCompleteResolution)),
+            solver.verify_extension_with_reason(&[])
+        );
+        assert!(solver.verify_extension(&[&"a1".to_string()]));
+    }
+}
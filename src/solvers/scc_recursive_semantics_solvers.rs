@@ -0,0 +1,771 @@
+use super::{
+    extension_enumeration, extension_existence,
+    extension_verification::CandidateExtension,
+    maximal_range_semantics_solvers,
+    naive_semantics_solver::{self, is_self_attacking},
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, SingleExtensionComputer, SingleLabellingComputer,
+    SkepticalAcceptanceComputer, UniqueExtensionChecker, VerifyExtension,
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
+    sat::{self, SatSolverFactoryFn},
+    utils::{self, Label, LabelType},
+};
+use num_bigint::BigUint;
+
+/// The type of functions used to enumerate the extensions of the base semantics on an AF.
+type BaseEnumeratorFn<T> = fn(
+    &AAFramework<T>,
+    &SatSolverFactoryFn,
+    &dyn ConstraintsEncoder<T>,
+    &mut dyn FnMut(&[&Argument<T>]) -> bool,
+);
+
+macro_rules! scc_recursive_solver {
+    ($solver_ident:ident, $sem_name:literal, $base_sem_name:literal, $base_enumerator:expr, $wrt_range:literal) => {
+        #[doc = concat!(" A SAT-based solver for the ", $sem_name, " semantics.")]
+        ///
+        /// This semantics is defined by the SCC-recursive schema of Baroni, Giacomin and Guida.
+        /// The strongly connected components (SCCs) of the AF are considered in a topological order.
+        /// The part of an extension lying in a SCC is an extension of the AF restricted to the arguments of the SCC which are not attacked by the previous parts of the extension,
+        /// computed recursively.
+        #[doc = concat!(" When this restricted AF is made of a single SCC, its extensions are its ", $base_sem_name, " extensions.")]
+        ///
+        #[doc = concat!(" The ", $base_sem_name, " extensions of the SCCs are computed by successive calls to a SAT solver.")]
+        /// The acceptance queries only consider the SCCs of the arguments under consideration and the ones of their ancestors.
+        /// The certificates for the acceptance queries are extensions.
+        ///
+        /// Checking whether a set of arguments is an extension ([VerifyExtension]) follows the recursive definition.
+        /// If a part of the set is not maximal in a SCC of the restricted AFs,
+        /// the parts of the set lying in the previous SCCs together with a larger part for this SCC are given as the reason.
+        ///
+        /// A non-empty extension exists iff an argument does not attack itself, so [NonEmptyExtensionChecker] only needs to compute a single extension.
+        /// The uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
+        pub struct $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            helper: SccRecursiveSemanticsHelper<'a, T>,
+        }
+
+        impl<'a, T> $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new(af);")]
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new(af: &'a AAFramework<T>) -> Self
+            where
+                T: LabelType,
+            {
+                Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat::CadicalSolver;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory(")]
+            ///         af,
+            ///         Box::new(|| Box::new(CadicalSolver::default()))
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    solver_factory,
+                    Box::new(aux_var_constraints_encoder::new_for_conflict_freeness()),
+                )
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            /// The constraints encoder must be an encoder for conflict-freeness.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat;
+            /// # use crustabri::encodings;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory_and_constraints_encoder(")]
+            ///         af,
+            ///         Box::new(|| sat::default_solver()),
+            ///         encodings::new_default_conflict_freeness_encoder(),
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory_and_constraints_encoder(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+                constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self {
+                    helper: SccRecursiveSemanticsHelper {
+                        af,
+                        solver_factory,
+                        constraints_encoder,
+                        base_enumerator: $base_enumerator,
+                        wrt_range: $wrt_range,
+                    },
+                }
+            }
+        }
+
+        impl<T> SingleExtensionComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+                self.helper.compute_one_extension()
+            }
+        }
+
+        impl<T> SingleLabellingComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+                let af = self.helper.af;
+                self.compute_one_extension()
+                    .map(|ext| Labelling::new_from_extension(af, &ext))
+            }
+        }
+
+        impl<T> ExtensionEnumerator<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+                self.helper.enumerate_extensions(callback)
+            }
+        }
+
+        impl<T> ExtensionCounter<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn count_extensions(&mut self) -> BigUint {
+                self.helper.count_extensions()
+            }
+        }
+
+        impl<T> NonEmptyExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_nonempty_extension_with_certificate(
+                &mut self,
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                match self.helper.compute_one_extension() {
+                    Some(ext) if !ext.is_empty() => (true, Some(ext)),
+                    _ => (false, None),
+                }
+            }
+        }
+
+        impl<T> UniqueExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+                self.helper.has_unique_extension_with_certificate()
+            }
+        }
+
+        impl<T> VerifyExtension<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn verify_extension_with_reason(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+                let candidate = CandidateExtension::new(self.helper.af, args);
+                let reason = candidate.check_conflict_freeness().or_else(|| {
+                    self.helper
+                        .find_larger_local_extension(self.helper.af, candidate.args())
+                        .map(NonExtensionReason::LargerLocalExtension)
+                });
+                (reason.is_none(), reason)
+            }
+        }
+
+        impl<T> CredulousAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_acceptance(args, true, false, false).0
+            }
+
+            fn are_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_acceptance(args, true, false, true)
+            }
+
+            fn are_all_credulously_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_acceptance(args, true, true, false).0
+            }
+
+            fn are_all_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_acceptance(args, true, true, true)
+            }
+        }
+
+        impl<T> SkepticalAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_acceptance(args, false, false, false).0
+            }
+
+            fn are_skeptically_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_acceptance(args, false, false, true)
+            }
+        }
+    };
+}
+
+scc_recursive_solver!(
+    Cf2SemanticsSolver,
+    "CF2",
+    "naive",
+    naive_semantics_solver::enumerate_naive_extensions,
+    false
+);
+
+scc_recursive_solver!(
+    Stage2SemanticsSolver,
+    "stage2",
+    "stage",
+    maximal_range_semantics_solvers::enumerate_maximal_range_extensions,
+    true
+);
+
+struct SccRecursiveSemanticsHelper<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+    base_enumerator: BaseEnumeratorFn<T>,
+    wrt_range: bool,
+}
+
+impl<'a, T> SccRecursiveSemanticsHelper<'a, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&self) -> Option<Vec<&'a Argument<T>>> {
+        let mut ext = None;
+        self.enumerate_extensions_in(self.af, &mut |e| {
+            ext = Some(e.to_vec());
+            false
+        });
+        ext
+    }
+
+    fn enumerate_extensions(&self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| {
+                self.enumerate_extensions_in(cc_af, cc_callback);
+            },
+            callback,
+        )
+    }
+
+    fn count_extensions(&self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| {
+                self.enumerate_extensions_in(cc_af, cc_callback);
+            },
+        )
+    }
+
+    fn has_unique_extension_with_certificate(&self) -> (bool, Vec<Vec<&'a Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| {
+                self.enumerate_extensions_in(cc_af, cc_callback);
+            },
+        )
+    }
+
+    // Enumerates the extensions of an AF; returns `false` iff the callback function stopped the enumeration.
+    fn enumerate_extensions_in<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        callback: &mut dyn FnMut(&[&'b Argument<T>]) -> bool,
+    ) -> bool {
+        let sccs = utils::strongly_connected_components(af);
+        if sccs.len() <= 1 {
+            return self.enumerate_base_extensions(af, callback);
+        }
+        let mut in_current = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        self.enumerate_from_scc(af, &sccs, 0, &mut Vec::new(), &mut in_current, callback)
+    }
+
+    fn enumerate_base_extensions<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        callback: &mut dyn FnMut(&[&'b Argument<T>]) -> bool,
+    ) -> bool {
+        if af.n_arguments() == 0 {
+            return callback(&[]);
+        }
+        let mut go_on = true;
+        (self.base_enumerator)(
+            af,
+            self.solver_factory.as_ref(),
+            self.constraints_encoder.as_ref(),
+            &mut |ext| {
+                let af_ext = ext
+                    .iter()
+                    .map(|a| af.argument_set().get_argument_by_id(a.id()))
+                    .collect::<Vec<&Argument<T>>>();
+                go_on = callback(&af_ext);
+                go_on
+            },
+        );
+        go_on
+    }
+
+    // Extends the current set with the extension parts of the SCCs starting at the given index.
+    //
+    // The SCCs must be given in a topological order, and the current set must be a valid extension part for the SCCs before the index.
+    // Returns `false` iff the callback function stopped the enumeration.
+    // When this function returns, the current set is restored to its initial state.
+    fn enumerate_from_scc<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        sccs: &[Vec<&'b Argument<T>>],
+        first_scc: usize,
+        current: &mut Vec<&'b Argument<T>>,
+        in_current: &mut Vec<bool>,
+        callback: &mut dyn FnMut(&[&'b Argument<T>]) -> bool,
+    ) -> bool {
+        let initial_len = current.len();
+        let mut scc_index = first_scc;
+        let result = loop {
+            if scc_index == sccs.len() {
+                break callback(current);
+            }
+            let undefeated = undefeated_args(af, &sccs[scc_index], in_current);
+            scc_index += 1;
+            if undefeated.len() <= 1 {
+                if let Some(arg) = undefeated.first() {
+                    if !is_self_attacking(af, arg) {
+                        current.push(arg);
+                        in_current[arg.id()] = true;
+                    }
+                }
+                continue;
+            }
+            let restricted_af = utils::restricted_framework(af, &undefeated);
+            break self.enumerate_extensions_in(&restricted_af, &mut |restricted_ext| {
+                let len_before = current.len();
+                restricted_ext.iter().for_each(|a| {
+                    let arg = af.argument_set().get_argument(a.label()).unwrap();
+                    current.push(arg);
+                    in_current[arg.id()] = true;
+                });
+                let go_on =
+                    self.enumerate_from_scc(af, sccs, scc_index, current, in_current, callback);
+                current
+                    .drain(len_before..)
+                    .for_each(|a| in_current[a.id()] = false);
+                go_on
+            });
+        };
+        current
+            .drain(initial_len..)
+            .for_each(|a| in_current[a.id()] = false);
+        result
+    }
+
+    // Looks for a SCC in which the part of the set is not maximal, following the recursive definition of the semantics.
+    //
+    // The set must be conflict-free.
+    // In case such a SCC is found, the arguments of the set lying in the previous SCCs are returned together with a larger part for this SCC.
+    fn find_larger_local_extension<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        set: &[&'b Argument<T>],
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let sccs = utils::strongly_connected_components(af);
+        if sccs.len() <= 1 {
+            let labels = set.iter().map(|a| a.label()).collect::<Vec<&T>>();
+            return CandidateExtension::new(af, &labels).find_larger_extension(
+                self.solver_factory.as_ref(),
+                self.constraints_encoder.as_ref(),
+                self.wrt_range,
+            );
+        }
+        let mut in_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        set.iter().for_each(|a| in_set[a.id()] = true);
+        let mut in_previous_sccs = vec![false; in_set.len()];
+        for scc in sccs.iter() {
+            let undefeated = undefeated_args(af, scc, &in_previous_sccs);
+            let larger = if undefeated.len() <= 1 {
+                match undefeated.first() {
+                    Some(arg) if !in_set[arg.id()] && !is_self_attacking(af, arg) => {
+                        Some(vec![*arg])
+                    }
+                    _ => None,
+                }
+            } else {
+                let restricted_af = utils::restricted_framework(af, &undefeated);
+                let restricted_set = set
+                    .iter()
+                    .filter_map(|a| restricted_af.argument_set().get_argument(a.label()).ok())
+                    .collect::<Vec<&Label<T>>>();
+                self.find_larger_local_extension(&restricted_af, &restricted_set)
+                    .map(|restricted_larger| {
+                        restricted_larger
+                            .iter()
+                            .map(|a| af.argument_set().get_argument(a.label()).unwrap())
+                            .collect::<Vec<&Argument<T>>>()
+                    })
+            };
+            if let Some(mut scc_larger) = larger {
+                let mut larger_set = set
+                    .iter()
+                    .filter(|a| in_previous_sccs[a.id()])
+                    .copied()
+                    .collect::<Vec<&Argument<T>>>();
+                larger_set.append(&mut scc_larger);
+                return Some(larger_set);
+            }
+            scc.iter()
+                .filter(|a| in_set[a.id()])
+                .for_each(|a| in_previous_sccs[a.id()] = true);
+        }
+        None
+    }
+
+    // Checks the acceptance of the arguments by enumerating the extensions of the AF restricted to the arguments and their ancestors.
+    //
+    // Since the semantics is directional, these extensions are the projections of the extensions of the whole AF.
+    fn check_acceptance(
+        &self,
+        args: &[&T],
+        is_credulous_acceptance: bool,
+        conjunctive: bool,
+        with_certificate: bool,
+    ) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let in_ancestors = utils::ancestors(self.af, &args);
+        let (mut sccs, other_sccs): (Vec<_>, Vec<_>) =
+            utils::strongly_connected_components(self.af)
+                .into_iter()
+                .partition(|scc| in_ancestors[scc[0].id()]);
+        let n_ancestor_sccs = sccs.len();
+        sccs.extend(other_sccs);
+        let mut in_current = vec![false; in_ancestors.len()];
+        let mut answer = None;
+        self.enumerate_from_scc(
+            self.af,
+            &sccs[..n_ancestor_sccs],
+            0,
+            &mut Vec::new(),
+            &mut in_current,
+            &mut |ext| {
+                let is_answer = if !is_credulous_acceptance {
+                    args.iter().all(|a| !ext.contains(a))
+                } else if conjunctive {
+                    args.iter().all(|a| ext.contains(a))
+                } else {
+                    args.iter().any(|a| ext.contains(a))
+                };
+                if is_answer {
+                    answer = Some(ext.to_vec());
+                }
+                !is_answer
+            },
+        );
+        let mut partial_ext = match answer {
+            None => return (!is_credulous_acceptance, None),
+            Some(_) if !with_certificate => return (is_credulous_acceptance, None),
+            Some(ext) => ext,
+        };
+        partial_ext.iter().for_each(|a| in_current[a.id()] = true);
+        let mut certificate = None;
+        self.enumerate_from_scc(
+            self.af,
+            &sccs,
+            n_ancestor_sccs,
+            &mut partial_ext,
+            &mut in_current,
+            &mut |ext| {
+                certificate = Some(ext.to_vec());
+                false
+            },
+        );
+        (is_credulous_acceptance, certificate)
+    }
+}
+
+// Returns the arguments of the SCC that are not attacked by the set.
+fn undefeated_args<'b, T>(
+    af: &'b AAFramework<T>,
+    scc: &[&'b Argument<T>],
+    in_set: &[bool],
+) -> Vec<&'b Argument<T>>
+where
+    T: LabelType,
+{
+    scc.iter()
+        .filter(|a| !af.iter_attacks_to(a).any(|att| in_set[att.attacker().id()]))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encodings::exp_constraints_encoder,
+        io::{AspartixReader, InstanceReader},
+    };
+
+    fn sorted_extensions<F>(mut enumerate: F) -> Vec<Vec<String>>
+    where
+        F: FnMut(&mut dyn FnMut(&[&Argument<String>]) -> bool),
+    {
+        let mut extensions = Vec::new();
+        enumerate(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().clone())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        extensions
+    }
+
+    #[test]
+    fn test_cf2_odd_cycle() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = Cf2SemanticsSolver::new(&af);
+        assert_eq!(
+            vec![vec!["a0", "a3"], vec!["a1", "a3"], vec!["a2"]],
+            sorted_extensions(|callback| solver.enumerate_extensions(callback))
+        );
+        assert_eq!(3, solver.count_extensions().try_into().unwrap());
+    }
+
+    #[test]
+    fn test_stage2_differs_from_cf2() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a0).
+        att(a0,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut cf2_solver =
+            Cf2SemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+                &af,
+                Box::new(|| sat::default_solver()),
+                Box::new(exp_constraints_encoder::new_for_conflict_freeness()),
+            );
+        assert_eq!(
+            vec![vec!["a0"], vec!["a1", "a3"], vec!["a2", "a3"]],
+            sorted_extensions(|callback| cf2_solver.enumerate_extensions(callback))
+        );
+        let mut stage2_solver = Stage2SemanticsSolver::new(&af);
+        assert_eq!(
+            vec![vec!["a1", "a3"]],
+            sorted_extensions(|callback| stage2_solver.enumerate_extensions(callback))
+        );
+        assert!(stage2_solver.has_unique_extension());
+        assert!(!cf2_solver.has_unique_extension());
+    }
+
+    #[test]
+    fn test_self_attacking_args() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a0).
+        att(a0,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let mut af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = Cf2SemanticsSolver::new(&af);
+        let ext = solver.compute_one_extension().unwrap();
+        assert_eq!(
+            vec!["a1"],
+            ext.iter().map(|a| a.label()).collect::<Vec<_>>()
+        );
+        assert!(solver.has_nonempty_extension());
+        af.remove_argument(&"a1".to_string()).unwrap();
+        let mut solver = Cf2SemanticsSolver::new(&af);
+        assert!(solver.compute_one_extension().unwrap().is_empty());
+        assert!(!solver.has_nonempty_extension());
+    }
+
+    #[test]
+    fn test_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a3).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = Cf2SemanticsSolver::new(&af);
+        let (accepted, certificate) =
+            solver.is_credulously_accepted_with_certificate(&"a3".to_string());
+        assert!(accepted);
+        let mut certificate = certificate
+            .unwrap()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        certificate.sort_unstable();
+        assert!(certificate == vec!["a0", "a3"] || certificate == vec!["a1", "a3"]);
+        let (accepted, certificate) =
+            solver.is_skeptically_accepted_with_certificate(&"a3".to_string());
+        assert!(!accepted);
+        let mut certificate = certificate
+            .unwrap()
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        certificate.sort_unstable();
+        assert_eq!(vec!["a2", "a4"], certificate);
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(solver.are_skeptically_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = Stage2SemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a3".to_string(), &"a1".to_string()]));
+        assert!(matches!(
+            solver
+                .verify_extension_with_reason(&[&"a0".to_string(), &"a1".to_string()])
+                .1,
+            Some(NonExtensionReason::ConflictingAttack(_, _))
+        ));
+        match solver.verify_extension_with_reason(&[&"a0".to_string()]).1 {
+            Some(NonExtensionReason::LargerLocalExtension(larger)) => {
+                let mut labels = larger
+                    .iter()
+                    .map(|a| a.label().as_str())
+                    .collect::<Vec<&str>>();
+                labels.sort_unstable();
+                assert_eq!(vec!["a0", "a3"], labels);
+            }
+            _ => panic!(),
+        }
+        match solver.verify_extension_with_reason(&[&"a3".to_string()]).1 {
+            Some(NonExtensionReason::LargerLocalExtension(larger)) => {
+                assert_eq!(1, larger.len());
+                assert_ne!("a3", larger[0].label());
+            }
+            _ => panic!(),
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{aa::Argument, utils::LabelType};
+use crate::{
+    aa::{Argument, Labelling},
+    utils::LabelType,
+};
+use num_bigint::BigUint;
 
 /// A trait for solvers able to compute an extension.
 pub trait SingleExtensionComputer<T>
@@ -12,6 +16,22 @@ where
     fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>>;
 }
 
+/// A trait for solvers able to compute a single labelling for a problem.
+///
+/// Contrary to [SingleExtensionComputer], the returned labelling distinguishes the arguments attacked by the extension (`OUT`)
+/// from the ones that are neither accepted nor attacked (`UNDEC`).
+/// Every solver implementing [SingleExtensionComputer] also implements this trait.
+pub trait SingleLabellingComputer<T>
+where
+    T: LabelType,
+{
+    /// Computes a single labelling.
+    ///
+    /// In case the problem admits no extension, [Option::None] is return.
+    /// In case an extension is found, its associated labelling is returned (see [Labelling::new_from_extension]).
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>>;
+}
+
 /// A trait for solvers able to check the credulous acceptance of an argument.
 pub trait CredulousAcceptanceComputer<T>
 where
@@ -62,6 +82,28 @@ where
         &mut self,
         args: &[&T],
     ) -> (bool, Option<Vec<&Argument<T>>>);
+
+    /// Checks the credulous acceptance of a conjunction of arguments, i.e. whether a single extension contains all of them.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn are_all_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_all_credulously_accepted_with_certificate(args).0
+    }
+
+    /// Checks the credulous acceptance of a conjunction of arguments, and provide a certificate if it is the case.
+    ///
+    /// The certificate is set to `None` if the result of the test is `false`.
+    /// Otherwise, the certificate is an extension containing all the arguments.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>);
 }
 
 /// A trait for solvers able to check the skeptical acceptance of an argument.
@@ -114,4 +156,176 @@ where
         &mut self,
         args: &[&T],
     ) -> (bool, Option<Vec<&Argument<T>>>);
+
+    /// Checks the skeptical acceptance of a conjunction of arguments, i.e. whether all the extensions contain all of them.
+    ///
+    /// Since this holds iff each argument is skeptically accepted, the default implementation checks the arguments one by one.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn are_all_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        args.iter().all(|a| self.is_skeptically_accepted(a))
+    }
+
+    /// Checks the skeptical acceptance of a conjunction of arguments, and provide a certificate if it is not the case.
+    ///
+    /// The certificate is set to `None` if the result of the test is `true`.
+    /// Otherwise, the certificate is an extension which does not contain one of the arguments.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn are_all_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        match args.iter().find(|a| !self.is_skeptically_accepted(a)) {
+            Some(a) => self.is_skeptically_accepted_with_certificate(a),
+            None => (true, None),
+        }
+    }
+}
+
+/// A trait for solvers able to enumerate the extensions.
+pub trait ExtensionEnumerator<T>
+where
+    T: LabelType,
+{
+    /// Enumerates the extensions, calling the callback function on each of them.
+    ///
+    /// Each extension is given exactly once, as a slice of arguments.
+    /// The enumeration stops when all the extensions have been given or when the callback function returns `false`.
+    /// In case the problem admits no extension, the callback function is never called.
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool);
+}
+
+/// A trait for solvers able to count the extensions.
+pub trait ExtensionCounter<T>
+where
+    T: LabelType,
+{
+    /// Counts the extensions.
+    ///
+    /// The number of extensions is given as an arbitrary-precision integer since it may be exponential in the number of arguments.
+    fn count_extensions(&mut self) -> BigUint;
+}
+
+/// The reason why a set of arguments is not an extension.
+///
+/// Such reasons are returned by solvers implementing the [VerifyExtension] trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NonExtensionReason<'a, T>
+where
+    T: LabelType,
+{
+    /// An argument of the set (the first one) attacks an argument of the set (the second one).
+    ConflictingAttack(&'a Argument<T>, &'a Argument<T>),
+    /// An argument of the set (the first one) is not defended by the set against one of its attackers (the second one).
+    UndefendedArgument(&'a Argument<T>, &'a Argument<T>),
+    /// An argument which is not in the set is defended by the set.
+    MissingDefendedArgument(&'a Argument<T>),
+    /// An argument which is not in the set is not attacked by the set.
+    UnattackedArgument(&'a Argument<T>),
+    /// An extension that is strictly larger than the set exists.
+    ///
+    /// Depending on the semantics, the extension is larger wrt. set inclusion or wrt. range inclusion.
+    LargerExtension(Vec<&'a Argument<T>>),
+    /// The semantics admits a single extension, and this extension differs from the set.
+    DistinctExtension(Vec<&'a Argument<T>>),
+    /// The set is admissible, but its arguments given here are only defended through a cycle of defenses involving themselves.
+    CircularDefense(Vec<&'a Argument<T>>),
+    /// The part of the set lying in a strongly connected component is not maximal wrt. an SCC-recursive semantics.
+    ///
+    /// The arguments of the set lying in the previous components are given, together with a larger part for this component.
+    LargerLocalExtension(Vec<&'a Argument<T>>),
+    /// An extension that is strictly smaller (wrt. set inclusion) than the set exists.
+    SmallerExtension(Vec<&'a Argument<T>>),
+    /// The set is not a complete extension of any of the full resolutions of the mutual attacks.
+    NoCompleteResolution,
+}
+
+impl<'a, T> NonExtensionReason<'a, T>
+where
+    T: LabelType,
+{
+    /// Returns the arguments involved in this reason.
+    ///
+    /// For reasons involving arguments, these arguments are returned in the order they appear in the reason.
+    /// For reasons involving extensions, the extension is returned.
+    pub fn involved_arguments(&self) -> Vec<&'a Argument<T>> {
+        match self {
+            NonExtensionReason::ConflictingAttack(a, b)
+            | NonExtensionReason::UndefendedArgument(a, b) => vec![a, b],
+            NonExtensionReason::MissingDefendedArgument(a)
+            | NonExtensionReason::UnattackedArgument(a) => vec![a],
+            NonExtensionReason::LargerExtension(ext)
+            | NonExtensionReason::DistinctExtension(ext)
+            | NonExtensionReason::CircularDefense(ext)
+            | NonExtensionReason::LargerLocalExtension(ext)
+            | NonExtensionReason::SmallerExtension(ext) => ext.clone(),
+            NonExtensionReason::NoCompleteResolution => vec![],
+        }
+    }
+}
+
+/// A trait for solvers able to check if a set of arguments is an extension.
+pub trait VerifyExtension<T>
+where
+    T: LabelType,
+{
+    /// Checks if a set of arguments is an extension.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn verify_extension(&mut self, args: &[&T]) -> bool {
+        self.verify_extension_with_reason(args).0
+    }
+
+    /// Checks if a set of arguments is an extension, and provide the reason why it is not if it is the case.
+    ///
+    /// The reason is set to `None` if the result of the test is `true`.
+    ///
+    /// # Panic
+    ///
+    /// If one of the provided arguments does not belong to the argument set, this function must panic.
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>);
+}
+
+/// A trait for solvers able to check the existence of a non-empty extension.
+pub trait NonEmptyExtensionChecker<T>
+where
+    T: LabelType,
+{
+    /// Checks if a non-empty extension exists.
+    fn has_nonempty_extension(&mut self) -> bool {
+        self.has_nonempty_extension_with_certificate().0
+    }
+
+    /// Checks if a non-empty extension exists, and provide such an extension if it is the case.
+    ///
+    /// The certificate is set to `None` if the result of the test is `false`.
+    /// Otherwise, the certificate is a non-empty extension.
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>);
+}
+
+/// A trait for solvers able to check if a single extension exists.
+pub trait UniqueExtensionChecker<T>
+where
+    T: LabelType,
+{
+    /// Checks if exactly one extension exists.
+    fn has_unique_extension(&mut self) -> bool {
+        self.has_unique_extension_with_certificate().0
+    }
+
+    /// Checks if exactly one extension exists, and provide the extensions that were found to decide.
+    ///
+    /// If the result of the test is `true`, the certificate is made of the unique extension.
+    /// Otherwise, the certificate is made of two distinct extensions, or is empty if there is no extension at all.
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>);
 }
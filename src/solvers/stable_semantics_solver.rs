@@ -1,12 +1,20 @@
-use super::specs::{
-    CredulousAcceptanceComputer, SingleExtensionComputer, SkepticalAcceptanceComputer,
+use super::{
+    extension_enumeration, extension_existence,
+    extension_verification::CandidateExtension,
+    specs::{
+        CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator,
+        NonEmptyExtensionChecker, NonExtensionReason, SingleExtensionComputer,
+        SingleLabellingComputer, SkepticalAcceptanceComputer, UniqueExtensionChecker,
+        VerifyExtension,
+    },
 };
 use crate::{
-    aa::{AAFramework, Argument},
+    aa::{AAFramework, Argument, Labelling},
     encodings::{ConstraintsEncoder, DefaultStableConstraintsEncoder},
     sat::{self, Literal, SatSolverFactoryFn},
     utils::{ConnectedComponentsComputer, Label, LabelType},
 };
+use num_bigint::BigUint;
 
 /// A SAT-based solver for the stable semantics.
 ///
@@ -18,6 +26,10 @@ use crate::{
 /// When a certificate is needed, a stable extension is given.
 /// It contains the argument under consideration when considering credulous acceptance, while it does not contain it while considering skeptical acceptance.
 ///
+/// Checking whether a set of arguments is a stable extension ([VerifyExtension]) does not require any call to the SAT solver.
+/// The existence of a non-empty extension ([NonEmptyExtensionChecker]) is checked with a single SAT call per connected component,
+/// while the uniqueness of the extension ([UniqueExtensionChecker]) is checked by enumerating at most two extensions per connected component.
+///
 pub struct StableSemanticsSolver<'a, T>
 where
     T: LabelType,
@@ -92,12 +104,46 @@ where
         }
     }
 
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_enumeration::enumerate_extensions(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+            callback,
+        );
+    }
+
+    fn compute_nonempty_extension_in_cc<'b>(
+        &self,
+        cc_af: &'b AAFramework<T>,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        extension_existence::compute_nonempty_extension_if_any(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+        )
+    }
+
     fn acceptance_with_model(
         &mut self,
         args: &[&Argument<T>],
         assumption_polarity: bool,
+        conjunctive: bool,
         status_on_unsat: bool,
     ) -> (bool, Option<Vec<&Argument<T>>>) {
+        // a disjunction of arguments is credulously accepted if a single connected component contains an extension with one of them
+        let disjunctive_credulous = assumption_polarity && !conjunctive;
+        let mut disjunction_satisfied = false;
         let mut merged = Vec::new();
         for cc_af in ConnectedComponentsComputer::iter_connected_components(self.af) {
             let mut solver = (self.solver_factory)();
@@ -107,64 +153,59 @@ where
                 .iter()
                 .filter_map(|a| cc_af.argument_set().get_argument(a.label()).ok())
                 .collect::<Vec<&Label<T>>>();
-            if !args_in_cc.is_empty() {
-                let mut opt_selector = None;
-                let assumption_lits = if assumption_polarity {
-                    let selector = Literal::from(1 + solver.n_vars() as isize);
-                    let clause = args_in_cc
-                        .iter()
-                        .map(|a| self.constraints_encoder.arg_to_lit(a))
-                        .collect::<Vec<Literal>>();
-                    opt_selector = Some(selector);
-                    solver.add_clause(clause);
-                    vec![selector]
-                } else {
-                    args_in_cc
-                        .iter()
-                        .map(|a| self.constraints_encoder.arg_to_lit(a).negate())
-                        .collect::<Vec<Literal>>()
-                };
-                let result = solver
-                    .solve_under_assumptions(&assumption_lits)
-                    .unwrap_model();
-                if assumption_polarity {
-                    solver.add_clause(vec![opt_selector.unwrap().negate()]);
-                }
-                match result {
+            let opt_assignment = if args_in_cc.is_empty() {
+                solver.solve().unwrap_model()
+            } else if disjunctive_credulous {
+                let selector = Literal::from(1 + solver.n_vars() as isize);
+                let mut clause = args_in_cc
+                    .iter()
+                    .map(|a| self.constraints_encoder.arg_to_lit(a))
+                    .collect::<Vec<Literal>>();
+                clause.push(selector.negate());
+                solver.add_clause(clause);
+                match solver.solve_under_assumptions(&[selector]).unwrap_model() {
                     Some(assignment) => {
-                        let cc_ext = self
-                            .constraints_encoder
-                            .assignment_to_extension(&assignment, &cc_af);
-                        merged.append(
-                            &mut cc_ext
-                                .iter()
-                                .map(|cc_arg| {
-                                    self.af.argument_set().get_argument(cc_arg.label()).unwrap()
-                                })
-                                .collect::<Vec<&Argument<T>>>(),
-                        );
+                        disjunction_satisfied = true;
+                        Some(assignment)
                     }
-                    None => return (status_on_unsat, None),
+                    None => solver.solve().unwrap_model(),
                 }
             } else {
-                match solver.solve().unwrap_model() {
-                    Some(assignment) => {
-                        let cc_ext = self
-                            .constraints_encoder
-                            .assignment_to_extension(&assignment, &cc_af);
-                        merged.append(
-                            &mut cc_ext
-                                .iter()
-                                .map(|cc_arg| {
-                                    self.af.argument_set().get_argument(cc_arg.label()).unwrap()
-                                })
-                                .collect::<Vec<&Argument<T>>>(),
-                        );
-                    }
-                    None => return (status_on_unsat, None),
+                let assumption_lits = args_in_cc
+                    .iter()
+                    .map(|a| {
+                        let lit = self.constraints_encoder.arg_to_lit(a);
+                        if assumption_polarity {
+                            lit
+                        } else {
+                            lit.negate()
+                        }
+                    })
+                    .collect::<Vec<Literal>>();
+                solver
+                    .solve_under_assumptions(&assumption_lits)
+                    .unwrap_model()
+            };
+            match opt_assignment {
+                Some(assignment) => {
+                    let cc_ext = self
+                        .constraints_encoder
+                        .assignment_to_extension(&assignment, &cc_af);
+                    merged.append(
+                        &mut cc_ext
+                            .iter()
+                            .map(|cc_arg| {
+                                self.af.argument_set().get_argument(cc_arg.label()).unwrap()
+                            })
+                            .collect::<Vec<&Argument<T>>>(),
+                    );
                 }
+                None => return (status_on_unsat, None),
             }
         }
+        if disjunctive_credulous && !disjunction_satisfied {
+            return (false, None);
+        }
         (!status_on_unsat, Some(merged))
     }
 }
@@ -200,6 +241,79 @@ where
     }
 }
 
+impl<T> SingleLabellingComputer<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+}
+
+impl<T> ExtensionCounter<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        extension_existence::check_nonempty_extension_by_connected_components(
+            self.af,
+            &mut |cc_af| self.compute_nonempty_extension_in_cc(cc_af),
+        )
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        extension_existence::check_uniqueness_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> VerifyExtension<T> for StableSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let reason = CandidateExtension::new(self.af, args).check_stability();
+        (reason.is_none(), reason)
+    }
+}
+
 impl<T> CredulousAcceptanceComputer<T> for StableSemanticsSolver<'_, T>
 where
     T: LabelType,
@@ -216,7 +330,18 @@ where
             .iter()
             .map(|a| self.af.argument_set().get_argument(a).unwrap())
             .collect::<Vec<&Argument<T>>>();
-        self.acceptance_with_model(&args, true, false)
+        self.acceptance_with_model(&args, true, false, false)
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Argument<T>>>();
+        self.acceptance_with_model(&args, true, true, false)
     }
 }
 
@@ -236,14 +361,17 @@ where
             .iter()
             .map(|a| self.af.argument_set().get_argument(a).unwrap())
             .collect::<Vec<&Argument<T>>>();
-        self.acceptance_with_model(&args, false, true)
+        self.acceptance_with_model(&args, false, false, true)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::io::{AspartixReader, InstanceReader};
+    use crate::{
+        aa::ArgumentSet,
+        io::{AspartixReader, InstanceReader},
+    };
 
     #[test]
     fn test_compute_one() {
@@ -452,6 +580,31 @@ mod tests {
         assert!(solver.are_credulously_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
 
+    #[test]
+    fn test_disj_credulous_acceptance_in_distinct_components() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        let (status, certificate) = solver
+            .are_credulously_accepted_with_certificate(&[&"a1".to_string(), &"a2".to_string()]);
+        assert!(status);
+        assert_eq!(
+            vec!["a0", "a2"],
+            certificate
+                .unwrap()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert!(!solver.are_credulously_accepted(&[&"a1".to_string()]));
+    }
+
     #[test]
     fn test_disj_skeptical_acceptance() {
         let instance = r#"
@@ -482,4 +635,214 @@ mod tests {
         assert!(!solver.are_skeptically_accepted(&[&"a2".to_string(), &"a4".to_string()]));
         assert!(!solver.are_skeptically_accepted(&[&"a3".to_string(), &"a4".to_string()]));
     }
+
+    #[test]
+    fn test_enumerate_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            let mut labels = ext
+                .iter()
+                .map(|a| a.label().to_string())
+                .collect::<Vec<String>>();
+            labels.sort_unstable();
+            extensions.push(labels);
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(vec![vec!["a0", "a2", "a3"], vec!["a1", "a3"]], extensions);
+    }
+
+    #[test]
+    fn test_count_extensions() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(2_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_count_extensions_no_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(0_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_count_extensions_many_connected_components() {
+        let labels = (0..140).collect::<Vec<usize>>();
+        let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+        (0..70).for_each(|i| {
+            af.new_attack(&(2 * i), &(2 * i + 1)).unwrap();
+            af.new_attack(&(2 * i + 1), &(2 * i)).unwrap();
+        });
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(1_usize) << 70, solver.count_extensions());
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a1".to_string(), &"a3".to_string()]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a1".to_string()]);
+        assert!(!result);
+        assert_eq!(
+            Some(NonExtensionReason::UnattackedArgument(
+                af.argument_set().get_argument(&"a3".to_string()).unwrap()
+            )),
+            reason
+        );
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert!(solver.has_nonempty_extension());
+        let (result, certificate) = solver.has_unique_extension_with_certificate();
+        assert!(result);
+        assert_eq!(1, certificate.len());
+        assert_eq!(2, certificate[0].len());
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension_no_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (false, vec![] as Vec<Vec<&Argument<String>>>),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
+
+    #[test]
+    fn test_labelling() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        let labelling = solver.compute_one_labelling().unwrap();
+        assert!(labelling.undec_arguments().is_empty());
+        assert_eq!(
+            af.n_arguments(),
+            labelling.in_arguments().len() + labelling.out_arguments().len()
+        );
+    }
+
+    #[test]
+    fn test_conj_acceptance() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a1,a3).
+        att(a2,a3).
+        att(a2,a4).
+        att(a3,a2).
+        att(a3,a4).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a2".to_string(), &"a3".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a4".to_string()]));
+        let (status, certificate) = solver
+            .are_all_credulously_accepted_with_certificate(&[&"a0".to_string(), &"a3".to_string()]);
+        assert!(status);
+        let certificate = certificate.unwrap();
+        assert!(["a0", "a3"]
+            .iter()
+            .all(|l| certificate.iter().any(|a| a.label() == l)));
+        assert!(solver.are_all_skeptically_accepted(&[&"a0".to_string()]));
+        assert!(!solver.are_all_skeptically_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        let (status, certificate) = solver
+            .are_all_skeptically_accepted_with_certificate(&[&"a0".to_string(), &"a2".to_string()]);
+        assert!(!status);
+        assert!(certificate.unwrap().iter().all(|a| a.label() != "a2"));
+    }
+
+    #[test]
+    fn test_conj_credulous_acceptance_other_component_without_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StableSemanticsSolver::new(&af);
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string()]));
+    }
 }
@@ -0,0 +1,406 @@
+use crate::{
+    aa::{AAFramework, Argument},
+    sat::{Literal, SatSolver, SatSolverFactoryFn},
+    utils::{self, LabelType},
+};
+
+/// Returns the arguments of a set which are not strongly defended by it.
+///
+/// Strongly defended arguments are the ones that can be added one by one to the empty set,
+/// each argument being defended by the ones added before it.
+/// The set is strongly admissible iff the returned vector is empty.
+pub(crate) fn unjustified_arguments<'a, T>(
+    af: &'a AAFramework<T>,
+    args: &[&'a Argument<T>],
+) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    let n = 1 + af.max_argument_id().unwrap_or_default();
+    let mut in_set = vec![false; n];
+    args.iter().for_each(|arg| in_set[arg.id()] = true);
+    let mut justified = vec![false; n];
+    let mut defeated = vec![false; n];
+    let mut n_undefeated_attackers = vec![0; n];
+    let mut queue = Vec::with_capacity(args.len());
+    args.iter().for_each(|arg| {
+        let cnt = af.iter_attacks_to(arg).count();
+        if cnt == 0 && !justified[arg.id()] {
+            justified[arg.id()] = true;
+            queue.push(*arg);
+        }
+        n_undefeated_attackers[arg.id()] = cnt;
+    });
+    let mut n_processed_args = 0;
+    while n_processed_args < queue.len() {
+        af.iter_attacks_from(queue[n_processed_args])
+            .for_each(|defeating_att| {
+                let attacker = defeating_att.attacked();
+                if defeated[attacker.id()] {
+                    return;
+                }
+                defeated[attacker.id()] = true;
+                af.iter_attacks_from(attacker).for_each(|defeated_att| {
+                    let defended = defeated_att.attacked();
+                    if !in_set[defended.id()] || justified[defended.id()] {
+                        return;
+                    }
+                    n_undefeated_attackers[defended.id()] -= 1;
+                    if n_undefeated_attackers[defended.id()] == 0 {
+                        justified[defended.id()] = true;
+                        queue.push(defended);
+                    }
+                });
+            });
+        n_processed_args += 1;
+    }
+    args.iter()
+        .filter(|arg| !justified[arg.id()])
+        .copied()
+        .collect()
+}
+
+/// Computes a strongly admissible set of minimum size containing the given arguments.
+///
+/// If `conjunctive` is `true`, the set must contain all the arguments;
+/// otherwise, it must contain at least one of them.
+/// Since strongly admissible sets are the subsets of the grounded extension that are strongly defended by themselves,
+/// `None` is returned iff no such set exists (i.e. the arguments are not in the grounded extension).
+///
+/// Computing such a set is NP-hard.
+/// A small strongly admissible set is first built by following the order in which the grounded extension is computed;
+/// then, a SAT solver is repeatedly called to look for strictly smaller sets, until none exists.
+/// The SAT encoding only involves the arguments of the grounded extension that may take part in the defense of the queried ones.
+pub(crate) fn minimum_strongly_admissible_set<'a, T>(
+    af: &'a AAFramework<T>,
+    args: &[&'a Argument<T>],
+    conjunctive: bool,
+    solver_factory: &SatSolverFactoryFn,
+) -> Option<Vec<&'a Argument<T>>>
+where
+    T: LabelType,
+{
+    let ranks = grounded_ranks(af);
+    if conjunctive {
+        minimum_strongly_admissible_set_with_ranks(af, &ranks, args, solver_factory)
+    } else {
+        args.iter()
+            .filter_map(|arg| {
+                minimum_strongly_admissible_set_with_ranks(af, &ranks, &[arg], solver_factory)
+            })
+            .min_by_key(|set| set.len())
+    }
+}
+
+// Returns the positions of the arguments in the grounded extension, as computed by the iterative algorithm.
+//
+// Each argument of the grounded extension is defended by arguments with lower ranks.
+fn grounded_ranks<T>(af: &AAFramework<T>) -> Vec<Option<usize>>
+where
+    T: LabelType,
+{
+    let mut ranks = vec![None; 1 + af.max_argument_id().unwrap_or_default()];
+    utils::grounded_extension(af)
+        .iter()
+        .enumerate()
+        .for_each(|(i, arg)| ranks[arg.id()] = Some(i));
+    ranks
+}
+
+fn minimum_strongly_admissible_set_with_ranks<'a, T>(
+    af: &'a AAFramework<T>,
+    ranks: &[Option<usize>],
+    args: &[&'a Argument<T>],
+    solver_factory: &SatSolverFactoryFn,
+) -> Option<Vec<&'a Argument<T>>>
+where
+    T: LabelType,
+{
+    if args.iter().any(|arg| ranks[arg.id()].is_none()) {
+        return None;
+    }
+    let mut targets: Vec<&Argument<T>> = Vec::with_capacity(args.len());
+    args.iter().for_each(|arg| {
+        if !targets.contains(arg) {
+            targets.push(arg);
+        }
+    });
+    let initial_set = defend_by_lower_ranks(af, ranks, &targets);
+    if initial_set.len() == targets.len() {
+        return Some(initial_set);
+    }
+    let relevant_args = potential_defenders(af, ranks, &targets);
+    let mut solver = solver_factory();
+    let encoding = MinimumSetEncoding::new(relevant_args.len(), initial_set.len());
+    encoding.encode(af, &relevant_args, &targets, solver.as_mut());
+    let mut best = initial_set;
+    while best.len() > targets.len() {
+        let assumption = encoding
+            .at_least_lit(relevant_args.len() - 1, best.len())
+            .negate();
+        match solver.solve_under_assumptions(&[assumption]).unwrap_model() {
+            Some(model) => {
+                best = relevant_args
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| model.value_of(encoding.in_var(*i)) == Some(true))
+                    .map(|(_, arg)| *arg)
+                    .collect()
+            }
+            None => break,
+        }
+    }
+    Some(best)
+}
+
+// Builds a strongly admissible set containing the targets, in which each argument is defended by arguments with lower ranks.
+fn defend_by_lower_ranks<'a, T>(
+    af: &'a AAFramework<T>,
+    ranks: &[Option<usize>],
+    targets: &[&'a Argument<T>],
+) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    let mut in_set = vec![false; ranks.len()];
+    let mut set = targets.to_vec();
+    set.iter().for_each(|arg| in_set[arg.id()] = true);
+    let mut n_processed_args = 0;
+    while n_processed_args < set.len() {
+        let arg = set[n_processed_args];
+        let arg_rank = ranks[arg.id()].unwrap();
+        af.iter_attacks_to(arg).for_each(|att| {
+            let defenders = af
+                .iter_attacks_to(att.attacker())
+                .filter_map(|defending_att| {
+                    let defender = defending_att.attacker();
+                    ranks[defender.id()]
+                        .filter(|r| *r < arg_rank)
+                        .map(|r| (r, defender))
+                })
+                .collect::<Vec<(usize, &Argument<T>)>>();
+            if defenders.iter().any(|(_, d)| in_set[d.id()]) {
+                return;
+            }
+            let (_, defender) = *defenders.iter().min_by_key(|(r, _)| *r).unwrap();
+            in_set[defender.id()] = true;
+            set.push(defender);
+        });
+        n_processed_args += 1;
+    }
+    set
+}
+
+// Returns the arguments of the grounded extension that may be involved in the defense of the targets (including them).
+fn potential_defenders<'a, T>(
+    af: &'a AAFramework<T>,
+    ranks: &[Option<usize>],
+    targets: &[&'a Argument<T>],
+) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    let mut in_set = vec![false; ranks.len()];
+    let mut set = targets.to_vec();
+    set.iter().for_each(|arg| in_set[arg.id()] = true);
+    let mut n_processed_args = 0;
+    while n_processed_args < set.len() {
+        af.iter_attacks_to(set[n_processed_args]).for_each(|att| {
+            af.iter_attacks_to(att.attacker())
+                .for_each(|defending_att| {
+                    let defender = defending_att.attacker();
+                    if ranks[defender.id()].is_some() && !in_set[defender.id()] {
+                        in_set[defender.id()] = true;
+                        set.push(defender);
+                    }
+                })
+        });
+        n_processed_args += 1;
+    }
+    set
+}
+
+// The encoding of strongly admissible sets with a bounded number of defense steps.
+//
+// For each argument, a variable indicates if it is in the set,
+// and a variable per step indicates if the argument is strongly defended after this step.
+// A unary counter is used to bound the size of the set.
+struct MinimumSetEncoding {
+    n_args: usize,
+    n_steps: usize,
+}
+
+impl MinimumSetEncoding {
+    fn new(n_args: usize, n_steps: usize) -> Self {
+        Self { n_args, n_steps }
+    }
+
+    fn encode<T>(
+        &self,
+        af: &AAFramework<T>,
+        args: &[&Argument<T>],
+        targets: &[&Argument<T>],
+        solver: &mut dyn SatSolver,
+    ) where
+        T: LabelType,
+    {
+        solver.reserve(self.n_args * (1 + 2 * self.n_steps));
+        let mut local_ids = vec![None; 1 + af.max_argument_id().unwrap_or_default()];
+        args.iter()
+            .enumerate()
+            .for_each(|(i, arg)| local_ids[arg.id()] = Some(i));
+        args.iter().enumerate().for_each(|(i, arg)| {
+            solver.add_clause(vec![
+                self.in_lit(i).negate(),
+                self.defended_lit(i, self.n_steps),
+            ]);
+            for step in 1..=self.n_steps {
+                solver.add_clause(vec![self.defended_lit(i, step).negate(), self.in_lit(i)]);
+            }
+            af.iter_attacks_to(arg).for_each(|att| {
+                let defenders = af
+                    .iter_attacks_to(att.attacker())
+                    .filter_map(|defending_att| local_ids[defending_att.attacker().id()])
+                    .collect::<Vec<usize>>();
+                for step in 1..=self.n_steps {
+                    let mut cl = Vec::with_capacity(1 + defenders.len());
+                    cl.push(self.defended_lit(i, step).negate());
+                    if step > 1 {
+                        defenders
+                            .iter()
+                            .for_each(|d| cl.push(self.defended_lit(*d, step - 1)));
+                    }
+                    solver.add_clause(cl);
+                }
+            });
+        });
+        targets
+            .iter()
+            .for_each(|arg| solver.add_clause(vec![self.in_lit(local_ids[arg.id()].unwrap())]));
+        for i in 0..self.n_args {
+            solver.add_clause(vec![self.in_lit(i).negate(), self.at_least_lit(i, 1)]);
+            if i == 0 {
+                continue;
+            }
+            for k in 1..=self.n_steps {
+                solver.add_clause(vec![
+                    self.at_least_lit(i - 1, k).negate(),
+                    self.at_least_lit(i, k),
+                ]);
+                if k > 1 {
+                    solver.add_clause(vec![
+                        self.in_lit(i).negate(),
+                        self.at_least_lit(i - 1, k - 1).negate(),
+                        self.at_least_lit(i, k),
+                    ]);
+                }
+            }
+        }
+    }
+
+    fn in_var(&self, i: usize) -> usize {
+        1 + i
+    }
+
+    fn in_lit(&self, i: usize) -> Literal {
+        Literal::from(self.in_var(i) as isize)
+    }
+
+    // The literal indicating that the argument is strongly defended within the given number of steps.
+    fn defended_lit(&self, i: usize, step: usize) -> Literal {
+        Literal::from((1 + self.n_args + i * self.n_steps + step - 1) as isize)
+    }
+
+    // The literal indicating that at least k arguments among the i+1 first ones are in the set.
+    fn at_least_lit(&self, i: usize, k: usize) -> Literal {
+        Literal::from((1 + self.n_args * (1 + self.n_steps) + i * self.n_steps + k - 1) as isize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::{AspartixReader, InstanceReader},
+        sat,
+    };
+
+    fn labels<'a>(args: &[&'a Argument<String>]) -> Vec<&'a str> {
+        let mut labels = args
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        labels.sort_unstable();
+        labels
+    }
+
+    #[test]
+    fn test_unjustified_arguments() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let arg = |l: &str| af.argument_set().get_argument(&l.to_string()).unwrap();
+        assert!(unjustified_arguments(&af, &[arg("a0"), arg("a2")]).is_empty());
+        assert_eq!(
+            vec!["a2"],
+            labels(&unjustified_arguments(&af, &[arg("a2")]))
+        );
+        assert!(unjustified_arguments(&af, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_minimum_set_needs_sat_solver() {
+        let instance = r#"
+        arg(u).
+        arg(v).
+        arg(w).
+        arg(b1).
+        arg(b2).
+        arg(t).
+        att(u,b1).
+        att(w,b1).
+        att(v,b2).
+        att(w,b2).
+        att(b1,t).
+        att(b2,t).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let arg = |l: &str| af.argument_set().get_argument(&l.to_string()).unwrap();
+        let ranks = grounded_ranks(&af);
+        assert_eq!(
+            vec!["t", "u", "v"],
+            labels(&defend_by_lower_ranks(&af, &ranks, &[arg("t")]))
+        );
+        let factory = || sat::default_solver();
+        assert_eq!(
+            vec!["t", "w"],
+            labels(&minimum_strongly_admissible_set(&af, &[arg("t")], true, &factory).unwrap())
+        );
+        assert_eq!(
+            vec!["u"],
+            labels(
+                &minimum_strongly_admissible_set(&af, &[arg("t"), arg("u")], false, &factory)
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            vec!["t", "w"],
+            labels(
+                &minimum_strongly_admissible_set(&af, &[arg("t"), arg("w")], true, &factory)
+                    .unwrap()
+            )
+        );
+        assert!(minimum_strongly_admissible_set(&af, &[arg("b1")], true, &factory).is_none());
+        assert!(
+            minimum_strongly_admissible_set(&af, &[arg("t"), arg("b1")], true, &factory).is_none()
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use super::{
+    extension_enumeration, extension_verification::CandidateExtension, strong_admissibility,
+    CredulousAcceptanceComputer, ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker,
+    NonExtensionReason, SingleExtensionComputer, SingleLabellingComputer,
+    SkepticalAcceptanceComputer, UniqueExtensionChecker, VerifyExtension,
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
+    sat::{self, SatSolverFactoryFn},
+    utils::{self, LabelType},
+};
+use num_bigint::BigUint;
+
+/// A solver used to solve queries for the strongly admissible semantics.
+///
+/// A set of arguments is strongly admissible if its arguments can be added one by one to the empty set,
+/// each argument being defended by the ones added before it.
+/// The strongly admissible sets are thus the subsets of the grounded extension that do not rely on cycles of defenses,
+/// and the grounded extension is the largest of them.
+///
+/// Computing an extension returns the grounded extension.
+/// An argument is credulously accepted iff it belongs to the grounded extension;
+/// in this case, the certificate is a strongly admissible set of minimum size containing it, computed with the help of a SAT solver.
+/// Since the empty set is always strongly admissible, no argument is skeptically accepted.
+///
+/// Extensions are enumerated by filtering the admissible subsets of the grounded extension, connected component by connected component.
+/// Checking whether a set of arguments is an extension ([VerifyExtension]) is done in polynomial time,
+/// as well as checking the existence of a non-empty extension ([NonEmptyExtensionChecker])
+/// and the uniqueness of the extension ([UniqueExtensionChecker]).
+pub struct StronglyAdmissibleSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+}
+
+impl<'a, T> StronglyAdmissibleSemanticsSolver<'a, T>
+where
+    T: LabelType,
+{
+    /// Builds a new solver dedicated to the strongly admissible semantics.
+    ///
+    /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::solvers::{SingleExtensionComputer, StronglyAdmissibleSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = StronglyAdmissibleSemanticsSolver::new(af);
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found an extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new(af: &'a AAFramework<T>) -> Self {
+        Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+    }
+
+    /// Builds a new solver dedicated to the strongly admissible semantics.
+    ///
+    /// The SAT solver to use in given through the solver factory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat::CadicalSolver;
+    /// # use crustabri::solvers::{SingleExtensionComputer, StronglyAdmissibleSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = StronglyAdmissibleSemanticsSolver::new_with_sat_solver_factory(
+    ///         af,
+    ///         Box::new(|| Box::new(CadicalSolver::default()))
+    ///     );
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found an extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new_with_sat_solver_factory(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+    ) -> Self {
+        Self::new_with_sat_solver_factory_and_constraints_encoder(
+            af,
+            solver_factory,
+            Box::new(aux_var_constraints_encoder::new_for_admissibility()),
+        )
+    }
+
+    /// Builds a new solver dedicated to the strongly admissible semantics.
+    ///
+    /// The SAT solver to use in given through the solver factory.
+    /// The constraints encoder must encode admissibility; it is used to enumerate the extensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crustabri::aa::{AAFramework};
+    /// # use crustabri::utils::LabelType;
+    /// # use crustabri::sat;
+    /// # use crustabri::encodings::aux_var_constraints_encoder;
+    /// # use crustabri::solvers::{SingleExtensionComputer, StronglyAdmissibleSemanticsSolver};
+    /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+    ///     let mut solver = StronglyAdmissibleSemanticsSolver::new_with_sat_solver_factory_and_constraints_encoder(
+    ///         af,
+    ///         Box::new(|| sat::default_solver()),
+    ///         Box::new(aux_var_constraints_encoder::new_for_admissibility()),
+    ///     );
+    ///     let ext = solver.compute_one_extension().unwrap();
+    ///     println!("found an extension: {:?}", ext);
+    /// }
+    /// # search_one_extension::<usize>(&AAFramework::default());
+    /// ```
+    pub fn new_with_sat_solver_factory_and_constraints_encoder(
+        af: &'a AAFramework<T>,
+        solver_factory: Box<SatSolverFactoryFn>,
+        constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+    ) -> Self {
+        Self {
+            af,
+            solver_factory,
+            constraints_encoder,
+        }
+    }
+
+    fn enumerate_extensions_in_cc(
+        &self,
+        cc_af: &AAFramework<T>,
+        callback: &mut dyn FnMut(&[&Argument<T>]) -> bool,
+    ) {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(cc_af, solver.as_mut());
+        let mut in_grounded = vec![false; 1 + cc_af.max_argument_id().unwrap_or_default()];
+        utils::grounded_extension(cc_af)
+            .iter()
+            .for_each(|arg| in_grounded[arg.id()] = true);
+        cc_af
+            .argument_set()
+            .iter()
+            .filter(|arg| !in_grounded[arg.id()])
+            .for_each(|arg| {
+                solver.add_clause(vec![self.constraints_encoder.arg_to_lit(arg).negate()])
+            });
+        extension_enumeration::enumerate_extensions(
+            cc_af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+            &mut |ext| {
+                if strong_admissibility::unjustified_arguments(cc_af, ext).is_empty() {
+                    callback(ext)
+                } else {
+                    true
+                }
+            },
+        );
+    }
+
+    fn check_credulous_acceptance_with_certificate(
+        &mut self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Argument<T>>>();
+        match strong_admissibility::minimum_strongly_admissible_set(
+            self.af,
+            &args,
+            conjunctive,
+            self.solver_factory.as_ref(),
+        ) {
+            Some(set) => (true, Some(set)),
+            None => (false, None),
+        }
+    }
+}
+
+impl<T> SingleExtensionComputer<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+        Some(self.af.grounded_extension())
+    }
+}
+
+impl<T> SingleLabellingComputer<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+        let af = self.af;
+        self.compute_one_extension()
+            .map(|ext| Labelling::new_from_extension(af, &ext))
+    }
+}
+
+impl<T> ExtensionEnumerator<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+        extension_enumeration::enumerate_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+            callback,
+        )
+    }
+}
+
+impl<T> ExtensionCounter<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn count_extensions(&mut self) -> BigUint {
+        extension_enumeration::count_extensions_by_connected_components(
+            self.af,
+            &mut |cc_af, cc_callback| self.enumerate_extensions_in_cc(cc_af, cc_callback),
+        )
+    }
+}
+
+impl<T> NonEmptyExtensionChecker<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_nonempty_extension_with_certificate(&mut self) -> (bool, Option<Vec<&Argument<T>>>) {
+        match self
+            .af
+            .argument_set()
+            .iter()
+            .find(|arg| self.af.iter_attacks_to(arg).next().is_none())
+        {
+            Some(arg) => (true, Some(vec![arg])),
+            None => (false, None),
+        }
+    }
+}
+
+impl<T> UniqueExtensionChecker<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+        match self.has_nonempty_extension_with_certificate() {
+            (true, Some(ext)) => (false, vec![vec![], ext]),
+            _ => (true, vec![vec![]]),
+        }
+    }
+}
+
+impl<T> VerifyExtension<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn verify_extension_with_reason(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+        let reason = CandidateExtension::new(self.af, args).check_strong_admissibility();
+        (reason.is_none(), reason)
+    }
+}
+
+impl<T> CredulousAcceptanceComputer<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        let grounded = self.af.grounded_extension();
+        args.iter()
+            .any(|a| grounded.contains(&self.af.argument_set().get_argument(a).unwrap()))
+    }
+
+    fn are_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance_with_certificate(args, false)
+    }
+
+    fn are_all_credulously_accepted(&mut self, args: &[&T]) -> bool {
+        let grounded = self.af.grounded_extension();
+        args.iter()
+            .all(|a| grounded.contains(&self.af.argument_set().get_argument(a).unwrap()))
+    }
+
+    fn are_all_credulously_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        self.check_credulous_acceptance_with_certificate(args, true)
+    }
+}
+
+impl<T> SkepticalAcceptanceComputer<T> for StronglyAdmissibleSemanticsSolver<'_, T>
+where
+    T: LabelType,
+{
+    fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+        self.are_skeptically_accepted_with_certificate(args).0
+    }
+
+    fn are_skeptically_accepted_with_certificate(
+        &mut self,
+        args: &[&T],
+    ) -> (bool, Option<Vec<&Argument<T>>>) {
+        args.iter().for_each(|a| {
+            self.af.argument_set().get_argument(a).unwrap();
+        });
+        (false, Some(vec![]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn sorted_labels(ext: &[&Argument<String>]) -> Vec<String> {
+        let mut labels = ext
+            .iter()
+            .map(|a| a.label().to_string())
+            .collect::<Vec<String>>();
+        labels.sort_unstable();
+        labels
+    }
+
+    const INSTANCE: &str = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a1).
+        att(a3,a4).
+        att(a4,a3).
+        "#;
+
+    #[test]
+    fn test_compute_one_extension() {
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut INSTANCE.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        assert_eq!(
+            vec!["a0", "a2"],
+            sorted_labels(&solver.compute_one_extension().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_enumerate_and_count_extensions() {
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut INSTANCE.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        let mut extensions = vec![];
+        solver.enumerate_extensions(&mut |ext| {
+            extensions.push(sorted_labels(ext));
+            true
+        });
+        extensions.sort_unstable();
+        assert_eq!(
+            vec![
+                vec![],
+                vec!["a0".to_string()],
+                vec!["a0".to_string(), "a2".to_string()]
+            ],
+            extensions
+        );
+        assert_eq!(BigUint::from(3_usize), solver.count_extensions());
+    }
+
+    #[test]
+    fn test_acceptance() {
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut INSTANCE.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        assert!(solver.is_credulously_accepted(&"a2".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a3".to_string()));
+        assert_eq!(
+            vec!["a0", "a2"],
+            sorted_labels(
+                &solver
+                    .is_credulously_accepted_with_certificate(&"a2".to_string())
+                    .1
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            (false, None),
+            solver.is_credulously_accepted_with_certificate(&"a3".to_string())
+        );
+        assert!(solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(!solver.are_all_credulously_accepted(&[&"a0".to_string(), &"a3".to_string()]));
+        assert_eq!(
+            (false, Some(vec![])),
+            solver.is_skeptically_accepted_with_certificate(&"a0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_verify_extension() {
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut INSTANCE.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        assert!(solver.verify_extension(&[&"a0".to_string(), &"a2".to_string()]));
+        assert!(solver.verify_extension(&[]));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a2".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::CircularDefense(_))
+        ));
+        let (result, reason) = solver.verify_extension_with_reason(&[&"a3".to_string()]);
+        assert!(!result);
+        assert!(matches!(
+            reason,
+            Some(NonExtensionReason::CircularDefense(_))
+        ));
+    }
+
+    #[test]
+    fn test_nonempty_and_unique_extension() {
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut INSTANCE.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        assert_eq!(
+            vec!["a0"],
+            sorted_labels(&solver.has_nonempty_extension_with_certificate().1.unwrap())
+        );
+        assert!(!solver.has_unique_extension());
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        "#;
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = StronglyAdmissibleSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        assert_eq!(
+            (true, vec![vec![]]),
+            solver.has_unique_extension_with_certificate()
+        );
+    }
+}
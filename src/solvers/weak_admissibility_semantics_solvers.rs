@@ -0,0 +1,910 @@
+use super::{
+    extension_enumeration, extension_verification::CandidateExtension, CredulousAcceptanceComputer,
+    ExtensionCounter, ExtensionEnumerator, NonEmptyExtensionChecker, NonExtensionReason,
+    SingleExtensionComputer, SingleLabellingComputer, SkepticalAcceptanceComputer,
+    UniqueExtensionChecker, VerifyExtension,
+};
+use crate::{
+    aa::{AAFramework, Argument, Labelling},
+    encodings::{aux_var_constraints_encoder, ConstraintsEncoder},
+    sat::{self, Literal, SatSolverFactoryFn},
+    utils::{Label, LabelType},
+};
+use num_bigint::BigUint;
+
+/// The semantics based on weak admissibility.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WeakSemantics {
+    Admissible,
+    Preferred,
+    Complete,
+    Grounded,
+}
+
+macro_rules! weak_admissibility_solver {
+    ($solver_ident:ident, $sem_name:literal, $weak_semantics:expr, $sem_doc:literal) => {
+        #[doc = concat!(" A SAT-based solver for the ", $sem_name, " semantics.")]
+        ///
+        /// The semantics based on weak admissibility were introduced by Baumann, Brewka and Ulbricht.
+        /// They rely on the reduct of an AF wrt. a set of arguments (see [AAFramework::reduct]).
+        /// A set of arguments is weakly admissible if it is conflict-free and none of its attackers belongs to a weakly admissible set of the reduct wrt. the set.
+        /// A set `E` weakly defends a set `X` if each attacker of `X` is either attacked by `E`,
+        /// or is neither in `E` nor in a weakly admissible set of the reduct wrt. `E`, in which case `X` must be included in a weakly admissible set.
+        ///
+        #[doc = $sem_doc]
+        ///
+        /// Since the reasoning problems for these semantics are PSPACE-complete, this solver relies on recursive enumerations of conflict-free sets,
+        /// each of them being computed by a SAT solver.
+        /// Admissible sets being weakly admissible, a SAT call looking for an admissible set is made before each of these enumerations.
+        /// This solver is thus intended for small AFs.
+        ///
+        /// The certificates for the acceptance queries are extensions.
+        /// When checking a set of arguments with [VerifyExtension],
+        /// an argument of the set and an attacker belonging to a weakly admissible set of the reduct are given by a [NonExtensionReason::UndefendedArgument] reason.
+        pub struct $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            helper: WeakAdmissibilityHelper<'a, T>,
+        }
+
+        impl<'a, T> $solver_ident<'a, T>
+        where
+            T: LabelType,
+        {
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The underlying SAT solver is one returned by [default_solver](crate::sat::default_solver).
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new(af);")]
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new(af: &'a AAFramework<T>) -> Self
+            where
+                T: LabelType,
+            {
+                Self::new_with_sat_solver_factory(af, Box::new(|| sat::default_solver()))
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat::CadicalSolver;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory(")]
+            ///         af,
+            ///         Box::new(|| Box::new(CadicalSolver::default()))
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self::new_with_sat_solver_factory_and_constraints_encoder(
+                    af,
+                    solver_factory,
+                    Box::new(aux_var_constraints_encoder::new_for_conflict_freeness()),
+                )
+            }
+
+            /// Builds a new SAT based solver for this semantics.
+            ///
+            /// The SAT solver to use in given through the solver factory.
+            /// The constraints encoder must be an encoder for conflict-freeness.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use crustabri::aa::{AAFramework};
+            /// # use crustabri::utils::LabelType;
+            /// # use crustabri::sat;
+            /// # use crustabri::encodings;
+            #[doc = concat!(" # use crustabri::solvers::{SingleExtensionComputer, ", stringify!($solver_ident), "};")]
+            /// fn search_one_extension<T>(af: &AAFramework<T>) where T: LabelType {
+            #[doc = concat!("     let mut solver = ", stringify!($solver_ident), "::new_with_sat_solver_factory_and_constraints_encoder(")]
+            ///         af,
+            ///         Box::new(|| sat::default_solver()),
+            ///         encodings::new_default_conflict_freeness_encoder(),
+            ///     );
+            ///     let ext = solver.compute_one_extension().unwrap();
+            #[doc = concat!("     println!(\"found a ", $sem_name, " extension: {:?}\", ext);")]
+            /// }
+            /// # search_one_extension::<usize>(&AAFramework::default());
+            /// ```
+            pub fn new_with_sat_solver_factory_and_constraints_encoder(
+                af: &'a AAFramework<T>,
+                solver_factory: Box<SatSolverFactoryFn>,
+                constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+            ) -> Self
+            where
+                T: LabelType,
+            {
+                Self {
+                    helper: WeakAdmissibilityHelper {
+                        af,
+                        solver_factory,
+                        constraints_encoder,
+                        admissibility_encoder: Box::new(
+                            aux_var_constraints_encoder::new_for_admissibility(),
+                        ),
+                        semantics: $weak_semantics,
+                    },
+                }
+            }
+        }
+
+        impl<T> SingleExtensionComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_extension(&mut self) -> Option<Vec<&Argument<T>>> {
+                self.helper.compute_one_extension()
+            }
+        }
+
+        impl<T> SingleLabellingComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn compute_one_labelling(&mut self) -> Option<Labelling<'_, T>> {
+                let af = self.helper.af;
+                self.compute_one_extension()
+                    .map(|ext| Labelling::new_from_extension(af, &ext))
+            }
+        }
+
+        impl<T> ExtensionEnumerator<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn enumerate_extensions(&mut self, callback: &mut dyn FnMut(&[&Argument<T>]) -> bool) {
+                self.helper.enumerate_extensions(callback)
+            }
+        }
+
+        impl<T> ExtensionCounter<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn count_extensions(&mut self) -> BigUint {
+                let mut n_extensions = BigUint::from(0_usize);
+                self.helper.enumerate_extensions(&mut |_| {
+                    n_extensions += 1_usize;
+                    true
+                });
+                n_extensions
+            }
+        }
+
+        impl<T> NonEmptyExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_nonempty_extension_with_certificate(
+                &mut self,
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.has_nonempty_extension_with_certificate()
+            }
+        }
+
+        impl<T> UniqueExtensionChecker<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn has_unique_extension_with_certificate(&mut self) -> (bool, Vec<Vec<&Argument<T>>>) {
+                let mut extensions = Vec::with_capacity(2);
+                self.helper.enumerate_extensions(&mut |ext| {
+                    extensions.push(ext.to_vec());
+                    extensions.len() < 2
+                });
+                (extensions.len() == 1, extensions)
+            }
+        }
+
+        impl<T> VerifyExtension<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn verify_extension_with_reason(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<NonExtensionReason<'_, T>>) {
+                let candidate = CandidateExtension::new(self.helper.af, args);
+                let reason = candidate
+                    .check_conflict_freeness()
+                    .or_else(|| self.helper.check_extension(candidate.args()));
+                (reason.is_none(), reason)
+            }
+        }
+
+        impl<T> CredulousAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_credulously_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_credulous_acceptance(args, false).0
+            }
+
+            fn are_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_credulous_acceptance(args, false)
+            }
+
+            fn are_all_credulously_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_credulous_acceptance(args, true)
+            }
+        }
+
+        impl<T> SkepticalAcceptanceComputer<T> for $solver_ident<'_, T>
+        where
+            T: LabelType,
+        {
+            fn are_skeptically_accepted(&mut self, args: &[&T]) -> bool {
+                self.helper.check_skeptical_acceptance(args).0
+            }
+
+            fn are_skeptically_accepted_with_certificate(
+                &mut self,
+                args: &[&T],
+            ) -> (bool, Option<Vec<&Argument<T>>>) {
+                self.helper.check_skeptical_acceptance(args)
+            }
+        }
+    };
+}
+
+weak_admissibility_solver!(
+    WeaklyAdmissibleSemanticsSolver,
+    "weakly admissible",
+    WeakSemantics::Admissible,
+    " The extensions are the weakly admissible sets. Since the admissible sets are weakly admissible, the extension computed by [SingleExtensionComputer] is the grounded extension."
+);
+
+weak_admissibility_solver!(
+    WeaklyPreferredSemanticsSolver,
+    "weakly preferred",
+    WeakSemantics::Preferred,
+    " The extensions are the maximal (wrt. set inclusion) weakly admissible sets."
+);
+
+weak_admissibility_solver!(
+    WeaklyCompleteSemanticsSolver,
+    "weakly complete",
+    WeakSemantics::Complete,
+    " The extensions are the weakly admissible sets `E` such that no strict superset of `E` is weakly defended by `E`. Since the weakly preferred extensions are weakly complete, they are used as certificates for credulous acceptance."
+);
+
+weak_admissibility_solver!(
+    WeaklyGroundedSemanticsSolver,
+    "weakly grounded",
+    WeakSemantics::Grounded,
+    " The extensions are the minimal (wrt. set inclusion) weakly complete extensions. Contrary to the grounded semantics, this semantics may admit several extensions."
+);
+
+struct WeakAdmissibilityHelper<'a, T>
+where
+    T: LabelType,
+{
+    af: &'a AAFramework<T>,
+    solver_factory: Box<SatSolverFactoryFn>,
+    constraints_encoder: Box<dyn ConstraintsEncoder<T>>,
+    admissibility_encoder: Box<dyn ConstraintsEncoder<T>>,
+    semantics: WeakSemantics,
+}
+
+impl<'a, T> WeakAdmissibilityHelper<'a, T>
+where
+    T: LabelType,
+{
+    fn compute_one_extension(&self) -> Option<Vec<&'a Argument<T>>> {
+        match self.semantics {
+            WeakSemantics::Admissible => Some(self.af.grounded_extension()),
+            WeakSemantics::Preferred | WeakSemantics::Complete => {
+                Some(self.extend_to_maximal(self.af, self.af.grounded_extension()))
+            }
+            WeakSemantics::Grounded => {
+                let mut ext = None;
+                self.enumerate_extensions(&mut |e| {
+                    ext = Some(e.to_vec());
+                    false
+                });
+                ext
+            }
+        }
+    }
+
+    fn enumerate_extensions(&self, callback: &mut dyn FnMut(&[&'a Argument<T>]) -> bool) {
+        match self.semantics {
+            WeakSemantics::Admissible => {
+                self.enumerate_weakly_admissible_sets(self.af, &[], callback);
+            }
+            WeakSemantics::Preferred => {
+                let mut sets = Vec::new();
+                self.enumerate_weakly_admissible_sets(self.af, &[], &mut |set| {
+                    sets.push(set.to_vec());
+                    true
+                });
+                for set in sets.iter() {
+                    if !sets.iter().any(|other| is_strict_subset(set, other)) && !callback(set) {
+                        break;
+                    }
+                }
+            }
+            WeakSemantics::Complete => {
+                self.enumerate_weakly_admissible_sets(self.af, &[], &mut |set| {
+                    self.find_weakly_defended_argument(self.af, set).is_some() || callback(set)
+                });
+            }
+            WeakSemantics::Grounded => {
+                let mut sets = Vec::new();
+                self.enumerate_weakly_admissible_sets(self.af, &[], &mut |set| {
+                    if self.find_weakly_defended_argument(self.af, set).is_none() {
+                        sets.push(set.to_vec());
+                    }
+                    true
+                });
+                for set in sets.iter() {
+                    if !sets.iter().any(|other| is_strict_subset(other, set)) && !callback(set) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn has_nonempty_extension_with_certificate(&self) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        if self.semantics == WeakSemantics::Grounded {
+            let mut certificate = None;
+            self.enumerate_extensions(&mut |ext| {
+                if !ext.is_empty() {
+                    certificate = Some(ext.to_vec());
+                }
+                certificate.is_none()
+            });
+            return (certificate.is_some(), certificate);
+        }
+        let args = self.af.argument_set().iter().collect::<Vec<&Argument<T>>>();
+        match self.find_weakly_admissible_set(self.af, &args, false) {
+            Some(set) if self.semantics == WeakSemantics::Admissible => (true, Some(set)),
+            Some(set) => (true, Some(self.extend_to_maximal(self.af, set))),
+            None => (false, None),
+        }
+    }
+
+    // Checks that a conflict-free set is an extension.
+    fn check_extension(&self, set: &[&'a Argument<T>]) -> Option<NonExtensionReason<'a, T>> {
+        if let Some((arg, attacker)) = self.find_weakly_undefended_argument(self.af, set) {
+            return Some(NonExtensionReason::UndefendedArgument(arg, attacker));
+        }
+        match self.semantics {
+            WeakSemantics::Admissible => None,
+            WeakSemantics::Preferred => self
+                .find_larger_weakly_admissible_set(self.af, set)
+                .map(NonExtensionReason::LargerExtension),
+            WeakSemantics::Complete => self
+                .find_weakly_defended_argument(self.af, set)
+                .map(NonExtensionReason::MissingDefendedArgument),
+            WeakSemantics::Grounded => {
+                if let Some(arg) = self.find_weakly_defended_argument(self.af, set) {
+                    return Some(NonExtensionReason::MissingDefendedArgument(arg));
+                }
+                let mut in_set = vec![false; 1 + self.af.max_argument_id().unwrap_or_default()];
+                set.iter().for_each(|a| in_set[a.id()] = true);
+                let mut clauses = self
+                    .af
+                    .argument_set()
+                    .iter()
+                    .filter(|a| !in_set[a.id()])
+                    .map(|a| vec![self.constraints_encoder.arg_to_lit(a).negate()])
+                    .collect::<Vec<Vec<Literal>>>();
+                clauses.push(
+                    set.iter()
+                        .map(|a| self.constraints_encoder.arg_to_lit(a).negate())
+                        .collect(),
+                );
+                let mut smaller = None;
+                self.enumerate_conflict_free_sets(self.af, &clauses, &mut |subset| {
+                    if self
+                        .find_weakly_undefended_argument(self.af, subset)
+                        .is_none()
+                        && self
+                            .find_weakly_defended_argument(self.af, subset)
+                            .is_none()
+                    {
+                        smaller = Some(subset.to_vec());
+                    }
+                    smaller.is_none()
+                });
+                smaller.map(NonExtensionReason::SmallerExtension)
+            }
+        }
+    }
+
+    fn check_credulous_acceptance(
+        &self,
+        args: &[&T],
+        conjunctive: bool,
+    ) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        let certificate = match self.semantics {
+            WeakSemantics::Admissible => {
+                self.find_weakly_admissible_set(self.af, &args, conjunctive)
+            }
+            WeakSemantics::Preferred | WeakSemantics::Complete => self
+                .find_weakly_admissible_set(self.af, &args, conjunctive)
+                .map(|set| self.extend_to_maximal(self.af, set)),
+            WeakSemantics::Grounded => {
+                let mut certificate = None;
+                self.enumerate_extensions(&mut |ext| {
+                    let is_answer = if conjunctive {
+                        args.iter().all(|a| ext.contains(a))
+                    } else {
+                        args.iter().any(|a| ext.contains(a))
+                    };
+                    if is_answer {
+                        certificate = Some(ext.to_vec());
+                    }
+                    !is_answer
+                });
+                certificate
+            }
+        };
+        (certificate.is_some(), certificate)
+    }
+
+    fn check_skeptical_acceptance(&self, args: &[&T]) -> (bool, Option<Vec<&'a Argument<T>>>) {
+        let args = args
+            .iter()
+            .map(|a| self.af.argument_set().get_argument(a).unwrap())
+            .collect::<Vec<&Label<T>>>();
+        if self.semantics == WeakSemantics::Admissible {
+            return (false, Some(vec![]));
+        }
+        let mut certificate = None;
+        self.enumerate_extensions(&mut |ext| {
+            if args.iter().all(|a| !ext.contains(a)) {
+                certificate = Some(ext.to_vec());
+            }
+            certificate.is_none()
+        });
+        (certificate.is_none(), certificate)
+    }
+
+    // Enumerates the weakly admissible sets of an AF including the provided arguments.
+    fn enumerate_weakly_admissible_sets<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        args: &[&'b Argument<T>],
+        callback: &mut dyn FnMut(&[&'b Argument<T>]) -> bool,
+    ) {
+        let clauses = args
+            .iter()
+            .map(|a| vec![self.constraints_encoder.arg_to_lit(a)])
+            .collect::<Vec<Vec<Literal>>>();
+        self.enumerate_conflict_free_sets(af, &clauses, &mut |set| {
+            self.find_weakly_undefended_argument(af, set).is_some() || callback(set)
+        });
+    }
+
+    // Enumerates the conflict-free sets of an AF satisfying additional clauses.
+    fn enumerate_conflict_free_sets<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        clauses: &[Vec<Literal>],
+        callback: &mut dyn FnMut(&[&'b Argument<T>]) -> bool,
+    ) {
+        let mut solver = (self.solver_factory)();
+        self.constraints_encoder
+            .encode_constraints(af, solver.as_mut());
+        clauses.iter().for_each(|cl| solver.add_clause(cl.to_vec()));
+        extension_enumeration::enumerate_extensions(
+            af,
+            solver.as_mut(),
+            self.constraints_encoder.as_ref(),
+            &mut |set| {
+                callback(
+                    &set.iter()
+                        .map(|a| af.argument_set().get_argument_by_id(a.id()))
+                        .collect::<Vec<&'b Argument<T>>>(),
+                )
+            },
+        );
+    }
+
+    // Looks for a weakly admissible set containing one of the provided arguments (or all of them if conjunctive is set).
+    fn find_weakly_admissible_set<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        args: &[&'b Argument<T>],
+        conjunctive: bool,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        if args.is_empty() {
+            return if conjunctive { Some(vec![]) } else { None };
+        }
+        if let Some(set) = self.find_admissible_set(af, args, conjunctive) {
+            return Some(set);
+        }
+        let clauses = if conjunctive {
+            args.iter()
+                .map(|a| vec![self.constraints_encoder.arg_to_lit(a)])
+                .collect()
+        } else {
+            vec![args
+                .iter()
+                .map(|a| self.constraints_encoder.arg_to_lit(a))
+                .collect()]
+        };
+        let mut result = None;
+        self.enumerate_conflict_free_sets(af, &clauses, &mut |set| {
+            if self.find_weakly_undefended_argument(af, set).is_none() {
+                result = Some(set.to_vec());
+            }
+            result.is_none()
+        });
+        result
+    }
+
+    fn find_admissible_set<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        args: &[&'b Argument<T>],
+        conjunctive: bool,
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let mut solver = (self.solver_factory)();
+        self.admissibility_encoder
+            .encode_constraints(af, solver.as_mut());
+        let lits = args
+            .iter()
+            .map(|a| self.admissibility_encoder.arg_to_lit(a));
+        if conjunctive {
+            lits.for_each(|l| solver.add_clause(vec![l]));
+        } else {
+            solver.add_clause(lits.collect());
+        }
+        solver.solve().unwrap_model().map(|model| {
+            self.admissibility_encoder
+                .assignment_to_extension(&model, af)
+        })
+    }
+
+    // Returns an argument of a conflict-free set and one of its attackers that belongs to a weakly admissible set of the reduct, if any.
+    fn find_weakly_undefended_argument<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        set: &[&'b Argument<T>],
+    ) -> Option<(&'b Argument<T>, &'b Argument<T>)> {
+        let reduct = af.reduct(set);
+        let mut checked = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        for arg in set {
+            for att in af.iter_attacks_to(arg) {
+                let attacker = att.attacker();
+                if checked[attacker.id()] {
+                    continue;
+                }
+                checked[attacker.id()] = true;
+                if let Ok(reduct_attacker) = reduct.argument_set().get_argument(attacker.label()) {
+                    if self
+                        .find_weakly_admissible_set(&reduct, &[reduct_attacker], false)
+                        .is_some()
+                    {
+                        return Some((arg, attacker));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    // Returns an argument that is not in the weakly admissible set but is weakly defended by it, if any.
+    //
+    // A set weakly defending a strict superset also weakly defends the set made of itself and any of the additional arguments,
+    // so checking the arguments one by one is enough.
+    fn find_weakly_defended_argument<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        set: &[&'b Argument<T>],
+    ) -> Option<&'b Argument<T>> {
+        let reduct = af.reduct(set);
+        let mut in_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        set.iter().for_each(|a| in_set[a.id()] = true);
+        let mut attacked_by_set = vec![false; in_set.len()];
+        set.iter().for_each(|a| {
+            af.iter_attacks_from(a)
+                .for_each(|att| attacked_by_set[att.attacked().id()] = true)
+        });
+        let is_admissible = set.iter().all(|a| {
+            af.iter_attacks_to(a)
+                .all(|att| attacked_by_set[att.attacker().id()])
+        });
+        let mut weakly_accepted_in_reduct = vec![None; in_set.len()];
+        for arg in af.argument_set().iter() {
+            if in_set[arg.id()] {
+                continue;
+            }
+            let mut all_attackers_attacked = true;
+            let mut is_weakly_defended = true;
+            for att in af.iter_attacks_to(arg) {
+                let attacker = att.attacker();
+                if attacked_by_set[attacker.id()] {
+                    continue;
+                }
+                all_attackers_attacked = false;
+                if in_set[attacker.id()] {
+                    is_weakly_defended = false;
+                    break;
+                }
+                let accepted = *weakly_accepted_in_reduct[attacker.id()].get_or_insert_with(|| {
+                    let reduct_attacker = reduct
+                        .argument_set()
+                        .get_argument(attacker.label())
+                        .unwrap();
+                    self.find_weakly_admissible_set(&reduct, &[reduct_attacker], false)
+                        .is_some()
+                });
+                if accepted {
+                    is_weakly_defended = false;
+                    break;
+                }
+            }
+            if !is_weakly_defended {
+                continue;
+            }
+            if all_attackers_attacked && is_admissible {
+                return Some(arg);
+            }
+            let mut extended = set.to_vec();
+            extended.push(arg);
+            if self
+                .find_weakly_admissible_set(af, &extended, true)
+                .is_some()
+            {
+                return Some(arg);
+            }
+        }
+        None
+    }
+
+    // Looks for a weakly admissible set that strictly includes the provided one.
+    fn find_larger_weakly_admissible_set<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        set: &[&'b Argument<T>],
+    ) -> Option<Vec<&'b Argument<T>>> {
+        let mut in_set = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+        set.iter().for_each(|a| in_set[a.id()] = true);
+        let mut clauses = set
+            .iter()
+            .map(|a| vec![self.constraints_encoder.arg_to_lit(a)])
+            .collect::<Vec<Vec<Literal>>>();
+        clauses.push(
+            af.argument_set()
+                .iter()
+                .filter(|a| !in_set[a.id()])
+                .map(|a| self.constraints_encoder.arg_to_lit(a))
+                .collect(),
+        );
+        let mut larger = None;
+        self.enumerate_conflict_free_sets(af, &clauses, &mut |superset| {
+            if self.find_weakly_undefended_argument(af, superset).is_none() {
+                larger = Some(superset.to_vec());
+            }
+            larger.is_none()
+        });
+        larger
+    }
+
+    // Extends a weakly admissible set into a weakly preferred extension.
+    fn extend_to_maximal<'b>(
+        &self,
+        af: &'b AAFramework<T>,
+        set: Vec<&'b Argument<T>>,
+    ) -> Vec<&'b Argument<T>> {
+        let mut current = set;
+        while let Some(larger) = self.find_larger_weakly_admissible_set(af, &current) {
+            current = larger;
+        }
+        current
+    }
+}
+
+fn is_strict_subset<T>(set: &[&Argument<T>], other: &[&Argument<T>]) -> bool
+where
+    T: LabelType,
+{
+    set.len() < other.len() && set.iter().all(|a| other.contains(a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn labels<'a>(args: &[&'a Argument<String>]) -> Vec<&'a str> {
+        let mut labels = args
+            .iter()
+            .map(|a| a.label().as_str())
+            .collect::<Vec<&str>>();
+        labels.sort_unstable();
+        labels
+    }
+
+    fn enumerate_labels(solver: &mut dyn ExtensionEnumerator<String>) -> Vec<Vec<String>> {
+        let mut extensions = Vec::new();
+        solver.enumerate_extensions(&mut |ext| {
+            extensions.push(labels(ext).iter().map(|l| l.to_string()).collect());
+            true
+        });
+        extensions.sort_unstable();
+        extensions
+    }
+
+    #[test]
+    fn test_self_attacker_does_not_count() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a0).
+        att(a0,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![vec![], vec!["a1".to_string()]],
+            enumerate_labels(&mut WeaklyAdmissibleSemanticsSolver::new(&af))
+        );
+        assert_eq!(
+            vec![vec!["a1".to_string()]],
+            enumerate_labels(&mut WeaklyPreferredSemanticsSolver::new(&af))
+        );
+        assert_eq!(
+            vec![vec!["a1".to_string()]],
+            enumerate_labels(&mut WeaklyCompleteSemanticsSolver::new(&af))
+        );
+        assert_eq!(
+            vec!["a1"],
+            labels(
+                &WeaklyGroundedSemanticsSolver::new(&af)
+                    .compute_one_extension()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_odd_cycle_attacker() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = WeaklyPreferredSemanticsSolver::new(&af);
+        assert_eq!(vec!["a3"], labels(&solver.compute_one_extension().unwrap()));
+        assert!(solver.is_skeptically_accepted(&"a3".to_string()));
+        assert!(!solver.is_credulously_accepted(&"a0".to_string()));
+        let mut solver = WeaklyAdmissibleSemanticsSolver::new(&af);
+        let (status, certificate) =
+            solver.is_credulously_accepted_with_certificate(&"a3".to_string());
+        assert!(status);
+        assert_eq!(vec!["a3"], labels(&certificate.unwrap()));
+        assert!(!solver.is_skeptically_accepted(&"a3".to_string()));
+    }
+
+    #[test]
+    fn test_nonempty_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let mut af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = WeaklyCompleteSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, None),
+            solver.has_nonempty_extension_with_certificate()
+        );
+        af.new_argument("a3".to_string());
+        af.new_attack(&"a2".to_string(), &"a3".to_string()).unwrap();
+        let mut solver = WeaklyCompleteSemanticsSolver::new(&af);
+        let (status, certificate) = solver.has_nonempty_extension_with_certificate();
+        assert!(status);
+        assert_eq!(vec!["a3"], labels(&certificate.unwrap()));
+    }
+
+    #[test]
+    fn test_verify_weakly_undefended_argument() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = WeaklyAdmissibleSemanticsSolver::new(&af);
+        let (status, reason) = solver.verify_extension_with_reason(&[&"a1".to_string()]);
+        assert!(!status);
+        assert_eq!(vec!["a1", "a0"], {
+            reason
+                .unwrap()
+                .involved_arguments()
+                .iter()
+                .map(|a| a.label().as_str())
+                .collect::<Vec<&str>>()
+        });
+        let mut solver = WeaklyPreferredSemanticsSolver::new(&af);
+        assert!(matches!(
+            solver.verify_extension_with_reason(&[]).1,
+            Some(NonExtensionReason::LargerExtension(_))
+        ));
+        let mut solver = WeaklyCompleteSemanticsSolver::new(&af);
+        assert!(matches!(
+            solver.verify_extension_with_reason(&[]).1,
+            Some(NonExtensionReason::MissingDefendedArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_weakly_grounded_smaller_extension() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        att(a0,a1).
+        att(a1,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let mut solver = WeaklyCompleteSemanticsSolver::new(&af);
+        assert_eq!(BigUint::from(3_usize), solver.count_extensions());
+        assert!(solver.verify_extension(&[&"a0".to_string()]));
+        let mut solver = WeaklyGroundedSemanticsSolver::new(&af);
+        assert_eq!(
+            (false, Some(NonExtensionReason::SmallerExtension(vec![]))),
+            solver.verify_extension_with_reason(&[&"a0".to_string()])
+        );
+        let (status, extensions) = solver.has_unique_extension_with_certificate();
+        assert!(status);
+        assert_eq!(vec![Vec::<&Argument<String>>::new()], extensions);
+    }
+}
@@ -1,5 +1,5 @@
 use super::LabelType;
-use crate::aa::{AAFramework, Argument, ArgumentSet};
+use crate::aa::{AAFramework, Argument};
 
 /// An object used to decompose an AF into its connected components.
 ///
@@ -73,8 +73,7 @@ where
         let mut current = vec![arg];
         let mut newly_in_current = vec![arg];
         self.update_next();
-        while !newly_in_current.is_empty() {
-            let arg = newly_in_current.pop().unwrap();
+        while let Some(arg) = newly_in_current.pop() {
             self.init_af
                 .iter_attacks_from(arg)
                 .chain(self.init_af.iter_attacks_to(arg))
@@ -113,28 +112,11 @@ where
     where
         T: LabelType,
     {
-        let mut arg_mapping = vec![None; 1 + self.init_af.max_argument_id().unwrap()];
-        connected_component
-            .iter()
-            .enumerate()
-            .for_each(|(i, a)| arg_mapping[a.id()] = Some(i));
-        let labels = connected_component
-            .iter()
-            .map(|a| a.label().clone())
-            .collect::<Vec<T>>();
-        let arguments = ArgumentSet::new_with_labels(&labels);
-        let mut new_af = AAFramework::new_with_argument_set(arguments);
-        self.init_af.iter_attacks().for_each(|att| {
-            if let Some(new_i) = arg_mapping[att.attacker().id()] {
-                let new_j = arg_mapping[att.attacked().id()].unwrap();
-                new_af.new_attack_by_ids(new_i, new_j).unwrap();
-            }
-        });
-        new_af
+        super::restricted_framework(self.init_af, connected_component)
     }
 
     /// Iterates over the connected components of an AF.
-    pub fn iter_connected_components(af: &AAFramework<T>) -> ConnectedComponentsIterator<T>
+    pub fn iter_connected_components(af: &AAFramework<T>) -> ConnectedComponentsIterator<'_, T>
     where
         T: LabelType,
     {
@@ -187,7 +169,7 @@ mod tests {
         let reader = AspartixReader::default();
         let af = reader.read(&mut instance.as_bytes()).unwrap();
         let arg = af.argument_set().get_argument(&"a1".to_string()).unwrap();
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut buffer0 = Cursor::new(Vec::new());
         writer.write_framework(&af, &mut buffer0).unwrap();
         let mut buffer1 = Cursor::new(Vec::new());
@@ -232,7 +214,7 @@ mod tests {
         let af = reader.read(&mut instance.as_bytes()).unwrap();
         let components = ConnectedComponentsComputer::iter_connected_components(&af)
             .collect::<Vec<AAFramework<String>>>();
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut instance0 = Cursor::new(Vec::new());
         writer
             .write_framework(&components[0], &mut instance0)
@@ -270,7 +252,7 @@ mod tests {
         let components_after = ConnectedComponentsComputer::iter_connected_components(&af)
             .collect::<Vec<AAFramework<String>>>();
         assert_eq!(2, components_after.len());
-        let writer = AspartixWriter::default();
+        let writer = AspartixWriter;
         let mut instance0 = Cursor::new(Vec::new());
         writer
             .write_framework(&components_after[0], &mut instance0)
@@ -105,7 +105,7 @@ where
             continue;
         }
         let opt_arg_propagations = if propagations[arg].is_some() {
-            std::mem::replace(&mut propagations[arg], Some(Vec::new()))
+            propagations[arg].replace(Vec::new())
         } else {
             propagate(af, &n_attacks_to, &[arg]).map(|p| p.0)
         };
@@ -0,0 +1,205 @@
+use super::{ConnectedComponentsComputer, EquivalencyComputer, LabelType};
+use crate::aa::AAFramework;
+
+/// Structural metrics computed on an AF.
+///
+/// The metrics are computed once for all when the object is built.
+///
+/// # Example
+///
+/// ```
+/// # use crustabri::aa::{AAFramework, ArgumentSet};
+/// # use crustabri::utils::FrameworkStatistics;
+/// let arg_labels = vec!["a", "b", "c"];
+/// let args = ArgumentSet::new_with_labels(&arg_labels);
+/// let mut af = AAFramework::new_with_argument_set(args);
+/// af.new_attack(&"a", &"b").unwrap();
+/// af.new_attack(&"b", &"a").unwrap();
+/// af.new_attack(&"c", &"c").unwrap();
+/// let stats = FrameworkStatistics::new(&af);
+/// assert_eq!(1, stats.n_self_attacks());
+/// assert_eq!(&[2, 1], stats.connected_component_sizes());
+/// assert_eq!(&[0, 3], stats.in_degree_distribution());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameworkStatistics {
+    n_arguments: usize,
+    n_attacks: usize,
+    n_self_attacks: usize,
+    n_unattacked_arguments: usize,
+    grounded_extension_size: usize,
+    connected_component_sizes: Vec<usize>,
+    n_strongly_connected_components: usize,
+    in_degree_distribution: Vec<usize>,
+    out_degree_distribution: Vec<usize>,
+    n_equivalency_classes: usize,
+}
+
+impl FrameworkStatistics {
+    /// Computes the metrics of an AF.
+    pub fn new<T>(af: &AAFramework<T>) -> Self
+    where
+        T: LabelType,
+    {
+        let mut connected_component_sizes =
+            ConnectedComponentsComputer::iter_connected_components(af)
+                .map(|cc| cc.n_arguments())
+                .collect::<Vec<usize>>();
+        connected_component_sizes.sort_unstable_by(|a, b| b.cmp(a));
+        let degree_distribution = |degrees: Vec<usize>| {
+            let mut distribution = vec![0; degrees.iter().max().map(|d| d + 1).unwrap_or(0)];
+            degrees.iter().for_each(|d| distribution[*d] += 1);
+            distribution
+        };
+        let in_degrees = af
+            .argument_set()
+            .iter()
+            .map(|a| af.iter_attacks_to(a).count())
+            .collect::<Vec<usize>>();
+        let out_degrees = af
+            .argument_set()
+            .iter()
+            .map(|a| af.iter_attacks_from(a).count())
+            .collect::<Vec<usize>>();
+        Self {
+            n_arguments: af.n_arguments(),
+            n_attacks: af.n_attacks(),
+            n_self_attacks: af
+                .iter_attacks()
+                .filter(|att| att.attacker().id() == att.attacked().id())
+                .count(),
+            n_unattacked_arguments: in_degrees.iter().filter(|d| **d == 0).count(),
+            grounded_extension_size: af.grounded_extension().len(),
+            connected_component_sizes,
+            n_strongly_connected_components: af.strongly_connected_components().len(),
+            in_degree_distribution: degree_distribution(in_degrees),
+            out_degree_distribution: degree_distribution(out_degrees),
+            n_equivalency_classes: EquivalencyComputer::new(af).reduced_af().n_arguments(),
+        }
+    }
+
+    /// Returns the number of arguments.
+    pub fn n_arguments(&self) -> usize {
+        self.n_arguments
+    }
+
+    /// Returns the number of attacks, including self-attacks.
+    pub fn n_attacks(&self) -> usize {
+        self.n_attacks
+    }
+
+    /// Returns the number of self-attacking arguments.
+    pub fn n_self_attacks(&self) -> usize {
+        self.n_self_attacks
+    }
+
+    /// Returns the number of arguments that are not attacked.
+    pub fn n_unattacked_arguments(&self) -> usize {
+        self.n_unattacked_arguments
+    }
+
+    /// Returns the number of arguments in the grounded extension.
+    pub fn grounded_extension_size(&self) -> usize {
+        self.grounded_extension_size
+    }
+
+    /// Returns the sizes of the connected components, in decreasing order.
+    ///
+    /// The number of connected components is the length of the returned slice.
+    pub fn connected_component_sizes(&self) -> &[usize] {
+        &self.connected_component_sizes
+    }
+
+    /// Returns the number of strongly connected components.
+    pub fn n_strongly_connected_components(&self) -> usize {
+        self.n_strongly_connected_components
+    }
+
+    /// Returns the density of the attack relation, that is the number of attacks divided by the square of the number of arguments.
+    ///
+    /// The density of an AF without arguments is `0`.
+    pub fn density(&self) -> f64 {
+        if self.n_arguments == 0 {
+            0.
+        } else {
+            self.n_attacks as f64 / (self.n_arguments * self.n_arguments) as f64
+        }
+    }
+
+    /// Returns the in-degree distribution, that is the number of arguments attacked by a given number of arguments, indexed by this number.
+    pub fn in_degree_distribution(&self) -> &[usize] {
+        &self.in_degree_distribution
+    }
+
+    /// Returns the out-degree distribution, that is the number of arguments attacking a given number of arguments, indexed by this number.
+    pub fn out_degree_distribution(&self) -> &[usize] {
+        &self.out_degree_distribution
+    }
+
+    /// Returns the number of equivalency classes computed by an [EquivalencyComputer].
+    pub fn n_equivalency_classes(&self) -> usize {
+        self.n_equivalency_classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    #[test]
+    fn test_statistics() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a1).
+        att(a3,a3).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let stats = FrameworkStatistics::new(&af);
+        assert_eq!(5, stats.n_arguments());
+        assert_eq!(4, stats.n_attacks());
+        assert_eq!(1, stats.n_self_attacks());
+        assert_eq!(2, stats.n_unattacked_arguments());
+        assert_eq!(3, stats.grounded_extension_size());
+        assert_eq!(&[3, 1, 1], stats.connected_component_sizes());
+        assert_eq!(4, stats.n_strongly_connected_components());
+        assert_eq!(3, stats.n_equivalency_classes());
+        assert_eq!(0.16, stats.density());
+        assert_eq!(&[2, 2, 1], stats.in_degree_distribution());
+        assert_eq!(&[1, 4], stats.out_degree_distribution());
+    }
+
+    #[test]
+    fn test_equivalent_arguments() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a1).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let stats = FrameworkStatistics::new(&af);
+        assert_eq!(0, stats.grounded_extension_size());
+        assert_eq!(2, stats.n_equivalency_classes());
+    }
+
+    #[test]
+    fn test_empty_framework() {
+        let stats = FrameworkStatistics::new(&AAFramework::<usize>::default());
+        assert_eq!(0, stats.n_arguments());
+        assert_eq!(0., stats.density());
+        assert!(stats.connected_component_sizes().is_empty());
+        assert!(stats.in_degree_distribution().is_empty());
+    }
+}
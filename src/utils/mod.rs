@@ -11,5 +11,13 @@ pub use label::Label;
 pub use label::LabelSet;
 pub use label::LabelType;
 
+mod framework_statistics;
+pub use framework_statistics::FrameworkStatistics;
+
 mod grounded_extension_computer;
 pub(crate) use grounded_extension_computer::grounded_extension;
+
+mod strongly_connected_components;
+pub(crate) use strongly_connected_components::ancestors;
+pub(crate) use strongly_connected_components::restricted_framework;
+pub(crate) use strongly_connected_components::strongly_connected_components;
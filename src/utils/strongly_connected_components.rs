@@ -0,0 +1,221 @@
+use super::LabelType;
+use crate::aa::{AAFramework, Argument, ArgumentSet};
+
+/// Computes the strongly connected components of an AF.
+///
+/// The components are given in a topological order wrt. the attack relation:
+/// the arguments of a component may only be attacked by arguments of the same component or of the previous ones.
+pub(crate) fn strongly_connected_components<T>(af: &AAFramework<T>) -> Vec<Vec<&Argument<T>>>
+where
+    T: LabelType,
+{
+    let n = match af.max_argument_id() {
+        Some(id) => id + 1,
+        None => return vec![],
+    };
+    let mut indices = vec![usize::MAX; n];
+    let mut lowlinks = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut call_stack = Vec::new();
+    let mut sccs = Vec::new();
+    let mut next_index = 0;
+    for root in af.argument_set().iter() {
+        if indices[root.id()] != usize::MAX {
+            continue;
+        }
+        let mut next_visit = Some(root);
+        loop {
+            if let Some(arg) = next_visit.take() {
+                indices[arg.id()] = next_index;
+                lowlinks[arg.id()] = next_index;
+                next_index += 1;
+                on_stack[arg.id()] = true;
+                stack.push(arg);
+                call_stack.push((arg, attacked_args(af, arg), 0));
+            }
+            let (arg, successors, position) = match call_stack.last_mut() {
+                Some(frame) => frame,
+                None => break,
+            };
+            let arg = *arg;
+            if *position < successors.len() {
+                let succ = successors[*position];
+                *position += 1;
+                if indices[succ.id()] == usize::MAX {
+                    next_visit = Some(succ);
+                } else if on_stack[succ.id()] {
+                    lowlinks[arg.id()] = usize::min(lowlinks[arg.id()], indices[succ.id()]);
+                }
+                continue;
+            }
+            call_stack.pop();
+            if let Some((parent, _, _)) = call_stack.last() {
+                lowlinks[parent.id()] = usize::min(lowlinks[parent.id()], lowlinks[arg.id()]);
+            }
+            if lowlinks[arg.id()] == indices[arg.id()] {
+                let mut scc = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member.id()] = false;
+                    scc.push(member);
+                    if member.id() == arg.id() {
+                        break;
+                    }
+                }
+                sccs.push(scc);
+            }
+        }
+    }
+    sccs.reverse();
+    sccs
+}
+
+fn attacked_args<'a, T>(af: &'a AAFramework<T>, arg: &Argument<T>) -> Vec<&'a Argument<T>>
+where
+    T: LabelType,
+{
+    af.iter_attacks_from(arg)
+        .map(|att| att.attacked())
+        .collect()
+}
+
+/// Builds the AF made of the provided arguments and the attacks between them.
+///
+/// Be careful that arguments in the new AF shares the labels of the initial AF but identifiers differ.
+pub(crate) fn restricted_framework<T>(af: &AAFramework<T>, args: &[&Argument<T>]) -> AAFramework<T>
+where
+    T: LabelType,
+{
+    let mut arg_mapping = vec![None; 1 + af.max_argument_id().unwrap_or_default()];
+    args.iter()
+        .enumerate()
+        .for_each(|(i, a)| arg_mapping[a.id()] = Some(i));
+    let labels = args.iter().map(|a| a.label().clone()).collect::<Vec<T>>();
+    let mut new_af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+    af.iter_attacks().for_each(|att| {
+        if let (Some(new_i), Some(new_j)) = (
+            arg_mapping[att.attacker().id()],
+            arg_mapping[att.attacked().id()],
+        ) {
+            new_af.new_attack_by_ids(new_i, new_j).unwrap();
+        }
+    });
+    new_af
+}
+
+/// Computes the arguments that are ancestors of the provided ones wrt. the attack relation, including themselves.
+///
+/// The result is a vector indexed by argument identifiers.
+pub(crate) fn ancestors<T>(af: &AAFramework<T>, args: &[&Argument<T>]) -> Vec<bool>
+where
+    T: LabelType,
+{
+    let mut in_ancestors = vec![false; 1 + af.max_argument_id().unwrap_or_default()];
+    let mut to_visit = args.to_vec();
+    to_visit.iter().for_each(|a| in_ancestors[a.id()] = true);
+    while let Some(arg) = to_visit.pop() {
+        af.iter_attacks_to(arg).for_each(|att| {
+            let attacker = att.attacker();
+            if !in_ancestors[attacker.id()] {
+                in_ancestors[attacker.id()] = true;
+                to_visit.push(attacker);
+            }
+        });
+    }
+    in_ancestors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{AspartixReader, InstanceReader};
+
+    fn scc_labels<'a>(sccs: &[Vec<&'a Argument<String>>]) -> Vec<Vec<&'a str>> {
+        sccs.iter()
+            .map(|scc| {
+                let mut labels = scc
+                    .iter()
+                    .map(|a| a.label().as_str())
+                    .collect::<Vec<&str>>();
+                labels.sort_unstable();
+                labels
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sccs_in_topological_order() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        arg(a3).
+        arg(a4).
+        arg(a5).
+        att(a3,a4).
+        att(a4,a3).
+        att(a4,a0).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        att(a2,a5).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        assert_eq!(
+            vec![vec!["a3", "a4"], vec!["a0", "a1", "a2"], vec!["a5"]],
+            scc_labels(&strongly_connected_components(&af))
+        );
+    }
+
+    #[test]
+    fn test_sccs_after_arg_removal() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a0).
+        att(a1,a2).
+        att(a2,a2).
+        "#;
+        let reader = AspartixReader::default();
+        let mut af = reader.read(&mut instance.as_bytes()).unwrap();
+        af.remove_argument(&"a0".to_string()).unwrap();
+        assert_eq!(
+            vec![vec!["a1"], vec!["a2"]],
+            scc_labels(&strongly_connected_components(&af))
+        );
+    }
+
+    #[test]
+    fn test_sccs_empty_af() {
+        let af = AAFramework::<usize>::default();
+        assert!(strongly_connected_components(&af).is_empty());
+    }
+
+    #[test]
+    fn test_restricted_framework() {
+        let instance = r#"
+        arg(a0).
+        arg(a1).
+        arg(a2).
+        att(a0,a1).
+        att(a1,a2).
+        att(a2,a0).
+        "#;
+        let reader = AspartixReader::default();
+        let af = reader.read(&mut instance.as_bytes()).unwrap();
+        let args = vec![
+            af.argument_set().get_argument(&"a2".to_string()).unwrap(),
+            af.argument_set().get_argument(&"a0".to_string()).unwrap(),
+        ];
+        let restricted = restricted_framework(&af, &args);
+        assert_eq!(2, restricted.n_arguments());
+        assert_eq!(1, restricted.n_attacks());
+        let att = restricted.iter_attacks().next().unwrap();
+        assert_eq!("a2", att.attacker().label());
+        assert_eq!("a0", att.attacked().label());
+    }
+}
@@ -0,0 +1,50 @@
+use assert_cmd::Command;
+use assert_fs::{prelude::FileWriteStr, NamedTempFile};
+
+// a (1) and b (2) attack each other through their contraries na (4) and nb (5), and q (3) is derived from b
+const INSTANCE: &str = "p aba 5\na 1\na 2\nc 1 4\nc 2 5\nr 4 2\nr 5 1\nr 3 2\n";
+
+fn solve(args: &[&str]) -> String {
+    let file = NamedTempFile::new("instance.aba").unwrap();
+    file.write_str(INSTANCE).unwrap();
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("solve")
+        .args(["-f", file.path().to_str().unwrap(), "-r", "iccma23_aba"])
+        .args(args)
+        .args(["--logging-level", "off"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_aba_acceptance() {
+    assert_eq!("YES\nw 2\n", solve(&["-p", "DC-ST", "-a", "3", "-c"]));
+    assert_eq!("NO\n", solve(&["-p", "DS-PR", "-a", "3"]));
+    assert_eq!(
+        "NO\n",
+        solve(&["-p", "DC-PR", "-a", "1", "-a", "3", "--conjunctive"])
+    );
+    assert_eq!("w\n", solve(&["-p", "SE-CO"]));
+}
+
+#[test]
+fn test_aba_unsupported_problem() {
+    let file = NamedTempFile::new("instance.aba").unwrap();
+    file.write_str(INSTANCE).unwrap();
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("solve")
+        .args(["-f", file.path().to_str().unwrap(), "-r", "iccma23_aba"])
+        .args(["-p", "SE-GR", "--logging-level", "off"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_aba_check() {
+    let file = NamedTempFile::new("instance.aba").unwrap();
+    file.write_str(INSTANCE).unwrap();
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("check")
+        .args(["-f", file.path().to_str().unwrap(), "-r", "iccma23_aba"])
+        .args(["--logging-level", "off"]);
+    cmd.assert().success();
+}
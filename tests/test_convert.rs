@@ -0,0 +1,145 @@
+use assert_cmd::Command;
+use assert_fs::{
+    prelude::{FileWriteStr, PathChild},
+    NamedTempFile, TempDir,
+};
+use crustabri::{
+    aa::AAFramework,
+    io::{AspartixReader, InstanceReader},
+};
+use predicates::prelude::predicate;
+
+fn convert(instance: &str, args: &[&str]) -> String {
+    let file = NamedTempFile::new("instance").unwrap();
+    file.write_str(instance).unwrap();
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("convert")
+        .args(["-f", file.path().to_str().unwrap()])
+        .args(args)
+        .args(["--logging-level", "off"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap()
+}
+
+fn convert_to_apx_and_read_back(instance: &str, reader: &str) -> (AAFramework<String>, String) {
+    let dir = TempDir::new().unwrap();
+    let output = dir.child("instance.apx");
+    let output_path = output.path().to_str().unwrap();
+    convert(instance, &["-r", reader, "-w", "apx", "-o", output_path]);
+    let af = AspartixReader::default()
+        .read(&mut std::fs::read_to_string(output_path).unwrap().as_bytes())
+        .unwrap();
+    let mapping = std::fs::read_to_string(format!("{}.map", output_path)).unwrap();
+    (af, mapping)
+}
+
+fn attack_labels(af: &AAFramework<String>) -> Vec<(String, String)> {
+    af.iter_attacks()
+        .map(|att| {
+            (
+                att.attacker().label().clone(),
+                att.attacked().label().clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_convert_iccma23_to_apx() {
+    let (af, mapping) = convert_to_apx_and_read_back("p af 3\n1 2\n3 3\n", "iccma23");
+    assert_eq!(3, af.n_arguments());
+    assert_eq!(
+        vec![
+            ("a1".to_string(), "a2".to_string()),
+            ("a3".to_string(), "a3".to_string())
+        ],
+        attack_labels(&af)
+    );
+    assert_eq!("a1 1\na2 2\na3 3\n", mapping);
+}
+
+#[test]
+fn test_convert_numeric_tgf_to_apx() {
+    let (af, mapping) = convert_to_apx_and_read_back("7\n4\n#\n4 7\n", "tgf");
+    assert_eq!(
+        vec![("a2".to_string(), "a1".to_string())],
+        attack_labels(&af)
+    );
+    assert_eq!("a1 7\na2 4\n", mapping);
+}
+
+#[test]
+fn test_convert_apx_to_iccma23() {
+    let mapping = NamedTempFile::new("labels.txt").unwrap();
+    let output = convert(
+        "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(c,c).\n",
+        &[
+            "-r",
+            "apx",
+            "-w",
+            "iccma23",
+            "--mapping",
+            mapping.path().to_str().unwrap(),
+        ],
+    );
+    assert_eq!("p af 3\n1 2\n3 3\n", output);
+    assert_eq!(
+        "1 a\n2 b\n3 c\n",
+        std::fs::read_to_string(mapping.path()).unwrap()
+    );
+}
+
+#[test]
+fn test_convert_to_stdout_requires_mapping() {
+    for (instance, args) in [
+        ("arg(a).\n", ["-r", "apx", "-w", "iccma23"]),
+        ("p af 1\n", ["-r", "iccma23", "-w", "apx"]),
+    ] {
+        let file = NamedTempFile::new("instance").unwrap();
+        file.write_str(instance).unwrap();
+        let mut cmd = Command::cargo_bin("crustabri").unwrap();
+        cmd.arg("convert")
+            .args(["-f", file.path().to_str().unwrap()])
+            .args(args);
+        cmd.assert()
+            .failure()
+            .stdout(predicate::str::contains("a mapping file must be given"));
+    }
+}
+
+#[test]
+fn test_convert_apx_to_iccma23_writes_mapping() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.child("instance.af");
+    let output_path = output.path().to_str().unwrap();
+    let mapping = dir.child("labels.txt");
+    let mapping_path = mapping.path().to_str().unwrap();
+    for (args, expected_mapping_path) in [
+        (vec!["-o", output_path], format!("{}.map", output_path)),
+        (
+            vec!["-o", output_path, "--mapping", mapping_path],
+            mapping_path.to_string(),
+        ),
+    ] {
+        let mut all_args = vec!["-r", "apx", "-w", "iccma23"];
+        all_args.extend(args);
+        convert("arg(a).\narg(b).\natt(b,a).\n", &all_args);
+        assert_eq!(
+            "p af 2\n2 1\n",
+            std::fs::read_to_string(output_path).unwrap()
+        );
+        assert_eq!(
+            "1 a\n2 b\n",
+            std::fs::read_to_string(expected_mapping_path).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_convert_tgf_round_trip() {
+    let instance = "a\nb\n#\na b\nb b\n";
+    let output = convert(instance, &["-r", "tgf", "-w", "apx"]);
+    assert_eq!("arg(a).\narg(b).\natt(a,b).\natt(b,b).\n", output);
+    let output = convert(&output, &["-r", "apx", "-w", "tgf"]);
+    assert_eq!(instance, output);
+}
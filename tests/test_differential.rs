@@ -0,0 +1,258 @@
+use crustabri::{
+    aa::{AAFramework, ArgumentSet, Semantics},
+    encodings::{
+        aux_var_constraints_encoder, exp_constraints_encoder, ConstraintsEncoder,
+        HybridCompleteConstraintsEncoder,
+    },
+    io::AspartixWriter,
+    sat,
+    solvers::{
+        reference::ReferenceSolver, AdmissibleSemanticsSolver, Cf2SemanticsSolver,
+        CompleteSemanticsSolver, ConflictFreeSemanticsSolver, CredulousAcceptanceComputer,
+        EagerSemanticsSolver, ExtensionEnumerator, GroundedSemanticsSolver, IdealSemanticsSolver,
+        NaiveSemanticsSolver, PreferredSemanticsSolver, ResolutionBasedGroundedSemanticsSolver,
+        SemiStableSemanticsSolver, SingleExtensionComputer, SkepticalAcceptanceComputer,
+        StableSemanticsSolver, Stage2SemanticsSolver, StageSemanticsSolver,
+        StronglyAdmissibleSemanticsSolver, WeaklyAdmissibleSemanticsSolver,
+        WeaklyCompleteSemanticsSolver, WeaklyGroundedSemanticsSolver,
+        WeaklyPreferredSemanticsSolver,
+    },
+};
+
+type Encoder = Box<dyn ConstraintsEncoder<usize>>;
+
+type NamedEncoderFn = (&'static str, Option<fn() -> Encoder>);
+
+// A linear congruential generator, so that the generated frameworks are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % bound
+    }
+}
+
+fn random_af(rng: &mut Rng, max_n_args: usize) -> AAFramework<usize> {
+    let n_args = 1 + rng.next(max_n_args);
+    let labels = (0..n_args).collect::<Vec<usize>>();
+    let mut af = AAFramework::new_with_argument_set(ArgumentSet::new_with_labels(&labels));
+    let density = 10 + rng.next(30);
+    for i in 0..n_args {
+        for j in 0..n_args {
+            if rng.next(100) < density {
+                af.new_attack(&i, &j).unwrap();
+            }
+        }
+    }
+    af
+}
+
+fn encoders_for(semantics: Semantics) -> Vec<NamedEncoderFn> {
+    match semantics {
+        Semantics::GR | Semantics::ST | Semantics::RGR => vec![("none", None)],
+        Semantics::AD | Semantics::SA => vec![(
+            "aux_var",
+            Some(|| Box::new(aux_var_constraints_encoder::new_for_admissibility())),
+        )],
+        Semantics::CF
+        | Semantics::NA
+        | Semantics::STG
+        | Semantics::CF2
+        | Semantics::STG2
+        | Semantics::WAD
+        | Semantics::WPR
+        | Semantics::WCO
+        | Semantics::WGR => vec![
+            (
+                "aux_var",
+                Some(|| Box::new(aux_var_constraints_encoder::new_for_conflict_freeness())),
+            ),
+            (
+                "exp",
+                Some(|| Box::new(exp_constraints_encoder::new_for_conflict_freeness())),
+            ),
+        ],
+        Semantics::CO | Semantics::PR | Semantics::SST | Semantics::ID | Semantics::EG => {
+            let mut encoders: Vec<NamedEncoderFn> = vec![
+                (
+                    "aux_var",
+                    Some(|| Box::new(aux_var_constraints_encoder::new_for_complete_semantics())),
+                ),
+                (
+                    "exp",
+                    Some(|| Box::new(exp_constraints_encoder::new_for_complete_semantics())),
+                ),
+                (
+                    "hybrid",
+                    Some(|| Box::<HybridCompleteConstraintsEncoder>::default()),
+                ),
+            ];
+            if semantics == Semantics::PR {
+                encoders.push((
+                    "aux_var (admissibility)",
+                    Some(|| Box::new(aux_var_constraints_encoder::new_for_admissibility())),
+                ));
+            }
+            encoders
+        }
+    }
+}
+
+macro_rules! encoded_solver {
+    ($solver:ident, $af:expr, $encoder:expr) => {
+        Box::new(
+            $solver::new_with_sat_solver_factory_and_constraints_encoder(
+                $af,
+                Box::new(|| sat::default_solver()),
+                $encoder.unwrap(),
+            ),
+        )
+    };
+}
+
+// Builds the solver used by the application for a semantics, given the arms specific to the query (CO and PR).
+macro_rules! solver_for_query {
+    ($semantics:expr, $af:expr, $encoder:expr, { $($query_specific_arms:tt)* }) => {
+        match $semantics {
+            $($query_specific_arms)*
+            Semantics::GR => Box::new(GroundedSemanticsSolver::new($af)),
+            Semantics::ST => Box::new(StableSemanticsSolver::new($af)),
+            Semantics::SST => encoded_solver!(SemiStableSemanticsSolver, $af, $encoder),
+            Semantics::STG => encoded_solver!(StageSemanticsSolver, $af, $encoder),
+            Semantics::ID => encoded_solver!(IdealSemanticsSolver, $af, $encoder),
+            Semantics::EG => encoded_solver!(EagerSemanticsSolver, $af, $encoder),
+            Semantics::AD => encoded_solver!(AdmissibleSemanticsSolver, $af, $encoder),
+            Semantics::CF => encoded_solver!(ConflictFreeSemanticsSolver, $af, $encoder),
+            Semantics::NA => encoded_solver!(NaiveSemanticsSolver, $af, $encoder),
+            Semantics::SA => encoded_solver!(StronglyAdmissibleSemanticsSolver, $af, $encoder),
+            Semantics::CF2 => encoded_solver!(Cf2SemanticsSolver, $af, $encoder),
+            Semantics::STG2 => encoded_solver!(Stage2SemanticsSolver, $af, $encoder),
+            Semantics::WAD => encoded_solver!(WeaklyAdmissibleSemanticsSolver, $af, $encoder),
+            Semantics::WPR => encoded_solver!(WeaklyPreferredSemanticsSolver, $af, $encoder),
+            Semantics::WCO => encoded_solver!(WeaklyCompleteSemanticsSolver, $af, $encoder),
+            Semantics::WGR => encoded_solver!(WeaklyGroundedSemanticsSolver, $af, $encoder),
+            Semantics::RGR => Box::new(ResolutionBasedGroundedSemanticsSolver::new($af)),
+        }
+    };
+}
+
+fn new_se_solver(
+    semantics: Semantics,
+    af: &AAFramework<usize>,
+    encoder: Option<Encoder>,
+) -> Box<dyn SingleExtensionComputer<usize> + '_> {
+    solver_for_query!(semantics, af, encoder, {
+        Semantics::CO => Box::new(GroundedSemanticsSolver::new(af)),
+        Semantics::PR => encoded_solver!(PreferredSemanticsSolver, af, encoder),
+    })
+}
+
+fn new_dc_solver(
+    semantics: Semantics,
+    af: &AAFramework<usize>,
+    encoder: Option<Encoder>,
+) -> Box<dyn CredulousAcceptanceComputer<usize> + '_> {
+    solver_for_query!(semantics, af, encoder, {
+        Semantics::CO | Semantics::PR => encoded_solver!(CompleteSemanticsSolver, af, encoder),
+    })
+}
+
+fn new_ds_solver(
+    semantics: Semantics,
+    af: &AAFramework<usize>,
+    encoder: Option<Encoder>,
+) -> Box<dyn SkepticalAcceptanceComputer<usize> + '_> {
+    solver_for_query!(semantics, af, encoder, {
+        Semantics::CO => Box::new(GroundedSemanticsSolver::new(af)),
+        Semantics::PR => encoded_solver!(PreferredSemanticsSolver, af, encoder),
+    })
+}
+
+fn sorted_ids(ext: &[&crustabri::aa::Argument<usize>]) -> Vec<usize> {
+    let mut ids = ext.iter().map(|a| *a.label()).collect::<Vec<usize>>();
+    ids.sort_unstable();
+    ids
+}
+
+fn check_against_reference(af: &AAFramework<usize>, semantics: Semantics) {
+    let mut apx = vec![];
+    AspartixWriter.write_framework(af, &mut apx).unwrap();
+    let apx = String::from_utf8(apx).unwrap();
+    let mut reference = ReferenceSolver::new(af, semantics);
+    let mut extensions = vec![];
+    reference.enumerate_extensions(&mut |ext| {
+        extensions.push(sorted_ids(ext));
+        true
+    });
+    for (encoder_name, encoder_fn) in encoders_for(semantics) {
+        let context = format!(
+            "semantics {}, encoder {}, AF:\n{}",
+            semantics.as_ref(),
+            encoder_name,
+            apx
+        );
+        let new_encoder = || encoder_fn.map(|f| f());
+        match new_se_solver(semantics, af, new_encoder()).compute_one_extension() {
+            Some(ext) => assert!(
+                extensions.contains(&sorted_ids(&ext)),
+                "SE: {:?} is not an extension; {}",
+                sorted_ids(&ext),
+                context
+            ),
+            None => assert!(extensions.is_empty(), "SE: no extension found; {}", context),
+        }
+        for arg in af.argument_set().iter() {
+            assert_eq!(
+                reference.is_credulously_accepted(arg.label()),
+                new_dc_solver(semantics, af, new_encoder()).is_credulously_accepted(arg.label()),
+                "DC for argument {}; {}",
+                arg.label(),
+                context
+            );
+            assert_eq!(
+                reference.is_skeptically_accepted(arg.label()),
+                new_ds_solver(semantics, af, new_encoder()).is_skeptically_accepted(arg.label()),
+                "DS for argument {}; {}",
+                arg.label(),
+                context
+            );
+        }
+    }
+}
+
+macro_rules! differential_test {
+    ($fn_name:ident, $semantics:expr, $n_afs:expr, $max_n_args:expr) => {
+        #[test]
+        fn $fn_name() {
+            let mut rng = Rng(0x5eed + $semantics as u64);
+            for _ in 0..$n_afs {
+                let af = random_af(&mut rng, $max_n_args);
+                check_against_reference(&af, $semantics);
+            }
+        }
+    };
+}
+
+differential_test!(test_differential_gr, Semantics::GR, 100, 10);
+differential_test!(test_differential_co, Semantics::CO, 100, 10);
+differential_test!(test_differential_pr, Semantics::PR, 100, 10);
+differential_test!(test_differential_st, Semantics::ST, 100, 10);
+differential_test!(test_differential_sst, Semantics::SST, 100, 10);
+differential_test!(test_differential_stg, Semantics::STG, 100, 10);
+differential_test!(test_differential_id, Semantics::ID, 100, 10);
+differential_test!(test_differential_eg, Semantics::EG, 100, 10);
+differential_test!(test_differential_ad, Semantics::AD, 100, 10);
+differential_test!(test_differential_cf, Semantics::CF, 100, 10);
+differential_test!(test_differential_na, Semantics::NA, 100, 10);
+differential_test!(test_differential_sa, Semantics::SA, 100, 10);
+differential_test!(test_differential_cf2, Semantics::CF2, 100, 10);
+differential_test!(test_differential_stg2, Semantics::STG2, 100, 10);
+differential_test!(test_differential_wad, Semantics::WAD, 30, 6);
+differential_test!(test_differential_wpr, Semantics::WPR, 30, 6);
+differential_test!(test_differential_wco, Semantics::WCO, 30, 6);
+differential_test!(test_differential_wgr, Semantics::WGR, 30, 6);
+differential_test!(test_differential_rgr, Semantics::RGR, 100, 10);
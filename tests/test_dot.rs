@@ -0,0 +1,78 @@
+use assert_cmd::Command;
+use assert_fs::{
+    prelude::{FileWriteStr, PathChild},
+    TempDir,
+};
+
+const INSTANCE: &str = "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(c,c).\n";
+
+fn run_with_dot(args: &[&str]) -> String {
+    let dir = TempDir::new().unwrap();
+    let instance = dir.child("instance.apx");
+    instance.write_str(INSTANCE).unwrap();
+    let dot = dir.child("instance.dot");
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.args(args)
+        .args(["-f", instance.path().to_str().unwrap(), "-r", "apx"])
+        .args(["--dot", dot.path().to_str().unwrap()])
+        .args(["--logging-level", "off"]);
+    cmd.assert().success();
+    std::fs::read_to_string(dot.path()).unwrap()
+}
+
+#[test]
+fn test_solve_dot() {
+    let dot = run_with_dot(&["solve", "-p", "SE-GR"]);
+    assert_eq!(
+        [
+            "digraph af {",
+            r#"    "a" [style=filled, fillcolor=green];"#,
+            r#"    "b" [style=filled, fillcolor=red];"#,
+            r#"    "c" [style=filled, fillcolor=grey];"#,
+            r#"    "a" -> "b";"#,
+            r#"    "c" -> "c";"#,
+            "}",
+            "",
+        ]
+        .join("\n"),
+        dot
+    );
+}
+
+#[test]
+fn test_solve_labelling_dot() {
+    let dot = run_with_dot(&["solve", "-p", "SE-PR", "--labelling"]);
+    assert_eq!(
+        [
+            "digraph af {",
+            r#"    "a" [style=filled, fillcolor=green];"#,
+            r#"    "b" [style=filled, fillcolor=red];"#,
+            r#"    "c" [style=filled, fillcolor=grey];"#,
+            r#"    "a" -> "b";"#,
+            r#"    "c" -> "c";"#,
+            "}",
+            "",
+        ]
+        .join("\n"),
+        dot
+    );
+}
+
+#[test]
+fn test_convert_dot() {
+    let dot = run_with_dot(&["convert", "-w", "tgf"]);
+    assert_eq!(
+        [
+            "digraph af {",
+            r#"    "a";"#,
+            r#"    "b";"#,
+            r#"    "c";"#,
+            r#"    "a" -> "b";"#,
+            r#"    "c" -> "c";"#,
+            "}",
+            "",
+        ]
+        .join("\n"),
+        dot
+    );
+}
@@ -0,0 +1,100 @@
+use assert_cmd::Command;
+use assert_fs::{
+    prelude::{FileWriteStr, PathChild},
+    TempDir,
+};
+use predicates::prelude::predicate;
+
+fn generate(args: &[&str]) -> String {
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("generate")
+        .args(args)
+        .args(["--logging-level", "off"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap()
+}
+
+const GRID_ARGS: [&str; 8] = [
+    "-m",
+    "grid",
+    "--rows",
+    "1",
+    "--columns",
+    "2",
+    "--probability",
+    "1",
+];
+
+#[test]
+fn test_generate_grid() {
+    let output = generate(&GRID_ARGS);
+    assert_eq!("p af 2\n1 2\n2 1\n", output);
+}
+
+#[test]
+fn test_generate_grid_apx() {
+    let mut args = GRID_ARGS.to_vec();
+    args.extend(["-w", "apx"]);
+    let output = generate(&args);
+    assert_eq!("arg(a1).\narg(a2).\natt(a1,a2).\natt(a2,a1).\n", output);
+}
+
+#[test]
+fn test_generate_then_solve() {
+    let dir = TempDir::new().unwrap();
+    let model_args = ["-m", "er", "-n", "4", "--probability", "0.5", "-s", "1"];
+    for format in ["iccma23", "apx", "tgf"] {
+        let instance = dir.child(format!("instance.{}", format));
+        let mut args = model_args.to_vec();
+        args.extend(["-w", format]);
+        instance.write_str(&generate(&args)).unwrap();
+        let mut cmd = Command::cargo_bin("crustabri").unwrap();
+        cmd.args(["solve", "-p", "SE-PR", "-r", format])
+            .args(["-f", instance.path().to_str().unwrap()])
+            .args(["--logging-level", "off"]);
+        cmd.assert().success();
+    }
+}
+
+#[test]
+fn test_generate_is_reproducible() {
+    for model_args in [
+        vec!["-m", "er", "-n", "20", "--probability", "0.2"],
+        vec!["-m", "ba", "-n", "20", "--links", "2"],
+        vec![
+            "-m",
+            "ws",
+            "-n",
+            "20",
+            "--neighbors",
+            "4",
+            "--probability",
+            "0.3",
+        ],
+        vec![
+            "-m",
+            "stable",
+            "-n",
+            "20",
+            "--extensions",
+            "3",
+            "--grounded",
+            "2",
+        ],
+    ] {
+        let mut args = model_args.clone();
+        args.extend(["-s", "42"]);
+        let output = generate(&args);
+        assert_eq!(Some("p af 20"), output.lines().next());
+        assert_eq!(output, generate(&args));
+    }
+}
+
+#[test]
+fn test_generate_missing_parameter() {
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.args(["generate", "-m", "ba", "-n", "20"]);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("missing parameter <LINKS>"));
+}
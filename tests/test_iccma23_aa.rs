@@ -405,6 +405,59 @@ fn test_ideal_ds_4() -> Result<(), Box<dyn std::error::Error>> {
     test_answer_for_track("DS-ID", &["YES\n"], Some("4"))
 }
 
+#[test]
+fn test_eager_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("SE-EG", &["w 4\n"], None)
+}
+
+#[test]
+fn test_eager_dc_not_ideal() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance(
+        "p af 3\n1 2\n2 1\n2 3\n3 3\n",
+        "DC-EG",
+        &["YES\nw 2\n"],
+        Some("2"),
+    )
+}
+
+#[test]
+fn test_weakly_preferred_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance("p af 4\n1 2\n2 3\n3 1\n3 4\n", "SE-WPR", &["w 4\n"], None)
+}
+
+#[test]
+fn test_weakly_grounded_ds() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance(
+        "p af 4\n1 2\n2 3\n3 1\n3 4\n",
+        "DS-WGR",
+        &["YES\n"],
+        Some("4"),
+    )
+}
+
+#[test]
+fn test_weakly_admissible_dc() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "DC-WAD",
+        &["YES\nw 1\n", "YES\nw 1 4\n", "YES\nw 4 1\n"],
+        Some("1"),
+    )
+}
+
+#[test]
+fn test_resolution_based_grounded_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "SE-RGR",
+        &["w 1 4\n", "w 4 1\n", "w 2 4\n", "w 4 2\n"],
+        None,
+    )
+}
+
+#[test]
+fn test_resolution_based_grounded_ds() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DS-RGR", &["YES\n"], Some("4"))
+}
+
 #[test]
 fn test_stg_se_no_constraints() -> Result<(), Box<dyn std::error::Error>> {
     test_answer_for_track_and_instance(
@@ -414,3 +467,283 @@ fn test_stg_se_no_constraints() -> Result<(), Box<dyn std::error::Error>> {
         Some("1"),
     )
 }
+
+#[test]
+fn test_admissible_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "SE-AD",
+        &[
+            "w\n", "w 1\n", "w 2\n", "w 1 4\n", "w 4 1\n", "w 2 4\n", "w 4 2\n",
+        ],
+        None,
+    )
+}
+
+#[test]
+fn test_admissible_dc_1() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "DC-AD",
+        &["YES\nw 1\n", "YES\nw 1 4\n", "YES\nw 4 1\n"],
+        Some("1"),
+    )
+}
+
+#[test]
+fn test_admissible_dc_3() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DC-AD", &["NO\n"], Some("3"))
+}
+
+#[test]
+fn test_admissible_ds_4() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DS-AD", &["NO\nw\n"], Some("4"))
+}
+
+#[test]
+fn test_conflict_free_dc_3() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DC-CF", &["YES\nw 3\n"], Some("3"))
+}
+
+#[test]
+fn test_conflict_free_ds_1() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DS-CF", &["NO\nw\n"], Some("1"))
+}
+
+#[test]
+fn test_naive_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "SE-NA",
+        &["w 1 4\n", "w 4 1\n", "w 2 4\n", "w 4 2\n", "w 3\n"],
+        None,
+    )
+}
+
+#[test]
+fn test_naive_dc_1() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DC-NA", &["YES\nw 1 4\n"], Some("1"))
+}
+
+#[test]
+fn test_naive_dc_3() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DC-NA", &["YES\nw 3\n"], Some("3"))
+}
+
+#[test]
+fn test_naive_ds_1() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "DS-NA",
+        &["NO\nw 2 4\n", "NO\nw 4 2\n", "NO\nw 3\n"],
+        Some("1"),
+    )
+}
+
+#[test]
+fn test_naive_ds_4() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DS-NA", &["NO\nw 3\n"], Some("4"))
+}
+
+#[test]
+fn test_cf2_se() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "SE-CF2",
+        &["w 1 4\n", "w 4 1\n", "w 2 4\n", "w 4 2\n"],
+        None,
+    )
+}
+
+#[test]
+fn test_cf2_dc_3() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DC-CF2", &["NO\n"], Some("3"))
+}
+
+#[test]
+fn test_stage2_ds_4() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("DS-STG2", &["YES\n"], Some("4"))
+}
+
+#[test]
+fn test_complete_ee() -> Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new("test_instance.aa")?;
+    file.write_str(INSTANCE)?;
+    let mut cmd = Command::cargo_bin("crustabri_iccma23")?;
+    cmd.arg("-f").arg(file.path()).arg("-p").arg("EE-CO");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let mut extensions = String::from_utf8(output)?
+        .lines()
+        .map(|l| {
+            let mut args = l.split_whitespace().collect::<Vec<&str>>();
+            args.sort_unstable();
+            args.join(" ")
+        })
+        .collect::<Vec<String>>();
+    extensions.sort_unstable();
+    assert_eq!(vec!["1 4 w", "2 4 w", "4 w", "w"], extensions);
+    file.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_stable_ee_no_extension() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance("p af 1\n1 1\n", "EE-ST", &["NO\n"], None)
+}
+
+#[test]
+fn test_complete_ce() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("CE-CO", &["4\n"], None)
+}
+
+#[test]
+fn test_stable_ce_no_extension() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance("p af 1\n1 1\n", "CE-ST", &["0\n"], None)
+}
+
+fn test_verification_for_track(
+    track: &str,
+    extension: &[&str],
+    possible_answers: &[&'static str],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new("test_instance.aa")?;
+    file.write_str(INSTANCE)?;
+    let mut cmd = Command::cargo_bin("crustabri_iccma23")?;
+    cmd.arg("-f")
+        .arg(file.path())
+        .arg("-p")
+        .arg(track)
+        .arg("-e");
+    cmd.args(extension);
+    let mut pred: BoxPredicate<str> = BoxPredicate::new(predicate::never());
+    for a in possible_answers {
+        pred = BoxPredicate::new(pred.or(predicate::eq(*a)));
+    }
+    cmd.assert().success().stdout(pred);
+    file.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_complete_ve_yes() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track("VE-CO", &["1", "4"], &["YES\n"])
+}
+
+#[test]
+fn test_complete_ve_empty_set() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track("VE-CO", &[], &["YES\n"])
+}
+
+#[test]
+fn test_complete_ve_conflict() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track("VE-CO", &["1", "2"], &["NO\nw 1 2\n", "NO\nw 2 1\n"])
+}
+
+#[test]
+fn test_stable_ve_unattacked() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track("VE-ST", &["4"], &["NO\nw 1\n", "NO\nw 2\n"])
+}
+
+#[test]
+fn test_preferred_ve_larger_extension() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track(
+        "VE-PR",
+        &["4"],
+        &["NO\nw 1 4\n", "NO\nw 4 1\n", "NO\nw 2 4\n", "NO\nw 4 2\n"],
+    )
+}
+
+#[test]
+fn test_grounded_ve_distinct_extension() -> Result<(), Box<dyn std::error::Error>> {
+    test_verification_for_track("VE-GR", &["1", "4"], &["NO\nw\n"])
+}
+
+#[test]
+fn test_ve_missing_extension() -> Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new("test_instance.aa")?;
+    file.write_str(INSTANCE)?;
+    let mut cmd = Command::cargo_bin("crustabri_iccma23")?;
+    cmd.arg("-f").arg(file.path()).arg("-p").arg("VE-CO");
+    cmd.assert().failure();
+    file.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_complete_ne() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track(
+        "NE-CO",
+        &[
+            "YES\nw 4\n",
+            "YES\nw 1 4\n",
+            "YES\nw 4 1\n",
+            "YES\nw 2 4\n",
+            "YES\nw 4 2\n",
+        ],
+        None,
+    )
+}
+
+#[test]
+fn test_grounded_ne() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("NE-GR", &["NO\n"], None)
+}
+
+#[test]
+fn test_stable_ne_no_extension() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_instance("p af 1\n1 1\n", "NE-ST", &["NO\n"], None)
+}
+
+#[test]
+fn test_grounded_un() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track("UN-GR", &["YES\nw\n"], None)
+}
+
+#[test]
+fn test_preferred_un() -> Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new("test_instance.aa")?;
+    file.write_str(INSTANCE)?;
+    let mut cmd = Command::cargo_bin("crustabri_iccma23")?;
+    cmd.arg("-f").arg(file.path()).arg("-p").arg("UN-PR");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let output = String::from_utf8(output)?;
+    let mut lines = output.lines();
+    assert_eq!(Some("NO"), lines.next());
+    let mut extensions = lines
+        .map(|l| {
+            let mut args = l.split_whitespace().collect::<Vec<&str>>();
+            args.sort_unstable();
+            args.join(" ")
+        })
+        .collect::<Vec<String>>();
+    extensions.sort_unstable();
+    assert_eq!(vec!["1 4 w", "2 4 w"], extensions);
+    file.close().unwrap();
+    Ok(())
+}
+
+fn test_answer_for_track_and_args(
+    track: &str,
+    possible_answers: &[&'static str],
+    args: &[&str],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new("test_instance.aa")?;
+    file.write_str(INSTANCE)?;
+    let mut cmd = Command::cargo_bin("crustabri_iccma23")?;
+    cmd.arg("-f").arg(file.path()).arg("-p").arg(track);
+    args.iter().for_each(|a| {
+        cmd.arg("-a").arg(a);
+    });
+    let mut pred: BoxPredicate<str> = BoxPredicate::new(predicate::never());
+    for a in possible_answers {
+        pred = BoxPredicate::new(pred.or(predicate::eq(*a)));
+    }
+    cmd.assert().success().stdout(pred);
+    file.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_complete_dc_several_args() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_args("DC-CO", &["YES\nw 1 4\n", "YES\nw 4 1\n"], &["3", "1"])
+}
+
+#[test]
+fn test_preferred_ds_several_args() -> Result<(), Box<dyn std::error::Error>> {
+    test_answer_for_track_and_args("DS-PR", &["YES\n"], &["3", "4"])
+}
@@ -0,0 +1,44 @@
+use assert_cmd::Command;
+use assert_fs::{prelude::FileWriteStr, NamedTempFile};
+
+fn stats(instance: &str, args: &[&str]) -> String {
+    let file = NamedTempFile::new("instance.apx").unwrap();
+    file.write_str(instance).unwrap();
+    let mut cmd = Command::cargo_bin("crustabri").unwrap();
+    cmd.arg("stats")
+        .args(["-f", file.path().to_str().unwrap(), "-r", "apx"])
+        .args(args)
+        .args(["--logging-level", "off"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap()
+}
+
+const INSTANCE: &str = "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(b,a).\natt(c,c).\n";
+
+#[test]
+fn test_stats_text() {
+    let output = stats(INSTANCE, &[]);
+    let expected = [
+        "arguments: 3",
+        "attacks: 3",
+        "self_attacks: 1",
+        "unattacked_arguments: 0",
+        "grounded_extension_size: 0",
+        "connected_components: 2",
+        "strongly_connected_components: 2",
+        "density: 0.3333333333333333",
+        "equivalency_classes: 3",
+        "connected_component_sizes: 2 1",
+        "in_degree_distribution: 0 3",
+        "out_degree_distribution: 0 3",
+    ];
+    assert_eq!(expected.join("\n") + "\n", output);
+}
+
+#[test]
+fn test_stats_json() {
+    let output = stats(INSTANCE, &["--json"]);
+    assert!(output.starts_with("{\n  \"arguments\": 3,\n"));
+    assert!(output.contains("  \"connected_component_sizes\": [2, 1],\n"));
+    assert!(output.ends_with("  \"out_degree_distribution\": [0, 3]\n}\n"));
+}